@@ -0,0 +1,183 @@
+//! Audit log of every request and response (`audit` feature).
+//!
+//! Register an [`AuditSink`] on a client with `set_audit_sink` to receive one [`AuditRecord`]
+//! per request, separate from the `log`/`tracing` output. Signatures are masked before the
+//! record is built. [`FileAuditSink`] writes the records as JSON lines to a rotating file:
+//!
+//! ```no_run
+//! use std::sync::Arc;
+//! use trade_sdk::audit::FileAuditSink;
+//! use trade_sdk::bybit::BybitClient;
+//!
+//! # fn example() -> trade_sdk::Result<()> {
+//! let mut client = BybitClient::new(None, None, false, false, 5000, None)?;
+//! client.set_audit_sink(Arc::new(FileAuditSink::new("audit.jsonl", 64 * 1024 * 1024)?));
+//! # Ok(())
+//! # }
+//! ```
+
+use std::fs::{File, OpenOptions};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+use reqwest::Method;
+use serde_json::{json, Value};
+
+use crate::error::{Error, Result};
+
+/// Query and body fields whose values never reach an audit record.
+const MASKED_FIELDS: &[&str] = &["signature", "sign"];
+
+const MASK: &str = "***";
+
+/// One request and its outcome, passed to [`AuditSink::record`].
+#[derive(Debug)]
+pub struct AuditRecord<'a> {
+    /// Local time the request completed, in ms since the Unix epoch.
+    pub timestamp_ms: i64,
+    /// Exchange name ("bybit", "bingx").
+    pub exchange: &'static str,
+    pub method: &'a Method,
+    /// Endpoint path without base URL or query, e.g. "/v5/order/create".
+    pub endpoint: &'a str,
+    /// Full request URL with the signature masked.
+    pub url: String,
+    /// JSON request body with the signature masked, `None` for GET.
+    pub body: Option<Value>,
+    /// HTTP status, `None` if no response was received (connection error, timeout).
+    pub status: Option<u16>,
+    /// Exchange return code (`retCode`/`code`), `None` if the body was not read.
+    pub ret_code: Option<i64>,
+    /// Response body, `None` if none was parsed.
+    pub response: Option<Value>,
+    /// Error message if the request failed.
+    pub error: Option<String>,
+}
+
+impl AuditRecord<'_> {
+    /// The record as one JSON object, as written by [`FileAuditSink`].
+    pub fn to_json(&self) -> Value {
+        json!({
+            "timestamp": self.timestamp_ms,
+            "exchange": self.exchange,
+            "method": self.method.as_str(),
+            "endpoint": self.endpoint,
+            "url": self.url,
+            "body": self.body,
+            "status": self.status,
+            "ret_code": self.ret_code,
+            "response": self.response,
+            "error": self.error,
+        })
+    }
+}
+
+/// Receiver of audit records.
+///
+/// Called synchronously after every request, so implementations should be quick.
+pub trait AuditSink: Send + Sync {
+    fn record(
+        &self,
+        record: &AuditRecord<'_>,
+    );
+}
+
+/// [`AuditSink`] appending JSON lines to a file.
+///
+/// When a line would grow the file beyond `max_bytes`, the file is renamed to `<path>.1`
+/// (replacing an older backup) and a new one is started. Write errors are logged and dropped.
+pub struct FileAuditSink {
+    path: PathBuf,
+    max_bytes: u64,
+    file: Mutex<File>,
+}
+
+impl FileAuditSink {
+    /// Open (or create) `path` for appending.
+    ///
+    /// Returns `Error::Config` if the file cannot be opened.
+    pub fn new(
+        path: impl Into<PathBuf>,
+        max_bytes: u64,
+    ) -> Result<Self> {
+        let path = path.into();
+        let file = open_append(&path)?;
+        Ok(Self {
+            path,
+            max_bytes,
+            file: Mutex::new(file),
+        })
+    }
+
+    /// Path of the current audit file.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    fn write_line(
+        &self,
+        line: &str,
+    ) -> Result<()> {
+        let mut file = self.file.lock().unwrap_or_else(|e| e.into_inner());
+        let len = file.metadata().map(|m| m.len()).unwrap_or(0);
+        if len > 0 && len + line.len() as u64 > self.max_bytes {
+            let mut backup = self.path.clone().into_os_string();
+            backup.push(".1");
+            std::fs::rename(&self.path, backup)
+                .map_err(|e| Error::Config(format!("Audit log rotation failed: {e}")))?;
+            *file = open_append(&self.path)?;
+        }
+        file.write_all(line.as_bytes())
+            .map_err(|e| Error::Config(format!("Audit log write failed: {e}")))
+    }
+}
+
+impl AuditSink for FileAuditSink {
+    fn record(
+        &self,
+        record: &AuditRecord<'_>,
+    ) {
+        let line = format!("{}\n", record.to_json());
+        if let Err(e) = self.write_line(&line) {
+            log::warn!("Dropping audit record for {}: {}", record.endpoint, e);
+        }
+    }
+}
+
+fn open_append(path: &Path) -> Result<File> {
+    OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)
+        .map_err(|e| Error::Config(format!("Cannot open audit log {}: {e}", path.display())))
+}
+
+/// `url` with the values of masked query parameters replaced by `***`.
+pub(crate) fn mask_url(url: &str) -> String {
+    let Some((base, query)) = url.split_once('?') else {
+        return url.to_string();
+    };
+    let query = query
+        .split('&')
+        .map(|pair| match pair.split_once('=') {
+            Some((key, _)) if MASKED_FIELDS.contains(&key) => format!("{key}={MASK}"),
+            _ => pair.to_string(),
+        })
+        .collect::<Vec<_>>()
+        .join("&");
+    format!("{base}?{query}")
+}
+
+/// `body` with the values of masked top-level fields replaced by `***`.
+pub(crate) fn mask_body(body: &Value) -> Value {
+    let mut body = body.clone();
+    if let Value::Object(fields) = &mut body {
+        for (key, value) in fields.iter_mut() {
+            if MASKED_FIELDS.contains(&key.as_str()) {
+                *value = Value::String(MASK.to_string());
+            }
+        }
+    }
+    body
+}
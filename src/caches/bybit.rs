@@ -1,41 +1,22 @@
-use once_cell::sync::Lazy;
-use std::collections::HashMap;
-use std::sync::{Arc, RwLock};
-use std::time::{Duration, Instant};
+use std::sync::Arc;
 
 use super::super::caches::ClientsCache;
 use crate::bybit::BybitClient;
 use crate::error::Result;
 
 /// Type alias for the unique cache key for Bybit clients.
-/// Format: (api_key, api_secret, demo, testnet)
-pub type BybitCacheKey = (String, String, bool, bool);
-
-/// Type alias for the type stored in the Bybit client cache.
-type BybitCacheValue = (Arc<BybitClient>, Instant);
-
-/// Global Bybit client cache (thread-safe, shared by all).
-static BYBIT_CACHE: Lazy<RwLock<HashMap<BybitCacheKey, BybitCacheValue>>> =
-    Lazy::new(|| RwLock::new(HashMap::new()));
-
-/// Default cache entry lifetime for Bybit client cache.
-static BYBIT_CACHE_LIFETIME: Lazy<RwLock<Duration>> =
-    Lazy::new(|| RwLock::new(Duration::from_secs(600))); // 10 minutes
-
-/// Cache for BybitClient connections, keyed by API credentials and flags.
-pub struct BybitClientsCache;
-
-impl ClientsCache<BybitCacheKey, BybitClient> for BybitClientsCache {
-    /// Returns a reference to the global cache storage.
-    fn cache() -> &'static Lazy<RwLock<HashMap<BybitCacheKey, (Arc<BybitClient>, Instant)>>> {
-        &BYBIT_CACHE
-    }
+/// Format: (api_key, api_secret, testnet, demo, base_url_override)
+///
+/// `testnet` and `demo` both select a different base URL, so each combination gets its own
+/// client; a client built with `with_base_url` is keyed by that URL as well.
+pub type BybitCacheKey = (String, String, bool, bool, Option<String>);
 
-    /// Returns a reference to the cache lifetime duration.
-    fn lifetime() -> &'static Lazy<RwLock<Duration>> {
-        &BYBIT_CACHE_LIFETIME
-    }
-}
+clients_cache!(
+    /// Cache for BybitClient connections, keyed by API credentials and flags.
+    BybitClientsCache,
+    BybitCacheKey,
+    BybitClient
+);
 
 /// Constructs a key for cache lookup or storage.
 ///
@@ -44,19 +25,29 @@ impl ClientsCache<BybitCacheKey, BybitClient> for BybitClientsCache {
 /// * `api_secret` - API secret as Into<String>
 /// * `testnet` - Use Bybit testnet
 /// * `demo` - Use demo mode
+/// * `base_url` - Base URL override, `None` for the Bybit URL of the flags
 #[inline]
 fn make_key(
     api_key: impl Into<String>,
     api_secret: impl Into<String>,
     testnet: bool,
     demo: bool,
-) -> (String, String, bool, bool) {
-    (api_key.into(), api_secret.into(), demo, testnet)
+    base_url: Option<&str>,
+) -> BybitCacheKey {
+    (
+        api_key.into(),
+        api_secret.into(),
+        testnet,
+        demo,
+        base_url.map(|url| url.trim_end_matches('/').to_string()),
+    )
 }
 
 impl BybitClientsCache {
     /// Fetch a BybitClient from the cache, or create and insert one if missing.
     ///
+    /// New clients use the `recv_window` and referral ID of [`crate::bybit::BybitDefaults`].
+    ///
     /// # Arguments
     /// * `api_key` - The Bybit API key (consumed as String)
     /// * `api_secret` - The Bybit API secret (consumed as String)
@@ -72,28 +63,21 @@ impl BybitClientsCache {
         testnet: bool,
         demo: bool,
     ) -> Result<Arc<BybitClient>> {
-        let key = make_key(api_key, api_secret, demo, testnet);
-
-        if let Some(client) = <Self as ClientsCache<BybitCacheKey, BybitClient>>::get(&key) {
-            return Ok(client);
-        }
-
-        let client = Arc::new(BybitClient::new(
-            Some(key.0.clone()),
-            Some(key.1.clone()),
-            testnet,
-            demo,
-            5000,
-            None,
-        )?);
-
-        <Self as ClientsCache<BybitCacheKey, BybitClient>>::add(key, Arc::clone(&client));
-
-        Ok(client)
+        let key = make_key(api_key, api_secret, testnet, demo, None);
+        <Self as ClientsCache<BybitCacheKey, BybitClient>>::try_get_or_create(key.clone(), || {
+            Ok(Arc::new(BybitClient::new_with_defaults(
+                Some(key.0.clone()),
+                Some(key.1.clone()),
+                testnet,
+                demo,
+            )?))
+        })
     }
 
     /// Fetch a BybitClient from the cache by credentials and flags.
     ///
+    /// Clients added with a base URL override are not returned; use [`Self::get_with_base_url`].
+    ///
     /// # Arguments
     /// * `api_key` - API key as &str
     /// * `api_secret` - API secret as &str
@@ -105,12 +89,64 @@ impl BybitClientsCache {
         testnet: bool,
         demo: bool,
     ) -> Option<Arc<BybitClient>> {
-        let key = make_key(api_key, api_secret, demo, testnet);
+        let key = make_key(api_key, api_secret, testnet, demo, None);
+        <Self as ClientsCache<BybitCacheKey, BybitClient>>::get(&key)
+    }
+
+    /// Like [`Self::get_or_create`], for a client built with `with_base_url(base_url)`, e.g. one
+    /// pointed at a gateway or a mock server.
+    ///
+    /// # Arguments
+    /// * `api_key` - The Bybit API key (consumed as String)
+    /// * `api_secret` - The Bybit API secret (consumed as String)
+    /// * `testnet` - Whether to use Bybit testnet API
+    /// * `demo` - Whether to use demo mode for client
+    /// * `base_url` - Base URL override for the client
+    pub fn get_or_create_with_base_url(
+        api_key: impl Into<String>,
+        api_secret: impl Into<String>,
+        testnet: bool,
+        demo: bool,
+        base_url: &str,
+    ) -> Result<Arc<BybitClient>> {
+        let key = make_key(api_key, api_secret, testnet, demo, Some(base_url));
+        <Self as ClientsCache<BybitCacheKey, BybitClient>>::try_get_or_create(key.clone(), || {
+            Ok(Arc::new(
+                BybitClient::new_with_defaults(
+                    Some(key.0.clone()),
+                    Some(key.1.clone()),
+                    testnet,
+                    demo,
+                )?
+                .with_base_url(base_url),
+            ))
+        })
+    }
+
+    /// Fetch a BybitClient built with `with_base_url(base_url)` from the cache.
+    ///
+    /// # Arguments
+    /// * `api_key` - API key as &str
+    /// * `api_secret` - API secret as &str
+    /// * `testnet` - Use Bybit testnet
+    /// * `demo` - Use demo mode
+    /// * `base_url` - Base URL the client was built with
+    pub fn get_with_base_url(
+        api_key: &str,
+        api_secret: &str,
+        testnet: bool,
+        demo: bool,
+        base_url: &str,
+    ) -> Option<Arc<BybitClient>> {
+        let key = make_key(api_key, api_secret, testnet, demo, Some(base_url));
         <Self as ClientsCache<BybitCacheKey, BybitClient>>::get(&key)
     }
 
     /// Add a BybitClient to the cache with the given credentials and flags.
     ///
+    /// A client built with `with_base_url` is keyed by that URL too, so it is only returned by
+    /// [`Self::get_with_base_url`], never in place of a client for the real Bybit URL.
+    ///
     /// # Arguments
     /// * `client` - Arc-wrapped BybitClient to insert
     /// * `api_key` - API key as &str
@@ -124,7 +160,13 @@ impl BybitClientsCache {
         testnet: bool,
         demo: bool,
     ) {
-        let key = make_key(api_key, api_secret, demo, testnet);
+        let key = make_key(
+            api_key,
+            api_secret,
+            testnet,
+            demo,
+            client.base_url_override(),
+        );
         <Self as ClientsCache<BybitCacheKey, BybitClient>>::add(key, client);
     }
 }
@@ -1,23 +1,69 @@
-/// Note: The client cache structs (such as `BybitClientsCache`, `BingxClientsCache`) implement nearly identical logic
-/// and mainly differ by key types and inner client types. This leads to a significant amount of duplicated code
-/// for managing client caching. In the future, these might be refactored to use generics, macros, or code generation
-/// to reduce duplication and improve maintainability, possibly consolidating these caches under a single generic structure.
+/// Note: The client cache structs (such as `BybitClientsCache`, `BingxClientsCache`) share all caching logic
+/// through the [`ClientsCache`] trait and only differ by key and client types. Their storage is declared with
+/// [`clients_cache!`], so a new exchange cache only adds its key type and typed `get_or_create`/`get`/`add`.
+use std::collections::hash_map::RandomState;
 use std::collections::HashMap;
-use std::hash::Hash;
-use std::sync::{Arc, RwLock};
+use std::hash::{BuildHasher, Hash, Hasher};
+use std::sync::{Arc, Mutex, RwLock};
 use std::time::{Duration, Instant};
 
 use once_cell::sync::Lazy;
 use tokio::task::JoinHandle;
+use tokio_util::sync::CancellationToken;
+
+/// Declare a unit struct implementing [`ClientsCache`] for `$key` and `$client`, with its own
+/// storage, 10-minute default lifetime and creation locks.
+macro_rules! clients_cache {
+    ($(#[$meta:meta])* $name:ident, $key:ty, $client:ty) => {
+        $(#[$meta])*
+        pub struct $name;
+
+        impl $crate::caches::ClientsCache<$key, $client> for $name {
+            fn cache() -> &'static once_cell::sync::Lazy<
+                std::sync::RwLock<$crate::caches::ClientCacheMap<$key, $client>>,
+            > {
+                static CACHE: once_cell::sync::Lazy<
+                    std::sync::RwLock<$crate::caches::ClientCacheMap<$key, $client>>,
+                > = once_cell::sync::Lazy::new(Default::default);
+                &CACHE
+            }
+
+            fn lifetime() -> &'static once_cell::sync::Lazy<std::sync::RwLock<std::time::Duration>> {
+                static LIFETIME: once_cell::sync::Lazy<std::sync::RwLock<std::time::Duration>> =
+                    once_cell::sync::Lazy::new(|| {
+                        std::sync::RwLock::new($crate::caches::DEFAULT_LIFETIME)
+                    });
+                &LIFETIME
+            }
+
+            fn creation_locks() -> &'static once_cell::sync::Lazy<
+                std::sync::Mutex<$crate::caches::CreationLocks<$key>>,
+            > {
+                static LOCKS: once_cell::sync::Lazy<
+                    std::sync::Mutex<$crate::caches::CreationLocks<$key>>,
+                > = once_cell::sync::Lazy::new(Default::default);
+                &LOCKS
+            }
+        }
+    };
+}
 
 mod bybit;
 pub use bybit::BybitClientsCache;
 mod bingx;
 pub use bingx::BingxClientsCache;
+mod okx;
+pub use okx::OkxClientsCache;
+
+/// Default cache entry lifetime of every client cache.
+const DEFAULT_LIFETIME: Duration = Duration::from_secs(600); // 10 minutes
 
 /// Type alias for the cache type used by all client caches.
 type ClientCacheMap<K, C> = HashMap<K, (Arc<C>, Instant)>;
 
+/// Type alias for the per-key locks held while a client is being created.
+type CreationLocks<K> = HashMap<K, Arc<Mutex<()>>>;
+
 /// Trait for generic client caching logic, where the client type is always stored as Arc<C>.
 pub trait ClientsCache<K, C>: Send + Sync + 'static
 where
@@ -30,6 +76,9 @@ where
     /// Returns a reference to the cache lifetime (RwLock around Duration)
     fn lifetime() -> &'static Lazy<RwLock<Duration>>;
 
+    /// Returns a reference to the per-key creation locks (Mutex around HashMap)
+    fn creation_locks() -> &'static Lazy<Mutex<CreationLocks<K>>>;
+
     /// Update the cache expiration lifetime (in seconds).
     fn configure(lifetime_seconds: u64) {
         *Self::lifetime().write().unwrap() = Duration::from_secs(lifetime_seconds);
@@ -59,24 +108,96 @@ where
     ) -> Arc<C>
     where
         F: FnOnce() -> Arc<C>,
+    {
+        match Self::try_get_or_create(key, || Ok::<_, std::convert::Infallible>(create())) {
+            Ok(client) => client,
+            Err(never) => match never {},
+        }
+    }
+
+    /// Fetch a client, or create and insert it if missing.
+    ///
+    /// Concurrent callers with the same key wait for a single `create` call instead of each
+    /// building their own client. If `create` fails, the error is returned and the next
+    /// caller retries.
+    fn try_get_or_create<F, E>(
+        key: K,
+        create: F,
+    ) -> std::result::Result<Arc<C>, E>
+    where
+        F: FnOnce() -> std::result::Result<Arc<C>, E>,
     {
         if let Some(c) = Self::get(&key) {
-            return c;
+            return Ok(c);
         }
-        let client = create();
-        Self::add(key, Arc::clone(&client));
-        client
+
+        let lock = {
+            let mut locks = Self::creation_locks().lock().unwrap();
+            Arc::clone(locks.entry(key.clone()).or_default())
+        };
+        let result = {
+            let _creating = lock.lock().unwrap_or_else(|e| e.into_inner());
+            // Another caller may have finished creating while we waited.
+            match Self::get(&key) {
+                Some(c) => Ok(c),
+                None => create().inspect(|client| Self::add(key.clone(), Arc::clone(client))),
+            }
+        };
+
+        let mut locks = Self::creation_locks().lock().unwrap();
+        if locks.get(&key).is_some_and(|l| Arc::ptr_eq(l, &lock)) && Arc::strong_count(&lock) == 2 {
+            locks.remove(&key);
+        }
+        result
+    }
+
+    /// Remove the entry for `key`, so the next `get_or_create` builds a fresh client.
+    /// Returns true if an entry was removed.
+    fn remove(key: &K) -> bool {
+        Self::cache()
+            .write()
+            .map(|mut c| c.remove(key).is_some())
+            .unwrap_or(false)
+    }
+
+    /// Time left before the entry for `key` expires, or None if it is missing or expired.
+    fn ttl_remaining(key: &K) -> Option<Duration> {
+        let now = Instant::now();
+        Self::cache().read().ok().and_then(|c| {
+            c.get(key)
+                .filter(|(_, exp)| exp > &now)
+                .map(|(_, exp)| *exp - now)
+        })
     }
 
     /// Remove all expired clients from the cache.
     /// Returns the number of entries removed.
+    ///
+    /// Expired keys are collected under the read lock, so lookups continue during the scan;
+    /// the write lock is only taken to remove them.
     fn cleanup_expired() -> usize {
         let now = Instant::now();
+        let expired: Vec<K> = match Self::cache().read() {
+            Ok(cache) => cache
+                .iter()
+                .filter(|(_, (_, exp))| *exp <= now)
+                .map(|(key, _)| key.clone())
+                .collect(),
+            Err(_) => return 0,
+        };
+        if expired.is_empty() {
+            return 0;
+        }
+        let Ok(mut cache) = Self::cache().write() else {
+            return 0;
+        };
         let mut removed = 0;
-        if let Ok(mut cache) = Self::cache().write() {
-            let initial = cache.len();
-            cache.retain(|_, (_, exp)| *exp > now);
-            removed = initial - cache.len();
+        for key in &expired {
+            // Skip entries re-added after the scan.
+            if cache.get(key).is_some_and(|(_, exp)| *exp <= now) {
+                cache.remove(key);
+                removed += 1;
+            }
         }
         removed
     }
@@ -93,21 +214,88 @@ where
         }
     }
 
-    /// Create background cleanup task.
-    fn create_cleanup_task(interval_seconds: u64) -> JoinHandle<()> {
-        tokio::spawn(async move {
-            let interval = tokio::time::Duration::from_secs(interval_seconds);
-            loop {
-                tokio::time::sleep(interval).await;
-                let removed = Self::cleanup_expired();
-                if removed > 0 {
-                    log::info!(
-                        "{}: cleaned {} entries",
-                        std::any::type_name::<Self>(),
-                        removed
-                    );
-                }
+    /// Create background cleanup task, removing expired clients every `interval_seconds`.
+    ///
+    /// The task runs until [`CleanupTask::stop`] is called; dropping the handle leaves it running.
+    fn create_cleanup_task(interval_seconds: u64) -> CleanupTask {
+        spawn_cleanup_task(
+            std::any::type_name::<Self>(),
+            Duration::from_secs(interval_seconds),
+            0.0,
+            Self::cleanup_expired,
+        )
+    }
+
+    /// [`Self::create_cleanup_task`] with each interval varied randomly by up to ±10%, so
+    /// tasks started together do not take the cache write locks at the same moments.
+    fn create_jittered_cleanup_task(interval_seconds: u64) -> CleanupTask {
+        spawn_cleanup_task(
+            std::any::type_name::<Self>(),
+            Duration::from_secs(interval_seconds),
+            CLEANUP_JITTER,
+            Self::cleanup_expired,
+        )
+    }
+}
+
+/// Largest relative deviation of a jittered cleanup interval.
+const CLEANUP_JITTER: f64 = 0.1;
+
+/// `interval` scaled by a random factor in `1 ± jitter`.
+fn jittered(
+    interval: Duration,
+    jitter: f64,
+) -> Duration {
+    if jitter <= 0.0 {
+        return interval;
+    }
+    let random = RandomState::new().build_hasher().finish();
+    let unit = random as f64 / u64::MAX as f64; // 0.0..=1.0
+    interval.mul_f64(1.0 + jitter * (2.0 * unit - 1.0))
+}
+
+/// Spawn the cleanup loop of cache `name`, calling `cleanup` after every (jittered) interval.
+fn spawn_cleanup_task(
+    name: &'static str,
+    interval: Duration,
+    jitter: f64,
+    cleanup: fn() -> usize,
+) -> CleanupTask {
+    let cancel = CancellationToken::new();
+    let token = cancel.clone();
+    let handle = tokio::spawn(async move {
+        loop {
+            tokio::select! {
+                _ = token.cancelled() => break,
+                _ = tokio::time::sleep(jittered(interval, jitter)) => {}
             }
-        })
+            let removed = cleanup();
+            if removed > 0 {
+                log::info!("{}: cleaned {} entries", name, removed);
+            }
+        }
+    });
+    CleanupTask { handle, cancel }
+}
+
+/// Handle to a cache cleanup task started by [`ClientsCache::create_cleanup_task`].
+pub struct CleanupTask {
+    handle: JoinHandle<()>,
+    cancel: CancellationToken,
+}
+
+impl CleanupTask {
+    /// Stop the task and wait for it to exit.
+    ///
+    /// A cleanup pass already running finishes first, so the cache lock is never abandoned
+    /// midway.
+    pub async fn stop(self) {
+        self.cancel.cancel();
+        let _ = self.handle.await;
+    }
+
+    /// Whether the task has exited.
+    pub fn is_finished(&self) -> bool {
+        self.handle.is_finished()
     }
 }
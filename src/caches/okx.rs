@@ -0,0 +1,83 @@
+use std::sync::Arc;
+
+use super::super::caches::ClientsCache;
+use crate::error::Result;
+use crate::okx::OkxClient;
+
+/// Type alias for the unique cache key for OKX clients.
+/// Format: (api_key, api_secret, passphrase, demo)
+pub type OkxCacheKey = (String, String, String, bool);
+
+clients_cache!(
+    /// Cache for OkxClient connections, keyed by API credentials and demo flag.
+    OkxClientsCache,
+    OkxCacheKey,
+    OkxClient
+);
+
+/// Constructs a key for cache lookup or storage.
+#[inline]
+fn make_key(
+    api_key: impl Into<String>,
+    api_secret: impl Into<String>,
+    passphrase: impl Into<String>,
+    demo: bool,
+) -> OkxCacheKey {
+    (api_key.into(), api_secret.into(), passphrase.into(), demo)
+}
+
+impl OkxClientsCache {
+    /// Fetch an OkxClient from the cache, or create and insert one if missing.
+    ///
+    /// # Arguments
+    /// * `api_key` - The OKX API key (consumed as String)
+    /// * `api_secret` - The OKX API secret (consumed as String)
+    /// * `passphrase` - The OKX API passphrase (consumed as String)
+    /// * `demo` - Whether to use demo trading for client
+    ///
+    /// # Returns
+    /// * Ok(Arc<OkxClient>) - Shared reference to the client
+    /// * Err(crate::error::Error) - If client creation fails
+    pub fn get_or_create(
+        api_key: impl Into<String>,
+        api_secret: impl Into<String>,
+        passphrase: impl Into<String>,
+        demo: bool,
+    ) -> Result<Arc<OkxClient>> {
+        let key = make_key(api_key, api_secret, passphrase, demo);
+        <Self as ClientsCache<OkxCacheKey, OkxClient>>::try_get_or_create(key.clone(), || {
+            Ok(Arc::new(OkxClient::new(
+                Some(key.0.clone()),
+                Some(key.1.clone()),
+                Some(key.2.clone()),
+                demo,
+            )?))
+        })
+    }
+
+    /// Fetch an OkxClient from the cache by credentials and demo flag.
+    pub fn get(
+        api_key: &str,
+        api_secret: &str,
+        passphrase: &str,
+        demo: bool,
+    ) -> Option<Arc<OkxClient>> {
+        let key = make_key(api_key, api_secret, passphrase, demo);
+        <Self as ClientsCache<OkxCacheKey, OkxClient>>::get(&key)
+    }
+
+    /// Add an OkxClient to the cache with the given credentials and demo flag.
+    ///
+    /// The client is stored as-is: one built with `with_base_url` is returned for these
+    /// credentials until it expires or is removed.
+    pub fn add(
+        client: Arc<OkxClient>,
+        api_key: &str,
+        api_secret: &str,
+        passphrase: &str,
+        demo: bool,
+    ) {
+        let key = make_key(api_key, api_secret, passphrase, demo);
+        <Self as ClientsCache<OkxCacheKey, OkxClient>>::add(key, client);
+    }
+}
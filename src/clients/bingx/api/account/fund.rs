@@ -1,6 +1,82 @@
 use crate::bingx::traits::account::FundApi;
+use crate::bingx::types::{
+    universal_transfer_type, AccountType, ApiResponse, UniversalTransferParams,
+};
 use crate::bingx::BingxClient;
+use crate::error::Result;
+use crate::http::HttpClient;
 use async_trait::async_trait;
+use linkme::distributed_slice;
+use serde_json::Value;
+use std::collections::HashMap;
+
+use crate::bingx::BINGX_IMPLEMENTED;
+
+#[distributed_slice(BINGX_IMPLEMENTED)]
+static UNIVERSAL_TRANSFER: &str = "universal_transfer";
+
+#[distributed_slice(BINGX_IMPLEMENTED)]
+static GET_TRANSFER_RECORDS: &str = "get_transfer_records";
 
 #[async_trait]
-impl FundApi for BingxClient {}
+impl FundApi for BingxClient {
+    async fn universal_transfer(
+        &self,
+        from: AccountType,
+        to: AccountType,
+        asset: &str,
+        amount: f64,
+    ) -> Result<ApiResponse<Value>> {
+        let transfer = UniversalTransferParams::new(&from, &to, asset, amount)?;
+        let json_value = serde_json::to_value(&transfer)?;
+        let mut params: HashMap<String, Value> = HashMap::new();
+
+        if let Some(obj) = json_value.as_object() {
+            for (key, value) in obj {
+                params.insert(key.clone(), value.clone());
+            }
+        }
+
+        let response = self
+            .post("/openApi/api/v3/asset/transfer", Some(&params), true)
+            .await?;
+        Ok(response.into_api_response())
+    }
+
+    async fn get_transfer_records(
+        &self,
+        from: AccountType,
+        to: AccountType,
+        tran_id: Option<i64>,
+        start_time: Option<i64>,
+        end_time: Option<i64>,
+        current: Option<u32>,
+        size: Option<u32>,
+    ) -> Result<ApiResponse<Value>> {
+        let mut params: HashMap<String, Value> = HashMap::new();
+        params.insert(
+            "type".to_string(),
+            Value::String(universal_transfer_type(&from, &to)?),
+        );
+        if let Some(tran_id) = tran_id {
+            params.insert("tranId".to_string(), Value::from(tran_id));
+        }
+        if let Some(start_time) = start_time {
+            params.insert("startTime".to_string(), Value::from(start_time));
+        }
+        if let Some(end_time) = end_time {
+            params.insert("endTime".to_string(), Value::from(end_time));
+        }
+        if let Some(current) = current {
+            params.insert("current".to_string(), Value::from(current));
+        }
+        if let Some(size) = size {
+            params.insert("size".to_string(), Value::from(size));
+        }
+
+        let response = self
+            .get("/openApi/api/v3/asset/transfer", Some(&params), true)
+            .await?;
+        Ok(response.into_api_response())
+    }
+}
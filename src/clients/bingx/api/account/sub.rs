@@ -1,17 +1,31 @@
 use crate::bingx::traits::account::SubAccountApi;
 use crate::bingx::types::ApiResponse;
 use crate::bingx::BingxClient;
-use crate::error::Result;
+use crate::error::{Error, Result};
 use crate::http::HttpClient;
+use crate::utils::bool_str;
 use async_trait::async_trait;
 use linkme::distributed_slice;
 use serde_json::Value;
+use std::collections::HashMap;
 
 use crate::bingx::BINGX_IMPLEMENTED;
 
 #[distributed_slice(BINGX_IMPLEMENTED)]
 pub static GET_API_PERMISSIONS: &'static str = "get_api_permissions";
 
+#[distributed_slice(BINGX_IMPLEMENTED)]
+static CREATE_SUB_ACCOUNT: &str = "create_sub_account";
+
+#[distributed_slice(BINGX_IMPLEMENTED)]
+static QUERY_SUB_ACCOUNT_LIST: &str = "query_sub_account_list";
+
+#[distributed_slice(BINGX_IMPLEMENTED)]
+static CREATE_SUB_ACCOUNT_API_KEY: &str = "create_sub_account_api_key";
+
+#[distributed_slice(BINGX_IMPLEMENTED)]
+static QUERY_SUB_ACCOUNT_ASSETS: &str = "query_sub_account_assets";
+
 #[async_trait]
 impl SubAccountApi for BingxClient {
     async fn get_api_permissions(&self) -> Result<ApiResponse<Value>> {
@@ -20,4 +34,112 @@ impl SubAccountApi for BingxClient {
             .await?;
         Ok(response.into_api_response())
     }
+
+    async fn create_sub_account(
+        &self,
+        sub_account_string: &str,
+        note: Option<&str>,
+    ) -> Result<ApiResponse<Value>> {
+        if sub_account_string.is_empty() {
+            return Err(Error::Validation(
+                "sub_account_string must not be empty".to_string(),
+            ));
+        }
+
+        let mut params: HashMap<String, Value> = HashMap::new();
+        params.insert(
+            "subAccountString".to_string(),
+            Value::String(sub_account_string.to_string()),
+        );
+        if let Some(note) = note {
+            params.insert("note".to_string(), Value::String(note.to_string()));
+        }
+
+        let response = self
+            .post("/openApi/subAccount/v1/create", Some(&params), true)
+            .await?;
+        Ok(response.into_api_response())
+    }
+
+    async fn query_sub_account_list(
+        &self,
+        page: u32,
+        limit: u32,
+        sub_uid: Option<i64>,
+        sub_account_string: Option<&str>,
+        is_freeze: Option<bool>,
+    ) -> Result<ApiResponse<Value>> {
+        if page == 0 {
+            return Err(Error::Validation("page must start at 1".to_string()));
+        }
+        if !(1..=1000).contains(&limit) {
+            return Err(Error::Validation(format!(
+                "limit must be between 1 and 1000, got {limit}"
+            )));
+        }
+
+        let mut params: HashMap<String, Value> = HashMap::new();
+        params.insert("page".to_string(), Value::from(page));
+        params.insert("limit".to_string(), Value::from(limit));
+        if let Some(sub_uid) = sub_uid {
+            params.insert("subUid".to_string(), Value::from(sub_uid));
+        }
+        if let Some(sub_account_string) = sub_account_string {
+            params.insert(
+                "subAccountString".to_string(),
+                Value::String(sub_account_string.to_string()),
+            );
+        }
+        if let Some(is_freeze) = is_freeze {
+            params.insert("isFeeze".to_string(), Value::from(bool_str(is_freeze)));
+        }
+
+        let response = self
+            .get("/openApi/subAccount/v1/list", Some(&params), true)
+            .await?;
+        Ok(response.into_api_response())
+    }
+
+    async fn create_sub_account_api_key(
+        &self,
+        sub_uid: i64,
+        note: &str,
+        permissions: &[i32],
+        ip: Option<&str>,
+    ) -> Result<ApiResponse<Value>> {
+        if permissions.is_empty() {
+            return Err(Error::Validation(
+                "At least one permission must be provided.".to_string(),
+            ));
+        }
+
+        let mut params: HashMap<String, Value> = HashMap::new();
+        params.insert("subUid".to_string(), Value::from(sub_uid));
+        params.insert("note".to_string(), Value::String(note.to_string()));
+        params.insert(
+            "permissions".to_string(),
+            Value::Array(permissions.iter().map(|p| Value::from(*p)).collect()),
+        );
+        if let Some(ip) = ip {
+            params.insert("ip".to_string(), Value::String(ip.to_string()));
+        }
+
+        let response = self
+            .post("/openApi/subAccount/v1/apiKey/create", Some(&params), true)
+            .await?;
+        Ok(response.into_api_response())
+    }
+
+    async fn query_sub_account_assets(
+        &self,
+        sub_uid: i64,
+    ) -> Result<ApiResponse<Value>> {
+        let mut params: HashMap<String, Value> = HashMap::new();
+        params.insert("subUid".to_string(), Value::from(sub_uid));
+
+        let response = self
+            .get("/openApi/subAccount/v1/assets", Some(&params), true)
+            .await?;
+        Ok(response.into_api_response())
+    }
 }
@@ -3,7 +3,7 @@ use async_trait::async_trait;
 use crate::bingx::traits::common::CommonApi;
 use crate::bingx::types::ApiResponse;
 use crate::bingx::BingxClient;
-use crate::error::Result;
+use crate::error::{Error, Result};
 use crate::http::HttpClient;
 
 use crate::bingx::BINGX_IMPLEMENTED;
@@ -21,3 +21,35 @@ impl CommonApi for BingxClient {
         Ok(response.into_api_response())
     }
 }
+
+impl BingxClient {
+    /// Server minus local clock in milliseconds, measured against the midpoint of a
+    /// `get_server_time` round trip.
+    ///
+    /// Returns `Error::Validation` if the response has no `serverTime`.
+    pub async fn server_time_offset(&self) -> Result<i64> {
+        let sent = self.now_ms();
+        let response = self.get_server_time().await?;
+        let received = self.now_ms();
+        let server_time = response
+            .data
+            .get("serverTime")
+            .and_then(|t| t.as_i64())
+            .ok_or_else(|| {
+                Error::Validation(format!(
+                    "get_server_time response has no serverTime: {}",
+                    response.data
+                ))
+            })?;
+        Ok(server_time - (sent + received) / 2)
+    }
+
+    /// Measure the server time offset and apply it to signing timestamps (see
+    /// `set_time_offset_ms`). Returns the applied offset; call again periodically to follow
+    /// further drift.
+    pub async fn sync_server_time(&self) -> Result<i64> {
+        let offset = self.server_time_offset().await?;
+        self.set_time_offset_ms(offset);
+        Ok(offset)
+    }
+}
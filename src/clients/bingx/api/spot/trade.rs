@@ -3,13 +3,16 @@ use std::collections::HashMap;
 use async_trait::async_trait;
 
 use crate::bingx::traits::spot::TradeApi;
-use crate::bingx::types::{ApiResponse, SpotOrderStatus, SpotOrderType};
+use crate::bingx::types::{ApiResponse, PlaceSpotOrderParams, SpotOrderStatus, SpotOrderType};
 use crate::bingx::BingxClient;
 use crate::bingx::BINGX_IMPLEMENTED;
 use crate::error::{Error, Result};
 use crate::http::HttpClient;
 use linkme::distributed_slice;
 
+#[distributed_slice(BINGX_IMPLEMENTED)]
+static PLACE_SPOT_ORDER: &str = "place_spot_order";
+
 #[distributed_slice(BINGX_IMPLEMENTED)]
 static GET_SPOT_ORDER_HISTORY: &str = "get_spot_order_history";
 
@@ -30,6 +33,34 @@ static CANCEL_ALL_SPOT_OPEN_ORDERS: &str = "cancel_all_spot_open_orders";
 
 #[async_trait]
 impl TradeApi for BingxClient {
+    async fn place_spot_order(
+        &self,
+        params: &PlaceSpotOrderParams,
+    ) -> Result<ApiResponse<serde_json::Value>> {
+        if !params.symbol.contains('-') {
+            return Err(Error::Validation(format!(
+                "Symbol must contain a hyphen (e.g. BTC-USDT), got: {}",
+                params.symbol
+            )));
+        }
+
+        let json_value = serde_json::to_value(params)?;
+        let mut order_data: HashMap<String, serde_json::Value> = HashMap::new();
+
+        if let Some(obj) = json_value.as_object() {
+            for (key, value) in obj {
+                if !value.is_null() {
+                    order_data.insert(key.clone(), value.clone());
+                }
+            }
+        }
+
+        let response = self
+            .post("/openApi/spot/v1/trade/order", Some(&order_data), true)
+            .await?;
+        Ok(response.into_api_response())
+    }
+
     async fn get_spot_order_history(
         &self,
         symbol: Option<&str>,
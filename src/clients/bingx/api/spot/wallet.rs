@@ -1,3 +1,136 @@
-use crate::bingx::{traits::spot::WalletApi, BingxClient};
+use crate::bingx::traits::spot::WalletApi;
+use crate::bingx::types::ApiResponse;
+use crate::bingx::BingxClient;
+use crate::bingx::BINGX_IMPLEMENTED;
+use crate::error::{Error, Result};
+use crate::http::HttpClient;
+use crate::utils::PlainDecimal;
+use async_trait::async_trait;
+use linkme::distributed_slice;
+use serde_json::Value;
+use std::collections::HashMap;
 
-impl WalletApi for BingxClient {}
+/// `walletType` for withdrawals from the fund (spot) account.
+const FUND_WALLET_TYPE: i32 = 1;
+
+#[distributed_slice(BINGX_IMPLEMENTED)]
+static GET_DEPOSIT_ADDRESS: &str = "get_deposit_address";
+
+#[distributed_slice(BINGX_IMPLEMENTED)]
+static WITHDRAW: &str = "withdraw";
+
+#[distributed_slice(BINGX_IMPLEMENTED)]
+static GET_WITHDRAW_HISTORY: &str = "get_withdraw_history";
+
+#[async_trait]
+impl WalletApi for BingxClient {
+    async fn get_deposit_address(
+        &self,
+        coin: &str,
+        network: Option<&str>,
+    ) -> Result<ApiResponse<Value>> {
+        let mut params: HashMap<String, Value> = HashMap::new();
+        params.insert("coin".to_string(), Value::String(coin.to_string()));
+        if let Some(network) = network {
+            params.insert("network".to_string(), Value::String(network.to_string()));
+        }
+        let response = self
+            .get(
+                "/openApi/wallets/v1/capital/deposit/address",
+                Some(&params),
+                true,
+            )
+            .await?;
+        Ok(response.into_api_response())
+    }
+
+    async fn withdraw(
+        &self,
+        coin: &str,
+        address: &str,
+        amount: f64,
+        network: &str,
+        address_tag: Option<&str>,
+    ) -> Result<ApiResponse<Value>> {
+        if !amount.is_finite() || amount <= 0.0 {
+            return Err(Error::Validation(format!(
+                "Withdrawal amount must be greater than 0, got {amount}"
+            )));
+        }
+        if network.trim().is_empty() {
+            return Err(Error::Validation(
+                "Withdrawal network must not be empty".to_string(),
+            ));
+        }
+
+        let mut params: HashMap<String, Value> = HashMap::new();
+        params.insert("coin".to_string(), Value::String(coin.to_string()));
+        params.insert("address".to_string(), Value::String(address.to_string()));
+        params.insert(
+            "amount".to_string(),
+            Value::String(amount.to_plain_string()),
+        );
+        params.insert("network".to_string(), Value::String(network.to_string()));
+        params.insert("walletType".to_string(), Value::from(FUND_WALLET_TYPE));
+        if let Some(address_tag) = address_tag {
+            params.insert(
+                "addressTag".to_string(),
+                Value::String(address_tag.to_string()),
+            );
+        }
+
+        let response = self
+            .post(
+                "/openApi/wallets/v1/capital/withdraw/apply",
+                Some(&params),
+                true,
+            )
+            .await?;
+        Ok(response.into_api_response())
+    }
+
+    async fn get_withdraw_history(
+        &self,
+        coin: Option<&str>,
+        withdraw_order_id: Option<&str>,
+        status: Option<i32>,
+        start_time: Option<i64>,
+        end_time: Option<i64>,
+        offset: Option<u32>,
+        limit: Option<u32>,
+    ) -> Result<ApiResponse<Value>> {
+        let mut params: HashMap<String, Value> = HashMap::new();
+        if let Some(coin) = coin {
+            params.insert("coin".to_string(), Value::String(coin.to_string()));
+        }
+        if let Some(withdraw_order_id) = withdraw_order_id {
+            params.insert(
+                "withdrawOrderId".to_string(),
+                Value::String(withdraw_order_id.to_string()),
+            );
+        }
+        if let Some(status) = status {
+            params.insert("status".to_string(), Value::from(status));
+        }
+        if let Some(start_time) = start_time {
+            params.insert("startTime".to_string(), Value::from(start_time));
+        }
+        if let Some(end_time) = end_time {
+            params.insert("endTime".to_string(), Value::from(end_time));
+        }
+        if let Some(offset) = offset {
+            params.insert("offset".to_string(), Value::from(offset));
+        }
+        if let Some(limit) = limit {
+            params.insert("limit".to_string(), Value::from(limit));
+        }
+        let response = self
+            .get(
+                "/openApi/api/v3/capital/withdraw/history",
+                Some(&params),
+                true,
+            )
+            .await?;
+        Ok(response.into_api_response())
+    }
+}
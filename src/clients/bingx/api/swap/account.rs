@@ -10,10 +10,17 @@ use serde_json::Value;
 use std::collections::HashMap;
 
 #[distributed_slice(BINGX_IMPLEMENTED)]
-static GET_SPOT_ACCOUNT_ASSETS: &str = "get_spot_account_assets";
+static GET_SWAP_POSITIONS: &str = "get_swap_positions";
 
 #[distributed_slice(BINGX_IMPLEMENTED)]
-static GET_ACCOUNT_ASSET_OVERVIEW: &str = "get_account_asset_overview";
+static GET_SWAP_BALANCE: &str = "get_swap_balance";
+
+/// Deprecated alias of `get_swap_balance`, provided by the trait.
+#[distributed_slice(BINGX_IMPLEMENTED)]
+static GET_SWAP_ACCOUNT_BALANCE: &str = "get_swap_account_balance";
+
+#[distributed_slice(BINGX_IMPLEMENTED)]
+static GET_SWAP_INCOME_HISTORY: &str = "get_swap_income_history";
 
 #[async_trait]
 impl AccountApi for BingxClient {
@@ -31,10 +38,43 @@ impl AccountApi for BingxClient {
         Ok(response.into_api_response())
     }
 
-    async fn get_swap_account_balance(&self) -> Result<ApiResponse<serde_json::Value>> {
+    async fn get_swap_balance(&self) -> Result<ApiResponse<serde_json::Value>> {
         let response = self
             .get("/openApi/swap/v3/user/balance", None, true)
             .await?;
         Ok(response.into_api_response())
     }
+
+    async fn get_swap_income_history(
+        &self,
+        symbol: Option<&str>,
+        income_type: Option<&str>,
+        start_time: Option<i64>,
+        end_time: Option<i64>,
+        limit: Option<u32>,
+    ) -> Result<ApiResponse<serde_json::Value>> {
+        let mut params: HashMap<String, Value> = HashMap::new();
+        if let Some(symbol) = symbol {
+            params.insert("symbol".to_string(), Value::String(symbol.to_string()));
+        }
+        if let Some(income_type) = income_type {
+            params.insert(
+                "incomeType".to_string(),
+                Value::String(income_type.to_string()),
+            );
+        }
+        if let Some(start_time) = start_time {
+            params.insert("startTime".to_string(), Value::from(start_time));
+        }
+        if let Some(end_time) = end_time {
+            params.insert("endTime".to_string(), Value::from(end_time));
+        }
+        if let Some(limit) = limit {
+            params.insert("limit".to_string(), Value::from(limit));
+        }
+        let response = self
+            .get("/openApi/swap/v2/user/income", Some(&params), true)
+            .await?;
+        Ok(response.into_api_response())
+    }
 }
@@ -1,8 +1,8 @@
 use crate::bingx::traits::swap::MarketApi;
-use crate::bingx::types::ApiResponse;
+use crate::bingx::types::{ApiResponse, SWAP_KLINE_INTERVALS};
 use crate::bingx::BingxClient;
 use crate::bingx::BINGX_IMPLEMENTED;
-use crate::error::Result;
+use crate::error::{Error, Result};
 use crate::http::HttpClient;
 use async_trait::async_trait;
 use linkme::distributed_slice;
@@ -12,9 +12,24 @@ use std::collections::HashMap;
 #[distributed_slice(BINGX_IMPLEMENTED)]
 static GET_SWAP_CONTRACTS: &str = "get_swap_contracts";
 
+#[distributed_slice(BINGX_IMPLEMENTED)]
+static GET_SWAP_DEPTH: &str = "get_swap_depth";
+
+#[distributed_slice(BINGX_IMPLEMENTED)]
+static GET_SWAP_TICKER: &str = "get_swap_ticker";
+
 #[distributed_slice(BINGX_IMPLEMENTED)]
 static GET_SWAP_KLINES: &str = "get_swap_klines";
 
+#[distributed_slice(BINGX_IMPLEMENTED)]
+static GET_SWAP_FUNDING_RATE: &str = "get_swap_funding_rate";
+
+#[distributed_slice(BINGX_IMPLEMENTED)]
+static GET_SWAP_FUNDING_RATE_HISTORY: &str = "get_swap_funding_rate_history";
+
+#[distributed_slice(BINGX_IMPLEMENTED)]
+static GET_SWAP_OPEN_INTEREST: &str = "get_swap_open_interest";
+
 #[async_trait]
 impl MarketApi for BingxClient {
     async fn get_swap_contracts(
@@ -31,6 +46,36 @@ impl MarketApi for BingxClient {
         Ok(response.into_api_response())
     }
 
+    async fn get_swap_depth(
+        &self,
+        symbol: &str,
+        limit: Option<u32>,
+    ) -> Result<ApiResponse<serde_json::Value>> {
+        let mut params: HashMap<String, Value> = HashMap::new();
+        params.insert("symbol".to_string(), Value::String(symbol.to_string()));
+        if let Some(limit) = limit {
+            params.insert("limit".to_string(), Value::from(limit));
+        }
+        let response = self
+            .get("/openApi/swap/v2/quote/depth", Some(&params), false)
+            .await?;
+        Ok(response.into_api_response())
+    }
+
+    async fn get_swap_ticker(
+        &self,
+        symbol: Option<&str>,
+    ) -> Result<ApiResponse<serde_json::Value>> {
+        let mut params: HashMap<String, Value> = HashMap::new();
+        if let Some(symbol) = symbol {
+            params.insert("symbol".to_string(), Value::String(symbol.to_string()));
+        }
+        let response = self
+            .get("/openApi/swap/v2/quote/ticker", Some(&params), false)
+            .await?;
+        Ok(response.into_api_response())
+    }
+
     async fn get_swap_klines(
         &self,
         symbol: &str,
@@ -39,6 +84,13 @@ impl MarketApi for BingxClient {
         end_time: Option<i64>,
         limit: Option<u32>,
     ) -> Result<ApiResponse<serde_json::Value>> {
+        if !SWAP_KLINE_INTERVALS.contains(&interval) {
+            return Err(Error::Validation(format!(
+                "Invalid kline interval: {interval}. Allowed: {}",
+                SWAP_KLINE_INTERVALS.join(", ")
+            )));
+        }
+
         let mut params: HashMap<String, Value> = HashMap::new();
         params.insert("symbol".to_string(), Value::String(symbol.to_string()));
         params.insert("interval".to_string(), Value::String(interval.to_string()));
@@ -57,4 +109,54 @@ impl MarketApi for BingxClient {
             .await?;
         Ok(response.into_api_response())
     }
+
+    async fn get_swap_funding_rate(
+        &self,
+        symbol: Option<&str>,
+    ) -> Result<ApiResponse<serde_json::Value>> {
+        let mut params: HashMap<String, Value> = HashMap::new();
+        if let Some(symbol) = symbol {
+            params.insert("symbol".to_string(), Value::String(symbol.to_string()));
+        }
+        let response = self
+            .get("/openApi/swap/v2/quote/premiumIndex", Some(&params), false)
+            .await?;
+        Ok(response.into_api_response())
+    }
+
+    async fn get_swap_funding_rate_history(
+        &self,
+        symbol: &str,
+        start_time: Option<i64>,
+        end_time: Option<i64>,
+        limit: Option<u32>,
+    ) -> Result<ApiResponse<serde_json::Value>> {
+        let mut params: HashMap<String, Value> = HashMap::new();
+        params.insert("symbol".to_string(), Value::String(symbol.to_string()));
+        if let Some(start_time) = start_time {
+            params.insert("startTime".to_string(), Value::from(start_time));
+        }
+        if let Some(end_time) = end_time {
+            params.insert("endTime".to_string(), Value::from(end_time));
+        }
+        if let Some(limit) = limit {
+            params.insert("limit".to_string(), Value::from(limit));
+        }
+        let response = self
+            .get("/openApi/swap/v2/quote/fundingRate", Some(&params), false)
+            .await?;
+        Ok(response.into_api_response())
+    }
+
+    async fn get_swap_open_interest(
+        &self,
+        symbol: &str,
+    ) -> Result<ApiResponse<serde_json::Value>> {
+        let mut params: HashMap<String, Value> = HashMap::new();
+        params.insert("symbol".to_string(), Value::String(symbol.to_string()));
+        let response = self
+            .get("/openApi/swap/v2/quote/openInterest", Some(&params), false)
+            .await?;
+        Ok(response.into_api_response())
+    }
 }
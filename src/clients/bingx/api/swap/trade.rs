@@ -5,7 +5,9 @@ use crate::bingx::types::{
 use crate::bingx::BingxClient;
 use crate::error::{Error, Result};
 use crate::http::HttpClient;
+use crate::utils::{bool_str, check_leverage, Amount, PlainDecimal};
 use async_trait::async_trait;
+use serde::Serialize;
 use std::collections::HashMap;
 
 use crate::bingx::BINGX_IMPLEMENTED;
@@ -54,22 +56,112 @@ static CHANGE_SWAP_MARGIN_TYPE: &str = "change_swap_margin_type";
 #[distributed_slice(BINGX_IMPLEMENTED)]
 static GET_SWAP_MARGIN_TYPE: &str = "get_swap_margin_type";
 
+#[distributed_slice(BINGX_IMPLEMENTED)]
+static AMEND_SWAP_ORDER: &str = "amend_swap_order";
+
+#[distributed_slice(BINGX_IMPLEMENTED)]
+static CANCEL_REPLACE_SWAP_ORDER: &str = "cancel_replace_swap_order";
+
+#[distributed_slice(BINGX_IMPLEMENTED)]
+static PLACE_SWAP_BATCH_ORDERS: &str = "place_swap_batch_orders";
+
+/// Maximum number of orders in one `place_swap_batch_orders` request.
+const MAX_SWAP_BATCH_ORDERS: usize = 5;
+
+/// Body of `/openApi/swap/v2/trade/batchOrders`; BingX expects the orders as a JSON string.
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct BatchOrdersParams<'a> {
+    #[serde(serialize_with = "crate::utils::serialize_as_json_string")]
+    batch_orders: Option<&'a [PlaceSwapOrderParams]>,
+}
+
+/// Modes accepted by `cancel_replace_swap_order`.
+const CANCEL_REPLACE_MODES: &[&str] = &["STOP_ON_FAILURE", "ALLOW_FAILURE"];
+
+/// Serialize order params into request params, skipping unset fields.
+fn order_params(params: &PlaceSwapOrderParams) -> Result<HashMap<String, serde_json::Value>> {
+    params.validate()?;
+    let json_value = serde_json::to_value(params)?;
+    let mut order_data: HashMap<String, serde_json::Value> = HashMap::new();
+
+    if let Some(obj) = json_value.as_object() {
+        for (key, value) in obj {
+            if !value.is_null() {
+                order_data.insert(key.clone(), value.clone());
+            }
+        }
+    }
+    Ok(order_data)
+}
+
+impl BingxClient {
+    /// Maximum swap leverage of `symbol` for `side`, from
+    /// `get_swap_leverage_and_available_positions`; `BOTH` takes the lower of long and short.
+    ///
+    /// Limits are cached per symbol for a minute, shared by clones.
+    pub async fn max_swap_leverage(
+        &self,
+        symbol: &str,
+        side: &PositionSide,
+    ) -> Result<f64> {
+        let long_key = format!("{symbol}:LONG");
+        let short_key = format!("{symbol}:SHORT");
+        let (long, short) = match (
+            self.leverage_limits.get(&long_key),
+            self.leverage_limits.get(&short_key),
+        ) {
+            (Some(long), Some(short)) => (long, short),
+            _ => {
+                let response = self
+                    .get_swap_leverage_and_available_positions(symbol)
+                    .await?;
+                let max = |field: &str| {
+                    let value = &response.data[field];
+                    value
+                        .as_f64()
+                        .or_else(|| value.as_str().and_then(|s| s.parse().ok()))
+                        .ok_or_else(|| {
+                            Error::Validation(format!("No {field} in the leverage of {symbol}"))
+                        })
+                };
+                let (long, short) = (max("maxLongLeverage")?, max("maxShortLeverage")?);
+                self.leverage_limits.insert(long_key, long);
+                self.leverage_limits.insert(short_key, short);
+                (long, short)
+            }
+        };
+        Ok(match side {
+            PositionSide::Long => long,
+            PositionSide::Short => short,
+            PositionSide::Both => long.min(short),
+        })
+    }
+
+    /// [`TradeApi::set_swap_leverage`], after checking `leverage` against
+    /// [`Self::max_swap_leverage`].
+    ///
+    /// Out-of-range values fail with `Error::Validation` naming the limit instead of an
+    /// exchange reject.
+    pub async fn set_swap_leverage_checked(
+        &self,
+        symbol: &str,
+        side: PositionSide,
+        leverage: i32,
+    ) -> Result<ApiResponse<serde_json::Value>> {
+        let max = self.max_swap_leverage(symbol, &side).await?;
+        check_leverage(symbol, f64::from(leverage), max)?;
+        self.set_swap_leverage(symbol, side, leverage).await
+    }
+}
+
 #[async_trait]
 impl TradeApi for BingxClient {
     async fn place_swap_order(
         &self,
         params: &PlaceSwapOrderParams,
     ) -> Result<ApiResponse<serde_json::Value>> {
-        let json_value = serde_json::to_value(params)?;
-        let mut order_data: HashMap<String, serde_json::Value> = HashMap::new();
-
-        if let Some(obj) = json_value.as_object() {
-            for (key, value) in obj {
-                if !value.is_null() {
-                    order_data.insert(key.clone(), value.clone());
-                }
-            }
-        }
+        let order_data = order_params(params)?;
 
         let response = self
             .post("/openApi/swap/v2/trade/order", Some(&order_data), true)
@@ -316,7 +408,7 @@ impl TradeApi for BingxClient {
             std::collections::HashMap::new();
         params.insert(
             "dualSidePosition".to_string(),
-            serde_json::Value::String(dual_side_position.to_string().to_lowercase()),
+            serde_json::Value::from(bool_str(dual_side_position)),
         );
         let response = self
             .post("/openApi/swap/v1/positionSide/dual", Some(&params), true)
@@ -408,4 +500,113 @@ impl TradeApi for BingxClient {
             .await?;
         Ok(response.into_api_response())
     }
+
+    async fn amend_swap_order(
+        &self,
+        symbol: &str,
+        order_id: Option<i64>,
+        client_order_id: Option<&str>,
+        quantity: Option<Amount>,
+        price: Option<Amount>,
+        stop_price: Option<Amount>,
+    ) -> Result<ApiResponse<serde_json::Value>> {
+        if order_id.is_none() && client_order_id.is_none() {
+            return Err(Error::Validation(
+                "At least one of order_id or client_order_id must be provided.".to_string(),
+            ));
+        }
+        if quantity.is_none() && price.is_none() && stop_price.is_none() {
+            return Err(Error::Validation(
+                "At least one of quantity, price or stop_price must be provided.".to_string(),
+            ));
+        }
+
+        let mut params: HashMap<String, serde_json::Value> = HashMap::new();
+        params.insert(
+            "symbol".to_string(),
+            serde_json::Value::String(symbol.to_string()),
+        );
+        if let Some(order_id) = order_id {
+            params.insert("orderId".to_string(), serde_json::Value::from(order_id));
+        }
+        if let Some(client_order_id) = client_order_id {
+            params.insert(
+                "clientOrderId".to_string(),
+                serde_json::Value::String(client_order_id.to_string()),
+            );
+        }
+        for (key, amount) in [
+            ("quantity", quantity),
+            ("price", price),
+            ("stopPrice", stop_price),
+        ] {
+            if let Some(amount) = amount {
+                params.insert(
+                    key.to_string(),
+                    serde_json::Value::String(amount.to_plain_string()),
+                );
+            }
+        }
+
+        let response = self
+            .post("/openApi/swap/v1/trade/modifyOrder", Some(&params), true)
+            .await?;
+        Ok(response.into_api_response())
+    }
+
+    async fn cancel_replace_swap_order(
+        &self,
+        cancel_order_id: i64,
+        new_order: &PlaceSwapOrderParams,
+        cancel_replace_mode: &str,
+    ) -> Result<ApiResponse<serde_json::Value>> {
+        if !CANCEL_REPLACE_MODES.contains(&cancel_replace_mode) {
+            return Err(Error::Validation(format!(
+                "cancel_replace_mode must be one of {CANCEL_REPLACE_MODES:?}, got {cancel_replace_mode:?}"
+            )));
+        }
+
+        let mut params = order_params(new_order)?;
+        params.insert(
+            "cancelOrderId".to_string(),
+            serde_json::Value::from(cancel_order_id),
+        );
+        params.insert(
+            "cancelReplaceMode".to_string(),
+            serde_json::Value::String(cancel_replace_mode.to_string()),
+        );
+
+        let response = self
+            .post("/openApi/swap/v1/trade/cancelReplace", Some(&params), true)
+            .await?;
+        Ok(response.into_api_response())
+    }
+
+    async fn place_swap_batch_orders(
+        &self,
+        orders: &[PlaceSwapOrderParams],
+    ) -> Result<ApiResponse<serde_json::Value>> {
+        if orders.is_empty() || orders.len() > MAX_SWAP_BATCH_ORDERS {
+            return Err(Error::Validation(format!(
+                "Batch must contain 1 to {MAX_SWAP_BATCH_ORDERS} orders, got {}",
+                orders.len()
+            )));
+        }
+        for order in orders {
+            order.validate()?;
+        }
+
+        let json_value = serde_json::to_value(BatchOrdersParams {
+            batch_orders: Some(orders),
+        })?;
+        let params: HashMap<String, serde_json::Value> = json_value
+            .as_object()
+            .map(|obj| obj.clone().into_iter().collect())
+            .unwrap_or_default();
+
+        let response = self
+            .post("/openApi/swap/v2/trade/batchOrders", Some(&params), true)
+            .await?;
+        Ok(response.into_api_response())
+    }
 }
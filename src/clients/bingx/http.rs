@@ -1,32 +1,21 @@
 //! HTTP client module for BingX API communication.
 
 use async_trait::async_trait;
-use hmac::{Hmac, Mac};
 use reqwest::Method;
-use sha2::Sha256;
 use std::collections::HashMap;
+use std::sync::atomic::{AtomicI64, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
 
+use crate::bingx::signing::{self, mask_headers, SignatureScheme};
 use crate::bingx::types::GenericResponse;
 use crate::error::{Error, ExchangeResponseError, Result};
-use crate::http::{BaseHttpClient, HttpClient, RequestArgs};
-use percent_encoding::{utf8_percent_encode, NON_ALPHANUMERIC};
-use std::collections::hash_map::Entry;
-
-/// Masks sensitive headers for logging; truncates API key/sign values for safety.
-fn mask_headers(headers: &HashMap<String, String>) -> HashMap<String, String> {
-    let mut masked = HashMap::new();
-    for (k, v) in headers.iter() {
-        let key = k.to_lowercase();
-        let value = v;
-        if key == "x-bx-apikey" {
-            let masked_val = format!("{}...", &value[..6.min(value.len())]);
-            masked.insert(key, masked_val);
-        } else {
-            masked.insert(key, value.clone());
-        }
-    }
-    masked
-}
+use crate::http::{
+    response_cache_key, status_error, BaseHttpClient, HttpClient, Observer, RequestArgs, TimeSource,
+};
+
+/// Exchange name reported to observers.
+const EXCHANGE: &str = "bingx";
 
 /// Mask the `signature` query parameter in a BingX API URL for safe logging.
 /// Replaces the signature value with "***" in the URL.
@@ -47,8 +36,13 @@ fn mask_signature(url: &str) -> String {
 }
 
 /// HTTP client for BingX API (main, testnet, demo).
+#[derive(Clone)]
 pub struct BingxHttpClient {
     base_client: BaseHttpClient,
+    broker_id: Option<String>,
+    signature_scheme: SignatureScheme,
+    /// Added to the local clock for signing timestamps; shared by clones.
+    time_offset_ms: Arc<AtomicI64>,
 }
 
 impl BingxHttpClient {
@@ -59,11 +53,13 @@ impl BingxHttpClient {
     /// * `api_secret` - Trading API secret (optional)
     /// * `demo` - Use vst (testnet) instead of mainnet
     /// * `recv_window` - Receive window in milliseconds (default 5000)
+    /// * `broker_id` - Optional broker source key, sent as `X-SOURCE-KEY`
     pub fn new(
         api_key: Option<String>,
         api_secret: Option<String>,
         demo: bool,
         recv_window: u32,
+        broker_id: Option<String>,
     ) -> Result<Self> {
         let base_url = if demo {
             "https://open-api-vst.bingx.com".to_string()
@@ -71,166 +67,422 @@ impl BingxHttpClient {
             "https://open-api.bingx.com".to_string()
         };
         let base_client = BaseHttpClient::new(base_url, api_key, api_secret, recv_window)?;
-        Ok(Self { base_client })
+        Ok(Self {
+            base_client,
+            broker_id,
+            signature_scheme: SignatureScheme::default(),
+            time_offset_ms: Arc::new(AtomicI64::new(0)),
+        })
     }
 
-    /// Bingx V5 signature (API v5).
-    fn generate_signature(
-        &self,
-        api_secret: &str,
-        payload: &str,
-    ) -> String {
-        let mut mac =
-            Hmac::<Sha256>::new_from_slice(api_secret.as_bytes()).expect("Invalid API secret");
-        mac.update(payload.as_bytes());
-        let signature = mac.finalize().into_bytes();
-        hex::encode(signature)
+    pub fn is_shared_session_enabled(&self) -> bool {
+        self.base_client.is_shared_session_enabled()
     }
 
-    /// Prepare BingX payload and URL-encoded payload, as per exchange rules.
+    /// Release the connection pool; a no-op for shared-session clients.
+    pub async fn close(self) {
+        self.base_client.close().await
+    }
+
+    pub fn set_recv_window(
+        &mut self,
+        recv_window: u32,
+    ) {
+        self.base_client.set_recv_window(recv_window)
+    }
+
+    /// Route this client through a proxy (`http://`, `https://` or `socks5://` URL).
     ///
-    /// Returns a tuple: (payload_for_signature, url_encoded_payload_for_query)
-    fn prepare_payload(
-        &self,
-        method: &Method,
-        params: &mut HashMap<String, serde_json::Value>,
-        timestamp: i64,
-    ) -> (String, Option<String>) {
-        // Helper to turn a serde_json::Value into a string for BingX params
-        // (number, float, bool, string all must stringify as normal; skip Null, convert arrays/objects as json)
-        fn value_to_str(v: &serde_json::Value) -> String {
-            match v {
-                serde_json::Value::String(s) => s.clone(),
-                serde_json::Value::Number(n) => n.to_string(),
-                serde_json::Value::Bool(b) => b.to_string(),
-                serde_json::Value::Null => "".to_string(),
-                serde_json::Value::Array(_) | serde_json::Value::Object(_) => v.to_string(),
-            }
-        }
+    /// Returns `Error::Config` if the URL is invalid.
+    pub fn with_proxy(
+        mut self,
+        url: &str,
+    ) -> Result<Self> {
+        self.base_client.set_proxy(url)?;
+        Ok(self)
+    }
 
-        if method == Method::GET {
-            // Sort params by key and check if any value contains struct/array indicators
-            let param_count = params.len();
-            let mut params_vec: Vec<(&str, String)> = Vec::with_capacity(param_count);
-            let mut contains_struct = false;
+    /// Override the base URL, e.g. to point the client at a mock server or a gateway.
+    pub fn with_base_url(
+        mut self,
+        base_url: &str,
+    ) -> Self {
+        self.base_client.set_base_url(base_url);
+        self
+    }
 
-            for (k, v) in params.iter() {
-                let s = value_to_str(v);
-                if !contains_struct && (s.contains('{') || s.contains('[')) {
-                    contains_struct = true;
-                }
-                params_vec.push((k.as_str(), s));
-            }
+    /// Sign requests for an Ed25519 or RSA API key instead of HMAC-SHA256; the API secret
+    /// must then be the private key (see [`SignatureScheme`]).
+    pub fn with_signature_scheme(
+        mut self,
+        scheme: SignatureScheme,
+    ) -> Self {
+        self.signature_scheme = scheme;
+        self
+    }
 
-            params_vec.sort_by(|a, b| a.0.cmp(b.0));
+    /// Scheme used to sign authenticated requests.
+    pub fn signature_scheme(&self) -> SignatureScheme {
+        self.signature_scheme
+    }
 
-            // Build signature string (plain, sorted, with timestamp)
-            let mut params_str = String::with_capacity(64 + param_count * 24);
-            for (i, (k, v)) in params_vec.iter().enumerate() {
-                if i > 0 {
-                    params_str.push('&');
-                }
-                params_str.push_str(k);
-                params_str.push('=');
-                params_str.push_str(v);
-            }
-            if !params_str.is_empty() {
-                params_str.push_str("&timestamp=");
-            } else {
-                params_str.push_str("timestamp=");
-            }
-            params_str.push_str(&timestamp.to_string());
+    /// Shift signing timestamps by `offset_ms` (server minus local clock) to compensate for
+    /// local clock drift. Applies to this client and its clones; 0 (the default) disables it.
+    pub fn set_time_offset_ms(
+        &self,
+        offset_ms: i64,
+    ) {
+        self.time_offset_ms.store(offset_ms, Ordering::Relaxed)
+    }
 
-            // Build URL-encoded query string if needed
-            let mut url_params_str = String::with_capacity(params_str.len());
-            for (i, (k, v)) in params_vec.iter().enumerate() {
-                if i > 0 {
-                    url_params_str.push('&');
-                }
-                url_params_str.push_str(k);
-                url_params_str.push('=');
-                if contains_struct {
-                    url_params_str.push_str(&utf8_percent_encode(v, NON_ALPHANUMERIC).to_string());
-                } else {
-                    url_params_str.push_str(v);
-                }
-            }
-            if !url_params_str.is_empty() {
-                url_params_str.push_str("&timestamp=");
-            } else {
-                url_params_str.push_str("timestamp=");
-            }
-            url_params_str.push_str(&timestamp.to_string());
+    /// Offset added to the local clock for signing timestamps, in milliseconds.
+    pub fn time_offset_ms(&self) -> i64 {
+        self.time_offset_ms.load(Ordering::Relaxed)
+    }
 
-            (params_str, Some(url_params_str))
-        } else {
-            // For POST/other: ensure "timestamp" is present (without overwriting)
-            if let Entry::Vacant(e) = params.entry("timestamp".to_owned()) {
-                e.insert(serde_json::Value::String(timestamp.to_string()));
-            }
+    /// Set the maximum accepted response body size in bytes (default 16 MiB).
+    pub fn set_max_response_bytes(
+        &mut self,
+        max_response_bytes: usize,
+    ) {
+        self.base_client.set_max_response_bytes(max_response_bytes)
+    }
 
-            // Sort params by key (as required by BingX)
-            let param_count = params.len();
-            let mut params_vec: Vec<(&str, String)> = Vec::with_capacity(param_count);
-            for (k, v) in params.iter() {
-                params_vec.push((k.as_str(), value_to_str(v)));
-            }
-            params_vec.sort_by(|a, b| a.0.cmp(b.0));
+    /// Limit the requests this client (and its clones) has in flight; further requests wait
+    /// for a free slot. `None` (the default) removes the limit, `Some(0)` is treated as 1.
+    pub fn set_max_concurrency(
+        &mut self,
+        max_concurrency: Option<usize>,
+    ) {
+        self.base_client.set_max_concurrency(max_concurrency)
+    }
 
-            // Build signature string (plain key=val pairs)
-            let mut params_str = String::with_capacity(64 + param_count * 24);
-            for (i, (k, v)) in params_vec.iter().enumerate() {
-                if i > 0 {
-                    params_str.push('&');
-                }
-                params_str.push_str(k);
-                params_str.push('=');
-                params_str.push_str(v);
-            }
-            (params_str, None)
+    /// Configured in-flight limit, `None` if unlimited.
+    pub fn max_concurrency(&self) -> Option<usize> {
+        self.base_client.max_concurrency()
+    }
+
+    /// Request slots currently free under the in-flight limit, `None` if unlimited.
+    pub fn available_request_slots(&self) -> Option<usize> {
+        self.base_client.available_request_slots()
+    }
+
+    /// GET `endpoint`, answering from memory if the same request (endpoint, params and `auth`)
+    /// succeeded within `ttl`; the client and its clones share the cache.
+    ///
+    /// Opt-in per call and meant for data that rarely changes, such as instruments or fee
+    /// rates. Errors are not cached.
+    pub async fn get_cached(
+        &self,
+        endpoint: &str,
+        params: Option<&HashMap<String, serde_json::Value>>,
+        auth: bool,
+        ttl: Duration,
+    ) -> Result<GenericResponse> {
+        let key = response_cache_key(endpoint, params, auth);
+        if let Some(response) = self.base_client.cached_response(&key) {
+            return Ok(response);
         }
+        let response = self.get(endpoint, params, auth).await?;
+        self.base_client.cache_response(key, &response, ttl);
+        Ok(response)
     }
 
-    pub fn is_shared_session_enabled(&self) -> bool {
-        self.base_client.is_shared_session_enabled()
+    /// Drop all responses cached by [`Self::get_cached`].
+    pub fn clear_response_cache(&self) {
+        self.base_client.clear_response_cache()
     }
 
-    pub fn set_recv_window(
+    /// Proxy URL used by this client, if any.
+    pub fn proxy(&self) -> Option<String> {
+        self.base_client.proxy()
+    }
+
+    /// Register an observer notified around every request of this client.
+    pub fn set_observer(
         &mut self,
-        recv_window: u32,
+        observer: Arc<dyn Observer>,
     ) {
-        self.base_client.set_recv_window(recv_window)
+        self.base_client.set_observer(observer)
     }
-}
 
-#[async_trait]
-impl HttpClient<GenericResponse> for BingxHttpClient {
-    async fn build_request_args(
+    /// Log exchange errors at the level `filter` returns for their code instead of `error`.
+    pub fn set_error_log_filter(
+        &mut self,
+        filter: impl Fn(i64) -> log::Level + Send + Sync + 'static,
+    ) {
+        self.base_client.set_error_log_filter(filter)
+    }
+
+    /// Level an exchange error with return code `code` is logged at.
+    pub fn error_log_level(
+        &self,
+        code: i64,
+    ) -> log::Level {
+        self.base_client.error_log_level(code)
+    }
+
+    /// Replace the clock used for signing timestamps (the system clock by default); shared
+    /// by clones made afterwards.
+    pub fn set_time_source(
+        &mut self,
+        time_source: Arc<dyn TimeSource>,
+    ) {
+        self.base_client.set_time_source(time_source)
+    }
+
+    /// Current time of the client's clock, in ms since the Unix epoch.
+    pub fn now_ms(&self) -> i64 {
+        self.base_client.now_ms()
+    }
+
+    /// Register a sink receiving an audit record (signature masked) for every request.
+    #[cfg(feature = "audit")]
+    pub fn set_audit_sink(
+        &mut self,
+        sink: Arc<dyn crate::audit::AuditSink>,
+    ) {
+        self.base_client.set_audit_sink(sink)
+    }
+
+    /// Stored API key and secret; `Error::Auth` if either is missing.
+    fn stored_credentials(&self) -> Result<(&str, &str)> {
+        let api_key = self.base_client.api_key.as_deref().ok_or_else(|| {
+            Error::Auth("API key must be set for authenticated requests.".to_string())
+        })?;
+        let api_secret = self.base_client.api_secret.as_deref().ok_or_else(|| {
+            Error::Auth("API secret must be set for authenticated requests.".to_string())
+        })?;
+        Ok((api_key, api_secret))
+    }
+
+    /// Send a GET request signed with `credentials` (`(api_key, api_secret)`) instead of the
+    /// stored key, e.g. for a one-off call on behalf of a sub-account. `None` signs with the
+    /// stored key.
+    pub async fn get_with_auth(
+        &self,
+        endpoint: &str,
+        params: Option<&HashMap<String, serde_json::Value>>,
+        credentials: Option<(&str, &str)>,
+    ) -> Result<GenericResponse> {
+        self.request_with_auth(Method::GET, endpoint, params, credentials)
+            .await
+    }
+
+    /// Send a POST request signed with `credentials`, see [`Self::get_with_auth`].
+    pub async fn post_with_auth(
+        &self,
+        endpoint: &str,
+        params: Option<&HashMap<String, serde_json::Value>>,
+        credentials: Option<(&str, &str)>,
+    ) -> Result<GenericResponse> {
+        self.request_with_auth(Method::POST, endpoint, params, credentials)
+            .await
+    }
+
+    /// Send a PUT request signed with `credentials`, see [`Self::get_with_auth`].
+    pub async fn put_with_auth(
+        &self,
+        endpoint: &str,
+        params: Option<&HashMap<String, serde_json::Value>>,
+        credentials: Option<(&str, &str)>,
+    ) -> Result<GenericResponse> {
+        self.request_with_auth(Method::PUT, endpoint, params, credentials)
+            .await
+    }
+
+    /// Send a DELETE request signed with `credentials`, see [`Self::get_with_auth`].
+    pub async fn delete_with_auth(
+        &self,
+        endpoint: &str,
+        params: Option<&HashMap<String, serde_json::Value>>,
+        credentials: Option<(&str, &str)>,
+    ) -> Result<GenericResponse> {
+        self.request_with_auth(Method::DELETE, endpoint, params, credentials)
+            .await
+    }
+
+    async fn request_with_auth(
+        &self,
+        method: Method,
+        endpoint: &str,
+        params: Option<&HashMap<String, serde_json::Value>>,
+        credentials: Option<(&str, &str)>,
+    ) -> Result<GenericResponse> {
+        let credentials = match credentials {
+            Some(credentials) => credentials,
+            None => self.stored_credentials()?,
+        };
+        self.request(method, endpoint, params, Some(credentials))
+            .await
+    }
+
+    /// Send a request, signed with `credentials` if given.
+    async fn request(
+        &self,
+        method: Method,
+        endpoint: &str,
+        params: Option<&HashMap<String, serde_json::Value>>,
+        credentials: Option<(&str, &str)>,
+    ) -> Result<GenericResponse> {
+        // Wait for a slot first, so queued requests are not counted as in flight.
+        let _permit = self.base_client.acquire_request_permit().await;
+        // Shared-pool requests are counted until they complete or are cancelled, so
+        // `SharedSessionManager::close()` can wait for them.
+        let _in_flight = self.base_client.in_flight_guard();
+        let request = self.execute_request(method.clone(), endpoint, params, credentials);
+        #[cfg(feature = "tracing")]
+        let request = tracing::Instrument::instrument(
+            request,
+            crate::http::request_span(EXCHANGE, &method, endpoint),
+        );
+        request.await
+    }
+
+    /// Build, send and check a request, notifying the observer around it.
+    async fn execute_request(
+        &self,
+        method: Method,
+        endpoint: &str,
+        params: Option<&HashMap<String, serde_json::Value>>,
+        credentials: Option<(&str, &str)>,
+    ) -> Result<GenericResponse> {
+        let request_args = self.request_args(method.clone(), endpoint, params, credentials)?;
+
+        let started = self.base_client.notify_request(EXCHANGE, &method, endpoint);
+        let mut status_code = None;
+        let result = self
+            .send_request(&method, &request_args, &mut status_code)
+            .await;
+        let ret_code = match &result {
+            Ok(response) => Some(i64::from(response.code)),
+            Err(e) => e.exchange_code(),
+        };
+        self.base_client.notify_response(
+            started,
+            EXCHANGE,
+            &method,
+            endpoint,
+            status_code,
+            ret_code,
+        );
+        #[cfg(feature = "tracing")]
+        crate::http::trace_outcome(
+            &mask_signature(&request_args.url),
+            status_code,
+            ret_code,
+            &result,
+        );
+        #[cfg(feature = "audit")]
+        if let Some(sink) = self.base_client.audit_sink() {
+            sink.record(&crate::audit::AuditRecord {
+                timestamp_ms: self.base_client.now_ms(),
+                exchange: EXCHANGE,
+                method: &method,
+                endpoint,
+                url: crate::audit::mask_url(&request_args.url),
+                body: request_args.json.as_ref().map(crate::audit::mask_body),
+                status: status_code,
+                ret_code,
+                response: match &result {
+                    Ok(response) => Some(serde_json::json!({
+                        "code": response.code,
+                        "msg": response.msg,
+                        "data": response.data,
+                    })),
+                    Err(Error::Exchange(e)) => Some(e.raw().clone()),
+                    Err(_) => None,
+                },
+                error: result.as_ref().err().map(ToString::to_string),
+            });
+        }
+        result
+    }
+
+    /// Send a prepared request and check the HTTP status and exchange return code.
+    ///
+    /// `status_code` is set as soon as a response is received.
+    async fn send_request(
+        &self,
+        method: &Method,
+        request_args: &RequestArgs,
+        status_code: &mut Option<u16>,
+    ) -> Result<GenericResponse> {
+        let mut request = self
+            .base_client
+            .http_client()
+            .request(method.clone(), &request_args.url);
+
+        if let Some(json) = &request_args.json {
+            request = request.json(json);
+        }
+
+        for (k, v) in &request_args.headers {
+            request = request.header(k, v);
+        }
+
+        let response = request.send().await.map_err(Error::Http)?;
+        let status = response.status();
+        *status_code = Some(status.as_u16());
+
+        if !status.is_success() {
+            log::error!(
+                "HTTP error during async request: method={}, url={}, headers={:?}, status={}, response={:?}",
+                method,
+                mask_signature(&request_args.url),
+                mask_headers(&request_args.headers),
+                status,
+                &response
+            );
+            return Err(status_error(response));
+        }
+
+        // First parse the response as serde_json::Value
+        let value = self.base_client.read_json(response).await?;
+        let ret_code = value.get("code").and_then(|v| v.as_i64()).unwrap_or(0);
+
+        if ret_code != 0 {
+            let err = ExchangeResponseError::from(value);
+            log::log!(
+                self.base_client.error_log_level(err.code()),
+                "ExchangeResponseError during async request: method={}, url={}, headers={:?}, status={}, error={}",
+                method,
+                mask_signature(&request_args.url),
+                mask_headers(&request_args.headers),
+                status,
+                err
+            );
+            return Err(Error::Exchange(err));
+        }
+
+        // It's ok, parse as GenericResponse
+        let generic: GenericResponse = serde_json::from_value(value).map_err(Error::Json)?;
+        Ok(generic)
+    }
+
+    /// Build the URL, body and headers of a request, signed with `credentials` if given.
+    fn request_args(
         &self,
         method: reqwest::Method,
         endpoint: &str,
         params: Option<&HashMap<String, serde_json::Value>>,
-        auth: bool,
+        credentials: Option<(&str, &str)>,
     ) -> Result<RequestArgs> {
         // Clone input parameters if any, else create empty HashMap
         let mut params = params.cloned().unwrap_or_else(HashMap::new);
         let mut headers = HashMap::new();
 
-        // Get timestamp in ms since Unix epoch
-        let timestamp = (std::time::SystemTime::now()
-            .duration_since(std::time::UNIX_EPOCH)
-            .unwrap()
-            .as_millis()) as i64;
+        // Get timestamp in ms since Unix epoch, corrected by the server time offset
+        let timestamp = self.base_client.now_ms() + self.time_offset_ms();
 
         // Insert API key header if auth
-        if auth {
-            let api_key = self.base_client.api_key.as_ref().ok_or_else(|| {
-                Error::Auth("API key must be set for authenticated requests.".to_string())
-            })?;
-            self.base_client.api_secret.as_ref().ok_or_else(|| {
-                Error::Auth("API secret must be set for authenticated requests.".to_string())
-            })?;
-            headers.insert("X-BX-APIKEY".to_owned(), api_key.clone());
+        if let Some((api_key, _)) = credentials {
+            headers.insert("X-BX-APIKEY".to_owned(), api_key.to_owned());
+        }
+
+        // Add broker header if present
+        if let Some(ref broker_id) = self.broker_id {
+            headers.insert("X-SOURCE-KEY".to_owned(), broker_id.clone());
         }
 
         // Always insert recvWindow from base config
@@ -240,17 +492,13 @@ impl HttpClient<GenericResponse> for BingxHttpClient {
         );
 
         // Prepare signature string and url-filtered params
-        let (req_payload, req_url_params) = self.prepare_payload(&method, &mut params, timestamp);
+        let (req_payload, req_url_params) =
+            signing::prepare_payload(&method, &mut params, timestamp);
 
         // Generate signature if auth required
-        let signature = if auth {
-            let api_secret = self.base_client.api_secret.as_ref().ok_or_else(|| {
-                Error::Auth("API secret must be set for authenticated requests.".to_string())
-            })?;
-            Some(self.generate_signature(api_secret, &req_payload))
-        } else {
-            None
-        };
+        let signature = credentials
+            .map(|(_, api_secret)| signing::sign(self.signature_scheme, api_secret, &req_payload))
+            .transpose()?;
 
         let base_req_url = format!("{}{}", self.base_client.base_url, endpoint);
 
@@ -267,7 +515,7 @@ impl HttpClient<GenericResponse> for BingxHttpClient {
                 } else {
                     req_url.push_str("?signature=");
                 }
-                req_url.push_str(signature);
+                req_url.push_str(&signing::encode_query_value(signature));
             }
             (req_url, None)
         } else {
@@ -306,6 +554,24 @@ impl HttpClient<GenericResponse> for BingxHttpClient {
             data: None,
         })
     }
+}
+
+#[async_trait]
+impl HttpClient<GenericResponse> for BingxHttpClient {
+    async fn build_request_args(
+        &self,
+        method: reqwest::Method,
+        endpoint: &str,
+        params: Option<&HashMap<String, serde_json::Value>>,
+        auth: bool,
+    ) -> Result<RequestArgs> {
+        let credentials = if auth {
+            Some(self.stored_credentials()?)
+        } else {
+            None
+        };
+        self.request_args(method, endpoint, params, credentials)
+    }
 
     async fn async_request(
         &self,
@@ -314,57 +580,11 @@ impl HttpClient<GenericResponse> for BingxHttpClient {
         params: Option<&HashMap<String, serde_json::Value>>,
         auth: bool,
     ) -> Result<GenericResponse> {
-        let request_args = self
-            .build_request_args(method.clone(), endpoint, params, auth)
-            .await?;
-
-        let mut request = self
-            .base_client
-            .client
-            .request(method.clone(), &request_args.url);
-
-        if let Some(json) = &request_args.json {
-            request = request.json(json);
-        }
-
-        for (k, v) in &request_args.headers {
-            request = request.header(k, v);
-        }
-
-        let response = request.send().await.map_err(Error::Http)?;
-        let status = response.status();
-
-        if !status.is_success() {
-            log::error!(
-                "HTTP error during async request: method={}, url={}, headers={:?}, status={}, response={:?}",
-                method,
-                mask_signature(&request_args.url),
-                mask_headers(&request_args.headers),
-                status,
-                &response
-            );
-            return Err(Error::Http(response.error_for_status().unwrap_err()));
-        }
-
-        // First parse the response as serde_json::Value
-        let value: serde_json::Value = response.json().await.map_err(Error::Http)?;
-        let ret_code = value.get("code").and_then(|v| v.as_i64()).unwrap_or(0);
-
-        if ret_code != 0 {
-            let err = ExchangeResponseError::from(value);
-            log::error!(
-                "ExchangeResponseError during async request: method={}, url={}, headers={:?}, status={}, error={}",
-                method,
-                mask_signature(&request_args.url),
-                mask_headers(&request_args.headers),
-                status,
-                err
-            );
-            return Err(Error::Exchange(err));
-        }
-
-        // It's ok, parse as GenericResponse
-        let generic: GenericResponse = serde_json::from_value(value).map_err(Error::Json)?;
-        Ok(generic)
+        let credentials = if auth {
+            Some(self.stored_credentials()?)
+        } else {
+            None
+        };
+        self.request(method, endpoint, params, credentials).await
     }
 }
@@ -1,32 +1,167 @@
 //! Bingx Trading API Client with all available methods.
 mod api;
 mod http;
+pub mod models;
+pub mod signing;
 pub mod traits;
 pub mod types;
 
-use crate::error::Result;
+use std::collections::HashMap;
+
+use async_trait::async_trait;
+use reqwest::Method;
+use serde_json::Value;
+use tokio_util::sync::CancellationToken;
+
+use crate::error::{Error, Result};
+use crate::http::{DynHttpClient, DynResponse, HttpClient};
+use crate::utils::LeverageLimits;
 use http::BingxHttpClient;
 use linkme::distributed_slice;
+use traits::swap::AccountApi as _;
+use types::ApiResponse;
+
+pub use signing::SignatureScheme;
 
 #[distributed_slice]
 pub static BINGX_IMPLEMENTED: [&'static str];
 
 /// Bybit Trading API Client with all available methods.
+///
+/// Cloning is cheap: clones share the connection pool and credentials.
+#[derive(Clone)]
 pub struct BingxClient {
     http_client: BingxHttpClient,
+    demo: bool,
+    leverage_limits: LeverageLimits,
 }
 
 impl BingxClient {
     /// Create a new Bybit client
+    ///
+    /// `broker_id` is the BingX broker program source key, sent as `X-SOURCE-KEY`.
     pub fn new(
         api_key: Option<String>,
         api_secret: Option<String>,
 
         demo: bool,
         recv_window: u32,
+        broker_id: Option<String>,
+    ) -> Result<Self> {
+        let http_client = BingxHttpClient::new(api_key, api_secret, demo, recv_window, broker_id)?;
+        Ok(Self {
+            http_client,
+            demo,
+            leverage_limits: LeverageLimits::default(),
+        })
+    }
+
+    /// Whether the client was created for demo trading (VST funds on `open-api-vst.bingx.com`).
+    pub fn is_demo(&self) -> bool {
+        self.demo
+    }
+
+    /// Get the VST (demo) perpetual swap balance.
+    ///
+    /// Returns `Error::Config` on a live client, so demo and live funds are never mixed up.
+    pub async fn get_vst_balance(&self) -> Result<ApiResponse<Value>> {
+        if !self.demo {
+            return Err(Error::Config(
+                "get_vst_balance requires a demo client; create it with demo = true".to_string(),
+            ));
+        }
+        self.get_swap_balance().await
+    }
+
+    /// Override the base URL, e.g. to point the client at a mock server or a gateway.
+    pub fn with_base_url(
+        self,
+        base_url: &str,
+    ) -> Self {
+        Self {
+            http_client: self.http_client.with_base_url(base_url),
+            ..self
+        }
+    }
+
+    /// Sign requests for an Ed25519 or RSA API key instead of HMAC-SHA256; pass the private
+    /// key as `api_secret` (see [`SignatureScheme`]).
+    pub fn with_signature_scheme(
+        self,
+        scheme: SignatureScheme,
+    ) -> Self {
+        Self {
+            http_client: self.http_client.with_signature_scheme(scheme),
+            ..self
+        }
+    }
+
+    /// Route this client through a proxy (`http://`, `https://` or `socks5://` URL).
+    ///
+    /// Returns `Error::Config` if the URL is invalid.
+    pub fn with_proxy(
+        self,
+        url: &str,
     ) -> Result<Self> {
-        let http_client = BingxHttpClient::new(api_key, api_secret, demo, recv_window)?;
-        Ok(Self { http_client })
+        Ok(Self {
+            http_client: self.http_client.with_proxy(url)?,
+            ..self
+        })
+    }
+
+    /// Send a request to any endpoint, including ones the SDK does not wrap yet.
+    ///
+    /// `params` are flattened (with `recvWindow` and `timestamp` added) and, with `auth`,
+    /// signed like every typed endpoint. The `data` payload is returned as-is.
+    pub async fn request_raw(
+        &self,
+        method: Method,
+        endpoint: &str,
+        params: Option<&HashMap<String, Value>>,
+        auth: bool,
+    ) -> Result<ApiResponse<Value>> {
+        let response = self
+            .http_client
+            .async_request(method, endpoint, params, auth)
+            .await?;
+        Ok(response.into_api_response())
+    }
+
+    /// [`Self::request_raw`] that is abandoned with [`crate::Error::Cancelled`] when `cancel`
+    /// fires before the response arrives.
+    pub async fn request_raw_cancellable(
+        &self,
+        method: Method,
+        endpoint: &str,
+        params: Option<&HashMap<String, Value>>,
+        auth: bool,
+        cancel: &CancellationToken,
+    ) -> Result<ApiResponse<Value>> {
+        let response = self
+            .http_client
+            .async_request_cancellable(method, endpoint, params, auth, cancel)
+            .await?;
+        Ok(response.into_api_response())
+    }
+
+    /// Close the client, draining its own connection pool.
+    ///
+    /// Clients on the shared session leave the pool open; close it with
+    /// [`crate::SharedSessionManager::close`].
+    pub async fn close(self) {
+        self.http_client.close().await
+    }
+
+    /// Whether `name` (a trait method such as `"place_order"`) is implemented rather than a stub.
+    pub fn is_implemented(name: &str) -> bool {
+        BINGX_IMPLEMENTED.contains(&name)
+    }
+
+    /// Names of all implemented methods, sorted.
+    pub fn implemented_methods() -> Vec<&'static str> {
+        let mut methods = BINGX_IMPLEMENTED.to_vec();
+        methods.sort_unstable();
+        methods
     }
 }
 
@@ -37,3 +172,32 @@ impl std::ops::Deref for BingxClient {
         &self.http_client
     }
 }
+
+impl std::ops::DerefMut for BingxClient {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.http_client
+    }
+}
+
+#[async_trait]
+impl DynHttpClient for BingxClient {
+    fn exchange(&self) -> &'static str {
+        "bingx"
+    }
+
+    async fn request_dyn(
+        &self,
+        method: Method,
+        endpoint: &str,
+        params: Option<&HashMap<String, Value>>,
+        auth: bool,
+    ) -> Result<DynResponse> {
+        let response = self.request_raw(method, endpoint, params, auth).await?;
+        Ok(DynResponse {
+            exchange: "bingx",
+            code: i64::from(response.code),
+            msg: response.msg,
+            data: response.data,
+        })
+    }
+}
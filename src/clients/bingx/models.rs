@@ -0,0 +1,79 @@
+//! Typed models for BingX responses.
+//!
+//! BingX sends prices and quantities as strings (`"0.0010"`); these models convert them into
+//! [`Amount`] so callers don't parse them by hand.
+
+use serde::Deserialize;
+
+use crate::utils::{amount_from_str, id_from_str_or_int, Amount};
+
+/// Perpetual swap order, as returned by the swap order history, details and open orders
+/// endpoints.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct SwapOrder {
+    /// Order ID; BingX sends it as a number, kept as a string to avoid precision loss.
+    #[serde(deserialize_with = "id_from_str_or_int")]
+    pub order_id: String,
+    pub symbol: String,
+    /// `BUY` or `SELL`.
+    pub side: String,
+    /// `LONG`, `SHORT` or `BOTH`.
+    pub position_side: String,
+    /// Limit price, zero for market orders.
+    #[serde(deserialize_with = "amount_from_str")]
+    pub price: Amount,
+    #[serde(deserialize_with = "amount_from_str")]
+    pub orig_qty: Amount,
+    #[serde(deserialize_with = "amount_from_str")]
+    pub executed_qty: Amount,
+    /// Average fill price, zero if nothing was filled.
+    #[serde(deserialize_with = "amount_from_str")]
+    pub avg_price: Amount,
+    /// `NEW`, `PARTIALLY_FILLED`, `FILLED`, `CANCELLED`, ...
+    pub status: String,
+    /// `LIMIT`, `MARKET`, `STOP_MARKET`, ...
+    #[serde(rename = "type")]
+    pub order_type: String,
+}
+
+/// One asset of the spot account.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct SpotAsset {
+    /// Coin name, e.g. `USDT`.
+    pub asset: String,
+    /// Available balance.
+    #[serde(deserialize_with = "amount_from_str")]
+    pub free: Amount,
+    /// Balance held by open orders.
+    #[serde(deserialize_with = "amount_from_str")]
+    pub locked: Amount,
+}
+
+/// Spot account assets, as returned by `get_spot_account_assets`.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct SpotBalances {
+    pub balances: Vec<SpotAsset>,
+}
+
+impl SpotBalances {
+    /// Entry for `asset` (case-insensitive), `None` if the account holds none.
+    pub fn get(
+        &self,
+        asset: &str,
+    ) -> Option<&SpotAsset> {
+        self.balances
+            .iter()
+            .find(|a| a.asset.eq_ignore_ascii_case(asset))
+    }
+
+    /// Available balance of `asset`, zero if the account holds none.
+    pub fn free(
+        &self,
+        asset: &str,
+    ) -> Amount {
+        self.get(asset).map(|a| a.free).unwrap_or_default()
+    }
+}
@@ -1,4 +1,53 @@
+use crate::{
+    bingx::types::{AccountType, ApiResponse},
+    error::Result,
+};
 use async_trait::async_trait;
+use serde_json::Value;
 
 #[async_trait]
-pub trait FundApi {}
+pub trait FundApi {
+    /// Transfer an asset between own accounts (e.g. spot -> USDT-M perpetual).
+    ///
+    /// POST /openApi/api/v3/asset/transfer
+    ///
+    /// https://bingx-api.github.io/docs-v3/#/en/Account%20and%20Wallet/Fund%20Account/Asset%20Transfer
+    ///
+    /// # Arguments
+    /// * `from` - Source account (`Spot`, `StdFutures` or `UsdtMPerp`).
+    /// * `to` - Destination account (`Spot`, `StdFutures` or `UsdtMPerp`).
+    /// * `asset` - Coin name, e.g. "USDT".
+    /// * `amount` - Transfer amount, must be greater than 0.
+    async fn universal_transfer(
+        &self,
+        from: AccountType,
+        to: AccountType,
+        asset: &str,
+        amount: f64,
+    ) -> Result<ApiResponse<Value>>;
+
+    /// Query universal transfer records for a transfer direction.
+    ///
+    /// GET /openApi/api/v3/asset/transfer
+    ///
+    /// https://bingx-api.github.io/docs-v3/#/en/Account%20and%20Wallet/Fund%20Account/Asset%20transfer%20records
+    ///
+    /// # Arguments
+    /// * `from` - Source account of the transfers.
+    /// * `to` - Destination account of the transfers.
+    /// * `tran_id` - Optional transaction ID.
+    /// * `start_time` - Optional start timestamp (ms).
+    /// * `end_time` - Optional end timestamp (ms).
+    /// * `current` - Optional page number (default 1).
+    /// * `size` - Optional page size (default 10, max 100).
+    async fn get_transfer_records(
+        &self,
+        from: AccountType,
+        to: AccountType,
+        tran_id: Option<i64>,
+        start_time: Option<i64>,
+        end_time: Option<i64>,
+        current: Option<u32>,
+        size: Option<u32>,
+    ) -> Result<ApiResponse<Value>>;
+}
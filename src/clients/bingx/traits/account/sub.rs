@@ -9,4 +9,72 @@ pub trait SubAccountApi {
     ///
     /// https://bingx-api.github.io/docs-v3/#/en/Account%20and%20Wallet/Sub-account%20Management/%20Query%20API%20KEY%20Permissions
     async fn get_api_permissions(&self) -> Result<ApiResponse<serde_json::Value>>;
+
+    /// Create a sub-account (master account only).
+    ///
+    /// POST /openApi/subAccount/v1/create
+    ///
+    /// https://bingx-api.github.io/docs-v3/#/en/Account%20and%20Wallet/Sub-account%20Management/Create%20sub-account
+    ///
+    /// # Arguments
+    /// * `sub_account_string` - Sub-account username (letters and digits, 6-20 chars).
+    /// * `note` - Optional note.
+    async fn create_sub_account(
+        &self,
+        sub_account_string: &str,
+        note: Option<&str>,
+    ) -> Result<ApiResponse<serde_json::Value>>;
+
+    /// Query the sub-account list (master account only).
+    ///
+    /// GET /openApi/subAccount/v1/list
+    ///
+    /// https://bingx-api.github.io/docs-v3/#/en/Account%20and%20Wallet/Sub-account%20Management/Query%20sub-account%20list
+    ///
+    /// # Arguments
+    /// * `page` - Page number, starting at 1.
+    /// * `limit` - Page size (1-1000).
+    /// * `sub_uid` - Optional sub-account UID filter.
+    /// * `sub_account_string` - Optional sub-account username filter.
+    /// * `is_freeze` - Optional: only frozen (`true`) or active (`false`) sub-accounts.
+    async fn query_sub_account_list(
+        &self,
+        page: u32,
+        limit: u32,
+        sub_uid: Option<i64>,
+        sub_account_string: Option<&str>,
+        is_freeze: Option<bool>,
+    ) -> Result<ApiResponse<serde_json::Value>>;
+
+    /// Create an API key for a sub-account (master account only).
+    ///
+    /// POST /openApi/subAccount/v1/apiKey/create
+    ///
+    /// https://bingx-api.github.io/docs-v3/#/en/Account%20and%20Wallet/Sub-account%20Management/Create%20API%20KEY%20for%20sub-account
+    ///
+    /// # Arguments
+    /// * `sub_uid` - Sub-account UID.
+    /// * `note` - Note for the API key.
+    /// * `permissions` - Permission IDs (1: spot trading, 2: read, 3: perpetual trading, 4: universal transfer, ...).
+    /// * `ip` - Optional IP whitelist, comma separated.
+    async fn create_sub_account_api_key(
+        &self,
+        sub_uid: i64,
+        note: &str,
+        permissions: &[i32],
+        ip: Option<&str>,
+    ) -> Result<ApiResponse<serde_json::Value>>;
+
+    /// Query the spot assets of a sub-account (master account only).
+    ///
+    /// GET /openApi/subAccount/v1/assets
+    ///
+    /// https://bingx-api.github.io/docs-v3/#/en/Account%20and%20Wallet/Sub-account%20Management/Query%20sub-account%20spot%20assets
+    ///
+    /// # Arguments
+    /// * `sub_uid` - Sub-account UID.
+    async fn query_sub_account_assets(
+        &self,
+        sub_uid: i64,
+    ) -> Result<ApiResponse<serde_json::Value>>;
 }
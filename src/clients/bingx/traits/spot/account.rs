@@ -9,6 +9,8 @@ use serde_json::Value;
 pub trait AccountApi {
     /// Get spot account balances from BingX API.
     ///
+    /// Use `parse_spot_balances()` on the response for typed balances.
+    ///
     /// See:
     /// https://bingx-api.github.io/docs-v3/#/en/Spot/Account%20Endpoints/Query%20Assets
     async fn get_spot_account_assets(&self) -> Result<ApiResponse<Value>>;
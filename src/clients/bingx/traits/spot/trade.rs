@@ -1,8 +1,22 @@
-use crate::bingx::types::{ApiResponse, SpotOrderStatus, SpotOrderType};
+use crate::bingx::types::{ApiResponse, PlaceSpotOrderParams, SpotOrderStatus, SpotOrderType};
 use crate::error::Result;
 
 #[async_trait::async_trait]
 pub trait TradeApi {
+    /// Place a new spot order.
+    ///
+    /// Endpoint: POST /openApi/spot/v1/trade/order
+    /// Docs: https://bingx-api.github.io/docs-v3/#/en/Spot/Trades%20Endpoints/Place%20order
+    ///
+    /// - `params.symbol` must contain a hyphen, e.g. "BTC-USDT".
+    /// - Fields left as `None` are not sent.
+    /// - UID Rate Limit: 5/second.
+    /// - Signature is required.
+    async fn place_spot_order(
+        &self,
+        params: &PlaceSpotOrderParams,
+    ) -> Result<ApiResponse<serde_json::Value>>;
+
     /// Retrieve the order history for BingX spot trading.
     ///
     /// Endpoint: GET /openApi/spot/v1/trade/historyOrders
@@ -1 +1,69 @@
-pub trait WalletApi {}
+use crate::{bingx::types::ApiResponse, error::Result};
+use async_trait::async_trait;
+use serde_json::Value;
+
+#[async_trait]
+pub trait WalletApi {
+    /// Get the main account deposit address for a coin.
+    ///
+    /// GET /openApi/wallets/v1/capital/deposit/address
+    ///
+    /// See: https://bingx-api.github.io/docs-v3/#/en/Wallet%20deposits%20and%20withdrawals/Main%20Account%20Deposit%20Address
+    ///
+    /// # Arguments
+    /// * `coin` - Coin name, e.g. "USDT".
+    /// * `network` - Optional network, e.g. "TRC20". If omitted, addresses for all networks are returned.
+    async fn get_deposit_address(
+        &self,
+        coin: &str,
+        network: Option<&str>,
+    ) -> Result<ApiResponse<Value>>;
+
+    /// Submit a withdrawal from the fund (spot) account.
+    ///
+    /// POST /openApi/wallets/v1/capital/withdraw/apply
+    ///
+    /// See: https://bingx-api.github.io/docs-v3/#/en/Wallet%20deposits%20and%20withdrawals/Withdraw
+    ///
+    /// # Arguments
+    /// * `coin` - Coin name, e.g. "USDT".
+    /// * `address` - Destination address.
+    /// * `amount` - Withdrawal amount, must be greater than 0.
+    /// * `network` - Network name, e.g. "TRC20". Must not be empty.
+    /// * `address_tag` - Optional tag/memo for coins that require it.
+    ///
+    /// Returns `Error::Validation` if `amount` is not positive or `network` is empty.
+    async fn withdraw(
+        &self,
+        coin: &str,
+        address: &str,
+        amount: f64,
+        network: &str,
+        address_tag: Option<&str>,
+    ) -> Result<ApiResponse<Value>>;
+
+    /// Query withdrawal history.
+    ///
+    /// GET /openApi/api/v3/capital/withdraw/history
+    ///
+    /// See: https://bingx-api.github.io/docs-v3/#/en/Wallet%20deposits%20and%20withdrawals/Withdraw%20records
+    ///
+    /// # Arguments
+    /// * `coin` - Optional coin filter.
+    /// * `withdraw_order_id` - Optional custom withdrawal ID.
+    /// * `status` - Optional status (4: under review, 5: failed, 6: completed).
+    /// * `start_time` - Optional start timestamp (ms).
+    /// * `end_time` - Optional end timestamp (ms).
+    /// * `offset` - Optional offset (default 0).
+    /// * `limit` - Optional page size (default 1000, max 1000).
+    async fn get_withdraw_history(
+        &self,
+        coin: Option<&str>,
+        withdraw_order_id: Option<&str>,
+        status: Option<i32>,
+        start_time: Option<i64>,
+        end_time: Option<i64>,
+        offset: Option<u32>,
+        limit: Option<u32>,
+    ) -> Result<ApiResponse<Value>>;
+}
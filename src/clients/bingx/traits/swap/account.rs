@@ -32,5 +32,37 @@ pub trait AccountApi {
     ///
     /// # Returns
     /// Returns an `ApiResponse` containing account balance data.
-    async fn get_swap_account_balance(&self) -> Result<ApiResponse<serde_json::Value>>;
+    async fn get_swap_balance(&self) -> Result<ApiResponse<serde_json::Value>>;
+
+    /// Retrieve user's Perpetual Swap account balance.
+    ///
+    /// Former name of [`AccountApi::get_swap_balance`], kept as an alias.
+    #[deprecated(note = "renamed to `get_swap_balance`")]
+    async fn get_swap_account_balance(&self) -> Result<ApiResponse<serde_json::Value>> {
+        self.get_swap_balance().await
+    }
+
+    /// Retrieve Perpetual Swap income history (realized PnL, funding fees, trading fees, ...).
+    ///
+    /// GET /openApi/swap/v2/user/income
+    ///
+    /// [BingX API Documentation - Get Fund Flow](https://bingx-api.github.io/docs-v3/#/en/Swap/Account%20Endpoints/Get%20Fund%20Flow)
+    ///
+    /// # Arguments
+    /// * `symbol` - Optionally filter by symbol (e.g., "BTC-USDT").
+    /// * `income_type` - Optional income type, e.g. "FUNDING_FEE", "TRADING_FEE", "REALIZED_PNL".
+    /// * `start_time` - Optional start timestamp (ms).
+    /// * `end_time` - Optional end timestamp (ms).
+    /// * `limit` - Optional number of records (default 100, max 1000).
+    ///
+    /// # Returns
+    /// Returns an `ApiResponse` containing income records.
+    async fn get_swap_income_history(
+        &self,
+        symbol: Option<&str>,
+        income_type: Option<&str>,
+        start_time: Option<i64>,
+        end_time: Option<i64>,
+        limit: Option<u32>,
+    ) -> Result<ApiResponse<serde_json::Value>>;
 }
@@ -24,6 +24,40 @@ pub trait MarketApi {
         symbol: Option<&str>,
     ) -> Result<ApiResponse<serde_json::Value>>;
 
+    /// Retrieve the order book depth for a BingX Perpetual Swap contract.
+    ///
+    /// GET /openApi/swap/v2/quote/depth
+    ///
+    /// [BingX API Documentation - Order Book](https://bingx-api.github.io/docs-v3/#/en/Swap/Market%20Data/Order%20Book)
+    ///
+    /// # Arguments
+    /// * `symbol` - Trading pair symbol (e.g., "BTC-USDT"), must contain a hyphen.
+    /// * `limit` - Optional depth (5, 10, 20, 50, 100, 500, 1000; default 20).
+    ///
+    /// # Returns
+    /// Returns an `ApiResponse` containing bids and asks.
+    async fn get_swap_depth(
+        &self,
+        symbol: &str,
+        limit: Option<u32>,
+    ) -> Result<ApiResponse<serde_json::Value>>;
+
+    /// Retrieve 24hr price change statistics for BingX Perpetual Swap contracts.
+    ///
+    /// GET /openApi/swap/v2/quote/ticker
+    ///
+    /// [BingX API Documentation - 24hr Ticker Price Change Statistics](https://bingx-api.github.io/docs-v3/#/en/Swap/Market%20Data/24hr%20Ticker%20Price%20Change%20Statistics)
+    ///
+    /// # Arguments
+    /// * `symbol` - Optionally filter by symbol (e.g., "BTC-USDT"). If `None`, returns all tickers.
+    ///
+    /// # Returns
+    /// Returns an `ApiResponse` containing ticker data.
+    async fn get_swap_ticker(
+        &self,
+        symbol: Option<&str>,
+    ) -> Result<ApiResponse<serde_json::Value>>;
+
     /// Retrieve Kline/Candlestick data for BingX Perpetual Swap contracts.
     ///
     /// GET /openApi/swap/v3/quote/klines
@@ -32,13 +66,14 @@ pub trait MarketApi {
     ///
     /// # Arguments
     /// * `symbol` - Trading pair symbol (e.g., "BTC-USDT"), must contain a hyphen.
-    /// * `interval` - Kline interval (e.g. "1m", "5m", "1h", "1d").
+    /// * `interval` - Kline interval, one of [`SWAP_KLINE_INTERVALS`](crate::bingx::types::SWAP_KLINE_INTERVALS) (e.g. "1m", "5m", "1h", "1d").
     /// * `start_time` - Optional start timestamp (ms), inclusive.
     /// * `end_time` - Optional end timestamp (ms), inclusive.
     /// * `limit` - Optional: How many klines to return (default 500, max 1440).
     ///
     /// # Returns
-    /// Returns an `ApiResponse` containing candlestick/kline data.
+    /// Returns an `ApiResponse` containing candlestick/kline data, or `Error::Validation`
+    /// for an unsupported interval.
     async fn get_swap_klines(
         &self,
         symbol: &str,
@@ -47,4 +82,58 @@ pub trait MarketApi {
         end_time: Option<i64>,
         limit: Option<u32>,
     ) -> Result<ApiResponse<serde_json::Value>>;
+
+    /// Retrieve the mark price and current funding rate for BingX Perpetual Swap contracts.
+    ///
+    /// GET /openApi/swap/v2/quote/premiumIndex
+    ///
+    /// [BingX API Documentation - Mark Price and Funding Rate](https://bingx-api.github.io/docs-v3/#/en/Swap/Market%20Data/Mark%20Price%20and%20Funding%20Rate)
+    ///
+    /// # Arguments
+    /// * `symbol` - Optionally filter by symbol (e.g., "BTC-USDT"). If `None`, returns all contracts.
+    ///
+    /// # Returns
+    /// Returns an `ApiResponse` containing mark price, index price, last and next funding info.
+    async fn get_swap_funding_rate(
+        &self,
+        symbol: Option<&str>,
+    ) -> Result<ApiResponse<serde_json::Value>>;
+
+    /// Retrieve historical funding rates for a BingX Perpetual Swap contract.
+    ///
+    /// GET /openApi/swap/v2/quote/fundingRate
+    ///
+    /// [BingX API Documentation - Get Funding Rate History](https://bingx-api.github.io/docs-v3/#/en/Swap/Market%20Data/Get%20Funding%20Rate%20History)
+    ///
+    /// # Arguments
+    /// * `symbol` - Trading pair symbol (e.g., "BTC-USDT"), must contain a hyphen.
+    /// * `start_time` - Optional start timestamp (ms), inclusive.
+    /// * `end_time` - Optional end timestamp (ms), inclusive.
+    /// * `limit` - Optional: How many records to return (default 100, max 1000).
+    ///
+    /// # Returns
+    /// Returns an `ApiResponse` containing funding rate history.
+    async fn get_swap_funding_rate_history(
+        &self,
+        symbol: &str,
+        start_time: Option<i64>,
+        end_time: Option<i64>,
+        limit: Option<u32>,
+    ) -> Result<ApiResponse<serde_json::Value>>;
+
+    /// Retrieve open interest for a BingX Perpetual Swap contract.
+    ///
+    /// GET /openApi/swap/v2/quote/openInterest
+    ///
+    /// [BingX API Documentation - Open Interest Statistics](https://bingx-api.github.io/docs-v3/#/en/Swap/Market%20Data/Open%20Interest%20Statistics)
+    ///
+    /// # Arguments
+    /// * `symbol` - Trading pair symbol (e.g., "BTC-USDT"), must contain a hyphen.
+    ///
+    /// # Returns
+    /// Returns an `ApiResponse` containing open interest data.
+    async fn get_swap_open_interest(
+        &self,
+        symbol: &str,
+    ) -> Result<ApiResponse<serde_json::Value>>;
 }
@@ -2,6 +2,7 @@ use crate::bingx::types::{
     ApiResponse, MarginMode, PlaceSwapOrderParams, PositionSide, QuoteCurrency, SwapOrderType,
 };
 use crate::error::Result;
+use crate::utils::Amount;
 use async_trait::async_trait;
 
 /// Trading methods for BingX swap API client.
@@ -16,7 +17,8 @@ pub trait TradeApi {
     /// Endpoint: POST /openApi/swap/v2/trade/order
     ///
     /// # Arguments
-    /// * `params` - Parameters for the swap order, compliant with BingX API.
+    /// * `params` - Parameters for the swap order, compliant with BingX API. Checked with
+    ///   [`PlaceSwapOrderParams::validate`] before sending.
     ///
     /// # Returns
     /// * `ApiResponse<serde_json::Value>` - The API response.
@@ -309,4 +311,68 @@ pub trait TradeApi {
         &self,
         symbol: &str,
     ) -> Result<ApiResponse<serde_json::Value>>;
+
+    /// Modify the quantity, price or trigger price of an open swap order in place.
+    ///
+    /// Endpoint: POST /openApi/swap/v1/trade/modifyOrder
+    ///
+    /// # Arguments
+    /// * `symbol` - Trading pair symbol, e.g. "BTC-USDT".
+    /// * `order_id` - Optional: Order ID.
+    /// * `client_order_id` - Optional: Custom user order ID.
+    /// * `quantity` - Optional: New order quantity.
+    /// * `price` - Optional: New limit price.
+    /// * `stop_price` - Optional: New trigger price.
+    ///
+    /// # Returns
+    /// * `ApiResponse<serde_json::Value>` - API response with the amended order.
+    ///
+    /// # Notes
+    /// - At least one of order_id or client_order_id must be provided.
+    /// - At least one of quantity, price or stop_price must be provided.
+    /// - Amounts are sent as plain decimal strings, e.g. `0.00001` rather than `1e-5`.
+    async fn amend_swap_order(
+        &self,
+        symbol: &str,
+        order_id: Option<i64>,
+        client_order_id: Option<&str>,
+        quantity: Option<Amount>,
+        price: Option<Amount>,
+        stop_price: Option<Amount>,
+    ) -> Result<ApiResponse<serde_json::Value>>;
+
+    /// Cancel an open swap order and place a new one in a single request.
+    ///
+    /// Endpoint: POST /openApi/swap/v1/trade/cancelReplace
+    ///
+    /// # Arguments
+    /// * `cancel_order_id` - Order ID to cancel.
+    /// * `new_order` - The replacement order, serialized like in `place_swap_order`.
+    /// * `cancel_replace_mode` - `"STOP_ON_FAILURE"` (skip the new order if the cancel fails)
+    ///   or `"ALLOW_FAILURE"` (place the new order regardless).
+    ///
+    /// # Returns
+    /// * `ApiResponse<serde_json::Value>` - API response with the cancel and place results.
+    async fn cancel_replace_swap_order(
+        &self,
+        cancel_order_id: i64,
+        new_order: &PlaceSwapOrderParams,
+        cancel_replace_mode: &str,
+    ) -> Result<ApiResponse<serde_json::Value>>;
+
+    /// Place up to 5 swap orders in a single request.
+    ///
+    /// Endpoint: POST /openApi/swap/v2/trade/batchOrders
+    ///
+    /// [BingX API Documentation - Place multiple orders](https://bingx-api.github.io/docs-v3/#/en/Swap/Trades%20Endpoints/Place%20multiple%20orders)
+    ///
+    /// # Arguments
+    /// * `orders` - 1 to 5 orders, sent as the stringified `batchOrders` array.
+    ///
+    /// # Returns
+    /// * `ApiResponse<serde_json::Value>` - API response with one result per order.
+    async fn place_swap_batch_orders(
+        &self,
+        orders: &[PlaceSwapOrderParams],
+    ) -> Result<ApiResponse<serde_json::Value>>;
 }
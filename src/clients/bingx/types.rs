@@ -1,5 +1,13 @@
 use serde::{Deserialize, Serialize};
 
+use crate::bingx::models::{SpotBalances, SwapOrder};
+use crate::error::{Error, Result};
+
+/// Kline intervals accepted by the BingX swap kline endpoint.
+pub const SWAP_KLINE_INTERVALS: &[&str] = &[
+    "1m", "3m", "5m", "15m", "30m", "1h", "2h", "4h", "6h", "8h", "12h", "1d", "3d", "1w", "1M",
+];
+
 /// Supported quote currencies: "USDT", "USDC"
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 #[serde(rename_all = "UPPERCASE")]
@@ -47,9 +55,10 @@ impl std::fmt::Display for SpotOrderStatus {
 
 /// Spot order type for BingX API.
 /// "MARKET", "LIMIT", "TAKE_STOP_LIMIT", "TAKE_STOP_MARKET", "TRIGGER_LIMIT", "TRIGGER_MARKET"
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Default)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum SpotOrderType {
+    #[default]
     Market,
     Limit,
     TakeStopLimit,
@@ -115,6 +124,79 @@ impl std::fmt::Display for AccountType {
     }
 }
 
+impl AccountType {
+    /// Account code used by the universal transfer `type` parameter (e.g. `FUND`, `PFUTURES`).
+    ///
+    /// Returns `None` for accounts that universal transfer does not support.
+    pub fn transfer_code(&self) -> Option<&'static str> {
+        match self {
+            AccountType::Spot => Some("FUND"),
+            AccountType::StdFutures => Some("SFUTURES"),
+            AccountType::UsdtMPerp => Some("PFUTURES"),
+            _ => None,
+        }
+    }
+}
+
+/// Composes the universal transfer `type` (e.g. `FUND_PFUTURES`) from source and destination.
+///
+/// Returns `Error::Validation` for unsupported accounts or identical source and destination.
+pub fn universal_transfer_type(
+    from: &AccountType,
+    to: &AccountType,
+) -> Result<String> {
+    let from_code = from.transfer_code().ok_or_else(|| {
+        Error::Validation(format!("Universal transfer not supported from {from}"))
+    })?;
+    let to_code = to
+        .transfer_code()
+        .ok_or_else(|| Error::Validation(format!("Universal transfer not supported to {to}")))?;
+    if from_code == to_code {
+        return Err(Error::Validation(
+            "Universal transfer requires different source and destination accounts".to_string(),
+        ));
+    }
+    Ok(format!("{from_code}_{to_code}"))
+}
+
+/// Request parameters for a BingX universal transfer between own accounts.
+///
+/// `type` is composed from source and destination accounts, e.g. `FUND_PFUTURES`
+/// for spot -> USDT-M perpetual.
+#[derive(Debug, Clone, Serialize)]
+pub struct UniversalTransferParams {
+    /// Transfer direction, e.g. "FUND_PFUTURES"
+    #[serde(rename = "type")]
+    pub transfer_type: String,
+    /// Coin name, e.g. "USDT"
+    pub asset: String,
+    /// Transfer amount, sent as a decimal string
+    #[serde(serialize_with = "crate::utils::as_str_f64")]
+    pub amount: f64,
+}
+
+impl UniversalTransferParams {
+    /// Builds transfer params, validating the account pair and amount.
+    pub fn new(
+        from: &AccountType,
+        to: &AccountType,
+        asset: &str,
+        amount: f64,
+    ) -> Result<Self> {
+        let transfer_type = universal_transfer_type(from, to)?;
+        if !amount.is_finite() || amount <= 0.0 {
+            return Err(Error::Validation(format!(
+                "Transfer amount must be greater than 0, got {amount}"
+            )));
+        }
+        Ok(Self {
+            transfer_type,
+            asset: asset.to_string(),
+            amount,
+        })
+    }
+}
+
 /// Margin mode for BingX swap accounts.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
@@ -354,6 +436,77 @@ pub struct PlaceSwapOrderParams {
     pub position_id: Option<i64>,
 }
 
+impl PlaceSwapOrderParams {
+    /// Reject parameters BingX is known to refuse: a symbol without a hyphen, both
+    /// `quantity` and `quote_order_qty`, or a `LIMIT`/`TRIGGER_LIMIT` order without a price.
+    pub fn validate(&self) -> Result<()> {
+        if !self.symbol.contains('-') {
+            return Err(Error::Validation(format!(
+                "BingX swap symbols need a hyphen, e.g. BTC-USDT; got {:?}",
+                self.symbol
+            )));
+        }
+        if self.quantity.is_some() && self.quote_order_qty.is_some() {
+            return Err(Error::Validation(
+                "Set either quantity or quote_order_qty, not both".to_string(),
+            ));
+        }
+        if matches!(
+            self.order_type,
+            SwapOrderType::Limit | SwapOrderType::TriggerLimit
+        ) && self.price.is_none()
+        {
+            return Err(Error::Validation(format!(
+                "{} orders require a price",
+                self.order_type
+            )));
+        }
+        Ok(())
+    }
+}
+
+/// Request parameters for placing a spot order on BingX.
+///
+/// There must be a hyphen "-" in the trading pair symbol (e.g. BTC-USDT).
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct PlaceSpotOrderParams {
+    /// Symbol, trading pair (e.g. BTC-USDT)
+    pub symbol: String,
+
+    /// Side ("BUY" or "SELL")
+    pub side: OrderSide,
+
+    /// Order type (MARKET, LIMIT, TAKE_STOP_LIMIT, etc.)
+    #[serde(rename = "type")]
+    pub order_type: SpotOrderType,
+
+    /// Order quantity in COIN, e.g. 0.1BTC. Optional.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub quantity: Option<f64>,
+
+    /// Order amount in quote asset, e.g. 100USDT. Optional.
+    /// If both are set, `quantity` takes precedence.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub quote_order_qty: Option<f64>,
+
+    /// Order price, e.g. 10000USDT. Required for limit orders.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub price: Option<f64>,
+
+    /// Trigger price for TAKE_STOP_* and TRIGGER_* orders. Optional.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stop_price: Option<f64>,
+
+    /// User-custom order ID (1-40 chars). Optional.
+    #[serde(rename = "newClientOrderId", skip_serializing_if = "Option::is_none")]
+    pub client_order_id: Option<String>,
+
+    /// Order execution time-in-force (PostOnly, GTC, IOC, FOK). Optional, default GTC.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub time_in_force: Option<TimeInForce>,
+}
+
 /// BingX API response for deserialization (fields are received from API, not for sending)
 #[derive(Debug, Clone, Deserialize)]
 pub struct ApiResponse<T> {
@@ -367,6 +520,35 @@ pub struct ApiResponse<T> {
     pub retryable: Option<bool>,
 }
 
+impl ApiResponse<serde_json::Value> {
+    /// Deserialize swap orders from `data`.
+    ///
+    /// Accepts the `data.orders` list of order history and open orders, the single `data.order`
+    /// of order details, or a bare list.
+    pub fn parse_swap_orders(&self) -> Result<Vec<SwapOrder>> {
+        let orders = match &self.data {
+            serde_json::Value::Object(map) if map.contains_key("orders") => {
+                Vec::deserialize(&map["orders"])
+            }
+            serde_json::Value::Object(map) if map.contains_key("order") => {
+                SwapOrder::deserialize(&map["order"]).map(|order| vec![order])
+            }
+            serde_json::Value::Null => Ok(Vec::new()),
+            data => Vec::deserialize(data),
+        };
+        orders.map_err(Error::Json)
+    }
+
+    /// Deserialize the spot account assets of `get_spot_account_assets`; a missing `data` is an
+    /// empty account.
+    pub fn parse_spot_balances(&self) -> Result<SpotBalances> {
+        match &self.data {
+            serde_json::Value::Null => Ok(SpotBalances::default()),
+            data => SpotBalances::deserialize(data).map_err(Error::Json),
+        }
+    }
+}
+
 /// Generic API response for deserialization (fields are received from API, not for sending)
 #[derive(Debug, Clone, Deserialize)]
 #[serde(rename_all = "camelCase")]
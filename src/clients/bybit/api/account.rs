@@ -7,21 +7,97 @@ use linkme::distributed_slice;
 use serde_json::Value;
 
 use crate::bybit::traits::AccountApi;
-use crate::bybit::types::{AccountType, ApiResponse, MarginMode};
+use crate::bybit::types::{AccountType, AllCategories, ApiResponse, MarginMode};
 use crate::bybit::BybitClient;
 use crate::bybit::BYBIT_IMPLEMENTED;
-use crate::error::Result;
+use crate::error::{Error, Result};
 use crate::http::HttpClient;
+use crate::utils::{Amount, PlainDecimal};
+
+/// Values accepted by `set_limit_price_behaviour`.
+const LIMIT_PRICE_BEHAVIOURS: &[&str] = &["modify", "reject"];
+
+/// Join coins as Bybit expects in `coin` filters: trimmed, uppercased, comma-separated.
+fn join_coins(coins: &[&str]) -> Result<String> {
+    if coins.is_empty() {
+        return Err(Error::Validation("Provide at least one coin".to_string()));
+    }
+    let coins = coins
+        .iter()
+        .map(|coin| match coin.trim() {
+            "" => Err(Error::Validation(format!("Empty coin in {coins:?}"))),
+            coin => Ok(coin.to_uppercase()),
+        })
+        .collect::<Result<Vec<_>>>()?;
+    Ok(coins.join(","))
+}
 
 #[distributed_slice(BYBIT_IMPLEMENTED)]
 pub static GET_WALLET_BALANCE: &'static str = "get_wallet_balance";
 
+#[distributed_slice(BYBIT_IMPLEMENTED)]
+pub static GET_WALLET_BALANCE_FOR_COINS: &'static str = "get_wallet_balance_for_coins";
+
+#[distributed_slice(BYBIT_IMPLEMENTED)]
+pub static GET_TRANSFERABLE_AMOUNT: &'static str = "get_transferable_amount";
+
+#[distributed_slice(BYBIT_IMPLEMENTED)]
+pub static GET_BORROW_HISTORY: &'static str = "get_borrow_history";
+
+#[distributed_slice(BYBIT_IMPLEMENTED)]
+pub static GET_SMP_GROUP_ID: &'static str = "get_smp_group_id";
+
+#[distributed_slice(BYBIT_IMPLEMENTED)]
+pub static GET_TRADE_BEHAVIOUR_SETTING: &'static str = "get_trade_behaviour_setting";
+
+#[distributed_slice(BYBIT_IMPLEMENTED)]
+pub static SET_LIMIT_PRICE_BEHAVIOUR: &'static str = "set_limit_price_behaviour";
+
 #[distributed_slice(BYBIT_IMPLEMENTED)]
 pub static GET_ACCOUNT_INFO: &'static str = "get_account_info";
 
 #[distributed_slice(BYBIT_IMPLEMENTED)]
 pub static SET_MARGIN_MODE: &'static str = "set_margin_mode";
 
+#[distributed_slice(BYBIT_IMPLEMENTED)]
+pub static GET_COLLATERAL_INFO: &'static str = "get_collateral_info";
+
+#[distributed_slice(BYBIT_IMPLEMENTED)]
+pub static SET_COLLATERAL_COIN: &'static str = "set_collateral_coin";
+
+#[distributed_slice(BYBIT_IMPLEMENTED)]
+pub static BATCH_SET_COLLATERAL_COIN: &'static str = "batch_set_collateral_coin";
+
+#[distributed_slice(BYBIT_IMPLEMENTED)]
+pub static GET_COIN_GREEKS: &'static str = "get_coin_greeks";
+
+#[distributed_slice(BYBIT_IMPLEMENTED)]
+pub static GET_DCP_INFO: &'static str = "get_dcp_info";
+
+#[distributed_slice(BYBIT_IMPLEMENTED)]
+pub static SET_SPOT_HEDGING: &'static str = "set_spot_hedging";
+
+#[distributed_slice(BYBIT_IMPLEMENTED)]
+pub static MANUAL_BORROW: &'static str = "manual_borrow";
+
+#[distributed_slice(BYBIT_IMPLEMENTED)]
+pub static MANUAL_REPAY: &'static str = "manual_repay";
+
+#[distributed_slice(BYBIT_IMPLEMENTED)]
+pub static REPAY_LIABILITY: &'static str = "repay_liability";
+
+#[distributed_slice(BYBIT_IMPLEMENTED)]
+pub static UPGRADE_TO_UNIFIED_ACCOUNT_PRO: &'static str = "upgrade_to_unified_account_pro";
+
+/// Bybit `ON`/`OFF` switch value.
+fn switch_value(on: bool) -> &'static str {
+    if on {
+        "ON"
+    } else {
+        "OFF"
+    }
+}
+
 #[async_trait]
 impl AccountApi for BybitClient {
     async fn get_wallet_balance(
@@ -45,6 +121,15 @@ impl AccountApi for BybitClient {
         Ok(response.into_api_response())
     }
 
+    async fn get_wallet_balance_for_coins(
+        &self,
+        account_type: Option<AccountType>,
+        coins: &[&str],
+    ) -> Result<ApiResponse<Value>> {
+        let coin = join_coins(coins)?;
+        self.get_wallet_balance(account_type, Some(&coin)).await
+    }
+
     async fn get_account_info(&self) -> Result<ApiResponse<Value>> {
         let response = self.get("/v5/account/info", None, true).await?;
         Ok(response.into_api_response())
@@ -66,8 +151,17 @@ impl AccountApi for BybitClient {
         Ok(response.into_api_response())
     }
 
-    async fn get_transferable_amount(&self) -> Result<ApiResponse<Value>> {
-        todo!("get_transferable_amount not implemented")
+    async fn get_transferable_amount(
+        &self,
+        coins: &[&str],
+    ) -> Result<ApiResponse<Value>> {
+        let mut params: HashMap<String, Value> = HashMap::new();
+        params.insert("coinName".to_string(), Value::String(join_coins(coins)?));
+
+        let response = self
+            .get("/v5/account/withdrawal", Some(&params), true)
+            .await?;
+        Ok(response.into_api_response())
     }
 
     async fn get_transaction_log(&self) -> Result<ApiResponse<Value>> {
@@ -78,48 +172,183 @@ impl AccountApi for BybitClient {
         todo!("get_account_instruments_info not implemented")
     }
 
-    async fn manual_borrow(&self) -> Result<ApiResponse<Value>> {
-        todo!("manual_borrow not implemented")
+    async fn manual_borrow(
+        &self,
+        coin: &str,
+        amount: Amount,
+    ) -> Result<ApiResponse<Value>> {
+        if amount <= Amount::default() {
+            return Err(Error::Validation(format!(
+                "Borrow amount must be greater than 0, got {amount}"
+            )));
+        }
+        let mut params: HashMap<String, Value> = HashMap::new();
+        params.insert("coin".to_string(), Value::String(coin.to_string()));
+        params.insert(
+            "amount".to_string(),
+            Value::String(amount.to_plain_string()),
+        );
+
+        let response = self.post("/v5/account/borrow", Some(&params), true).await?;
+        Ok(response.into_api_response())
     }
 
     async fn manual_repay_without_asset_conversion(&self) -> Result<ApiResponse<Value>> {
         todo!("manual_repay_without_asset_conversion not implemented")
     }
 
-    async fn manual_repay(&self) -> Result<ApiResponse<Value>> {
-        todo!("manual_repay not implemented")
+    async fn manual_repay(
+        &self,
+        coin: Option<&str>,
+    ) -> Result<ApiResponse<Value>> {
+        let mut params: HashMap<String, Value> = HashMap::new();
+        if let Some(coin) = coin {
+            params.insert("coin".to_string(), Value::String(coin.to_string()));
+        }
+
+        let response = self
+            .post("/v5/account/quick-repayment", Some(&params), true)
+            .await?;
+        Ok(response.into_api_response())
     }
 
     async fn get_fee_rate(&self) -> Result<ApiResponse<Value>> {
         todo!("get_fee_rate not implemented")
     }
 
-    async fn get_collateral_info(&self) -> Result<ApiResponse<Value>> {
-        todo!("get_collateral_info not implemented")
+    async fn get_collateral_info(
+        &self,
+        currency: Option<&str>,
+    ) -> Result<ApiResponse<Value>> {
+        let mut params: HashMap<String, Value> = HashMap::new();
+        if let Some(currency) = currency {
+            params.insert("currency".to_string(), Value::String(currency.to_string()));
+        }
+
+        let response = self
+            .get("/v5/account/collateral-info", Some(&params), true)
+            .await?;
+        Ok(response.into_api_response())
     }
 
     async fn get_dcp_info(&self) -> Result<ApiResponse<Value>> {
-        todo!("get_dcp_info not implemented")
+        let response = self.get("/v5/account/query-dcp-info", None, true).await?;
+        Ok(response.into_api_response())
     }
 
-    async fn set_collateral_coin(&self) -> Result<ApiResponse<Value>> {
-        todo!("set_collateral_coin not implemented")
+    async fn set_collateral_coin(
+        &self,
+        coin: &str,
+        collateral_switch: bool,
+    ) -> Result<ApiResponse<Value>> {
+        let mut params: HashMap<String, Value> = HashMap::new();
+        params.insert("coin".to_string(), Value::String(coin.to_string()));
+        params.insert(
+            "collateralSwitch".to_string(),
+            Value::String(switch_value(collateral_switch).to_string()),
+        );
+
+        let response = self
+            .post("/v5/account/set-collateral-switch", Some(&params), true)
+            .await?;
+        Ok(response.into_api_response())
     }
 
-    async fn set_spot_hedging(&self) -> Result<ApiResponse<Value>> {
-        todo!("set_spot_hedging not implemented")
+    async fn set_spot_hedging(
+        &self,
+        set_hedging_mode: bool,
+    ) -> Result<ApiResponse<Value>> {
+        let mut params: HashMap<String, Value> = HashMap::new();
+        params.insert(
+            "setHedgingMode".to_string(),
+            Value::String(switch_value(set_hedging_mode).to_string()),
+        );
+
+        let response = self
+            .post("/v5/account/set-hedging-mode", Some(&params), true)
+            .await?;
+        Ok(response.into_api_response())
     }
 
-    async fn get_borrow_history(&self) -> Result<ApiResponse<Value>> {
-        todo!("get_borrow_history not implemented")
+    async fn get_borrow_history(
+        &self,
+        currency: Option<&str>,
+        start_time: Option<i64>,
+        end_time: Option<i64>,
+        limit: Option<i32>,
+        cursor: Option<&str>,
+    ) -> Result<ApiResponse<Value>> {
+        let mut params: HashMap<String, Value> = HashMap::new();
+        if let Some(currency) = currency {
+            params.insert("currency".to_string(), Value::String(currency.to_string()));
+        }
+        if let Some(start_time) = start_time {
+            params.insert(
+                "startTime".to_string(),
+                Value::String(start_time.to_string()),
+            );
+        }
+        if let Some(end_time) = end_time {
+            params.insert("endTime".to_string(), Value::String(end_time.to_string()));
+        }
+        if let Some(limit) = limit {
+            params.insert("limit".to_string(), Value::String(limit.to_string()));
+        }
+        if let Some(cursor) = cursor {
+            params.insert("cursor".to_string(), Value::String(cursor.to_string()));
+        }
+
+        let response = self
+            .get("/v5/account/borrow-history", Some(&params), true)
+            .await?;
+        Ok(response.into_api_response())
     }
 
-    async fn batch_set_collateral_coin(&self) -> Result<ApiResponse<Value>> {
-        todo!("batch_set_collateral_coin not implemented")
+    async fn batch_set_collateral_coin(
+        &self,
+        switches: &[(String, bool)],
+    ) -> Result<ApiResponse<Value>> {
+        if switches.is_empty() {
+            return Err(Error::Validation(
+                "Provide at least one coin to switch".to_string(),
+            ));
+        }
+        let request: Vec<Value> = switches
+            .iter()
+            .map(|(coin, on)| {
+                serde_json::json!({
+                    "coin": coin,
+                    "collateralSwitch": switch_value(*on),
+                })
+            })
+            .collect();
+
+        let mut params: HashMap<String, Value> = HashMap::new();
+        params.insert("request".to_string(), Value::Array(request));
+
+        let response = self
+            .post(
+                "/v5/account/set-collateral-switch-batch",
+                Some(&params),
+                true,
+            )
+            .await?;
+        Ok(response.into_api_response())
     }
 
-    async fn get_coin_greeks(&self) -> Result<ApiResponse<Value>> {
-        todo!("get_coin_greeks not implemented")
+    async fn get_coin_greeks(
+        &self,
+        base_coin: Option<&str>,
+    ) -> Result<ApiResponse<Value>> {
+        let mut params: HashMap<String, Value> = HashMap::new();
+        if let Some(base_coin) = base_coin {
+            params.insert("baseCoin".to_string(), Value::String(base_coin.to_string()));
+        }
+
+        let response = self
+            .get("/v5/asset/coin-greeks", Some(&params), true)
+            .await?;
+        Ok(response.into_api_response())
     }
 
     async fn get_mmp_state(&self) -> Result<ApiResponse<Value>> {
@@ -135,22 +364,64 @@ impl AccountApi for BybitClient {
     }
 
     async fn get_smp_group_id(&self) -> Result<ApiResponse<Value>> {
-        todo!("get_smp_group_id not implemented")
+        let response = self.get("/v5/account/smp-group", None, true).await?;
+        Ok(response.into_api_response())
     }
 
     async fn get_trade_behaviour_setting(&self) -> Result<ApiResponse<Value>> {
-        todo!("get_trade_behaviour_setting not implemented")
+        let response = self
+            .get("/v5/account/user-setting-config", None, true)
+            .await?;
+        Ok(response.into_api_response())
     }
 
-    async fn set_limit_price_behaviour(&self) -> Result<ApiResponse<Value>> {
-        todo!("set_limit_price_behaviour not implemented")
+    async fn set_limit_price_behaviour(
+        &self,
+        category: AllCategories,
+        behaviour: &str,
+    ) -> Result<ApiResponse<Value>> {
+        if category == AllCategories::Option {
+            return Err(Error::Validation(
+                "set_limit_price_behaviour supports only linear, inverse and spot categories, got option"
+                    .to_string(),
+            ));
+        }
+        let modify_enable = match behaviour {
+            "modify" => true,
+            "reject" => false,
+            other => {
+                return Err(Error::Validation(format!(
+                    "Limit price behaviour must be one of {LIMIT_PRICE_BEHAVIOURS:?}, got {other:?}"
+                )))
+            }
+        };
+        let mut params: HashMap<String, Value> = HashMap::new();
+        params.insert("category".to_string(), Value::String(category.to_string()));
+        params.insert("modifyEnable".to_string(), Value::Bool(modify_enable));
+
+        let response = self
+            .post("/v5/account/set-limit-px-action", Some(&params), true)
+            .await?;
+        Ok(response.into_api_response())
     }
 
-    async fn repay_liability(&self) -> Result<ApiResponse<Value>> {
-        todo!("repay_liability not implemented")
+    async fn repay_liability(
+        &self,
+        coin: Option<&str>,
+    ) -> Result<ApiResponse<Value>> {
+        let mut params: HashMap<String, Value> = HashMap::new();
+        if let Some(coin) = coin {
+            params.insert("coin".to_string(), Value::String(coin.to_string()));
+        }
+
+        let response = self
+            .post("/v5/account/repay-liability", Some(&params), true)
+            .await?;
+        Ok(response.into_api_response())
     }
 
     async fn upgrade_to_unified_account_pro(&self) -> Result<ApiResponse<Value>> {
-        todo!("upgrade_to_unified_account_pro not implemented")
+        let response = self.post("/v5/account/upgrade-to-uta", None, true).await?;
+        Ok(response.into_api_response())
     }
 }
@@ -0,0 +1,207 @@
+//! Asset API implementation.
+
+use std::collections::HashMap;
+
+use async_trait::async_trait;
+use linkme::distributed_slice;
+use serde_json::Value;
+
+use crate::bybit::traits::AssetApi;
+use crate::bybit::types::{AccountType, ApiResponse};
+use crate::bybit::BybitClient;
+use crate::bybit::BYBIT_IMPLEMENTED;
+use crate::error::{Error, Result};
+use crate::http::HttpClient;
+use crate::utils::{Amount, PlainDecimal};
+
+#[distributed_slice(BYBIT_IMPLEMENTED)]
+pub static CREATE_INTERNAL_TRANSFER: &'static str = "create_internal_transfer";
+
+#[distributed_slice(BYBIT_IMPLEMENTED)]
+pub static GET_TRANSFER_HISTORY: &'static str = "get_transfer_history";
+
+#[distributed_slice(BYBIT_IMPLEMENTED)]
+pub static GET_COIN_INFO: &'static str = "get_coin_info";
+
+#[distributed_slice(BYBIT_IMPLEMENTED)]
+pub static GET_WITHDRAWABLE_AMOUNT: &'static str = "get_withdrawable_amount";
+
+#[distributed_slice(BYBIT_IMPLEMENTED)]
+pub static WITHDRAW: &'static str = "withdraw";
+
+#[async_trait]
+impl AssetApi for BybitClient {
+    async fn create_internal_transfer(
+        &self,
+        coin: &str,
+        amount: Amount,
+        from_account_type: AccountType,
+        to_account_type: AccountType,
+    ) -> Result<ApiResponse<Value>> {
+        if amount <= Amount::default() {
+            return Err(Error::Validation(format!(
+                "Transfer amount must be greater than 0, got {amount}"
+            )));
+        }
+        if from_account_type == to_account_type {
+            return Err(Error::Validation(format!(
+                "Transfer needs two different accounts, got {from_account_type} twice"
+            )));
+        }
+        let mut params: HashMap<String, Value> = HashMap::new();
+        params.insert(
+            "transferId".to_string(),
+            Value::String(uuid::Uuid::now_v7().to_string()),
+        );
+        params.insert("coin".to_string(), Value::String(coin.to_string()));
+        params.insert(
+            "amount".to_string(),
+            Value::String(amount.to_plain_string()),
+        );
+        params.insert(
+            "fromAccountType".to_string(),
+            Value::String(from_account_type.to_string()),
+        );
+        params.insert(
+            "toAccountType".to_string(),
+            Value::String(to_account_type.to_string()),
+        );
+
+        let response = self
+            .post("/v5/asset/transfer/inter-transfer", Some(&params), true)
+            .await?;
+        Ok(response.into_api_response())
+    }
+
+    async fn get_transfer_history(
+        &self,
+        transfer_id: Option<&str>,
+        coin: Option<&str>,
+        status: Option<&str>,
+        start_time: Option<i64>,
+        end_time: Option<i64>,
+        limit: Option<i32>,
+        cursor: Option<&str>,
+    ) -> Result<ApiResponse<Value>> {
+        let mut params: HashMap<String, Value> = HashMap::new();
+        if let Some(transfer_id) = transfer_id {
+            params.insert(
+                "transferId".to_string(),
+                Value::String(transfer_id.to_string()),
+            );
+        }
+        if let Some(coin) = coin {
+            params.insert("coin".to_string(), Value::String(coin.to_string()));
+        }
+        if let Some(status) = status {
+            params.insert("status".to_string(), Value::String(status.to_string()));
+        }
+        if let Some(start_time) = start_time {
+            params.insert(
+                "startTime".to_string(),
+                Value::String(start_time.to_string()),
+            );
+        }
+        if let Some(end_time) = end_time {
+            params.insert("endTime".to_string(), Value::String(end_time.to_string()));
+        }
+        if let Some(limit) = limit {
+            params.insert("limit".to_string(), Value::String(limit.to_string()));
+        }
+        if let Some(cursor) = cursor {
+            params.insert("cursor".to_string(), Value::String(cursor.to_string()));
+        }
+
+        let response = self
+            .get(
+                "/v5/asset/transfer/query-inter-transfer-list",
+                Some(&params),
+                true,
+            )
+            .await?;
+        Ok(response.into_api_response())
+    }
+
+    async fn get_coin_info(
+        &self,
+        coin: Option<&str>,
+    ) -> Result<ApiResponse<Value>> {
+        let mut params: HashMap<String, Value> = HashMap::new();
+        if let Some(coin) = coin {
+            params.insert("coin".to_string(), Value::String(coin.to_string()));
+        }
+
+        let response = self
+            .get("/v5/asset/coin/query-info", Some(&params), true)
+            .await?;
+        Ok(response.into_api_response())
+    }
+
+    async fn get_withdrawable_amount(
+        &self,
+        coin: &str,
+    ) -> Result<ApiResponse<Value>> {
+        let mut params: HashMap<String, Value> = HashMap::new();
+        params.insert("coin".to_string(), Value::String(coin.to_string()));
+
+        let response = self
+            .get(
+                "/v5/asset/withdraw/withdrawable-amount",
+                Some(&params),
+                true,
+            )
+            .await?;
+        Ok(response.into_api_response())
+    }
+
+    async fn withdraw(
+        &self,
+        coin: &str,
+        chain: &str,
+        address: &str,
+        tag: Option<&str>,
+        amount: Amount,
+        account_type: Option<&str>,
+        request_id: Option<&str>,
+    ) -> Result<ApiResponse<Value>> {
+        if amount <= Amount::default() {
+            return Err(Error::Validation(format!(
+                "Withdrawal amount must be greater than 0, got {amount}"
+            )));
+        }
+        if address.trim().is_empty() {
+            return Err(Error::Validation(
+                "Withdrawal address must not be empty".to_string(),
+            ));
+        }
+        let mut params: HashMap<String, Value> = HashMap::new();
+        params.insert("coin".to_string(), Value::String(coin.to_string()));
+        params.insert("chain".to_string(), Value::String(chain.to_string()));
+        params.insert("address".to_string(), Value::String(address.to_string()));
+        if let Some(tag) = tag {
+            params.insert("tag".to_string(), Value::String(tag.to_string()));
+        }
+        params.insert(
+            "amount".to_string(),
+            Value::String(amount.to_plain_string()),
+        );
+        params.insert("timestamp".to_string(), Value::from(self.now_ms()));
+        if let Some(account_type) = account_type {
+            params.insert(
+                "accountType".to_string(),
+                Value::String(account_type.to_string()),
+            );
+        }
+        if let Some(request_id) = request_id {
+            params.insert(
+                "requestId".to_string(),
+                Value::String(request_id.to_string()),
+            );
+        }
+
+        let response = self
+            .post("/v5/asset/withdraw/create", Some(&params), true)
+            .await?;
+        Ok(response.into_api_response())
+    }
+}
@@ -3,17 +3,65 @@
 use std::collections::HashMap;
 
 use async_trait::async_trait;
+use futures::stream::{self, StreamExt};
 use linkme::distributed_slice;
 use serde_json::Value;
 
 use crate::bybit::traits::MarketApi;
 use crate::bybit::types::{AllCategories, ApiResponse, InstrumentStatus, SymbolType};
 use crate::bybit::BybitClient;
-use crate::error::Result;
+use crate::error::{Error, Result};
 use crate::http::HttpClient;
+use crate::utils::OptionSymbol;
 
 use crate::bybit::BYBIT_IMPLEMENTED;
 
+/// Deepest RPI orderbook Bybit serves per side.
+const MAX_RPI_ORDERBOOK_DEPTH: i32 = 50;
+
+/// Reject categories an endpoint does not serve before sending the request.
+fn require_category(
+    method: &str,
+    category: &AllCategories,
+    allowed: &[AllCategories],
+) -> Result<()> {
+    if allowed.contains(category) {
+        return Ok(());
+    }
+    let allowed = allowed
+        .iter()
+        .map(ToString::to_string)
+        .collect::<Vec<_>>()
+        .join(", ");
+    Err(Error::Validation(format!(
+        "{method} supports only {allowed} categories, got {category}"
+    )))
+}
+
+/// Reject a malformed option symbol before sending an `option` category request.
+fn require_option_symbol(
+    category: &AllCategories,
+    symbol: Option<&str>,
+) -> Result<()> {
+    match symbol {
+        Some(symbol) if *category == AllCategories::Option => {
+            OptionSymbol::parse(symbol).map(|_| ())
+        }
+        _ => Ok(()),
+    }
+}
+
+/// Reject an empty symbol before sending the request.
+fn require_symbol(
+    method: &str,
+    symbol: &str,
+) -> Result<()> {
+    if symbol.trim().is_empty() {
+        return Err(Error::Validation(format!("{method} requires a symbol")));
+    }
+    Ok(())
+}
+
 #[distributed_slice(BYBIT_IMPLEMENTED)]
 pub static GET_SERVER_TIME: &'static str = "get_server_time";
 
@@ -23,6 +71,87 @@ pub static GET_KLINE: &'static str = "get_kline";
 #[distributed_slice(BYBIT_IMPLEMENTED)]
 pub static GET_INSTRUMENTS_INFO: &'static str = "get_instruments_info";
 
+#[distributed_slice(BYBIT_IMPLEMENTED)]
+pub static GET_HISTORICAL_VOLATILITY: &'static str = "get_historical_volatility";
+
+#[distributed_slice(BYBIT_IMPLEMENTED)]
+pub static GET_INSURANCE_POOL: &'static str = "get_insurance_pool";
+
+#[distributed_slice(BYBIT_IMPLEMENTED)]
+pub static GET_DELIVERY_PRICE: &'static str = "get_delivery_price";
+
+#[distributed_slice(BYBIT_IMPLEMENTED)]
+pub static GET_NEW_DELIVERY_PRICE: &'static str = "get_new_delivery_price";
+
+#[distributed_slice(BYBIT_IMPLEMENTED)]
+pub static GET_INDEX_PRICE_COMPONENTS: &'static str = "get_index_price_components";
+
+#[distributed_slice(BYBIT_IMPLEMENTED)]
+pub static GET_ORDER_PRICE_LIMIT: &'static str = "get_order_price_limit";
+
+#[distributed_slice(BYBIT_IMPLEMENTED)]
+pub static GET_ADL_ALERT: &'static str = "get_adl_alert";
+
+#[distributed_slice(BYBIT_IMPLEMENTED)]
+pub static GET_FEE_GROUP_STRUCTURE: &'static str = "get_fee_group_structure";
+
+#[distributed_slice(BYBIT_IMPLEMENTED)]
+pub static GET_RPI_ORDERBOOK: &'static str = "get_rpi_orderbook";
+
+#[distributed_slice(BYBIT_IMPLEMENTED)]
+pub static GET_TICKERS: &'static str = "get_tickers";
+
+impl BybitClient {
+    /// Fetch the ticker of each symbol with one `get_tickers` call per symbol, at most
+    /// `concurrency` (at least 1) in flight at a time.
+    ///
+    /// The SDK has no client-side rate limiter, so `concurrency` is what keeps the burst within
+    /// the exchange's limits. Returns the ticker objects and the errors, both keyed by symbol;
+    /// a symbol the exchange answers with an empty list is reported as an error.
+    pub async fn get_tickers_many(
+        &self,
+        category: AllCategories,
+        symbols: &[&str],
+        concurrency: usize,
+    ) -> (HashMap<String, Value>, HashMap<String, Error>) {
+        let requests = symbols.iter().map(|&symbol| {
+            let category = category.clone();
+            async move {
+                let ticker = self
+                    .get_tickers(category, Some(symbol), None, None)
+                    .await
+                    .and_then(|response| {
+                        response
+                            .result
+                            .get("list")
+                            .and_then(Value::as_array)
+                            .and_then(|list| list.first())
+                            .cloned()
+                            .ok_or_else(|| {
+                                Error::Validation(format!("no ticker returned for {symbol}"))
+                            })
+                    });
+                (symbol.to_string(), ticker)
+            }
+        });
+
+        let mut tickers = HashMap::with_capacity(symbols.len());
+        let mut errors = HashMap::new();
+        let mut results = stream::iter(requests).buffer_unordered(concurrency.max(1));
+        while let Some((symbol, ticker)) = results.next().await {
+            match ticker {
+                Ok(ticker) => {
+                    tickers.insert(symbol, ticker);
+                }
+                Err(e) => {
+                    errors.insert(symbol, e);
+                }
+            }
+        }
+        (tickers, errors)
+    }
+}
+
 /// Default implementation of MarketApi for BybitClient
 #[async_trait]
 impl MarketApi for BybitClient {
@@ -119,12 +248,64 @@ impl MarketApi for BybitClient {
         todo!("get_orderbook not implemented")
     }
 
-    async fn get_rpi_orderbook(&self) -> Result<ApiResponse<Value>> {
-        todo!("get_rpi_orderbook not implemented")
+    async fn get_rpi_orderbook(
+        &self,
+        category: AllCategories,
+        symbol: &str,
+        limit: Option<i32>,
+    ) -> Result<ApiResponse<Value>> {
+        require_category(
+            "get_rpi_orderbook",
+            &category,
+            &[
+                AllCategories::Spot,
+                AllCategories::Linear,
+                AllCategories::Inverse,
+            ],
+        )?;
+        require_symbol("get_rpi_orderbook", symbol)?;
+        let limit = limit.unwrap_or(MAX_RPI_ORDERBOOK_DEPTH);
+        if !(1..=MAX_RPI_ORDERBOOK_DEPTH).contains(&limit) {
+            return Err(Error::Validation(format!(
+                "get_rpi_orderbook limit must be between 1 and {MAX_RPI_ORDERBOOK_DEPTH}, got {limit}"
+            )));
+        }
+
+        let mut params: HashMap<String, Value> = HashMap::new();
+        params.insert("category".to_string(), Value::String(category.to_string()));
+        params.insert("symbol".to_string(), Value::String(symbol.to_string()));
+        params.insert("limit".to_string(), Value::String(limit.to_string()));
+
+        let response = self
+            .get("/v5/market/rpi_orderbook", Some(&params), false)
+            .await?;
+        Ok(response.into_api_response())
     }
 
-    async fn get_tickers(&self) -> Result<ApiResponse<Value>> {
-        todo!("get_tickers not implemented")
+    async fn get_tickers(
+        &self,
+        category: AllCategories,
+        symbol: Option<&str>,
+        base_coin: Option<&str>,
+        exp_date: Option<&str>,
+    ) -> Result<ApiResponse<Value>> {
+        require_option_symbol(&category, symbol)?;
+
+        let mut params: HashMap<String, Value> = HashMap::new();
+        params.insert("category".to_string(), Value::String(category.to_string()));
+
+        if let Some(symbol) = symbol {
+            params.insert("symbol".to_string(), Value::String(symbol.to_string()));
+        }
+        if let Some(base_coin) = base_coin {
+            params.insert("baseCoin".to_string(), Value::String(base_coin.to_string()));
+        }
+        if let Some(exp_date) = exp_date {
+            params.insert("expDate".to_string(), Value::String(exp_date.to_string()));
+        }
+
+        let response = self.get("/v5/market/tickers", Some(&params), false).await?;
+        Ok(response.into_api_response())
     }
 
     async fn get_funding_rate_history(&self) -> Result<ApiResponse<Value>> {
@@ -139,43 +320,205 @@ impl MarketApi for BybitClient {
         todo!("get_open_interest not implemented")
     }
 
-    async fn get_historical_volatility(&self) -> Result<ApiResponse<Value>> {
-        todo!("get_historical_volatility not implemented")
+    async fn get_historical_volatility(
+        &self,
+        category: AllCategories,
+        base_coin: Option<&str>,
+        period: Option<i32>,
+        start_time: Option<i64>,
+        end_time: Option<i64>,
+    ) -> Result<ApiResponse<Value>> {
+        require_category(
+            "get_historical_volatility",
+            &category,
+            &[AllCategories::Option],
+        )?;
+
+        let mut params: HashMap<String, Value> = HashMap::new();
+        params.insert("category".to_string(), Value::String(category.to_string()));
+
+        if let Some(base_coin) = base_coin {
+            params.insert("baseCoin".to_string(), Value::String(base_coin.to_string()));
+        }
+        if let Some(period) = period {
+            params.insert("period".to_string(), Value::String(period.to_string()));
+        }
+        if let Some(start_time) = start_time {
+            params.insert(
+                "startTime".to_string(),
+                Value::String(start_time.to_string()),
+            );
+        }
+        if let Some(end_time) = end_time {
+            params.insert("endTime".to_string(), Value::String(end_time.to_string()));
+        }
+
+        let response = self
+            .get("/v5/market/historical-volatility", Some(&params), false)
+            .await?;
+        Ok(response.into_api_response())
     }
 
-    async fn get_insurance_pool(&self) -> Result<ApiResponse<Value>> {
-        todo!("get_insurance_pool not implemented")
+    async fn get_insurance_pool(
+        &self,
+        coin: Option<&str>,
+    ) -> Result<ApiResponse<Value>> {
+        let mut params: HashMap<String, Value> = HashMap::new();
+        if let Some(coin) = coin {
+            params.insert("coin".to_string(), Value::String(coin.to_string()));
+        }
+
+        let response = self
+            .get("/v5/market/insurance", Some(&params), false)
+            .await?;
+        Ok(response.into_api_response())
     }
 
     async fn get_risk_limit(&self) -> Result<ApiResponse<Value>> {
         todo!("get_risk_limit not implemented")
     }
 
-    async fn get_delivery_price(&self) -> Result<ApiResponse<Value>> {
-        todo!("get_delivery_price not implemented")
+    async fn get_delivery_price(
+        &self,
+        category: AllCategories,
+        symbol: Option<&str>,
+        base_coin: Option<&str>,
+        settle_coin: Option<&str>,
+        limit: Option<i32>,
+        cursor: Option<&str>,
+    ) -> Result<ApiResponse<Value>> {
+        require_category(
+            "get_delivery_price",
+            &category,
+            &[AllCategories::Inverse, AllCategories::Option],
+        )?;
+        require_option_symbol(&category, symbol)?;
+
+        let mut params: HashMap<String, Value> = HashMap::new();
+        params.insert("category".to_string(), Value::String(category.to_string()));
+
+        if let Some(symbol) = symbol {
+            params.insert("symbol".to_string(), Value::String(symbol.to_string()));
+        }
+        if let Some(base_coin) = base_coin {
+            params.insert("baseCoin".to_string(), Value::String(base_coin.to_string()));
+        }
+        if let Some(settle_coin) = settle_coin {
+            params.insert(
+                "settleCoin".to_string(),
+                Value::String(settle_coin.to_string()),
+            );
+        }
+        if let Some(limit) = limit {
+            params.insert("limit".to_string(), Value::String(limit.to_string()));
+        }
+        if let Some(cursor) = cursor {
+            params.insert("cursor".to_string(), Value::String(cursor.to_string()));
+        }
+
+        let response = self
+            .get("/v5/market/delivery-price", Some(&params), false)
+            .await?;
+        Ok(response.into_api_response())
     }
 
-    async fn get_new_delivery_price(&self) -> Result<ApiResponse<Value>> {
-        todo!("get_new_delivery_price not implemented")
+    async fn get_new_delivery_price(
+        &self,
+        category: AllCategories,
+        base_coin: &str,
+        settle_coin: Option<&str>,
+    ) -> Result<ApiResponse<Value>> {
+        require_category(
+            "get_new_delivery_price",
+            &category,
+            &[AllCategories::Option],
+        )?;
+
+        let mut params: HashMap<String, Value> = HashMap::new();
+        params.insert("category".to_string(), Value::String(category.to_string()));
+        params.insert("baseCoin".to_string(), Value::String(base_coin.to_string()));
+
+        if let Some(settle_coin) = settle_coin {
+            params.insert(
+                "settleCoin".to_string(),
+                Value::String(settle_coin.to_string()),
+            );
+        }
+
+        let response = self
+            .get("/v5/market/new-delivery-price", Some(&params), false)
+            .await?;
+        Ok(response.into_api_response())
     }
 
     async fn get_long_short_ratio(&self) -> Result<ApiResponse<Value>> {
         todo!("get_long_short_ratio not implemented")
     }
 
-    async fn get_index_price_components(&self) -> Result<ApiResponse<Value>> {
-        todo!("get_index_price_components not implemented")
+    async fn get_index_price_components(
+        &self,
+        symbol: &str,
+    ) -> Result<ApiResponse<Value>> {
+        require_symbol("get_index_price_components", symbol)?;
+
+        let mut params: HashMap<String, Value> = HashMap::new();
+        params.insert("indexName".to_string(), Value::String(symbol.to_string()));
+
+        let response = self
+            .get("/v5/market/index-price-components", Some(&params), false)
+            .await?;
+        Ok(response.into_api_response())
     }
 
-    async fn get_order_price_limit(&self) -> Result<ApiResponse<Value>> {
-        todo!("get_order_price_limit not implemented")
+    async fn get_order_price_limit(
+        &self,
+        category: AllCategories,
+        symbol: &str,
+    ) -> Result<ApiResponse<Value>> {
+        require_symbol("get_order_price_limit", symbol)?;
+
+        let mut params: HashMap<String, Value> = HashMap::new();
+        params.insert("category".to_string(), Value::String(category.to_string()));
+        params.insert("symbol".to_string(), Value::String(symbol.to_string()));
+
+        let response = self
+            .get("/v5/market/price-limit", Some(&params), false)
+            .await?;
+        Ok(response.into_api_response())
     }
 
-    async fn get_adl_alert(&self) -> Result<ApiResponse<Value>> {
-        todo!("get_adl_alert not implemented")
+    async fn get_adl_alert(
+        &self,
+        symbol: Option<&str>,
+    ) -> Result<ApiResponse<Value>> {
+        let mut params: HashMap<String, Value> = HashMap::new();
+        if let Some(symbol) = symbol {
+            params.insert("symbol".to_string(), Value::String(symbol.to_string()));
+        }
+
+        let response = self
+            .get("/v5/market/adlAlert", Some(&params), false)
+            .await?;
+        Ok(response.into_api_response())
     }
 
-    async fn get_fee_group_structure(&self) -> Result<ApiResponse<Value>> {
-        todo!("get_fee_group_structure not implemented")
+    async fn get_fee_group_structure(
+        &self,
+        product_type: &str,
+        group_id: Option<&str>,
+    ) -> Result<ApiResponse<Value>> {
+        let mut params: HashMap<String, Value> = HashMap::new();
+        params.insert(
+            "productType".to_string(),
+            Value::String(product_type.to_string()),
+        );
+        if let Some(group_id) = group_id {
+            params.insert("groupId".to_string(), Value::String(group_id.to_string()));
+        }
+
+        let response = self
+            .get("/v5/market/fee-group-info", Some(&params), false)
+            .await?;
+        Ok(response.into_api_response())
     }
 }
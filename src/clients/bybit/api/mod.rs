@@ -1,6 +1,7 @@
 //! API modules for different Bybit API endpoints.
 
 mod account;
+mod asset;
 mod market;
 mod position;
 mod trade;
@@ -5,16 +5,125 @@ use std::collections::HashMap;
 use async_trait::async_trait;
 use serde_json::Value;
 
-use crate::bybit::traits::PositionApi;
-use crate::bybit::types::{AllCategories, ApiResponse, SetTradingStopParams};
+use crate::bybit::models::InstrumentsInfoResult;
+use crate::bybit::traits::{MarketApi, PositionApi};
+use crate::bybit::types::{AllCategories, ApiResponse, PositionMode, SetTradingStopParams};
 use crate::bybit::BybitClient;
 use crate::error::Error;
 use crate::error::Result;
 use crate::http::HttpClient;
+use crate::utils::{amount_to_f64, check_leverage, OptionSymbol};
 
 use crate::bybit::BYBIT_IMPLEMENTED;
 use linkme::distributed_slice;
 
+/// Reject categories other than `linear` and `inverse` for derivative-only `method`.
+fn require_derivative(
+    method: &str,
+    category: &AllCategories,
+) -> Result<()> {
+    if category.is_derivative() {
+        return Ok(());
+    }
+    Err(Error::Validation(format!(
+        "{method} supports only linear and inverse categories, got {category}"
+    )))
+}
+
+impl BybitClient {
+    /// Maximum leverage of `symbol`, from `/v5/market/instruments-info`.
+    ///
+    /// The value is cached per category and symbol for a minute, shared by clones.
+    pub async fn max_leverage(
+        &self,
+        category: AllCategories,
+        symbol: &str,
+    ) -> Result<f64> {
+        require_derivative("max_leverage", &category)?;
+        let key = format!("{category}:{symbol}");
+        if let Some(max) = self.leverage_limits.get(&key) {
+            return Ok(max);
+        }
+
+        let info = self
+            .get_instruments_info(category, Some(symbol), None, None, None, None, None)
+            .await?
+            .parse::<InstrumentsInfoResult>()?;
+        let instrument = info
+            .list
+            .into_iter()
+            .find(|instrument| instrument.symbol == symbol)
+            .ok_or_else(|| Error::Validation(format!("Unknown symbol {symbol}")))?;
+        let max = amount_to_f64(instrument.leverage_filter.max_leverage);
+        self.leverage_limits.insert(key, max);
+        Ok(max)
+    }
+
+    /// Position mode of `symbol`, read from `/v5/position/list`.
+    ///
+    /// A symbol listing a hedge-mode position index (1 or 2) is in hedge mode, otherwise in
+    /// one-way mode. The mode is cached per category and symbol for a minute, shared by clones,
+    /// and updated by [`PositionApi::switch_position_mode`].
+    ///
+    /// Returns `Error::Validation` if the exchange lists no position for `symbol`.
+    pub async fn position_mode(
+        &self,
+        category: AllCategories,
+        symbol: &str,
+    ) -> Result<PositionMode> {
+        require_derivative("position_mode", &category)?;
+        let key = format!("{category}:{symbol}");
+        if let Some(mode) = self.position_modes.get(&key) {
+            return Ok(mode);
+        }
+
+        let response = self
+            .get_position_info(category, Some(symbol), None, None, None, None)
+            .await?;
+        let indexes = response
+            .result
+            .get("list")
+            .and_then(Value::as_array)
+            .map(|list| {
+                list.iter()
+                    .filter_map(|position| position.get("positionIdx").and_then(Value::as_i64))
+                    .collect::<Vec<_>>()
+            })
+            .unwrap_or_default();
+        if indexes.is_empty() {
+            return Err(Error::Validation(format!(
+                "No position listed for {symbol}, cannot tell its position mode"
+            )));
+        }
+        let mode = if indexes.iter().any(|idx| *idx != 0) {
+            PositionMode::Hedge
+        } else {
+            PositionMode::OneWay
+        };
+        self.position_modes.insert(key, mode);
+        Ok(mode)
+    }
+
+    /// [`PositionApi::set_leverage`], after checking both leverages against
+    /// [`Self::max_leverage`].
+    ///
+    /// Out-of-range values fail with `Error::Validation` naming the limit instead of an
+    /// exchange reject.
+    pub async fn set_leverage_checked(
+        &self,
+        category: AllCategories,
+        symbol: &str,
+        buy_leverage: u32,
+        sell_leverage: u32,
+    ) -> Result<ApiResponse<Value>> {
+        let max = self.max_leverage(category.clone(), symbol).await?;
+        check_leverage(symbol, f64::from(buy_leverage), max)?;
+        check_leverage(symbol, f64::from(sell_leverage), max)?;
+        self.set_leverage(category, symbol, buy_leverage, sell_leverage)
+            .await
+    }
+}
+
 #[distributed_slice(BYBIT_IMPLEMENTED)]
 pub static GET_POSITION_INFO: &'static str = "get_position_info";
 
@@ -42,6 +151,10 @@ impl PositionApi for BybitClient {
         limit: Option<u32>,
         cursor: Option<&str>,
     ) -> Result<ApiResponse<Value>> {
+        if let (AllCategories::Option, Some(symbol)) = (&category, symbol) {
+            OptionSymbol::parse(symbol)?;
+        }
+
         let mut params: HashMap<String, Value> = HashMap::new();
         params.insert("category".to_string(), Value::String(category.to_string()));
 
@@ -75,6 +188,7 @@ impl PositionApi for BybitClient {
         buy_leverage: u32,
         sell_leverage: u32,
     ) -> Result<ApiResponse<Value>> {
+        require_derivative("set_leverage", &category)?;
         let mut params: HashMap<String, Value> = HashMap::new();
         params.insert("category".to_string(), Value::String(category.to_string()));
         params.insert("symbol".to_string(), Value::String(symbol.to_string()));
@@ -100,6 +214,7 @@ impl PositionApi for BybitClient {
         symbol: Option<&str>,
         coin: Option<&str>,
     ) -> Result<ApiResponse<Value>> {
+        require_derivative("switch_position_mode", &category)?;
         if symbol.is_none() && coin.is_none() {
             return Err(Error::Validation(
                 "Either symbol or coin must be provided".to_string(),
@@ -120,6 +235,14 @@ impl PositionApi for BybitClient {
         let response = self
             .post("/v5/position/switch-mode", Some(&params), true)
             .await?;
+
+        // Keep `position_mode` in step; a switch by coin affects symbols we cannot name.
+        match (symbol, PositionMode::from_mode(mode)) {
+            (Some(symbol), Some(mode)) => self
+                .position_modes
+                .insert(format!("{category}:{symbol}"), mode),
+            _ => self.position_modes.remove_prefix(&format!("{category}:")),
+        }
         Ok(response.into_api_response())
     }
 
@@ -128,6 +251,8 @@ impl PositionApi for BybitClient {
         category: AllCategories,
         params: &SetTradingStopParams,
     ) -> Result<ApiResponse<Value>> {
+        params.validate()?;
+
         let json_value = serde_json::to_value(params)?;
         let mut api_params: HashMap<String, Value> = HashMap::new();
 
@@ -167,6 +292,7 @@ impl PositionApi for BybitClient {
         limit: Option<u32>,
         cursor: Option<&str>,
     ) -> Result<ApiResponse<Value>> {
+        require_derivative("get_closed_pnl", &category)?;
         let mut params: HashMap<String, Value> = HashMap::new();
         params.insert("category".to_string(), Value::String(category.to_string()));
 
@@ -1,11 +1,21 @@
 //! Trading API implementation.
 
+use std::borrow::Cow;
 use std::collections::HashMap;
+use std::io::Write;
+use std::time::Duration;
 
 use async_trait::async_trait;
+use futures::StreamExt;
 use linkme::distributed_slice;
+use serde::Deserialize;
 use serde_json::Value;
 
+use crate::bybit::idempotency::{
+    is_duplicate_order_link_id, place_with_stable_order_link_id_and_retry_hook,
+};
+use crate::bybit::models::{CancelAllResult, OrderAck, OrderResult};
+use crate::bybit::pagination::paginate;
 use crate::bybit::traits::TradeApi;
 use crate::bybit::types::{
     AllCategories, ApiResponse, CancelOrderFilter, CancelOrderParams, GetOrderHistoryParams,
@@ -17,6 +27,41 @@ use crate::http::HttpClient;
 
 use crate::bybit::BYBIT_IMPLEMENTED;
 
+/// Columns of `BybitClient::export_order_history_csv`, named after the Bybit order fields.
+const ORDER_HISTORY_CSV_COLUMNS: &[&str] = &[
+    "orderId",
+    "orderLinkId",
+    "symbol",
+    "side",
+    "orderType",
+    "orderStatus",
+    "price",
+    "qty",
+    "avgPrice",
+    "cumExecQty",
+    "cumExecValue",
+    "cumExecFee",
+    "timeInForce",
+    "reduceOnly",
+    "createdTime",
+    "updatedTime",
+];
+
+/// One CSV cell: strings as-is, other values in JSON form, missing or null as empty; quoted
+/// when it contains a comma, quote or line break.
+fn csv_cell(value: Option<&Value>) -> String {
+    let text = match value {
+        None | Some(Value::Null) => return String::new(),
+        Some(Value::String(s)) => s.clone(),
+        Some(other) => other.to_string(),
+    };
+    if text.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", text.replace('"', "\"\""))
+    } else {
+        text
+    }
+}
+
 #[distributed_slice(BYBIT_IMPLEMENTED)]
 pub static PLACE_ORDER: &'static str = "place_order";
 
@@ -38,13 +83,290 @@ pub static BATCH_PLACE_ORDER: &'static str = "batch_place_order";
 #[distributed_slice(BYBIT_IMPLEMENTED)]
 pub static BATCH_CANCEL_ORDER: &'static str = "batch_cancel_order";
 
-/// Default implementation of TradeApi for BybitClient
-#[async_trait]
-impl TradeApi for BybitClient {
-    async fn place_order(
+/// Maximum number of orders in one batch request for `category`.
+fn batch_limit(category: &AllCategories) -> usize {
+    match category {
+        AllCategories::Option => 10,
+        AllCategories::Spot | AllCategories::Linear | AllCategories::Inverse => 20,
+    }
+}
+
+/// Reject empty or over-limit batches before sending them.
+fn validate_batch_size(
+    category: &AllCategories,
+    len: usize,
+) -> Result<()> {
+    let max = batch_limit(category);
+    if len == 0 {
+        return Err(Error::Validation(
+            "Batch must contain at least one order".to_string(),
+        ));
+    }
+    if len > max {
+        return Err(Error::Validation(format!(
+            "Batch of {} orders exceeds the {} limit of {}",
+            len, category, max
+        )));
+    }
+    Ok(())
+}
+
+/// Pair each order of a batch with its entry in `result.list` and `retExtInfo.list`.
+fn order_results(
+    chunk: &[PlaceOrderParams],
+    response: &ApiResponse<Value>,
+) -> Vec<OrderResult> {
+    let list = response.result.get("list").and_then(Value::as_array);
+    let ext = response.batch_ext().unwrap_or_default();
+    chunk
+        .iter()
+        .enumerate()
+        .map(|(i, order)| {
+            let entry = list.and_then(|l| l.get(i));
+            let status = ext.list.get(i);
+            let field = |key: &str| {
+                entry
+                    .and_then(|o| o.get(key))
+                    .and_then(Value::as_str)
+                    .map(str::to_string)
+            };
+            OrderResult {
+                order_id: field("orderId").unwrap_or_default(),
+                order_link_id: field("orderLinkId")
+                    .filter(|id| !id.is_empty())
+                    .or_else(|| order.order_link_id.clone())
+                    .unwrap_or_default(),
+                code: status.map_or(0, |s| s.code),
+                msg: status.map_or_else(|| "OK".to_string(), |s| s.msg.clone()),
+            }
+        })
+        .collect()
+}
+
+impl BybitClient {
+    /// Place any number of orders, split into batches of the category's limit (20, or 10 for
+    /// option) sent concurrently.
+    ///
+    /// Returns one [`OrderResult`] per order in input order. A failed batch does not abort the
+    /// others: its orders are reported with the request error.
+    pub async fn batch_place_order_chunked(
+        &self,
+        category: AllCategories,
+        orders: &[PlaceOrderParams],
+    ) -> Vec<OrderResult> {
+        let requests = orders.chunks(batch_limit(&category)).map(|chunk| {
+            let category = category.clone();
+            async move { (chunk, self.batch_place_order(category, chunk).await) }
+        });
+
+        let mut results = Vec::with_capacity(orders.len());
+        for (chunk, response) in futures::future::join_all(requests).await {
+            match response {
+                Ok(response) => results.extend(order_results(chunk, &response)),
+                Err(e) => {
+                    let code = e.exchange_code().unwrap_or(-1);
+                    let msg = e.to_string();
+                    results.extend(chunk.iter().map(|order| OrderResult {
+                        order_id: String::new(),
+                        order_link_id: order.order_link_id.clone().unwrap_or_default(),
+                        code,
+                        msg: msg.clone(),
+                    }));
+                }
+            }
+        }
+        results
+    }
+
+    /// [`TradeApi::place_order`] returning the parsed [`OrderAck`].
+    ///
+    /// Fails with `Error::Json` if the exchange accepted the request but the response
+    /// carries no `orderId`.
+    pub async fn place_order_typed(
         &self,
         category: AllCategories,
         params: &PlaceOrderParams,
+    ) -> Result<OrderAck> {
+        let response = self.place_order(category, params).await?;
+        OrderAck::from_result(&response.result)
+    }
+
+    /// [`TradeApi::cancel_all_orders`] returning the parsed [`CancelAllResult`], to see which
+    /// orders were cancelled.
+    pub async fn cancel_all_orders_typed(
+        &self,
+        category: AllCategories,
+        symbol: Option<&str>,
+        base_coin: Option<&str>,
+        settle_coin: Option<&str>,
+        order_filter: Option<&OrderFilter>,
+        stop_order_type: Option<&str>,
+    ) -> Result<CancelAllResult> {
+        let response = self
+            .cancel_all_orders(
+                category,
+                symbol,
+                base_coin,
+                settle_coin,
+                order_filter,
+                stop_order_type,
+            )
+            .await?;
+        CancelAllResult::deserialize(&response.result).map_err(Error::Json)
+    }
+
+    /// Write every order of [`TradeApi::get_order_history`] matching `params` to `writer` as
+    /// CSV, fetching all pages; returns the number of rows written after the header.
+    ///
+    /// The columns are the Bybit order fields `orderId, orderLinkId, symbol, side, orderType,
+    /// orderStatus, price, qty, avgPrice, cumExecQty, cumExecValue, cumExecFee, timeInForce,
+    /// reduceOnly, createdTime, updatedTime`; a field missing from an order is an empty cell.
+    /// `params.cursor` is ignored. Write failures are `Error::Io`.
+    pub async fn export_order_history_csv<W: Write>(
+        &self,
+        category: AllCategories,
+        params: Option<&GetOrderHistoryParams>,
+        mut writer: W,
+    ) -> Result<usize> {
+        let params = params.cloned().unwrap_or_default();
+        let orders = paginate(
+            |cursor| {
+                let category = category.clone();
+                let page = GetOrderHistoryParams {
+                    cursor,
+                    ..params.clone()
+                };
+                async move { self.get_order_history(category, Some(&page)).await }
+            },
+            "nextPageCursor",
+        );
+        futures::pin_mut!(orders);
+
+        writeln!(writer, "{}", ORDER_HISTORY_CSV_COLUMNS.join(","))?;
+        let mut rows = 0;
+        while let Some(order) = orders.next().await {
+            let order = order?;
+            let row = ORDER_HISTORY_CSV_COLUMNS
+                .iter()
+                .map(|column| csv_cell(order.get(*column)))
+                .collect::<Vec<_>>()
+                .join(",");
+            writeln!(writer, "{row}")?;
+            rows += 1;
+        }
+        writer.flush()?;
+        Ok(rows)
+    }
+
+    /// Cancel all orders of `symbol`, then poll open orders with backoff until none are left
+    /// or `timeout` passes.
+    ///
+    /// Returns the number of open orders still reported at the end, so 0 means the book is flat.
+    pub async fn cancel_all_and_confirm(
+        &self,
+        category: AllCategories,
+        symbol: &str,
+        timeout: Duration,
+    ) -> Result<usize> {
+        self.cancel_all_orders(category.clone(), Some(symbol), None, None, None, None)
+            .await?;
+
+        let deadline = tokio::time::Instant::now() + timeout;
+        let mut delay = Duration::from_millis(100);
+        loop {
+            let response = self
+                .get_open_and_closed_orders(
+                    category.clone(),
+                    Some(symbol),
+                    None,
+                    None,
+                    None,
+                    None,
+                    Some(true),
+                    None,
+                    Some(50),
+                    None,
+                )
+                .await?;
+            let open = response
+                .result
+                .get("list")
+                .and_then(Value::as_array)
+                .map_or(0, Vec::len);
+            let now = tokio::time::Instant::now();
+            if open == 0 || now >= deadline {
+                return Ok(open);
+            }
+            log::debug!("{} open orders left on {}, polling again", open, symbol);
+            tokio::time::sleep(delay.min(deadline - now)).await;
+            delay = (delay * 2).min(Duration::from_secs(2));
+        }
+    }
+
+    /// `params` with `position_idx` filled from the symbol's position mode, if enabled with
+    /// `with_auto_position_idx` and the order is a linear or inverse one without an index.
+    async fn fill_position_idx<'a>(
+        &self,
+        category: &AllCategories,
+        params: &'a PlaceOrderParams,
+    ) -> Result<Cow<'a, PlaceOrderParams>> {
+        if !self.auto_position_idx() || params.position_idx.is_some() || !category.is_derivative() {
+            return Ok(Cow::Borrowed(params));
+        }
+        let mode = self.position_mode(category.clone(), &params.symbol).await?;
+        let mut params = params.clone();
+        params.position_idx =
+            Some(mode.position_idx(&params.side, params.reduce_only.unwrap_or(false)));
+        Ok(Cow::Owned(params))
+    }
+
+    /// Ack for an order whose retry was rejected as a duplicate `order_link_id`, built from
+    /// the order found by that id. Returns `duplicate` if no such order is found.
+    async fn find_placed_order(
+        &self,
+        category: &AllCategories,
+        symbol: &str,
+        order_link_id: &str,
+        duplicate: Error,
+    ) -> Result<ApiResponse<Value>> {
+        log::warn!(
+            "Order {order_link_id} was rejected as a duplicate after a retry, looking it up"
+        );
+        let response = self
+            .get_open_and_closed_orders(
+                category.clone(),
+                Some(symbol),
+                None,
+                None,
+                None,
+                Some(order_link_id),
+                None,
+                None,
+                None,
+                None,
+            )
+            .await?;
+        let Some(order) = response.result["list"]
+            .as_array()
+            .and_then(|list| list.first())
+        else {
+            return Err(duplicate);
+        };
+        Ok(ApiResponse {
+            result: serde_json::json!({
+                "orderId": order["orderId"],
+                "orderLinkId": order_link_id,
+            }),
+            ret_ext_info: serde_json::json!({}),
+            ..response
+        })
+    }
+
+    /// POST a single order to `/v5/order/create`.
+    async fn send_place_order(
+        &self,
+        category: &AllCategories,
+        params: &PlaceOrderParams,
     ) -> Result<ApiResponse<Value>> {
         let mut api_params: HashMap<String, Value> = HashMap::new();
 
@@ -66,6 +388,60 @@ impl TradeApi for BybitClient {
             .await?;
         Ok(response.into_api_response())
     }
+}
+
+/// Default implementation of TradeApi for BybitClient
+#[async_trait]
+impl TradeApi for BybitClient {
+    async fn place_order(
+        &self,
+        category: AllCategories,
+        params: &PlaceOrderParams,
+    ) -> Result<ApiResponse<Value>> {
+        let params = self.fill_position_idx(&category, params).await?;
+        let Some(settings) = self.auto_client_order_id() else {
+            return self.send_place_order(&category, &params).await;
+        };
+
+        let mut retried = false;
+        let (order_link_id, result) = place_with_stable_order_link_id_and_retry_hook(
+            &params,
+            settings,
+            |params| {
+                let category = category.clone();
+                async move { self.send_place_order(&category, &params).await }
+            },
+            |attempt, reason| {
+                retried = true;
+                self.notify_retry("/v5/order/create", attempt, reason)
+            },
+        )
+        .await;
+        let result = match result {
+            // An earlier attempt that timed out did create the order.
+            Err(e) if retried && is_duplicate_order_link_id(&e) => {
+                self.find_placed_order(&category, &params.symbol, &order_link_id, e)
+                    .await
+            }
+            result => result,
+        };
+        let mut response = result.map_err(|source| Error::OrderPlacement {
+            order_link_id: order_link_id.clone(),
+            source: Box::new(source),
+        })?;
+
+        // Make sure the ack carries the id we generated.
+        if let Value::Object(result) = &mut response.result {
+            let missing = result
+                .get("orderLinkId")
+                .and_then(Value::as_str)
+                .is_none_or(str::is_empty);
+            if missing {
+                result.insert("orderLinkId".to_string(), Value::String(order_link_id));
+            }
+        }
+        Ok(response)
+    }
 
     async fn cancel_order(
         &self,
@@ -260,6 +636,8 @@ impl TradeApi for BybitClient {
         category: AllCategories,
         orders: &[PlaceOrderParams],
     ) -> Result<ApiResponse<Value>> {
+        validate_batch_size(&category, orders.len())?;
+
         let mut request_data = Vec::with_capacity(orders.len());
         for order in orders {
             request_data.push(serde_json::to_value(order)?);
@@ -280,6 +658,8 @@ impl TradeApi for BybitClient {
         category: AllCategories,
         orders: &[CancelOrderParams],
     ) -> Result<ApiResponse<Value>> {
+        validate_batch_size(&category, orders.len())?;
+
         // Validate that each order has either orderId or orderLinkId
         for (i, order) in orders.iter().enumerate() {
             if order.order_id.is_none() && order.order_link_id.is_none() {
@@ -0,0 +1,46 @@
+//! Process-wide defaults for new Bybit clients.
+
+use std::sync::RwLock;
+
+use once_cell::sync::Lazy;
+
+/// `recv_window` used when no default has been set.
+const DEFAULT_RECV_WINDOW: u32 = 5000;
+
+static BYBIT_DEFAULTS: Lazy<RwLock<(u32, Option<String>)>> =
+    Lazy::new(|| RwLock::new((DEFAULT_RECV_WINDOW, None)));
+
+/// `recv_window` and referral ID applied by [`super::BybitClient::new_with_defaults`] and by
+/// clients created through [`crate::BybitClientsCache`].
+///
+/// Set once at startup; clients created earlier keep their settings.
+pub struct BybitDefaults;
+
+impl BybitDefaults {
+    /// Set the defaults for clients created from now on.
+    pub fn set(
+        recv_window: u32,
+        referral_id: Option<String>,
+    ) {
+        *BYBIT_DEFAULTS.write().unwrap_or_else(|e| e.into_inner()) = (recv_window, referral_id);
+    }
+
+    /// Restore the built-in defaults: 5000 ms and no referral ID.
+    pub fn reset() {
+        Self::set(DEFAULT_RECV_WINDOW, None)
+    }
+
+    /// Current default `recv_window` in milliseconds.
+    pub fn recv_window() -> u32 {
+        BYBIT_DEFAULTS.read().unwrap_or_else(|e| e.into_inner()).0
+    }
+
+    /// Current default referral ID.
+    pub fn referral_id() -> Option<String> {
+        BYBIT_DEFAULTS
+            .read()
+            .unwrap_or_else(|e| e.into_inner())
+            .1
+            .clone()
+    }
+}
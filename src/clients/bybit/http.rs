@@ -1,14 +1,20 @@
 //! HTTP client module for Bybit API communication.
 
 use async_trait::async_trait;
-use hmac::{Hmac, Mac};
 use reqwest::Method;
-use sha2::Sha256;
 use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
 
+use crate::bybit::signing::{self, SignType};
 use crate::bybit::types::GenericResponse;
 use crate::error::{Error, ExchangeResponseError, Result};
-use crate::http::{BaseHttpClient, HttpClient, RequestArgs};
+use crate::http::{
+    response_cache_key, status_error, BaseHttpClient, HttpClient, Observer, RequestArgs, TimeSource,
+};
+
+/// Exchange name reported to observers.
+const EXCHANGE: &str = "bybit";
 
 /// Domain constants.
 const DOMAIN_MAIN: &str = "bybit";
@@ -31,16 +37,21 @@ fn mask_headers(headers: &HashMap<String, String>) -> HashMap<String, String> {
 }
 
 /// HTTP client for Bybit API (main, testnet, demo; NO bytick).
+#[derive(Clone)]
 pub struct BybitHttpClient {
     base_client: BaseHttpClient,
     referral_id: Option<String>,
+    sign_type: SignType,
+    /// Base URL set with `with_base_url`, replacing the one for the testnet and demo flags.
+    base_url_override: Option<String>,
 }
 
 impl BybitHttpClient {
     /// Create a new Bybit HTTP client.
     ///
     /// * `api_key` - Optional API key.
-    /// * `api_secret` - Optional API secret.
+    /// * `api_secret` - Optional API secret, or the private key of an RSA API key in PEM form
+    ///   (detected with [`SignType::detect`]).
     /// * `testnet` - Use testnet if true, mainnet if false.
     /// * `demo` - Use demo trading endpoints if true.
     /// * `recv_window` - RecvWindow in ms.
@@ -62,125 +73,473 @@ impl BybitHttpClient {
 
         let base_url = format!("https://{}.{}.{}", sub, DOMAIN_MAIN, TLD_MAIN);
 
+        let sign_type = api_secret
+            .as_deref()
+            .map(SignType::detect)
+            .unwrap_or_default();
         let base_client = BaseHttpClient::new(base_url, api_key, api_secret, recv_window)?;
 
         Ok(Self {
             base_client,
             referral_id,
+            sign_type,
+            base_url_override: None,
         })
     }
 
-    /// Generates HMAC-SHA256 signature for Bybit V5 API.
-    fn generate_signature(
-        &self,
-        api_key: &str,
-        api_secret: &str,
-        payload: &str,
-        timestamp: i64,
-    ) -> Result<String> {
-        let param_str = format!(
-            "{}{}{}{}",
-            timestamp, api_key, self.base_client.recv_window, payload
-        );
+    pub fn is_shared_session_enabled(&self) -> bool {
+        self.base_client.is_shared_session_enabled()
+    }
 
-        let mut mac = Hmac::<Sha256>::new_from_slice(api_secret.as_bytes())
-            .map_err(|_| Error::Auth("Invalid API secret".to_string()))?;
+    /// Release the connection pool; a no-op for shared-session clients.
+    pub async fn close(self) {
+        self.base_client.close().await
+    }
 
-        mac.update(param_str.as_bytes());
-        let result = mac.finalize();
-        let signature = result.into_bytes();
+    pub fn set_recv_window(
+        &mut self,
+        recv_window: u32,
+    ) {
+        self.base_client.set_recv_window(recv_window)
+    }
 
-        Ok(hex::encode(signature))
+    /// Receive window in milliseconds sent with signed requests.
+    pub fn recv_window(&self) -> u32 {
+        self.base_client.recv_window
     }
 
-    /// Prepare HTTP payload string for signing (GET = query param string, others = sorted JSON).
-    fn prepare_payload(
-        method: &Method,
-        params: &HashMap<String, serde_json::Value>,
-    ) -> String {
-        if method == Method::GET {
-            if params.is_empty() {
-                return String::new();
-            }
-            let mut sorted_params: Vec<_> = params.iter().collect();
-            sorted_params.sort_by(|a, b| a.0.cmp(b.0));
-
-            sorted_params
-                .into_iter()
-                .filter_map(|(k, v)| {
-                    // Skip empty-string values.
-                    // We'll treat JSON null as absence.
-                    match v {
-                        serde_json::Value::Null => None,
-                        // Strings: only add if not empty.
-                        serde_json::Value::String(s) if s.is_empty() => None,
-                        other => {
-                            // For GET, according Bybit rules, everything should be flattened into a string
-                            // Integer, bool, float types get .to_string()
-                            // String just use as is
-                            // Else, for objects/arrays, use compact JSON value
-                            let sval = match other {
-                                serde_json::Value::String(s) => s.clone(),
-                                serde_json::Value::Number(n) => n.to_string(),
-                                serde_json::Value::Bool(b) => b.to_string(),
-                                _ => other.to_string(),
-                            };
-                            if sval.is_empty() {
-                                None
-                            } else {
-                                Some(format!("{}={}", k, sval))
-                            }
-                        }
-                    }
-                })
-                .collect::<Vec<_>>()
-                .join("&")
-        } else if params.is_empty() {
-            "{}".to_string()
-        } else {
-            let mut sorted_params: Vec<_> = params.iter().collect();
-            sorted_params.sort_by(|a, b| a.0.cmp(b.0));
-            let filtered: serde_json::Map<String, serde_json::Value> = sorted_params
-                .into_iter()
-                .filter_map(|(k, v)| match v {
-                    serde_json::Value::Null => None,
-                    serde_json::Value::String(s) if s.is_empty() => None,
-                    _ => Some((k.clone(), v.clone())),
-                })
-                .collect();
-            serde_json::to_string(&filtered).unwrap_or_else(|_| "{}".to_string())
+    /// Referral ID sent as the `Referer` header, if any.
+    pub fn referral_id(&self) -> Option<&str> {
+        self.referral_id.as_deref()
+    }
+
+    /// Sign requests for an RSA API key instead of HMAC-SHA256; the API secret must then be
+    /// the private key (see [`SignType`]).
+    pub fn with_sign_type(
+        mut self,
+        sign_type: SignType,
+    ) -> Self {
+        self.sign_type = sign_type;
+        self
+    }
+
+    /// Key type used to sign authenticated requests.
+    pub fn sign_type(&self) -> SignType {
+        self.sign_type
+    }
+
+    /// Route this client through a proxy (`http://`, `https://` or `socks5://` URL).
+    ///
+    /// Returns `Error::Config` if the URL is invalid.
+    pub fn with_proxy(
+        mut self,
+        url: &str,
+    ) -> Result<Self> {
+        self.base_client.set_proxy(url)?;
+        Ok(self)
+    }
+
+    /// Override the base URL, e.g. to point the client at a mock server or a gateway.
+    pub fn with_base_url(
+        mut self,
+        base_url: &str,
+    ) -> Self {
+        self.base_client.set_base_url(base_url);
+        self.base_url_override = Some(self.base_client.base_url.clone());
+        self
+    }
+
+    /// Base URL requests are sent to.
+    pub fn base_url(&self) -> &str {
+        &self.base_client.base_url
+    }
+
+    /// Base URL set with [`Self::with_base_url`], `None` if the client uses the Bybit URL for
+    /// its testnet and demo flags.
+    pub fn base_url_override(&self) -> Option<&str> {
+        self.base_url_override.as_deref()
+    }
+
+    /// Set the maximum accepted response body size in bytes (default 16 MiB).
+    pub fn set_max_response_bytes(
+        &mut self,
+        max_response_bytes: usize,
+    ) {
+        self.base_client.set_max_response_bytes(max_response_bytes)
+    }
+
+    /// Limit the requests this client (and its clones) has in flight; further requests wait
+    /// for a free slot. `None` (the default) removes the limit, `Some(0)` is treated as 1.
+    pub fn set_max_concurrency(
+        &mut self,
+        max_concurrency: Option<usize>,
+    ) {
+        self.base_client.set_max_concurrency(max_concurrency)
+    }
+
+    /// Configured in-flight limit, `None` if unlimited.
+    pub fn max_concurrency(&self) -> Option<usize> {
+        self.base_client.max_concurrency()
+    }
+
+    /// Request slots currently free under the in-flight limit, `None` if unlimited.
+    pub fn available_request_slots(&self) -> Option<usize> {
+        self.base_client.available_request_slots()
+    }
+
+    /// GET `endpoint`, answering from memory if the same request (endpoint, params and `auth`)
+    /// succeeded within `ttl`; the client and its clones share the cache.
+    ///
+    /// Opt-in per call and meant for data that rarely changes, such as instruments or fee
+    /// rates. Errors are not cached.
+    pub async fn get_cached(
+        &self,
+        endpoint: &str,
+        params: Option<&HashMap<String, serde_json::Value>>,
+        auth: bool,
+        ttl: Duration,
+    ) -> Result<GenericResponse> {
+        let key = response_cache_key(endpoint, params, auth);
+        if let Some(response) = self.base_client.cached_response(&key) {
+            return Ok(response);
         }
+        let response = self.get(endpoint, params, auth).await?;
+        self.base_client.cache_response(key, &response, ttl);
+        Ok(response)
     }
 
-    pub fn is_shared_session_enabled(&self) -> bool {
-        self.base_client.is_shared_session_enabled()
+    /// Drop all responses cached by [`Self::get_cached`].
+    pub fn clear_response_cache(&self) {
+        self.base_client.clear_response_cache()
     }
 
-    pub fn set_recv_window(
+    /// Proxy URL used by this client, if any.
+    pub fn proxy(&self) -> Option<String> {
+        self.base_client.proxy()
+    }
+
+    /// Register an observer notified around every request of this client.
+    pub fn set_observer(
         &mut self,
-        recv_window: u32,
+        observer: Arc<dyn Observer>,
     ) {
-        self.base_client.set_recv_window(recv_window)
+        self.base_client.set_observer(observer)
     }
-}
 
-#[async_trait]
-impl HttpClient<GenericResponse> for BybitHttpClient {
-    async fn build_request_args(
+    /// Log exchange errors at the level `filter` returns for their code instead of `error`.
+    pub fn set_error_log_filter(
+        &mut self,
+        filter: impl Fn(i64) -> log::Level + Send + Sync + 'static,
+    ) {
+        self.base_client.set_error_log_filter(filter)
+    }
+
+    /// Level an exchange error with return code `code` is logged at.
+    pub fn error_log_level(
+        &self,
+        code: i64,
+    ) -> log::Level {
+        self.base_client.error_log_level(code)
+    }
+
+    /// Replace the clock used for signing timestamps (the system clock by default); shared
+    /// by clones made afterwards.
+    pub fn set_time_source(
+        &mut self,
+        time_source: Arc<dyn TimeSource>,
+    ) {
+        self.base_client.set_time_source(time_source)
+    }
+
+    /// Current time of the client's clock, in ms since the Unix epoch.
+    pub fn now_ms(&self) -> i64 {
+        self.base_client.now_ms()
+    }
+
+    /// Register a sink receiving an audit record (signature masked) for every request.
+    #[cfg(feature = "audit")]
+    pub fn set_audit_sink(
+        &mut self,
+        sink: Arc<dyn crate::audit::AuditSink>,
+    ) {
+        self.base_client.set_audit_sink(sink)
+    }
+
+    /// Warn when the server `time` of responses drifts from the local clock by more than
+    /// `threshold_ms`, once per `warn_every` such responses.
+    pub fn set_drift_check(
+        &mut self,
+        threshold_ms: u64,
+        warn_every: u64,
+    ) {
+        self.base_client.set_drift_check(threshold_ms, warn_every)
+    }
+
+    /// Count a retry of `endpoint` and notify the observer.
+    pub(crate) fn notify_retry(
+        &self,
+        endpoint: &str,
+        attempt: u32,
+        reason: &Error,
+    ) {
+        self.base_client
+            .notify_retry(EXCHANGE, endpoint, attempt, reason)
+    }
+
+    /// Total number of retries made by this client.
+    pub fn total_retries(&self) -> u64 {
+        self.base_client.total_retries()
+    }
+
+    /// Last measured server minus local clock difference in milliseconds, if checked.
+    pub fn last_server_drift_ms(&self) -> Option<i64> {
+        self.base_client.last_server_drift_ms()
+    }
+
+    /// Stored API key and secret; `Error::Auth` if either is missing.
+    fn stored_credentials(&self) -> Result<(&str, &str)> {
+        let api_key = self.base_client.api_key.as_deref().ok_or_else(|| {
+            Error::Auth("API key required for authenticated requests".to_string())
+        })?;
+        let api_secret = self.base_client.api_secret.as_deref().ok_or_else(|| {
+            Error::Auth("API secret required for authenticated requests".to_string())
+        })?;
+        Ok((api_key, api_secret))
+    }
+
+    /// Send a GET request signed with `credentials` (`(api_key, api_secret)`) instead of the
+    /// stored key, e.g. for a one-off call on behalf of a sub-account. `None` signs with the
+    /// stored key.
+    pub async fn get_with_auth(
+        &self,
+        endpoint: &str,
+        params: Option<&HashMap<String, serde_json::Value>>,
+        credentials: Option<(&str, &str)>,
+    ) -> Result<GenericResponse> {
+        self.request_with_auth(Method::GET, endpoint, params, credentials)
+            .await
+    }
+
+    /// Send a POST request signed with `credentials`, see [`Self::get_with_auth`].
+    pub async fn post_with_auth(
+        &self,
+        endpoint: &str,
+        params: Option<&HashMap<String, serde_json::Value>>,
+        credentials: Option<(&str, &str)>,
+    ) -> Result<GenericResponse> {
+        self.request_with_auth(Method::POST, endpoint, params, credentials)
+            .await
+    }
+
+    /// Send a PUT request signed with `credentials`, see [`Self::get_with_auth`].
+    pub async fn put_with_auth(
+        &self,
+        endpoint: &str,
+        params: Option<&HashMap<String, serde_json::Value>>,
+        credentials: Option<(&str, &str)>,
+    ) -> Result<GenericResponse> {
+        self.request_with_auth(Method::PUT, endpoint, params, credentials)
+            .await
+    }
+
+    /// Send a DELETE request signed with `credentials`, see [`Self::get_with_auth`].
+    pub async fn delete_with_auth(
+        &self,
+        endpoint: &str,
+        params: Option<&HashMap<String, serde_json::Value>>,
+        credentials: Option<(&str, &str)>,
+    ) -> Result<GenericResponse> {
+        self.request_with_auth(Method::DELETE, endpoint, params, credentials)
+            .await
+    }
+
+    async fn request_with_auth(
+        &self,
+        method: Method,
+        endpoint: &str,
+        params: Option<&HashMap<String, serde_json::Value>>,
+        credentials: Option<(&str, &str)>,
+    ) -> Result<GenericResponse> {
+        let credentials = match credentials {
+            Some(credentials) => credentials,
+            None => self.stored_credentials()?,
+        };
+        self.request(method, endpoint, params, Some(credentials))
+            .await
+    }
+
+    /// Send a request, signed with `credentials` if given.
+    async fn request(
+        &self,
+        method: Method,
+        endpoint: &str,
+        params: Option<&HashMap<String, serde_json::Value>>,
+        credentials: Option<(&str, &str)>,
+    ) -> Result<GenericResponse> {
+        // Wait for a slot first, so queued requests are not counted as in flight.
+        let _permit = self.base_client.acquire_request_permit().await;
+        // Shared-pool requests are counted until they complete or are cancelled, so
+        // `SharedSessionManager::close()` can wait for them.
+        let _in_flight = self.base_client.in_flight_guard();
+        let request = self.execute_request(method.clone(), endpoint, params, credentials);
+        #[cfg(feature = "tracing")]
+        let request = tracing::Instrument::instrument(
+            request,
+            crate::http::request_span(EXCHANGE, &method, endpoint),
+        );
+        request.await
+    }
+
+    /// Build, send and check a request, notifying the observer around it.
+    async fn execute_request(
+        &self,
+        method: Method,
+        endpoint: &str,
+        params: Option<&HashMap<String, serde_json::Value>>,
+        credentials: Option<(&str, &str)>,
+    ) -> Result<GenericResponse> {
+        let request_args = self.request_args(method.clone(), endpoint, params, credentials)?;
+
+        let started = self.base_client.notify_request(EXCHANGE, &method, endpoint);
+        let sent_ms = self.base_client.now_ms();
+        let mut status_code = None;
+        let result = self
+            .send_request(&method, &request_args, &mut status_code)
+            .await;
+        if let Ok(response) = &result {
+            if self.base_client.drift_check_enabled() && response.time > 0 {
+                self.base_client.record_server_time(
+                    response.time as i64,
+                    sent_ms,
+                    self.base_client.now_ms(),
+                );
+            }
+        }
+        let ret_code = match &result {
+            Ok(response) => Some(i64::from(response.ret_code)),
+            Err(e) => e.exchange_code(),
+        };
+        self.base_client.notify_response(
+            started,
+            EXCHANGE,
+            &method,
+            endpoint,
+            status_code,
+            ret_code,
+        );
+        #[cfg(feature = "tracing")]
+        crate::http::trace_outcome(&request_args.url, status_code, ret_code, &result);
+        #[cfg(feature = "audit")]
+        if let Some(sink) = self.base_client.audit_sink() {
+            sink.record(&crate::audit::AuditRecord {
+                timestamp_ms: self.base_client.now_ms(),
+                exchange: EXCHANGE,
+                method: &method,
+                endpoint,
+                url: crate::audit::mask_url(&request_args.url),
+                body: request_args.json.as_ref().map(crate::audit::mask_body),
+                status: status_code,
+                ret_code,
+                response: match &result {
+                    Ok(response) => Some(serde_json::json!({
+                        "retCode": response.ret_code,
+                        "retMsg": response.ret_msg,
+                        "result": response.result,
+                        "retExtInfo": response.ret_ext_info,
+                        "time": response.time,
+                    })),
+                    Err(Error::Exchange(e)) => Some(e.raw().clone()),
+                    Err(_) => None,
+                },
+                error: result.as_ref().err().map(ToString::to_string),
+            });
+        }
+        result
+    }
+
+    /// Send a prepared request and check the HTTP status and exchange return code.
+    ///
+    /// `status_code` is set as soon as a response is received.
+    async fn send_request(
+        &self,
+        method: &Method,
+        request_args: &RequestArgs,
+        status_code: &mut Option<u16>,
+    ) -> Result<GenericResponse> {
+        let mut request = self
+            .base_client
+            .http_client()
+            .request(method.clone(), &request_args.url);
+
+        if let Some(json) = &request_args.json {
+            request = request.json(json);
+        }
+
+        for (k, v) in &request_args.headers {
+            request = request.header(k, v);
+        }
+
+        if log::log_enabled!(log::Level::Debug) {
+            log::debug!(
+                "Making async {:?} request to {} with headers: {:?}",
+                method,
+                &request_args.url,
+                mask_headers(&request_args.headers),
+            );
+        }
+
+        let response = request.send().await.map_err(Error::Http)?;
+        let status = response.status();
+        *status_code = Some(status.as_u16());
+
+        if !status.is_success() {
+            log::error!(
+									"HTTP error during async request: method={}, url={}, headers={:?}, status={}, response={:?}",
+									method,
+									&request_args.url,
+									mask_headers(&request_args.headers),
+									status,
+									&response
+							);
+            return Err(status_error(response));
+        }
+
+        // First parse the response as serde_json::Value
+        let value = self.base_client.read_json(response).await?;
+        let ret_code = value.get("retCode").and_then(|v| v.as_i64()).unwrap_or(0);
+
+        if ret_code != 0 {
+            let err = ExchangeResponseError::from(value);
+            log::log!(
+                self.base_client.error_log_level(err.code()),
+                "ExchangeResponseError during async request: method={}, url={}, headers={:?}, status={}, error={}",
+                method,
+                &request_args.url,
+                mask_headers(&request_args.headers),
+                status,
+                err
+            );
+            return Err(Error::Exchange(err));
+        }
+
+        // It's ok, parse as GenericResponse
+        let generic: GenericResponse = serde_json::from_value(value).map_err(Error::Json)?;
+        Ok(generic)
+    }
+
+    /// Build the URL, body and headers of a request, signed with `credentials` if given.
+    fn request_args(
         &self,
         method: reqwest::Method,
         endpoint: &str,
         params: Option<&HashMap<String, serde_json::Value>>,
-        auth: bool,
+        credentials: Option<(&str, &str)>,
     ) -> Result<RequestArgs> {
         // Build request args
         let params = params.cloned().unwrap_or_default();
-        let timestamp = (std::time::SystemTime::now()
-            .duration_since(std::time::UNIX_EPOCH)
-            .unwrap()
-            .as_millis() as u64) as i64;
+        let timestamp = self.base_client.now_ms();
 
-        let payload = Self::prepare_payload(&method, &params);
+        let payload = signing::prepare_payload(&method, &params);
 
         let url = if method == reqwest::Method::GET && !payload.is_empty() {
             format!("{}{}?{}", self.base_client.base_url, endpoint, payload)
@@ -199,19 +558,22 @@ impl HttpClient<GenericResponse> for BybitHttpClient {
 
         // Prepare authentication headers if required
         let mut headers = HashMap::new();
-        if auth {
-            let api_key = self.base_client.api_key.as_ref().ok_or_else(|| {
-                Error::Auth("API key required for authenticated requests".to_string())
-            })?;
-            let api_secret = self.base_client.api_secret.as_ref().ok_or_else(|| {
-                Error::Auth("API secret required for authenticated requests".to_string())
-            })?;
-
-            let signature = self.generate_signature(api_key, api_secret, &payload, timestamp)?;
-
-            headers.insert("X-BAPI-API-KEY".to_string(), api_key.clone());
+        if let Some((api_key, api_secret)) = credentials {
+            let signature = signing::sign(
+                self.sign_type,
+                api_key,
+                api_secret,
+                self.base_client.recv_window,
+                &payload,
+                timestamp,
+            )?;
+
+            headers.insert("X-BAPI-API-KEY".to_string(), api_key.to_string());
             headers.insert("X-BAPI-SIGN".to_string(), signature);
-            headers.insert("X-BAPI-SIGN-TYPE".to_string(), "2".to_string());
+            headers.insert(
+                "X-BAPI-SIGN-TYPE".to_string(),
+                signing::SIGN_TYPE_HEADER_VALUE.to_string(),
+            );
             headers.insert("X-BAPI-TIMESTAMP".to_string(), timestamp.to_string());
             headers.insert(
                 "X-BAPI-RECV-WINDOW".to_string(),
@@ -241,6 +603,24 @@ impl HttpClient<GenericResponse> for BybitHttpClient {
             data: None,
         })
     }
+}
+
+#[async_trait]
+impl HttpClient<GenericResponse> for BybitHttpClient {
+    async fn build_request_args(
+        &self,
+        method: reqwest::Method,
+        endpoint: &str,
+        params: Option<&HashMap<String, serde_json::Value>>,
+        auth: bool,
+    ) -> Result<RequestArgs> {
+        let credentials = if auth {
+            Some(self.stored_credentials()?)
+        } else {
+            None
+        };
+        self.request_args(method, endpoint, params, credentials)
+    }
 
     async fn async_request(
         &self,
@@ -249,59 +629,11 @@ impl HttpClient<GenericResponse> for BybitHttpClient {
         params: Option<&HashMap<String, serde_json::Value>>,
         auth: bool,
     ) -> Result<GenericResponse> {
-        let request_args = self
-            .build_request_args(method.clone(), endpoint, params, auth)
-            .await?;
-
-        let mut request = self
-            .base_client
-            .client
-            .request(method.clone(), &request_args.url);
-
-        if let Some(json) = &request_args.json {
-            request = request.json(json);
-        }
-
-        for (k, v) in &request_args.headers {
-            request = request.header(k, v);
-        }
-
-        println!("Request Args: {:?}", request_args);
-
-        let response = request.send().await.map_err(Error::Http)?;
-        let status = response.status();
-
-        if !status.is_success() {
-            log::error!(
-									"HTTP error during async request: method={}, url={}, headers={:?}, status={}, response={:?}",
-									method,
-									&request_args.url,
-									mask_headers(&request_args.headers),
-									status,
-									&response
-							);
-            return Err(Error::Http(response.error_for_status().unwrap_err()));
-        }
-
-        // First parse the response as serde_json::Value
-        let value: serde_json::Value = response.json().await.map_err(Error::Http)?;
-        let ret_code = value.get("retCode").and_then(|v| v.as_i64()).unwrap_or(0);
-
-        if ret_code != 0 {
-            let err = ExchangeResponseError::from(value);
-            log::error!(
-									"ExchangeResponseError during async request: method={}, url={}, headers={:?}, status={}, error={}",
-									method,
-									&request_args.url,
-									mask_headers(&request_args.headers),
-									status,
-									err
-							);
-            return Err(Error::Exchange(err));
-        }
-
-        // It's ok, parse as GenericResponse
-        let generic: GenericResponse = serde_json::from_value(value).map_err(Error::Json)?;
-        Ok(generic)
+        let credentials = if auth {
+            Some(self.stored_credentials()?)
+        } else {
+            None
+        };
+        self.request(method, endpoint, params, credentials).await
     }
 }
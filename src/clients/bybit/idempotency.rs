@@ -0,0 +1,162 @@
+//! Auto-generated `orderLinkId`s for safely retrying order placement.
+//!
+//! When a `place_order` request times out, the order may or may not have been created.
+//! Retrying with the same `orderLinkId` is safe: Bybit rejects a duplicate with
+//! [`DUPLICATE_ORDER_LINK_ID_CODE`] instead of placing a second order.
+
+use std::future::Future;
+
+use crate::bybit::types::PlaceOrderParams;
+use crate::error::{Error, Result};
+
+/// Bybit `retCode` rejecting an `orderLinkId` that is already in use.
+pub const DUPLICATE_ORDER_LINK_ID_CODE: i64 = 110072;
+
+/// Maximum length of a Bybit `orderLinkId`.
+pub const ORDER_LINK_ID_MAX_LEN: usize = 36;
+
+/// Length of the encoded UUIDv7 part of a generated id.
+const ENCODED_UUID_LEN: usize = 22;
+
+/// Maximum prefix length, so that prefix + encoded UUID fits in [`ORDER_LINK_ID_MAX_LEN`].
+pub const ORDER_LINK_ID_MAX_PREFIX_LEN: usize = ORDER_LINK_ID_MAX_LEN - ENCODED_UUID_LEN;
+
+const BASE62: &[u8; 62] = b"0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz";
+
+/// Settings for auto-generated order link ids, see `BybitClient::with_auto_client_order_id`.
+#[derive(Debug, Clone)]
+pub struct AutoClientOrderId {
+    prefix: String,
+    max_retries: u32,
+}
+
+impl AutoClientOrderId {
+    /// Create settings with an id `prefix` and the number of retries after a transport error.
+    ///
+    /// The prefix may contain letters, digits, `-` and `_`, and be at most
+    /// [`ORDER_LINK_ID_MAX_PREFIX_LEN`] (14) characters long.
+    pub fn new(
+        prefix: &str,
+        max_retries: u32,
+    ) -> Result<Self> {
+        if prefix.len() > ORDER_LINK_ID_MAX_PREFIX_LEN {
+            return Err(Error::Validation(format!(
+                "orderLinkId prefix {prefix:?} is longer than {ORDER_LINK_ID_MAX_PREFIX_LEN} characters"
+            )));
+        }
+        if !prefix
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+        {
+            return Err(Error::Validation(format!(
+                "orderLinkId prefix {prefix:?} may only contain letters, digits, '-' and '_'"
+            )));
+        }
+        Ok(Self {
+            prefix: prefix.to_string(),
+            max_retries,
+        })
+    }
+
+    pub fn prefix(&self) -> &str {
+        &self.prefix
+    }
+
+    pub fn max_retries(&self) -> u32 {
+        self.max_retries
+    }
+
+    /// Generate a new order link id.
+    pub fn generate(&self) -> String {
+        generate_order_link_id(&self.prefix)
+    }
+}
+
+/// Generate an order link id: `prefix` followed by a UUIDv7 encoded as 22 base62 characters.
+///
+/// UUIDv7 ids are time-ordered, so generated ids sort by creation time. The prefix is
+/// truncated so the result never exceeds [`ORDER_LINK_ID_MAX_LEN`].
+pub fn generate_order_link_id(prefix: &str) -> String {
+    let prefix = &prefix[..prefix.len().min(ORDER_LINK_ID_MAX_PREFIX_LEN)];
+    let mut value = uuid::Uuid::now_v7().as_u128();
+    let mut encoded = [b'0'; ENCODED_UUID_LEN];
+    for slot in encoded.iter_mut().rev() {
+        *slot = BASE62[(value % 62) as usize];
+        value /= 62;
+    }
+    let mut id = String::with_capacity(prefix.len() + ENCODED_UUID_LEN);
+    id.push_str(prefix);
+    id.push_str(std::str::from_utf8(&encoded).expect("base62 is ASCII"));
+    id
+}
+
+/// Whether a failed request may not have reached the exchange and is safe to retry
+/// with the same order link id (connection errors, timeouts).
+pub fn is_retryable(error: &Error) -> bool {
+    match error {
+        Error::Http(e) => {
+            !e.is_status() && (error.is_connect() || error.is_timeout() || e.is_request())
+        }
+        _ => false,
+    }
+}
+
+/// Whether `error` is Bybit rejecting an `orderLinkId` that is already in use.
+///
+/// After a retry this means an earlier attempt did create the order.
+pub fn is_duplicate_order_link_id(error: &Error) -> bool {
+    error.exchange_code() == Some(DUPLICATE_ORDER_LINK_ID_CODE)
+}
+
+/// Send an order, retrying transport errors up to `max_retries` times with the same
+/// `order_link_id`.
+///
+/// If `params.order_link_id` is `None`, one is generated with `settings` before the first
+/// attempt. Returns the order link id used together with the result of the last attempt.
+pub async fn place_with_stable_order_link_id<F, Fut, T>(
+    params: &PlaceOrderParams,
+    settings: &AutoClientOrderId,
+    send: F,
+) -> (String, Result<T>)
+where
+    F: FnMut(PlaceOrderParams) -> Fut,
+    Fut: Future<Output = Result<T>>,
+{
+    place_with_stable_order_link_id_and_retry_hook(params, settings, send, |_, _| {}).await
+}
+
+/// Like [`place_with_stable_order_link_id`], calling `on_retry(attempt, error)` before each
+/// re-send with the retry number (starting at 1) and the error of the previous attempt.
+pub async fn place_with_stable_order_link_id_and_retry_hook<F, Fut, T, R>(
+    params: &PlaceOrderParams,
+    settings: &AutoClientOrderId,
+    mut send: F,
+    mut on_retry: R,
+) -> (String, Result<T>)
+where
+    F: FnMut(PlaceOrderParams) -> Fut,
+    Fut: Future<Output = Result<T>>,
+    R: FnMut(u32, &Error),
+{
+    let mut params = params.clone();
+    let order_link_id = params
+        .order_link_id
+        .get_or_insert_with(|| settings.generate())
+        .clone();
+
+    let mut attempt = 0;
+    loop {
+        let result = send(params.clone()).await;
+        match &result {
+            Err(e) if attempt < settings.max_retries && is_retryable(e) => {
+                attempt += 1;
+                log::warn!(
+                    "Retrying order {order_link_id} after transport error (attempt {attempt}/{}): {e}",
+                    settings.max_retries
+                );
+                on_retry(attempt, e);
+            }
+            _ => return (order_link_id, result),
+        }
+    }
+}
@@ -0,0 +1,185 @@
+//! Rotation across several API keys of one account.
+//!
+//! [`KeyPool`] hands out a client per request, spreading requests over the keys in proportion
+//! to their weights. The pool builds one client per key up front, so rotating does not create a
+//! new client per request. [`KeyPool::with_max_concurrency`] limits each key's in-flight
+//! requests; keys without a free slot are skipped while another key has one.
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::Instant;
+
+use reqwest::Method;
+use serde_json::Value;
+
+use crate::bybit::types::ApiResponse;
+use crate::bybit::BybitClient;
+use crate::error::{Error, Result};
+
+/// One key of the pool, its client and its usage.
+struct PooledKey {
+    api_key: String,
+    client: Arc<BybitClient>,
+    weight: u32,
+    uses: u64,
+    last_used: Option<Instant>,
+}
+
+impl PooledKey {
+    /// Uses relative to the key's share of the traffic; the key with the lowest load goes next.
+    fn load(&self) -> f64 {
+        self.uses as f64 / f64::from(self.weight)
+    }
+}
+
+/// Weighted least-recently-used rotation over several (key, secret) pairs.
+pub struct KeyPool {
+    keys: Mutex<Vec<PooledKey>>,
+}
+
+impl KeyPool {
+    /// Pool of equally weighted keys.
+    ///
+    /// Returns `Error::Config` if `keys` is empty.
+    pub fn new(
+        keys: Vec<(String, String)>,
+        testnet: bool,
+        demo: bool,
+    ) -> Result<Self> {
+        let keys = keys
+            .into_iter()
+            .map(|(api_key, api_secret)| (api_key, api_secret, 1))
+            .collect();
+        Self::with_weights(keys, testnet, demo)
+    }
+
+    /// Pool of `(key, secret, weight)` entries; a key with weight 2 serves twice as many
+    /// requests as a key with weight 1.
+    ///
+    /// Returns `Error::Config` if `keys` is empty or a weight is 0.
+    pub fn with_weights(
+        keys: Vec<(String, String, u32)>,
+        testnet: bool,
+        demo: bool,
+    ) -> Result<Self> {
+        if keys.is_empty() {
+            return Err(Error::Config(
+                "KeyPool needs at least one API key".to_string(),
+            ));
+        }
+        if let Some((api_key, _, _)) = keys.iter().find(|(_, _, weight)| *weight == 0) {
+            return Err(Error::Config(format!(
+                "KeyPool weight for key {api_key} must be greater than 0"
+            )));
+        }
+        let keys = keys
+            .into_iter()
+            .map(|(api_key, api_secret, weight)| {
+                let client = BybitClient::new_with_defaults(
+                    Some(api_key.clone()),
+                    Some(api_secret),
+                    testnet,
+                    demo,
+                )?;
+                Ok(PooledKey {
+                    api_key,
+                    client: Arc::new(client),
+                    weight,
+                    uses: 0,
+                    last_used: None,
+                })
+            })
+            .collect::<Result<_>>()?;
+        Ok(Self {
+            keys: Mutex::new(keys),
+        })
+    }
+
+    /// Send the pool's requests to `base_url`, e.g. a gateway or a mock server.
+    pub fn with_base_url(
+        self,
+        base_url: &str,
+    ) -> Self {
+        self.map_clients(|client| client.with_base_url(base_url))
+    }
+
+    /// Limit each key's in-flight requests to `max_concurrency`; every key has its own limit.
+    /// `Some(0)` is treated as 1, `None` removes the limits.
+    pub fn with_max_concurrency(
+        self,
+        max_concurrency: Option<usize>,
+    ) -> Self {
+        self.map_clients(|mut client| {
+            client.set_max_concurrency(max_concurrency);
+            client
+        })
+    }
+
+    /// Replace every key's client with `f` applied to a copy of it.
+    fn map_clients(
+        mut self,
+        f: impl Fn(BybitClient) -> BybitClient,
+    ) -> Self {
+        let keys = self.keys.get_mut().unwrap_or_else(|e| e.into_inner());
+        for key in keys.iter_mut() {
+            key.client = Arc::new(f(BybitClient::clone(&key.client)));
+        }
+        self
+    }
+
+    /// Number of keys in the pool.
+    pub fn len(&self) -> usize {
+        self.keys.lock().unwrap_or_else(|e| e.into_inner()).len()
+    }
+
+    /// Always false: a pool holds at least one key.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Client for the next request.
+    ///
+    /// Picks the key with the lowest weighted use count among those with a free request slot
+    /// (all keys if none has one), the least recently used on a tie.
+    pub fn client(&self) -> Arc<BybitClient> {
+        let mut keys = self.keys.lock().unwrap_or_else(|e| e.into_inner());
+        let has_slot = |key: &PooledKey| key.client.available_request_slots() != Some(0);
+        let any_free = keys.iter().any(has_slot);
+
+        let key = keys
+            .iter_mut()
+            .filter(|key| !any_free || has_slot(key))
+            .min_by(|a, b| {
+                a.load()
+                    .total_cmp(&b.load())
+                    .then(a.last_used.cmp(&b.last_used))
+            })
+            .expect("KeyPool is never empty");
+        key.uses += 1;
+        key.last_used = Some(Instant::now());
+        Arc::clone(&key.client)
+    }
+
+    /// Requests served per API key so far.
+    pub fn usage(&self) -> HashMap<String, u64> {
+        self.keys
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .iter()
+            .map(|key| (key.api_key.clone(), key.uses))
+            .collect()
+    }
+
+    /// [`BybitClient::request_raw`] on the client of the next key.
+    pub async fn request_raw(
+        &self,
+        method: Method,
+        endpoint: &str,
+        params: Option<&HashMap<String, Value>>,
+        auth: bool,
+    ) -> Result<ApiResponse<Value>> {
+        self.client()
+            .request_raw(method, endpoint, params, auth)
+            .await
+    }
+}
@@ -1,23 +1,56 @@
 //! Bybit Trading API Client with all available methods.
 mod api;
+mod defaults;
 mod http;
+pub mod idempotency;
+mod key_pool;
+pub mod models;
+pub mod pagination;
+mod position_mode;
+pub mod signing;
 pub mod traits;
 pub mod types;
 
+use std::collections::HashMap;
+
+use async_trait::async_trait;
+use reqwest::Method;
+use serde_json::Value;
+use tokio_util::sync::CancellationToken;
+
 use crate::error::Result;
+use crate::http::{DynHttpClient, DynResponse, HttpClient};
+use crate::utils::LeverageLimits;
+pub use defaults::BybitDefaults;
 use http::BybitHttpClient;
+use idempotency::AutoClientOrderId;
+pub use key_pool::KeyPool;
 use linkme::distributed_slice;
+pub use pagination::paginate;
+use position_mode::PositionModes;
+pub use signing::SignType;
+use types::ApiResponse;
 
 #[distributed_slice]
 pub static BYBIT_IMPLEMENTED: [&'static str];
 
 /// Bybit Trading API Client with all available methods.
+///
+/// Cloning is cheap: clones share the connection pool and credentials.
+#[derive(Clone)]
 pub struct BybitClient {
     http_client: BybitHttpClient,
+    auto_client_order_id: Option<AutoClientOrderId>,
+    auto_position_idx: bool,
+    leverage_limits: LeverageLimits,
+    position_modes: PositionModes,
 }
 
 impl BybitClient {
     /// Create a new Bybit client
+    ///
+    /// An `api_secret` holding a PEM private key signs with RSA (see [`SignType::detect`]);
+    /// use [`Self::with_sign_type`] for a bare base64 RSA key.
     pub fn new(
         api_key: Option<String>,
         api_secret: Option<String>,
@@ -28,7 +61,174 @@ impl BybitClient {
     ) -> Result<Self> {
         let http_client =
             BybitHttpClient::new(api_key, api_secret, testnet, demo, recv_window, referral_id)?;
-        Ok(Self { http_client })
+        Ok(Self {
+            http_client,
+            auto_client_order_id: None,
+            auto_position_idx: false,
+            leverage_limits: LeverageLimits::default(),
+            position_modes: PositionModes::default(),
+        })
+    }
+
+    /// Create a client with the process-wide `recv_window` and referral ID set by
+    /// [`BybitDefaults::set`].
+    pub fn new_with_defaults(
+        api_key: Option<String>,
+        api_secret: Option<String>,
+        testnet: bool,
+        demo: bool,
+    ) -> Result<Self> {
+        Self::new(
+            api_key,
+            api_secret,
+            testnet,
+            demo,
+            BybitDefaults::recv_window(),
+            BybitDefaults::referral_id(),
+        )
+    }
+
+    /// Override the base URL, e.g. to point the client at a mock server or a gateway.
+    pub fn with_base_url(
+        self,
+        base_url: &str,
+    ) -> Self {
+        Self {
+            http_client: self.http_client.with_base_url(base_url),
+            ..self
+        }
+    }
+
+    /// Sign requests for an RSA API key instead of HMAC-SHA256; pass the private key as
+    /// `api_secret` (see [`SignType`]).
+    pub fn with_sign_type(
+        self,
+        sign_type: SignType,
+    ) -> Self {
+        Self {
+            http_client: self.http_client.with_sign_type(sign_type),
+            ..self
+        }
+    }
+
+    /// Route this client through a proxy (`http://`, `https://` or `socks5://` URL).
+    ///
+    /// Returns `Error::Config` if the URL is invalid.
+    pub fn with_proxy(
+        self,
+        url: &str,
+    ) -> Result<Self> {
+        Ok(Self {
+            http_client: self.http_client.with_proxy(url)?,
+            ..self
+        })
+    }
+
+    /// Enable auto-generated order link ids for `place_order`.
+    ///
+    /// When `PlaceOrderParams::order_link_id` is `None`, an id is generated as `prefix` plus a
+    /// 22-character base62 UUIDv7 (Bybit allows at most 36 characters, so the prefix is limited
+    /// to 14). Transport errors (timeouts, connection failures) are retried up to `max_retries`
+    /// times with the same id, so an order that did reach the exchange is not placed twice.
+    /// If a retry is rejected because the id is already in use, the order created by the
+    /// earlier attempt is looked up and returned.
+    ///
+    /// The id used is returned as `orderLinkId` in the response. On failure the error is
+    /// [`crate::Error::OrderPlacement`], which carries the id so the order can be looked up.
+    pub fn with_auto_client_order_id(
+        self,
+        prefix: &str,
+        max_retries: u32,
+    ) -> Result<Self> {
+        Ok(Self {
+            auto_client_order_id: Some(AutoClientOrderId::new(prefix, max_retries)?),
+            ..self
+        })
+    }
+
+    /// Fill `PlaceOrderParams::position_idx` on `place_order` when it is `None`.
+    ///
+    /// For linear and inverse orders the symbol's position mode is looked up with
+    /// [`Self::position_mode`] and the index matching the order side is sent, so hedge-mode
+    /// orders do not need it spelled out and one-way orders never carry a hedge index.
+    pub fn with_auto_position_idx(self) -> Self {
+        Self {
+            auto_position_idx: true,
+            ..self
+        }
+    }
+
+    /// Send a request to any endpoint, including ones the SDK does not wrap yet.
+    ///
+    /// `params` go to the query string for GET and to the JSON body otherwise; with `auth`
+    /// the request is signed like every typed endpoint. The `result` payload is returned as-is.
+    pub async fn request_raw(
+        &self,
+        method: Method,
+        endpoint: &str,
+        params: Option<&HashMap<String, Value>>,
+        auth: bool,
+    ) -> Result<ApiResponse<Value>> {
+        let response = self
+            .http_client
+            .async_request(method, endpoint, params, auth)
+            .await?;
+        Ok(response.into_api_response())
+    }
+
+    /// [`Self::request_raw`] that is abandoned with [`crate::Error::Cancelled`] when `cancel`
+    /// fires before the response arrives.
+    pub async fn request_raw_cancellable(
+        &self,
+        method: Method,
+        endpoint: &str,
+        params: Option<&HashMap<String, Value>>,
+        auth: bool,
+        cancel: &CancellationToken,
+    ) -> Result<ApiResponse<Value>> {
+        let response = self
+            .http_client
+            .async_request_cancellable(method, endpoint, params, auth, cancel)
+            .await?;
+        Ok(response.into_api_response())
+    }
+
+    /// Whether `name` (a trait method such as `"place_order"`) is implemented rather than a stub.
+    pub fn is_implemented(name: &str) -> bool {
+        BYBIT_IMPLEMENTED.contains(&name)
+    }
+
+    /// Names of all implemented methods, sorted.
+    pub fn implemented_methods() -> Vec<&'static str> {
+        let mut methods = BYBIT_IMPLEMENTED.to_vec();
+        methods.sort_unstable();
+        methods
+    }
+
+    /// Close the client, draining its own connection pool.
+    ///
+    /// Clients on the shared session leave the pool open; close it with
+    /// [`crate::SharedSessionManager::close`]. The client is consumed:
+    ///
+    /// ```compile_fail
+    /// # use trade_sdk::bybit::{traits::MarketApi, BybitClient};
+    /// # async fn example(client: BybitClient) {
+    /// client.close().await;
+    /// client.get_server_time().await;
+    /// # }
+    /// ```
+    pub async fn close(self) {
+        self.http_client.close().await
+    }
+
+    /// Auto-generated order link id settings, if enabled.
+    pub fn auto_client_order_id(&self) -> Option<&AutoClientOrderId> {
+        self.auto_client_order_id.as_ref()
+    }
+
+    /// Whether `place_order` fills a missing `position_idx`.
+    pub fn auto_position_idx(&self) -> bool {
+        self.auto_position_idx
     }
 }
 
@@ -39,3 +239,32 @@ impl std::ops::Deref for BybitClient {
         &self.http_client
     }
 }
+
+impl std::ops::DerefMut for BybitClient {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.http_client
+    }
+}
+
+#[async_trait]
+impl DynHttpClient for BybitClient {
+    fn exchange(&self) -> &'static str {
+        "bybit"
+    }
+
+    async fn request_dyn(
+        &self,
+        method: Method,
+        endpoint: &str,
+        params: Option<&HashMap<String, Value>>,
+        auth: bool,
+    ) -> Result<DynResponse> {
+        let response = self.request_raw(method, endpoint, params, auth).await?;
+        Ok(DynResponse {
+            exchange: "bybit",
+            code: i64::from(response.ret_code),
+            msg: response.ret_msg,
+            data: response.result,
+        })
+    }
+}
@@ -0,0 +1,391 @@
+//! Typed models for Bybit V5 responses.
+//!
+//! Bybit sends numbers as strings (`"0.5"`, or `""` when not applicable); these models convert
+//! them into [`Amount`] so callers don't parse them by hand.
+
+use serde::Deserialize;
+use serde_json::Value;
+
+use crate::bybit::types::ApiResponse;
+use crate::error::{Error, Result};
+use crate::utils::{amount_from_str, id_from_str_or_int, Amount};
+
+/// Row of `/v5/position/list`.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct Position {
+    pub symbol: String,
+    /// `Buy`, `Sell`, or empty for an empty one-way position.
+    pub side: String,
+    /// 0: one-way mode, 1: hedge-mode buy side, 2: hedge-mode sell side.
+    pub position_idx: i32,
+    #[serde(deserialize_with = "amount_from_str")]
+    pub size: Amount,
+    #[serde(deserialize_with = "amount_from_str")]
+    pub avg_price: Amount,
+    #[serde(deserialize_with = "amount_from_str")]
+    pub position_value: Amount,
+    #[serde(deserialize_with = "amount_from_str")]
+    pub unrealised_pnl: Amount,
+    #[serde(deserialize_with = "amount_from_str")]
+    pub cum_realised_pnl: Amount,
+    #[serde(deserialize_with = "amount_from_str")]
+    pub leverage: Amount,
+    #[serde(deserialize_with = "amount_from_str")]
+    pub mark_price: Amount,
+    /// Zero when the position has no liquidation price.
+    #[serde(deserialize_with = "amount_from_str")]
+    pub liq_price: Amount,
+    #[serde(deserialize_with = "amount_from_str")]
+    pub take_profit: Amount,
+    #[serde(deserialize_with = "amount_from_str")]
+    pub stop_loss: Amount,
+    /// `Normal`, `Liq` or `Adl`.
+    pub position_status: String,
+    /// Creation time, ms timestamp as a string.
+    pub created_time: String,
+    /// Last update time, ms timestamp as a string.
+    pub updated_time: String,
+}
+
+/// `result` of `/v5/position/list`.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct PositionInfoResult {
+    pub category: String,
+    pub list: Vec<Position>,
+    /// Cursor for the next page, empty on the last page.
+    pub next_page_cursor: String,
+}
+
+/// `result` of `/v5/account/wallet-balance`.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct WalletBalanceResult {
+    pub list: Vec<AccountBalance>,
+}
+
+/// Balance of one account type.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct AccountBalance {
+    /// `UNIFIED`, `CONTRACT`, ...
+    pub account_type: String,
+    /// Total equity in USD.
+    #[serde(deserialize_with = "amount_from_str")]
+    pub total_equity: Amount,
+    /// Total wallet balance in USD.
+    #[serde(deserialize_with = "amount_from_str")]
+    pub total_wallet_balance: Amount,
+    pub coin: Vec<CoinBalance>,
+}
+
+/// Balance of one coin within an account.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct CoinBalance {
+    pub coin: String,
+    #[serde(deserialize_with = "amount_from_str")]
+    pub wallet_balance: Amount,
+    #[serde(deserialize_with = "amount_from_str")]
+    pub equity: Amount,
+    /// Empty for unified accounts, which report zero here.
+    #[serde(deserialize_with = "amount_from_str")]
+    pub available_to_withdraw: Amount,
+}
+
+/// Candle of `/v5/market/kline`, parsed from
+/// `[startTime, openPrice, highPrice, lowPrice, closePrice, volume, turnover]`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Deserialize)]
+#[serde(try_from = "KlineRow")]
+pub struct Candle {
+    /// Start time of the candle, ms timestamp.
+    pub start: i64,
+    pub open: Amount,
+    pub high: Amount,
+    pub low: Amount,
+    pub close: Amount,
+    /// Volume in the base coin (contracts for inverse).
+    pub volume: Amount,
+    /// Turnover in the quote coin.
+    pub turnover: Amount,
+}
+
+/// Raw kline row as sent by Bybit.
+#[derive(Deserialize)]
+struct KlineRow(
+    #[serde(deserialize_with = "id_from_str_or_int")] String,
+    #[serde(deserialize_with = "amount_from_str")] Amount,
+    #[serde(deserialize_with = "amount_from_str")] Amount,
+    #[serde(deserialize_with = "amount_from_str")] Amount,
+    #[serde(deserialize_with = "amount_from_str")] Amount,
+    #[serde(deserialize_with = "amount_from_str")] Amount,
+    #[serde(deserialize_with = "amount_from_str")] Amount,
+);
+
+impl TryFrom<KlineRow> for Candle {
+    type Error = String;
+
+    fn try_from(row: KlineRow) -> std::result::Result<Self, Self::Error> {
+        let KlineRow(start, open, high, low, close, volume, turnover) = row;
+        Ok(Self {
+            start: start
+                .parse()
+                .map_err(|e| format!("invalid kline start time {start:?}: {e}"))?,
+            open,
+            high,
+            low,
+            close,
+            volume,
+            turnover,
+        })
+    }
+}
+
+/// Parse the candles of a `get_kline` response.
+///
+/// Bybit lists candles newest first; with `oldest_first` they are returned in chronological
+/// order instead. A result without `list` yields no candles; a malformed row fails with
+/// `Error::Json`.
+pub fn parse_klines(
+    response: &ApiResponse<Value>,
+    oldest_first: bool,
+) -> Result<Vec<Candle>> {
+    let list = response.result.get("list").unwrap_or(&Value::Null);
+    let mut candles = if list.is_null() {
+        Vec::new()
+    } else {
+        Vec::<Candle>::deserialize(list).map_err(Error::Json)?
+    };
+    if oldest_first {
+        candles.reverse();
+    }
+    Ok(candles)
+}
+
+/// `result` of `/v5/order/create`, see `BybitClient::place_order_typed`.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct OrderAck {
+    /// Exchange order ID.
+    pub order_id: String,
+    /// User-defined order ID, empty if none was set.
+    pub order_link_id: String,
+}
+
+impl OrderAck {
+    /// Parse a create-order `result`; `Error::Json` if it carries no `orderId`.
+    pub fn from_result(result: &Value) -> Result<Self> {
+        let ack = Self::deserialize(result).map_err(Error::Json)?;
+        if ack.order_id.is_empty() {
+            return Err(Error::Json(serde::de::Error::custom(format!(
+                "Order acknowledgement has no orderId: {result}"
+            ))));
+        }
+        Ok(ack)
+    }
+}
+
+/// Order cancelled by `/v5/order/cancel-all`.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct CancelledOrder {
+    pub order_id: String,
+    /// User-defined order ID, empty if none was set.
+    pub order_link_id: String,
+}
+
+/// `result` of `/v5/order/cancel-all`, see `BybitClient::cancel_all_orders_typed`.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct CancelAllResult {
+    /// Cancelled orders, empty if nothing was open.
+    pub list: Vec<CancelledOrder>,
+    /// `"1"` on success, `"0"` on failure; empty for categories that don't report it.
+    pub success: String,
+}
+
+impl CancelAllResult {
+    /// Exchange IDs of the cancelled orders.
+    pub fn order_ids(&self) -> Vec<&str> {
+        self.list.iter().map(|o| o.order_id.as_str()).collect()
+    }
+}
+
+/// Outcome of one order in a chunked batch, see `BybitClient::batch_place_order_chunked`.
+#[derive(Debug, Clone, Default)]
+pub struct OrderResult {
+    /// Exchange order ID, empty if the order was not placed.
+    pub order_id: String,
+    /// User-defined order ID, echoed back or taken from the request.
+    pub order_link_id: String,
+    /// 0 on success; the Bybit error code otherwise, or -1 if the request failed without one.
+    pub code: i64,
+    pub msg: String,
+}
+
+impl OrderResult {
+    /// True if the order was placed.
+    pub fn is_ok(&self) -> bool {
+        self.code == 0
+    }
+}
+
+/// Per-order status in `retExtInfo` of batch endpoints, in request order.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct BatchResultExt {
+    pub list: Vec<BatchItemResult>,
+}
+
+impl BatchResultExt {
+    /// Indices of the orders that failed.
+    pub fn failed_indices(&self) -> Vec<usize> {
+        self.list
+            .iter()
+            .enumerate()
+            .filter(|(_, item)| !item.is_ok())
+            .map(|(i, _)| i)
+            .collect()
+    }
+}
+
+/// Status of one order in a batch request.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct BatchItemResult {
+    /// 0 on success, the Bybit error code otherwise.
+    pub code: i64,
+    pub msg: String,
+}
+
+impl BatchItemResult {
+    /// True if the order succeeded.
+    pub fn is_ok(&self) -> bool {
+        self.code == 0
+    }
+}
+
+/// `result` of `/v5/account/info`.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct AccountInfo {
+    /// 1: classic, 3: UTA 1.0, 4: UTA 1.0 Pro, 5: UTA 2.0, 6: UTA 2.0 Pro.
+    pub unified_margin_status: i32,
+    /// `ISOLATED_MARGIN`, `REGULAR_MARGIN` or `PORTFOLIO_MARGIN`.
+    pub margin_mode: String,
+    pub is_master_trader: bool,
+    /// `ON` or `OFF`.
+    pub spot_hedging_status: String,
+    /// Last update time, ms timestamp as a string.
+    pub updated_time: String,
+}
+
+impl AccountInfo {
+    /// True if the account is already a Unified Trading Account (any version).
+    pub fn is_unified(&self) -> bool {
+        self.unified_margin_status >= 3
+    }
+}
+
+/// `result` of `/v5/account/upgrade-to-uta`.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct UpgradeToUtaResult {
+    /// `FAIL`, `PROCESS` or `SUCCESS`.
+    pub unified_update_status: String,
+    /// Reasons the upgrade failed, if any.
+    pub unified_update_msg: Option<UnifiedUpdateMsg>,
+}
+
+/// Failure details of a UTA upgrade.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct UnifiedUpdateMsg {
+    pub msg: Vec<String>,
+}
+
+/// `result` of `/v5/asset/coin-greeks`.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct CoinGreeksResult {
+    pub list: Vec<CoinGreeks>,
+}
+
+/// Account greeks of the options on one base coin.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct CoinGreeks {
+    pub base_coin: String,
+    #[serde(deserialize_with = "amount_from_str")]
+    pub total_delta: Amount,
+    #[serde(deserialize_with = "amount_from_str")]
+    pub total_gamma: Amount,
+    #[serde(deserialize_with = "amount_from_str")]
+    pub total_vega: Amount,
+    #[serde(deserialize_with = "amount_from_str")]
+    pub total_theta: Amount,
+}
+
+/// `result` of `/v5/market/instruments-info`.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct InstrumentsInfoResult {
+    pub category: String,
+    pub list: Vec<Instrument>,
+    /// Cursor for the next page, empty on the last page.
+    pub next_page_cursor: String,
+}
+
+/// Row of `/v5/market/instruments-info`.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct Instrument {
+    pub symbol: String,
+    /// `Trading`, `PreLaunch`, `Delivering` or `Closed`.
+    pub status: String,
+    pub base_coin: String,
+    pub quote_coin: String,
+    pub price_filter: PriceFilter,
+    pub lot_size_filter: LotSizeFilter,
+    /// Absent for spot instruments.
+    pub leverage_filter: LeverageFilter,
+}
+
+/// Price limits of an [`Instrument`]; round prices with [`crate::round_to_tick`].
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct PriceFilter {
+    #[serde(deserialize_with = "amount_from_str")]
+    pub min_price: Amount,
+    #[serde(deserialize_with = "amount_from_str")]
+    pub max_price: Amount,
+    #[serde(deserialize_with = "amount_from_str")]
+    pub tick_size: Amount,
+}
+
+/// Quantity limits of an [`Instrument`]; round quantities with [`crate::round_to_step`].
+///
+/// Spot instruments send `basePrecision` instead of `qtyStep`; it is read into `qty_step`.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct LotSizeFilter {
+    #[serde(deserialize_with = "amount_from_str", alias = "basePrecision")]
+    pub qty_step: Amount,
+    #[serde(deserialize_with = "amount_from_str")]
+    pub min_order_qty: Amount,
+    #[serde(deserialize_with = "amount_from_str")]
+    pub max_order_qty: Amount,
+}
+
+/// Leverage limits of a derivatives [`Instrument`].
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct LeverageFilter {
+    #[serde(deserialize_with = "amount_from_str")]
+    pub min_leverage: Amount,
+    #[serde(deserialize_with = "amount_from_str")]
+    pub max_leverage: Amount,
+    #[serde(deserialize_with = "amount_from_str")]
+    pub leverage_step: Amount,
+}
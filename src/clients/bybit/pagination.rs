@@ -0,0 +1,93 @@
+//! Cursor-based auto-pagination for Bybit list endpoints.
+
+use std::collections::VecDeque;
+use std::future::Future;
+
+use futures::stream::{self, Stream};
+use serde_json::Value;
+
+use crate::bybit::types::ApiResponse;
+use crate::error::Result;
+
+/// Stream every `result.list` item across all pages of a cursor endpoint.
+///
+/// `fetch` is called with `None` for the first page and then with the cursor read from
+/// `result.<cursor_field>` (usually `"nextPageCursor"`) until the cursor is empty.
+/// A request error is yielded once and ends the stream.
+///
+/// # Example
+/// ```no_run
+/// # use futures::StreamExt;
+/// # use trade_sdk::bybit::{paginate, traits::PositionApi, types::AllCategories, BybitClient};
+/// # async fn run(client: &BybitClient) -> trade_sdk::Result<()> {
+/// let pnl = paginate(
+///     |cursor| async move {
+///         client
+///             .get_closed_pnl(AllCategories::Linear, None, None, None, Some(100), cursor.as_deref())
+///             .await
+///     },
+///     "nextPageCursor",
+/// );
+/// futures::pin_mut!(pnl);
+/// while let Some(record) = pnl.next().await {
+///     println!("{}", record?);
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub fn paginate<F, Fut>(
+    fetch: F,
+    cursor_field: &str,
+) -> impl Stream<Item = Result<Value>>
+where
+    F: FnMut(Option<String>) -> Fut,
+    Fut: Future<Output = Result<ApiResponse<Value>>>,
+{
+    struct State<F> {
+        fetch: F,
+        cursor_field: String,
+        cursor: Option<String>,
+        buffer: VecDeque<Value>,
+        done: bool,
+    }
+
+    let state = State {
+        fetch,
+        cursor_field: cursor_field.to_string(),
+        cursor: None,
+        buffer: VecDeque::new(),
+        done: false,
+    };
+
+    stream::unfold(state, |mut state| async move {
+        loop {
+            if let Some(item) = state.buffer.pop_front() {
+                return Some((Ok(item), state));
+            }
+            if state.done {
+                return None;
+            }
+
+            let response = match (state.fetch)(state.cursor.clone()).await {
+                Ok(response) => response,
+                Err(e) => {
+                    state.done = true;
+                    return Some((Err(e), state));
+                }
+            };
+
+            if let Some(Value::Array(list)) = response.result.get("list") {
+                state.buffer.extend(list.iter().cloned());
+            }
+            let next = response
+                .result
+                .get(&state.cursor_field)
+                .and_then(Value::as_str)
+                .filter(|c| !c.is_empty())
+                .map(str::to_string);
+            // Stop on an empty cursor, or a repeated one to avoid looping forever.
+            state.done = next.is_none() || next == state.cursor;
+            state.cursor = next;
+        }
+    })
+}
@@ -0,0 +1,47 @@
+//! Cache of the position mode per symbol, used to fill `position_idx` on `place_order`.
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use crate::bybit::types::PositionMode;
+
+/// How long a fetched position mode is trusted; the mode can also be switched outside the SDK.
+pub(crate) const POSITION_MODE_TTL: Duration = Duration::from_secs(60);
+
+/// Position mode per `category:symbol` key, cached for [`POSITION_MODE_TTL`]; clones share
+/// the cache.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct PositionModes(Arc<Mutex<HashMap<String, (PositionMode, Instant)>>>);
+
+impl PositionModes {
+    /// Cached mode for `key`, if fetched or set within the TTL.
+    pub(crate) fn get(
+        &self,
+        key: &str,
+    ) -> Option<PositionMode> {
+        let modes = self.0.lock().unwrap_or_else(|e| e.into_inner());
+        modes
+            .get(key)
+            .filter(|(_, fetched)| fetched.elapsed() < POSITION_MODE_TTL)
+            .map(|(mode, _)| *mode)
+    }
+
+    pub(crate) fn insert(
+        &self,
+        key: String,
+        mode: PositionMode,
+    ) {
+        let mut modes = self.0.lock().unwrap_or_else(|e| e.into_inner());
+        modes.insert(key, (mode, Instant::now()));
+    }
+
+    /// Forget every cached mode whose key starts with `prefix`.
+    pub(crate) fn remove_prefix(
+        &self,
+        prefix: &str,
+    ) {
+        let mut modes = self.0.lock().unwrap_or_else(|e| e.into_inner());
+        modes.retain(|key, _| !key.starts_with(prefix));
+    }
+}
@@ -6,6 +6,7 @@ use crate::bybit::types::{
     InstrumentStatus, MarginMode, OrderFilter, PlaceOrderParams, SetTradingStopParams, SymbolType,
 };
 use crate::error::Result;
+use crate::utils::Amount;
 
 /// Trait defining Bybit market data API endpoints.
 ///
@@ -29,7 +30,8 @@ pub trait MarketApi {
     ///
     /// # Returns
     /// Kline data including symbol, category, and a list of candles.  
-    /// Each candle contains `[startTime, openPrice, highPrice, lowPrice, closePrice, volume, turnover]`.
+    /// Each candle contains `[startTime, openPrice, highPrice, lowPrice, closePrice, volume, turnover]`,
+    /// newest first; [`crate::bybit::models::parse_klines`] turns them into typed candles.
     async fn get_kline(
         &self,
         symbol: &str,
@@ -61,7 +63,7 @@ pub trait MarketApi {
     /// * `cursor` – Cursor for page pagination (from API response).
     ///
     /// # Returns
-    /// Response struct containing the instruments info from Bybit.
+    /// Instruments info response; `parse::<models::InstrumentsInfoResult>()` for the typed result.
     async fn get_instruments_info(
         &self,
         category: AllCategories,
@@ -76,11 +78,40 @@ pub trait MarketApi {
     /// Returns the current orderbook.
     async fn get_orderbook(&self) -> Result<ApiResponse<serde_json::Value>>;
 
-    /// Returns the current RPI orderbook.
-    async fn get_rpi_orderbook(&self) -> Result<ApiResponse<serde_json::Value>>;
+    /// Returns the orderbook including Retail Price Improvement (RPI) quotes.
+    ///
+    /// # Arguments
+    /// * `category` – `spot`, `linear` or `inverse`; `option` fails with [`crate::Error::Validation`].
+    /// * `symbol` – Symbol name, e.g. `"BTCUSDT"`.
+    /// * `limit` – Depth per side (`1–50`); Bybit requires it, so `None` sends `50`.
+    ///
+    /// # Returns
+    /// Bids and asks as `[price, nonRpiSize, rpiSize]`, plus update ID and timestamps.
+    async fn get_rpi_orderbook(
+        &self,
+        category: AllCategories,
+        symbol: &str,
+        limit: Option<i32>,
+    ) -> Result<ApiResponse<serde_json::Value>>;
 
-    /// Returns ticker information.
-    async fn get_tickers(&self) -> Result<ApiResponse<serde_json::Value>>;
+    /// Returns the latest price snapshot, best bid/ask and 24h volume.
+    ///
+    /// # Arguments
+    /// * `category` – Product type ("spot", "linear", "inverse", "option").
+    /// * `symbol` – Symbol name (uppercase); all symbols of the category when `None`. Option
+    ///   symbols are checked with [`OptionSymbol::parse`](crate::OptionSymbol::parse).
+    /// * `base_coin` – Base coin (uppercase), option only; `option` needs it or `symbol`.
+    /// * `exp_date` – Expiry date, e.g. `"25DEC22"`, option only.
+    ///
+    /// # Returns
+    /// Tickers response with `category` and `list`.
+    async fn get_tickers(
+        &self,
+        category: AllCategories,
+        symbol: Option<&str>,
+        base_coin: Option<&str>,
+        exp_date: Option<&str>,
+    ) -> Result<ApiResponse<serde_json::Value>>;
 
     /// Returns the funding rate history.
     async fn get_funding_rate_history(&self) -> Result<ApiResponse<serde_json::Value>>;
@@ -91,35 +122,133 @@ pub trait MarketApi {
     /// Returns open interest data.
     async fn get_open_interest(&self) -> Result<ApiResponse<serde_json::Value>>;
 
-    /// Returns historical volatility information.
-    async fn get_historical_volatility(&self) -> Result<ApiResponse<serde_json::Value>>;
+    /// Returns historical volatility of an option base coin.
+    ///
+    /// # Arguments
+    /// * `category` – Must be `option`; other categories fail with [`crate::Error::Validation`].
+    /// * `base_coin` – Base coin, e.g. `"ETH"` (default: `BTC`).
+    /// * `period` – Period in days: `7`, `14`, `21`, `30`, `60`, `90`, `180` or `270`.
+    /// * `start_time` – Start timestamp (milliseconds).
+    /// * `end_time` – End timestamp (milliseconds); the window may span at most 30 days.
+    ///
+    /// # Returns
+    /// List of `{period, value, time}` entries.
+    async fn get_historical_volatility(
+        &self,
+        category: AllCategories,
+        base_coin: Option<&str>,
+        period: Option<i32>,
+        start_time: Option<i64>,
+        end_time: Option<i64>,
+    ) -> Result<ApiResponse<serde_json::Value>>;
 
-    /// Returns insurance pool information.
-    async fn get_insurance_pool(&self) -> Result<ApiResponse<serde_json::Value>>;
+    /// Returns insurance pool balances.
+    ///
+    /// # Arguments
+    /// * `coin` – Coin, e.g. `"USDT"`; all coins when `None`.
+    async fn get_insurance_pool(
+        &self,
+        coin: Option<&str>,
+    ) -> Result<ApiResponse<serde_json::Value>>;
 
     /// Returns risk limit information.
     async fn get_risk_limit(&self) -> Result<ApiResponse<serde_json::Value>>;
 
-    /// Returns delivery price information.
-    async fn get_delivery_price(&self) -> Result<ApiResponse<serde_json::Value>>;
+    /// Returns settlement prices of expired inverse futures and options.
+    ///
+    /// # Arguments
+    /// * `category` – `inverse` or `option`; others fail with [`crate::Error::Validation`].
+    /// * `symbol` – Symbol name, e.g. `"BTC-27JUN25-100000-C"`.
+    /// * `base_coin` – Base coin (options only; default: `BTC`).
+    /// * `settle_coin` – Settle coin (options only; default: `USDC`).
+    /// * `limit` – Records per page (`1–200`, default: 50).
+    /// * `cursor` – Cursor for page pagination (from API response).
+    ///
+    /// # Returns
+    /// List of `{symbol, deliveryPrice, deliveryTime}` entries.
+    async fn get_delivery_price(
+        &self,
+        category: AllCategories,
+        symbol: Option<&str>,
+        base_coin: Option<&str>,
+        settle_coin: Option<&str>,
+        limit: Option<i32>,
+        cursor: Option<&str>,
+    ) -> Result<ApiResponse<serde_json::Value>>;
 
-    /// Returns new delivery price information.
-    async fn get_new_delivery_price(&self) -> Result<ApiResponse<serde_json::Value>>;
+    /// Returns the latest option delivery prices per expiry.
+    ///
+    /// # Arguments
+    /// * `category` – Must be `option`; others fail with [`crate::Error::Validation`].
+    /// * `base_coin` – Base coin, e.g. `"BTC"`.
+    /// * `settle_coin` – Settle coin (default: `USDC`).
+    ///
+    /// # Returns
+    /// List of `{deliveryPrice, deliveryTime}` entries, newest first.
+    async fn get_new_delivery_price(
+        &self,
+        category: AllCategories,
+        base_coin: &str,
+        settle_coin: Option<&str>,
+    ) -> Result<ApiResponse<serde_json::Value>>;
 
     /// Returns long-short ratio.
     async fn get_long_short_ratio(&self) -> Result<ApiResponse<serde_json::Value>>;
 
-    /// Returns index price components.
-    async fn get_index_price_components(&self) -> Result<ApiResponse<serde_json::Value>>;
+    /// Returns the exchange prices and weights behind an index price.
+    ///
+    /// # Arguments
+    /// * `symbol` – Index name, e.g. `"BTCUSDT"`; sent as `indexName`. Must not be empty.
+    ///
+    /// # Returns
+    /// Index name, last price and the `components` list (`exchange`, `spotPair`, `equivalentPrice`,
+    /// `multiplier`, `price`, `weight`).
+    async fn get_index_price_components(
+        &self,
+        symbol: &str,
+    ) -> Result<ApiResponse<serde_json::Value>>;
 
-    /// Returns order price limit info.
-    async fn get_order_price_limit(&self) -> Result<ApiResponse<serde_json::Value>>;
+    /// Returns the highest buy and lowest sell price currently accepted for a symbol.
+    ///
+    /// Check this before sending aggressive limit orders; prices outside the band are rejected.
+    ///
+    /// # Arguments
+    /// * `category` – Product type: `spot`, `linear` or `inverse`.
+    /// * `symbol` – Symbol name, e.g. `"BTCUSDT"`. Must not be empty.
+    ///
+    /// # Returns
+    /// `{symbol, buyLmt, sellLmt, ts}`.
+    async fn get_order_price_limit(
+        &self,
+        category: AllCategories,
+        symbol: &str,
+    ) -> Result<ApiResponse<serde_json::Value>>;
 
-    /// Returns ADL alert data.
-    async fn get_adl_alert(&self) -> Result<ApiResponse<serde_json::Value>>;
+    /// Returns auto-deleveraging (ADL) alerts and insurance pool thresholds per contract.
+    ///
+    /// # Arguments
+    /// * `symbol` – Contract name, e.g. `"BTCUSDT"`; all contracts when `None`.
+    ///
+    /// # Returns
+    /// Update time and a `list` of per-symbol ADL thresholds.
+    async fn get_adl_alert(
+        &self,
+        symbol: Option<&str>,
+    ) -> Result<ApiResponse<serde_json::Value>>;
 
-    /// Returns the fee group structure for markets.
-    async fn get_fee_group_structure(&self) -> Result<ApiResponse<serde_json::Value>>;
+    /// Returns the fee groups and their tiered rates.
+    ///
+    /// # Arguments
+    /// * `product_type` – Product type; Bybit currently only accepts `"contract"`.
+    /// * `group_id` – Group ID (`1–7`); all groups when `None`.
+    ///
+    /// # Returns
+    /// A `list` of fee groups with their symbols and `feeRates` per tier.
+    async fn get_fee_group_structure(
+        &self,
+        product_type: &str,
+        group_id: Option<&str>,
+    ) -> Result<ApiResponse<serde_json::Value>>;
 }
 
 /// Trade management HTTP methods.
@@ -127,7 +256,8 @@ pub trait MarketApi {
 pub trait TradeApi {
     /// Places an order using the supplied parameters.
     ///
-    /// Only fields defined in [`PlaceOrderParams`] are used.
+    /// Only fields defined in [`PlaceOrderParams`] are used. A missing `position_idx` is filled
+    /// from the symbol's position mode when enabled with `BybitClient::with_auto_position_idx`.
     ///
     /// # Arguments
     /// * `category` - Product type ("linear", "inverse", "spot", "option").
@@ -228,7 +358,7 @@ pub trait TradeApi {
     ///
     /// # Arguments
     /// * `category` - Product type.
-    /// * `orders` - List of order parameters.
+    /// * `orders` - List of order parameters (1-20 orders, 1-10 for option).
     async fn batch_place_order(
         &self,
         category: AllCategories,
@@ -242,7 +372,7 @@ pub trait TradeApi {
     ///
     /// # Arguments
     /// * `category` - Product type.
-    /// * `orders` - List of cancellation parameters.
+    /// * `orders` - List of cancellation parameters (1-20 orders, 1-10 for option).
     async fn batch_cancel_order(
         &self,
         category: AllCategories,
@@ -281,8 +411,35 @@ pub trait AccountApi {
         coin: Option<&str>,
     ) -> Result<ApiResponse<serde_json::Value>>;
 
-    /// Gets transferable amount (Unified account).
-    async fn get_transferable_amount(&self) -> Result<ApiResponse<serde_json::Value>>;
+    /// Gets wallet balance for a list of coins.
+    ///
+    /// Like [`Self::get_wallet_balance`], but the coins are trimmed, uppercased and joined
+    /// without spaces, so `["btc", " eth"]` is sent as `BTC,ETH`.
+    ///
+    /// # Arguments
+    /// * `account_type` - Account type. Default is "UNIFIED".
+    /// * `coins` - Coins to return; an empty list or an empty coin fails with `Error::Validation`.
+    ///
+    /// # Returns
+    /// Bybit wallet balance response.
+    async fn get_wallet_balance_for_coins(
+        &self,
+        account_type: Option<AccountType>,
+        coins: &[&str],
+    ) -> Result<ApiResponse<serde_json::Value>>;
+
+    /// Gets the amount of each coin that can be transferred out of the Unified account.
+    ///
+    /// # Arguments
+    /// * `coins` - Coins to query (up to 20), joined like in
+    ///   [`Self::get_wallet_balance_for_coins`]; an empty list fails with `Error::Validation`.
+    ///
+    /// # Returns
+    /// Bybit response with `availableWithdrawal` and `availableWithdrawalMap`.
+    async fn get_transferable_amount(
+        &self,
+        coins: &[&str],
+    ) -> Result<ApiResponse<serde_json::Value>>;
 
     /// Gets transaction log (Unified Trading Account).
     async fn get_transaction_log(&self) -> Result<ApiResponse<serde_json::Value>>;
@@ -290,33 +447,61 @@ pub trait AccountApi {
     /// Gets account info from Bybit API.
     ///
     /// # Returns
-    /// Bybit account info response.
+    /// Bybit account info response; `parse::<models::AccountInfo>()` for the typed result.
     async fn get_account_info(&self) -> Result<ApiResponse<serde_json::Value>>;
 
     /// Gets account instruments info.
     async fn get_account_instruments_info(&self) -> Result<ApiResponse<serde_json::Value>>;
 
-    /// Performs manual borrow.
-    async fn manual_borrow(&self) -> Result<ApiResponse<serde_json::Value>>;
+    /// Borrows a coin manually (Unified Trading Account).
+    ///
+    /// # Arguments
+    /// * `coin` - Coin name, e.g. "USDT".
+    /// * `amount` - Amount to borrow, must be greater than 0.
+    async fn manual_borrow(
+        &self,
+        coin: &str,
+        amount: Amount,
+    ) -> Result<ApiResponse<serde_json::Value>>;
 
     /// Manually repays without asset conversion.
     async fn manual_repay_without_asset_conversion(&self)
         -> Result<ApiResponse<serde_json::Value>>;
 
-    /// Manually repays liability.
-    async fn manual_repay(&self) -> Result<ApiResponse<serde_json::Value>>;
+    /// Repays liabilities using quick repayment.
+    ///
+    /// # Arguments
+    /// * `coin` - Optional. Coin to repay; all liabilities if omitted.
+    async fn manual_repay(
+        &self,
+        coin: Option<&str>,
+    ) -> Result<ApiResponse<serde_json::Value>>;
 
     /// Gets fee rate.
     async fn get_fee_rate(&self) -> Result<ApiResponse<serde_json::Value>>;
 
-    /// Gets collateral info.
-    async fn get_collateral_info(&self) -> Result<ApiResponse<serde_json::Value>>;
+    /// Gets collateral info of the coins in the Unified Trading Account.
+    ///
+    /// # Arguments
+    /// * `currency` - Optional. Coin name (e.g. "BTC"); all coins if omitted.
+    async fn get_collateral_info(
+        &self,
+        currency: Option<&str>,
+    ) -> Result<ApiResponse<serde_json::Value>>;
 
-    /// Gets DCP (Dynamic Collateral Portfolio) info.
+    /// Gets DCP (Disconnected Cancel All Protection) settings per product.
     async fn get_dcp_info(&self) -> Result<ApiResponse<serde_json::Value>>;
 
-    /// Sets collateral coin.
-    async fn set_collateral_coin(&self) -> Result<ApiResponse<serde_json::Value>>;
+    /// Turns a coin on or off as collateral.
+    ///
+    /// # Arguments
+    /// * `coin` - Coin name, e.g. "BTC".
+    /// * `collateral_switch` - `true` to use the coin as collateral ("ON"), `false` for "OFF".
+    async fn set_collateral_coin(
+        &self,
+        coin: &str,
+        collateral_switch: bool,
+    ) -> Result<ApiResponse<serde_json::Value>>;
 
     /// Sets margin mode from Bybit API.
     ///
@@ -330,17 +515,55 @@ pub trait AccountApi {
         set_margin_mode: MarginMode,
     ) -> Result<ApiResponse<serde_json::Value>>;
 
-    /// Sets spot hedging.
-    async fn set_spot_hedging(&self) -> Result<ApiResponse<serde_json::Value>>;
+    /// Turns spot hedging on or off for the Unified Trading Account (portfolio margin).
+    ///
+    /// # Arguments
+    /// * `set_hedging_mode` - `true` for "ON", `false` for "OFF".
+    async fn set_spot_hedging(
+        &self,
+        set_hedging_mode: bool,
+    ) -> Result<ApiResponse<serde_json::Value>>;
 
-    /// Gets borrow history (up to 2 years).
-    async fn get_borrow_history(&self) -> Result<ApiResponse<serde_json::Value>>;
+    /// Gets interest records of borrowed coins (up to 2 years back).
+    ///
+    /// # Arguments
+    /// * `currency` - Optional. Coin, e.g. "USDT"; all coins if omitted.
+    /// * `start_time` - Optional. Start timestamp (ms).
+    /// * `end_time` - Optional. End timestamp (ms); the window may span at most 30 days.
+    /// * `limit` - Optional. Records per page [1, 50], default 20.
+    /// * `cursor` - Optional. Cursor for pagination (from `nextPageCursor`).
+    ///
+    /// # Returns
+    /// Bybit borrow history response.
+    async fn get_borrow_history(
+        &self,
+        currency: Option<&str>,
+        start_time: Option<i64>,
+        end_time: Option<i64>,
+        limit: Option<i32>,
+        cursor: Option<&str>,
+    ) -> Result<ApiResponse<serde_json::Value>>;
 
-    /// Batch sets collateral coin.
-    async fn batch_set_collateral_coin(&self) -> Result<ApiResponse<serde_json::Value>>;
+    /// Turns several coins on or off as collateral in one request.
+    ///
+    /// # Arguments
+    /// * `switches` - `(coin, collateral_switch)` pairs, see `set_collateral_coin`.
+    async fn batch_set_collateral_coin(
+        &self,
+        switches: &[(String, bool)],
+    ) -> Result<ApiResponse<serde_json::Value>>;
 
-    /// Gets coin greeks.
-    async fn get_coin_greeks(&self) -> Result<ApiResponse<serde_json::Value>>;
+    /// Gets the current account greeks per option base coin.
+    ///
+    /// # Arguments
+    /// * `base_coin` - Optional. Base coin, e.g. "BTC"; all base coins if omitted.
+    ///
+    /// # Returns
+    /// Greeks response; `parse::<models::CoinGreeksResult>()` for the typed result.
+    async fn get_coin_greeks(
+        &self,
+        base_coin: Option<&str>,
+    ) -> Result<ApiResponse<serde_json::Value>>;
 
     /// Gets Market Maker Protection (MMP) state.
     async fn get_mmp_state(&self) -> Result<ApiResponse<serde_json::Value>>;
@@ -351,19 +574,46 @@ pub trait AccountApi {
     /// Sets Market Maker Protection (MMP).
     async fn set_mmp(&self) -> Result<ApiResponse<serde_json::Value>>;
 
-    /// Gets SMP (Single Market Position) group ID.
+    /// Gets the self-match prevention (SMP) group ID of the account.
+    ///
+    /// # Returns
+    /// Bybit response with `smpGroup` (`0` when the account is in no group).
     async fn get_smp_group_id(&self) -> Result<ApiResponse<serde_json::Value>>;
 
-    /// Gets trade behaviour setting.
+    /// Gets how orders priced beyond the allowed limit are handled.
+    ///
+    /// # Returns
+    /// Bybit response with `lpaSpot` and `lpaPerp`: `true` when such prices are adjusted.
     async fn get_trade_behaviour_setting(&self) -> Result<ApiResponse<serde_json::Value>>;
 
-    /// Sets limit price behaviour.
-    async fn set_limit_price_behaviour(&self) -> Result<ApiResponse<serde_json::Value>>;
+    /// Sets how orders priced beyond the allowed limit are handled.
+    ///
+    /// # Arguments
+    /// * `category` - Product type ("linear", "inverse", "spot").
+    /// * `behaviour` - `"modify"` to adjust the price to the limit, or `"reject"` to reject the
+    ///   order. Other values fail with `Error::Validation`.
+    ///
+    /// # Returns
+    /// Bybit response.
+    async fn set_limit_price_behaviour(
+        &self,
+        category: AllCategories,
+        behaviour: &str,
+    ) -> Result<ApiResponse<serde_json::Value>>;
 
     /// Repays liability.
-    async fn repay_liability(&self) -> Result<ApiResponse<serde_json::Value>>;
+    ///
+    /// # Arguments
+    /// * `coin` - Optional. Coin to repay; all liabilities if omitted.
+    async fn repay_liability(
+        &self,
+        coin: Option<&str>,
+    ) -> Result<ApiResponse<serde_json::Value>>;
 
-    /// Upgrades to unified account pro.
+    /// Upgrades the account to Unified Trading Account (UTA) Pro.
+    ///
+    /// # Returns
+    /// Upgrade status response; `parse::<models::UpgradeToUtaResult>()` for the typed result.
     async fn upgrade_to_unified_account_pro(&self) -> Result<ApiResponse<serde_json::Value>>;
 }
 
@@ -402,7 +652,7 @@ pub trait PositionApi {
     ///
     /// # Arguments
     /// * `category` - Product type ("linear", "inverse").
-    ///   **Note:** `category` does **not** support `"spot"` and `"option"`.
+    ///   **Note:** `"spot"` and `"option"` are rejected with `Error::Validation`.
     /// * `symbol` - Symbol name, e.g., "BTCUSDT", uppercase.
     /// * `buy_leverage` - Buy leverage [1, max leverage].
     /// * `sell_leverage` - Sell leverage [1, max leverage].
@@ -422,8 +672,8 @@ pub trait PositionApi {
     /// Supports switching between one-way mode (0) and hedge mode (3).
     ///
     /// # Arguments
-    /// * `category` - Product type (e.g., "linear" for USDT contract).
-    ///   **Note:** Only supports `"linear"` category.
+    /// * `category` - Product type ("linear" for USDT contract, "inverse" for inverse futures).
+    ///   **Note:** `"spot"` and `"option"` are rejected with `Error::Validation`.
     /// * `mode` - Position mode (0: one-way, 3: hedge).
     /// * `symbol` - Optional symbol name.
     /// * `coin` - Optional coin.
@@ -443,7 +693,8 @@ pub trait PositionApi {
     /// # Arguments
     /// * `category` - Product type ("linear", "inverse").
     ///   **Note:** `category` does **not** support `"spot"` and `"option"`.
-    /// * `params` - Trading stop parameters as a JSON object.
+    /// * `params` - Trading stop parameters as a JSON object; checked with
+    ///   [`SetTradingStopParams::validate`] before sending.
     ///
     /// Refer to Bybit API docs for full parameter list.
     ///
@@ -464,8 +715,8 @@ pub trait PositionApi {
     /// Gets closed PnL from Bybit API.
     ///
     /// # Arguments
-    /// * `category` - Product type (e.g., "linear").
-    ///   **Note:** Only supports `"linear"` category.
+    /// * `category` - Product type ("linear", "inverse").
+    ///   **Note:** `"spot"` and `"option"` are rejected with `Error::Validation`.
     /// * `symbol` - Optional symbol.
     /// * `start_time` - Optional start timestamp (ms).
     /// * `end_time` - Optional end timestamp (ms).
@@ -493,3 +744,102 @@ pub trait PositionApi {
     /// Confirms new risk limit.
     async fn confirm_new_risk_limit(&self) -> Result<ApiResponse<serde_json::Value>>;
 }
+
+/// Trait defining Bybit asset API endpoints.
+///
+/// This trait provides asynchronous methods to move funds between the accounts of one UID,
+/// look up coin and chain details, and withdraw.
+///
+/// Returned data generally mirrors the structure from Bybit's endpoints where possible.
+#[async_trait]
+pub trait AssetApi {
+    /// Transfers a coin between two accounts of the same UID, e.g. from `FUND` to `UNIFIED`.
+    ///
+    /// A new UUID is generated as `transferId` for every call; Bybit returns it as
+    /// `result.transferId`.
+    ///
+    /// # Arguments
+    /// * `coin` - Coin, e.g. "USDT".
+    /// * `amount` - Amount to transfer, sent as a plain decimal string; must be greater than 0.
+    /// * `from_account_type` - Account the funds leave.
+    /// * `to_account_type` - Account the funds arrive in; must differ from `from_account_type`.
+    ///
+    /// # Returns
+    /// Bybit response with `transferId` and `status`.
+    async fn create_internal_transfer(
+        &self,
+        coin: &str,
+        amount: Amount,
+        from_account_type: AccountType,
+        to_account_type: AccountType,
+    ) -> Result<ApiResponse<serde_json::Value>>;
+
+    /// Gets internal transfer records.
+    ///
+    /// # Arguments
+    /// * `transfer_id` - Optional. A single transfer by its UUID.
+    /// * `coin` - Optional. Coin, e.g. "USDT".
+    /// * `status` - Optional. "SUCCESS", "PENDING" or "FAILED".
+    /// * `start_time` - Optional. Start timestamp (ms).
+    /// * `end_time` - Optional. End timestamp (ms); the window may span at most 7 days.
+    /// * `limit` - Optional. Records per page [1, 50], default 20.
+    /// * `cursor` - Optional. Cursor for pagination (from `nextPageCursor`).
+    ///
+    /// # Returns
+    /// Bybit transfer history response.
+    async fn get_transfer_history(
+        &self,
+        transfer_id: Option<&str>,
+        coin: Option<&str>,
+        status: Option<&str>,
+        start_time: Option<i64>,
+        end_time: Option<i64>,
+        limit: Option<i32>,
+        cursor: Option<&str>,
+    ) -> Result<ApiResponse<serde_json::Value>>;
+
+    /// Gets coin details: chains with their deposit and withdrawal status, fees and minimums.
+    ///
+    /// # Arguments
+    /// * `coin` - Optional. Coin, e.g. "USDT"; all coins if omitted.
+    async fn get_coin_info(
+        &self,
+        coin: Option<&str>,
+    ) -> Result<ApiResponse<serde_json::Value>>;
+
+    /// Gets the amount of a coin that can be withdrawn from the Funding and Unified accounts.
+    ///
+    /// # Arguments
+    /// * `coin` - Coin, e.g. "USDT".
+    async fn get_withdrawable_amount(
+        &self,
+        coin: &str,
+    ) -> Result<ApiResponse<serde_json::Value>>;
+
+    /// Withdraws a coin to an on-chain address (must be on the account's address book
+    /// whitelist).
+    ///
+    /// # Arguments
+    /// * `coin` - Coin, e.g. "USDT".
+    /// * `chain` - Chain, e.g. "ETH" or "TRX"; see `chains[].chain` of [`Self::get_coin_info`].
+    /// * `address` - Destination address; must not be empty.
+    /// * `tag` - Optional. Memo/tag, required by some chains.
+    /// * `amount` - Amount to withdraw, sent as a plain decimal string; must be greater than 0.
+    /// * `account_type` - Optional. Wallet to withdraw from: "FUND" (default) or "UTA".
+    /// * `request_id` - Optional. Idempotency key; a repeated ID is rejected for 30 minutes.
+    ///
+    /// The current time is sent as `timestamp`, which Bybit uses against replays.
+    ///
+    /// # Returns
+    /// Bybit response with the withdrawal `id`.
+    async fn withdraw(
+        &self,
+        coin: &str,
+        chain: &str,
+        address: &str,
+        tag: Option<&str>,
+        amount: Amount,
+        account_type: Option<&str>,
+        request_id: Option<&str>,
+    ) -> Result<ApiResponse<serde_json::Value>>;
+}
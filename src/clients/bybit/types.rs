@@ -1,5 +1,6 @@
 //! Type definitions for trade-sdk.
-use crate::utils::{as_str_f64, as_str_opt};
+use crate::error::{Error, Result};
+use crate::utils::{as_str_f64, as_str_opt, Amount};
 use serde::{Deserialize, Serialize};
 
 /// Enum for all possible instrument categories.
@@ -27,8 +28,15 @@ impl std::fmt::Display for AllCategories {
     }
 }
 
+impl AllCategories {
+    /// Whether the category is a perpetual or futures contract (`linear` or `inverse`).
+    pub fn is_derivative(&self) -> bool {
+        matches!(self, AllCategories::Linear | AllCategories::Inverse)
+    }
+}
+
 // Account types
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum AccountType {
     Unified,
@@ -136,13 +144,34 @@ pub enum MarketUnit {
     QuoteCoin,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub enum OrderPriceTriggerBy {
+/// Price type that triggers a conditional order, TP or SL.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+pub enum TriggerBy {
     LastPrice,
     IndexPrice,
     MarkPrice,
 }
 
+impl std::fmt::Display for TriggerBy {
+    fn fmt(
+        &self,
+        f: &mut std::fmt::Formatter<'_>,
+    ) -> std::fmt::Result {
+        let s = match self {
+            TriggerBy::LastPrice => "LastPrice",
+            TriggerBy::IndexPrice => "IndexPrice",
+            TriggerBy::MarkPrice => "MarkPrice",
+        };
+        write!(f, "{s}")
+    }
+}
+
+/// Trigger price type of a conditional order (`triggerBy`).
+pub type OrderPriceTriggerBy = TriggerBy;
+
+/// Trigger price type of take profit / stop loss (`tpTriggerBy`, `slTriggerBy`).
+pub type TpSlTriggerBy = TriggerBy;
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "UPPERCASE")]
 pub enum TimeInForce {
@@ -156,7 +185,7 @@ pub enum TimeInForce {
 /// 0 = one-way mode position
 /// 1 = Buy side of hedge-mode position
 /// 2 = Sell side of hedge-mode position
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 pub enum PositionIdx {
     /// one-way mode position
     #[serde(rename = "0")]
@@ -171,11 +200,44 @@ pub enum PositionIdx {
     HedgeSell = 2,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub enum TpSlTriggerBy {
-    LastPrice,
-    IndexPrice,
-    MarkPrice,
+/// Position mode of a symbol, see `PositionApi::switch_position_mode`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PositionMode {
+    /// One position per symbol (mode 0).
+    OneWay,
+    /// Separate long and short positions (mode 3).
+    Hedge,
+}
+
+impl PositionMode {
+    /// Mode for a `switch_position_mode` `mode` value, `None` if unknown.
+    pub fn from_mode(mode: u8) -> Option<Self> {
+        match mode {
+            0 => Some(Self::OneWay),
+            3 => Some(Self::Hedge),
+            _ => None,
+        }
+    }
+
+    /// Position index an order on `side` must carry in this mode.
+    ///
+    /// In hedge mode a reduce-only order closes the opposite side: a reduce-only sell closes
+    /// the long position (index 1).
+    pub fn position_idx(
+        self,
+        side: &Side,
+        reduce_only: bool,
+    ) -> PositionIdx {
+        match (self, side, reduce_only) {
+            (Self::OneWay, _, _) => PositionIdx::OneWay,
+            (Self::Hedge, Side::Buy, false) | (Self::Hedge, Side::Sell, true) => {
+                PositionIdx::HedgeBuy
+            }
+            (Self::Hedge, Side::Buy, true) | (Self::Hedge, Side::Sell, false) => {
+                PositionIdx::HedgeSell
+            }
+        }
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -201,33 +263,67 @@ pub struct SetTradingStopParams {
 
     // Optional TP/SL fields (serialized as strings, skip if None)
     #[serde(skip_serializing_if = "Option::is_none", serialize_with = "as_str_opt")]
-    pub take_profit: Option<f64>,
+    pub take_profit: Option<Amount>,
     #[serde(skip_serializing_if = "Option::is_none", serialize_with = "as_str_opt")]
-    pub stop_loss: Option<f64>,
+    pub stop_loss: Option<Amount>,
     #[serde(skip_serializing_if = "Option::is_none", serialize_with = "as_str_opt")]
-    pub trailing_stop: Option<f64>,
+    pub trailing_stop: Option<Amount>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub tp_trigger_by: Option<TpSlTriggerBy>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub sl_trigger_by: Option<TpSlTriggerBy>,
     #[serde(skip_serializing_if = "Option::is_none", serialize_with = "as_str_opt")]
-    pub active_price: Option<f64>,
+    pub active_price: Option<Amount>,
 
     // Partial mode fields (serialized as strings, skip if None)
     #[serde(skip_serializing_if = "Option::is_none", serialize_with = "as_str_opt")]
-    pub tp_size: Option<f64>,
+    pub tp_size: Option<Amount>,
     #[serde(skip_serializing_if = "Option::is_none", serialize_with = "as_str_opt")]
-    pub sl_size: Option<f64>,
+    pub sl_size: Option<Amount>,
     #[serde(skip_serializing_if = "Option::is_none", serialize_with = "as_str_opt")]
-    pub tp_limit_price: Option<f64>,
+    pub tp_limit_price: Option<Amount>,
     #[serde(skip_serializing_if = "Option::is_none", serialize_with = "as_str_opt")]
-    pub sl_limit_price: Option<f64>,
+    pub sl_limit_price: Option<Amount>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub tp_order_type: Option<TpSlOrderType>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub sl_order_type: Option<TpSlOrderType>,
 }
 
+impl SetTradingStopParams {
+    /// Reject combinations Bybit is known to refuse: `Partial` mode without `tp_size` or
+    /// `sl_size`, a `Limit` TP/SL order type without its limit price, or `trailing_stop`
+    /// together with `take_profit`.
+    pub fn validate(&self) -> Result<()> {
+        if matches!(self.tpsl_mode, TpSlMode::Partial)
+            && self.tp_size.is_none()
+            && self.sl_size.is_none()
+        {
+            return Err(Error::Validation(
+                "Partial tpsl_mode requires tp_size or sl_size".to_string(),
+            ));
+        }
+        if matches!(self.tp_order_type, Some(TpSlOrderType::Limit)) && self.tp_limit_price.is_none()
+        {
+            return Err(Error::Validation(
+                "Limit tp_order_type requires tp_limit_price".to_string(),
+            ));
+        }
+        if matches!(self.sl_order_type, Some(TpSlOrderType::Limit)) && self.sl_limit_price.is_none()
+        {
+            return Err(Error::Validation(
+                "Limit sl_order_type requires sl_limit_price".to_string(),
+            ));
+        }
+        if self.trailing_stop.is_some() && self.take_profit.is_some() {
+            return Err(Error::Validation(
+                "Set either trailing_stop or take_profit, not both".to_string(),
+            ));
+        }
+        Ok(())
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum OrderFilter {
     #[serde(rename = "Order")]
@@ -298,7 +394,7 @@ pub enum OrderStatus {
 }
 
 /// Parameters for querying order history.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct GetOrderHistoryParams {
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -385,7 +481,7 @@ pub struct PlaceOrderParams {
     /// For Perps, Futures & Options: Always order by quantity.
     /// For Perps & Futures: If qty="0" and you set `reduce_only=True` and `close_on_trigger=True`, you can close the position up to maxMktOrderQty or maxOrderQty (see "Get Instruments Info" for the relevant symbol).
     #[serde(serialize_with = "as_str_f64")]
-    pub qty: f64, // needs to be str in serialization
+    pub qty: Amount, // needs to be str in serialization
 
     /// Select the unit for qty when creating Spot market orders. Optional.
     /// "baseCoin": For example, buy BTCUSDT, then "qty" unit is BTC.
@@ -398,13 +494,13 @@ pub struct PlaceOrderParams {
     /// Please check the min price and price precision from the instrument info endpoint.
     /// If you have a position, price must be better than the liquidation price.
     #[serde(skip_serializing_if = "Option::is_none", serialize_with = "as_str_opt")]
-    pub price: Option<f64>,
+    pub price: Option<Amount>,
 
     /// The conditional order trigger price.
     /// For Perps & Futures: Set trigger_price > market price if you expect the price to rise to trigger your order. Otherwise, set trigger_price < market price.
     /// For Spot: Used for TP/SL and Conditional order trigger price.
     #[serde(skip_serializing_if = "Option::is_none", serialize_with = "as_str_opt")]
-    pub trigger_price: Option<f64>,
+    pub trigger_price: Option<Amount>,
 
     /// Trigger price type, Conditional order param for Perps & Futures. Valid for linear & inverse.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -430,12 +526,12 @@ pub struct PlaceOrderParams {
     /// Take profit price.
     /// Spot Limit order supports take profit, stop loss or limit take profit, limit stop loss when creating an order.
     #[serde(skip_serializing_if = "Option::is_none", serialize_with = "as_str_opt")]
-    pub take_profit: Option<f64>,
+    pub take_profit: Option<Amount>,
 
     /// Stop loss price.
     /// Spot Limit order supports take profit, stop loss or limit take profit, limit stop loss when creating an order.
     #[serde(skip_serializing_if = "Option::is_none", serialize_with = "as_str_opt")]
-    pub stop_loss: Option<f64>,
+    pub stop_loss: Option<Amount>,
 
     /// The price type to trigger take profit. MarkPrice, IndexPrice, default: LastPrice. Valid for linear & inverse.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -504,6 +600,19 @@ pub struct ApiResponse<T> {
     pub time: u64,
 }
 
+impl ApiResponse<serde_json::Value> {
+    /// Deserialize `result` into a typed model, e.g. `response.parse::<WalletBalanceResult>()`.
+    pub fn parse<T: serde::de::DeserializeOwned>(&self) -> crate::error::Result<T> {
+        T::deserialize(&self.result).map_err(crate::error::Error::Json)
+    }
+
+    /// Deserialize the per-order statuses in `retExtInfo` of a batch response.
+    pub fn batch_ext(&self) -> crate::error::Result<crate::bybit::models::BatchResultExt> {
+        crate::bybit::models::BatchResultExt::deserialize(&self.ret_ext_info)
+            .map_err(crate::error::Error::Json)
+    }
+}
+
 /// Generic API response for deserialization
 #[derive(Debug, Clone, Deserialize)]
 #[serde(rename_all = "camelCase")]
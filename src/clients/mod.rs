@@ -1,2 +1,3 @@
 pub mod bingx;
 pub mod bybit;
+pub mod okx;
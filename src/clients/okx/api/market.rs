@@ -0,0 +1,44 @@
+//! Market data API implementation.
+
+use std::collections::HashMap;
+
+use async_trait::async_trait;
+use linkme::distributed_slice;
+use serde_json::Value;
+
+use crate::error::Result;
+use crate::http::HttpClient;
+use crate::okx::traits::MarketApi;
+use crate::okx::types::{ApiResponse, InstType};
+use crate::okx::OkxClient;
+
+use crate::okx::OKX_IMPLEMENTED;
+
+#[distributed_slice(OKX_IMPLEMENTED)]
+static GET_TICKERS: &str = "get_tickers";
+
+#[async_trait]
+impl MarketApi for OkxClient {
+    async fn get_tickers(
+        &self,
+        inst_type: InstType,
+        uly: Option<&str>,
+        inst_family: Option<&str>,
+    ) -> Result<ApiResponse<Value>> {
+        let mut params = HashMap::new();
+        params.insert("instType".to_string(), Value::String(inst_type.to_string()));
+        if let Some(uly) = uly {
+            params.insert("uly".to_string(), Value::String(uly.to_string()));
+        }
+        if let Some(inst_family) = inst_family {
+            params.insert(
+                "instFamily".to_string(),
+                Value::String(inst_family.to_string()),
+            );
+        }
+        let response = self
+            .get("/api/v5/market/tickers", Some(&params), false)
+            .await?;
+        Ok(response.into_api_response())
+    }
+}
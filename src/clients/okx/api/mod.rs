@@ -0,0 +1,4 @@
+//! API modules for different OKX API endpoints.
+
+mod market;
+mod trade;
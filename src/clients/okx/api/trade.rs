@@ -0,0 +1,65 @@
+//! Trade API implementation.
+
+use std::collections::HashMap;
+
+use async_trait::async_trait;
+use linkme::distributed_slice;
+use serde_json::Value;
+
+use crate::error::{Error, Result};
+use crate::http::HttpClient;
+use crate::okx::traits::TradeApi;
+use crate::okx::types::{ApiResponse, PlaceOrderParams};
+use crate::okx::OkxClient;
+
+use crate::okx::OKX_IMPLEMENTED;
+
+#[distributed_slice(OKX_IMPLEMENTED)]
+static PLACE_ORDER: &str = "place_order";
+
+#[distributed_slice(OKX_IMPLEMENTED)]
+static CANCEL_ORDER: &str = "cancel_order";
+
+#[async_trait]
+impl TradeApi for OkxClient {
+    async fn place_order(
+        &self,
+        params: &PlaceOrderParams,
+    ) -> Result<ApiResponse<Value>> {
+        let mut api_params: HashMap<String, Value> = HashMap::new();
+        if let Value::Object(obj) = serde_json::to_value(params)? {
+            api_params.extend(obj.into_iter().filter(|(_, value)| !value.is_null()));
+        }
+        let response = self
+            .post("/api/v5/trade/order", Some(&api_params), true)
+            .await?;
+        Ok(response.into_api_response())
+    }
+
+    async fn cancel_order(
+        &self,
+        inst_id: &str,
+        ord_id: Option<&str>,
+        cl_ord_id: Option<&str>,
+    ) -> Result<ApiResponse<Value>> {
+        let mut params = HashMap::new();
+        params.insert("instId".to_string(), Value::String(inst_id.to_string()));
+        match (ord_id, cl_ord_id) {
+            (Some(ord_id), _) => {
+                params.insert("ordId".to_string(), Value::String(ord_id.to_string()));
+            }
+            (None, Some(cl_ord_id)) => {
+                params.insert("clOrdId".to_string(), Value::String(cl_ord_id.to_string()));
+            }
+            (None, None) => {
+                return Err(Error::Validation(
+                    "cancel_order requires ord_id or cl_ord_id".to_string(),
+                ));
+            }
+        }
+        let response = self
+            .post("/api/v5/trade/cancel-order", Some(&params), true)
+            .await?;
+        Ok(response.into_api_response())
+    }
+}
@@ -0,0 +1,375 @@
+//! HTTP client module for OKX API communication.
+
+use async_trait::async_trait;
+use reqwest::Method;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use crate::error::{Error, ExchangeResponseError, Result};
+use crate::http::{status_error, BaseHttpClient, HttpClient, Observer, RequestArgs, TimeSource};
+use crate::okx::signing::{self, mask_headers};
+use crate::okx::types::GenericResponse;
+
+/// Exchange name reported to observers.
+const EXCHANGE: &str = "okx";
+
+/// OKX serves live and demo trading from the same host.
+const BASE_URL: &str = "https://www.okx.com";
+
+/// Header that routes a request to the demo trading environment.
+const SIMULATED_TRADING_HEADER: &str = "x-simulated-trading";
+
+/// HTTP client for OKX API (live, demo).
+#[derive(Clone)]
+pub struct OkxHttpClient {
+    base_client: BaseHttpClient,
+    passphrase: Option<String>,
+    demo: bool,
+}
+
+impl OkxHttpClient {
+    /// Initialize a new OkxHttpClient.
+    ///
+    /// # Arguments
+    /// * `api_key` - Trading API key (optional)
+    /// * `api_secret` - Trading API secret (optional)
+    /// * `passphrase` - Passphrase chosen when the API key was created (optional)
+    /// * `demo` - Send `x-simulated-trading: 1` to trade on the demo account
+    pub fn new(
+        api_key: Option<String>,
+        api_secret: Option<String>,
+        passphrase: Option<String>,
+        demo: bool,
+    ) -> Result<Self> {
+        // OKX has no recvWindow; the signing timestamp must be within 30 seconds of server time.
+        let base_client = BaseHttpClient::new(BASE_URL.to_string(), api_key, api_secret, 0)?;
+        Ok(Self {
+            base_client,
+            passphrase,
+            demo,
+        })
+    }
+
+    pub fn is_shared_session_enabled(&self) -> bool {
+        self.base_client.is_shared_session_enabled()
+    }
+
+    /// Release the connection pool; a no-op for shared-session clients.
+    pub async fn close(self) {
+        self.base_client.close().await
+    }
+
+    /// Base URL requests are sent to.
+    pub fn base_url(&self) -> &str {
+        &self.base_client.base_url
+    }
+
+    /// Route this client through a proxy (`http://`, `https://` or `socks5://` URL).
+    ///
+    /// Returns `Error::Config` if the URL is invalid.
+    pub fn with_proxy(
+        mut self,
+        url: &str,
+    ) -> Result<Self> {
+        self.base_client.set_proxy(url)?;
+        Ok(self)
+    }
+
+    /// Override the base URL, e.g. to point the client at a mock server or a gateway.
+    pub fn with_base_url(
+        mut self,
+        base_url: &str,
+    ) -> Self {
+        self.base_client.set_base_url(base_url);
+        self
+    }
+
+    /// Limit the requests this client (and its clones) has in flight; further requests wait
+    /// for a free slot. `None` (the default) removes the limit, `Some(0)` is treated as 1.
+    pub fn set_max_concurrency(
+        &mut self,
+        max_concurrency: Option<usize>,
+    ) {
+        self.base_client.set_max_concurrency(max_concurrency)
+    }
+
+    /// Request slots currently free under the in-flight limit, `None` if unlimited.
+    pub fn available_request_slots(&self) -> Option<usize> {
+        self.base_client.available_request_slots()
+    }
+
+    /// Register an observer notified around every request of this client.
+    pub fn set_observer(
+        &mut self,
+        observer: Arc<dyn Observer>,
+    ) {
+        self.base_client.set_observer(observer)
+    }
+
+    /// Log exchange errors at the level `filter` returns for their code instead of `error`.
+    pub fn set_error_log_filter(
+        &mut self,
+        filter: impl Fn(i64) -> log::Level + Send + Sync + 'static,
+    ) {
+        self.base_client.set_error_log_filter(filter)
+    }
+
+    /// Level an exchange error with return code `code` is logged at.
+    pub fn error_log_level(
+        &self,
+        code: i64,
+    ) -> log::Level {
+        self.base_client.error_log_level(code)
+    }
+
+    /// Replace the clock used for signing timestamps (the system clock by default); shared
+    /// by clones made afterwards.
+    pub fn set_time_source(
+        &mut self,
+        time_source: Arc<dyn TimeSource>,
+    ) {
+        self.base_client.set_time_source(time_source)
+    }
+
+    /// Current time of the client's clock, in ms since the Unix epoch.
+    pub fn now_ms(&self) -> i64 {
+        self.base_client.now_ms()
+    }
+
+    /// Register a sink receiving an audit record for every request.
+    #[cfg(feature = "audit")]
+    pub fn set_audit_sink(
+        &mut self,
+        sink: Arc<dyn crate::audit::AuditSink>,
+    ) {
+        self.base_client.set_audit_sink(sink)
+    }
+
+    /// Stored API key, secret and passphrase; `Error::Auth` if any is missing.
+    fn stored_credentials(&self) -> Result<(&str, &str, &str)> {
+        let api_key = self.base_client.api_key.as_deref().ok_or_else(|| {
+            Error::Auth("API key must be set for authenticated requests.".to_string())
+        })?;
+        let api_secret = self.base_client.api_secret.as_deref().ok_or_else(|| {
+            Error::Auth("API secret must be set for authenticated requests.".to_string())
+        })?;
+        let passphrase = self.passphrase.as_deref().ok_or_else(|| {
+            Error::Auth("API passphrase must be set for authenticated requests.".to_string())
+        })?;
+        Ok((api_key, api_secret, passphrase))
+    }
+
+    /// Send a request, signed with the stored credentials if `auth`.
+    async fn request(
+        &self,
+        method: Method,
+        endpoint: &str,
+        params: Option<&HashMap<String, serde_json::Value>>,
+        auth: bool,
+    ) -> Result<GenericResponse> {
+        // Wait for a slot first, so queued requests are not counted as in flight.
+        let _permit = self.base_client.acquire_request_permit().await;
+        // Shared-pool requests are counted until they complete or are cancelled, so
+        // `SharedSessionManager::close()` can wait for them.
+        let _in_flight = self.base_client.in_flight_guard();
+        let request = self.execute_request(method.clone(), endpoint, params, auth);
+        #[cfg(feature = "tracing")]
+        let request = tracing::Instrument::instrument(
+            request,
+            crate::http::request_span(EXCHANGE, &method, endpoint),
+        );
+        request.await
+    }
+
+    /// Build, send and check a request, notifying the observer around it.
+    async fn execute_request(
+        &self,
+        method: Method,
+        endpoint: &str,
+        params: Option<&HashMap<String, serde_json::Value>>,
+        auth: bool,
+    ) -> Result<GenericResponse> {
+        let request_args = self
+            .build_request_args(method.clone(), endpoint, params, auth)
+            .await?;
+
+        let started = self.base_client.notify_request(EXCHANGE, &method, endpoint);
+        let mut status_code = None;
+        let result = self
+            .send_request(&method, &request_args, &mut status_code)
+            .await;
+        let ret_code = match &result {
+            Ok(response) => response.code.parse().ok(),
+            Err(e) => e.exchange_code(),
+        };
+        self.base_client.notify_response(
+            started,
+            EXCHANGE,
+            &method,
+            endpoint,
+            status_code,
+            ret_code,
+        );
+        #[cfg(feature = "tracing")]
+        crate::http::trace_outcome(&request_args.url, status_code, ret_code, &result);
+        #[cfg(feature = "audit")]
+        if let Some(sink) = self.base_client.audit_sink() {
+            sink.record(&crate::audit::AuditRecord {
+                timestamp_ms: self.base_client.now_ms(),
+                exchange: EXCHANGE,
+                method: &method,
+                endpoint,
+                url: request_args.url.clone(),
+                body: request_args.json.clone(),
+                status: status_code,
+                ret_code,
+                response: match &result {
+                    Ok(response) => Some(serde_json::json!({
+                        "code": response.code,
+                        "msg": response.msg,
+                        "data": response.data,
+                    })),
+                    Err(Error::Exchange(e)) => Some(e.raw().clone()),
+                    Err(_) => None,
+                },
+                error: result.as_ref().err().map(ToString::to_string),
+            });
+        }
+        result
+    }
+
+    /// Send a prepared request and check the HTTP status and exchange return code.
+    ///
+    /// `status_code` is set as soon as a response is received.
+    async fn send_request(
+        &self,
+        method: &Method,
+        request_args: &RequestArgs,
+        status_code: &mut Option<u16>,
+    ) -> Result<GenericResponse> {
+        let mut request = self
+            .base_client
+            .http_client()
+            .request(method.clone(), &request_args.url);
+
+        if let Some(json) = &request_args.json {
+            request = request.json(json);
+        }
+
+        for (k, v) in &request_args.headers {
+            request = request.header(k, v);
+        }
+
+        let response = request.send().await.map_err(Error::Http)?;
+        let status = response.status();
+        *status_code = Some(status.as_u16());
+
+        if !status.is_success() {
+            log::error!(
+                "HTTP error during async request: method={}, url={}, headers={:?}, status={}, response={:?}",
+                method,
+                &request_args.url,
+                mask_headers(&request_args.headers),
+                status,
+                &response
+            );
+            return Err(status_error(response));
+        }
+
+        let value = self.base_client.read_json(response).await?;
+        // OKX sends the code as a string; "0" means success.
+        let success = value.get("code").and_then(|v| v.as_str()) == Some("0");
+
+        if !success {
+            let err = ExchangeResponseError::from(value);
+            log::log!(
+                self.base_client.error_log_level(err.code()),
+                "ExchangeResponseError during async request: method={}, url={}, headers={:?}, status={}, error={}",
+                method,
+                &request_args.url,
+                mask_headers(&request_args.headers),
+                status,
+                err
+            );
+            return Err(Error::Exchange(err));
+        }
+
+        let generic: GenericResponse = serde_json::from_value(value).map_err(Error::Json)?;
+        Ok(generic)
+    }
+}
+
+#[async_trait]
+impl HttpClient<GenericResponse> for OkxHttpClient {
+    async fn build_request_args(
+        &self,
+        method: reqwest::Method,
+        endpoint: &str,
+        params: Option<&HashMap<String, serde_json::Value>>,
+        auth: bool,
+    ) -> Result<RequestArgs> {
+        let credentials = if auth {
+            Some(self.stored_credentials()?)
+        } else {
+            None
+        };
+        let params = params.cloned().unwrap_or_default();
+        let mut headers = HashMap::new();
+
+        // GET parameters go into the query string, anything else into the JSON body; both
+        // are part of the signed string.
+        let (request_path, body) = if method == Method::GET {
+            let request_path = match signing::query_string(&params) {
+                Some(query) => format!("{endpoint}?{query}"),
+                None => endpoint.to_string(),
+            };
+            (request_path, None)
+        } else {
+            let body = serde_json::Value::Object(params.clone().into_iter().collect());
+            (endpoint.to_string(), Some(body))
+        };
+
+        if let Some((api_key, api_secret, passphrase)) = credentials {
+            let timestamp = signing::iso_timestamp(self.base_client.now_ms());
+            let body_str = body.as_ref().map(ToString::to_string).unwrap_or_default();
+            let signature =
+                signing::sign(api_secret, &timestamp, &method, &request_path, &body_str);
+            headers.insert("OK-ACCESS-KEY".to_owned(), api_key.to_owned());
+            headers.insert("OK-ACCESS-SIGN".to_owned(), signature);
+            headers.insert("OK-ACCESS-TIMESTAMP".to_owned(), timestamp);
+            headers.insert("OK-ACCESS-PASSPHRASE".to_owned(), passphrase.to_owned());
+        }
+
+        if self.demo {
+            headers.insert(SIMULATED_TRADING_HEADER.to_owned(), "1".to_owned());
+        }
+
+        if log::log_enabled!(log::Level::Debug) {
+            log::debug!(
+                "Making async {:?} request to {}{} with params: {:?}",
+                method,
+                self.base_client.base_url,
+                endpoint,
+                params,
+            );
+        }
+
+        Ok(RequestArgs {
+            url: format!("{}{}", self.base_client.base_url, request_path),
+            headers,
+            params: None,
+            json: body,
+            data: None,
+        })
+    }
+
+    async fn async_request(
+        &self,
+        method: reqwest::Method,
+        endpoint: &str,
+        params: Option<&HashMap<String, serde_json::Value>>,
+        auth: bool,
+    ) -> Result<GenericResponse> {
+        self.request(method, endpoint, params, auth).await
+    }
+}
@@ -0,0 +1,151 @@
+//! OKX Trading API Client with a minimal set of market and trade methods.
+mod api;
+mod http;
+pub mod signing;
+pub mod traits;
+pub mod types;
+
+use std::collections::HashMap;
+
+use async_trait::async_trait;
+use reqwest::Method;
+use serde_json::Value;
+
+use crate::error::Result;
+use crate::http::{DynHttpClient, DynResponse, HttpClient};
+use http::OkxHttpClient;
+use linkme::distributed_slice;
+use types::ApiResponse;
+
+#[distributed_slice]
+pub static OKX_IMPLEMENTED: [&'static str];
+
+/// OKX Trading API Client with all available methods.
+///
+/// Cloning is cheap: clones share the connection pool and credentials.
+#[derive(Clone)]
+pub struct OkxClient {
+    http_client: OkxHttpClient,
+    demo: bool,
+}
+
+impl OkxClient {
+    /// Create a new OKX client
+    ///
+    /// `passphrase` is the one chosen when the API key was created; it is required for
+    /// authenticated requests. With `demo`, requests carry `x-simulated-trading: 1` and trade
+    /// on the demo account, which needs a demo API key.
+    pub fn new(
+        api_key: Option<String>,
+        api_secret: Option<String>,
+        passphrase: Option<String>,
+        demo: bool,
+    ) -> Result<Self> {
+        let http_client = OkxHttpClient::new(api_key, api_secret, passphrase, demo)?;
+        Ok(Self { http_client, demo })
+    }
+
+    /// Whether the client was created for demo trading.
+    pub fn is_demo(&self) -> bool {
+        self.demo
+    }
+
+    /// Override the base URL, e.g. to point the client at a mock server or a gateway.
+    pub fn with_base_url(
+        self,
+        base_url: &str,
+    ) -> Self {
+        Self {
+            http_client: self.http_client.with_base_url(base_url),
+            ..self
+        }
+    }
+
+    /// Route this client through a proxy (`http://`, `https://` or `socks5://` URL).
+    ///
+    /// Returns `Error::Config` if the URL is invalid.
+    pub fn with_proxy(
+        self,
+        url: &str,
+    ) -> Result<Self> {
+        Ok(Self {
+            http_client: self.http_client.with_proxy(url)?,
+            ..self
+        })
+    }
+
+    /// Send a request to any endpoint, including ones the SDK does not wrap yet.
+    ///
+    /// GET `params` go into the query string, others into the JSON body; with `auth` the
+    /// request is signed like every typed endpoint. The `data` payload is returned as-is.
+    pub async fn request_raw(
+        &self,
+        method: Method,
+        endpoint: &str,
+        params: Option<&HashMap<String, Value>>,
+        auth: bool,
+    ) -> Result<ApiResponse<Value>> {
+        let response = self
+            .http_client
+            .async_request(method, endpoint, params, auth)
+            .await?;
+        Ok(response.into_api_response())
+    }
+
+    /// Close the client, draining its own connection pool.
+    ///
+    /// Clients on the shared session leave the pool open; close it with
+    /// [`crate::SharedSessionManager::close`].
+    pub async fn close(self) {
+        self.http_client.close().await
+    }
+
+    /// Whether `name` (a trait method such as `"place_order"`) is implemented.
+    pub fn is_implemented(name: &str) -> bool {
+        OKX_IMPLEMENTED.contains(&name)
+    }
+
+    /// Names of all implemented methods, sorted.
+    pub fn implemented_methods() -> Vec<&'static str> {
+        let mut methods = OKX_IMPLEMENTED.to_vec();
+        methods.sort_unstable();
+        methods
+    }
+}
+
+impl std::ops::Deref for OkxClient {
+    type Target = OkxHttpClient;
+
+    fn deref(&self) -> &Self::Target {
+        &self.http_client
+    }
+}
+
+impl std::ops::DerefMut for OkxClient {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.http_client
+    }
+}
+
+#[async_trait]
+impl DynHttpClient for OkxClient {
+    fn exchange(&self) -> &'static str {
+        "okx"
+    }
+
+    async fn request_dyn(
+        &self,
+        method: Method,
+        endpoint: &str,
+        params: Option<&HashMap<String, Value>>,
+        auth: bool,
+    ) -> Result<DynResponse> {
+        let response = self.request_raw(method, endpoint, params, auth).await?;
+        Ok(DynResponse {
+            exchange: "okx",
+            code: response.code.parse().unwrap_or_default(),
+            msg: response.msg,
+            data: response.data,
+        })
+    }
+}
@@ -0,0 +1,103 @@
+//! Request signing for the OKX API.
+//!
+//! The signature is an HMAC-SHA256 over `timestamp + METHOD + requestPath + body`, base64
+//! encoded, where `timestamp` is the UTC ISO-8601 time with milliseconds, `requestPath`
+//! includes the query string of a GET and `body` is the JSON body of a POST (empty for GET).
+//! Credential headers are masked with [`mask_headers`] before logging.
+
+use base64::engine::general_purpose::STANDARD as BASE64;
+use base64::Engine;
+use hmac::{Hmac, Mac};
+use reqwest::Method;
+use sha2::Sha256;
+use std::collections::{BTreeMap, HashMap};
+
+/// The string OKX signs: `timestamp + METHOD + requestPath + body`.
+pub fn prehash(
+    timestamp: &str,
+    method: &Method,
+    request_path: &str,
+    body: &str,
+) -> String {
+    format!("{timestamp}{}{request_path}{body}", method.as_str())
+}
+
+/// OKX v5 signature: base64 of the HMAC-SHA256 of [`prehash`] with the API secret.
+pub fn sign(
+    api_secret: &str,
+    timestamp: &str,
+    method: &Method,
+    request_path: &str,
+    body: &str,
+) -> String {
+    let mut mac =
+        Hmac::<Sha256>::new_from_slice(api_secret.as_bytes()).expect("Invalid API secret");
+    mac.update(prehash(timestamp, method, request_path, body).as_bytes());
+    BASE64.encode(mac.finalize().into_bytes())
+}
+
+/// Format ms since the Unix epoch as the `OK-ACCESS-TIMESTAMP` value, e.g.
+/// `2020-12-08T09:08:57.715Z`.
+pub fn iso_timestamp(timestamp_ms: i64) -> String {
+    let days = timestamp_ms.div_euclid(86_400_000);
+    let ms_of_day = timestamp_ms.rem_euclid(86_400_000);
+    let (year, month, day) = civil_from_days(days);
+    format!(
+        "{year:04}-{month:02}-{day:02}T{:02}:{:02}:{:02}.{:03}Z",
+        ms_of_day / 3_600_000,
+        ms_of_day / 60_000 % 60,
+        ms_of_day / 1000 % 60,
+        ms_of_day % 1000,
+    )
+}
+
+/// Calendar date of a day count since 1970-01-01 (proleptic Gregorian).
+fn civil_from_days(days: i64) -> (i64, i64, i64) {
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z.rem_euclid(146_097);
+    let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + i64::from(month <= 2);
+    (year, month, day)
+}
+
+/// Query string of GET parameters, sorted by key so the signed path is deterministic.
+///
+/// String values are sent without JSON quotes; `None` if there are no parameters.
+pub fn query_string(params: &HashMap<String, serde_json::Value>) -> Option<String> {
+    if params.is_empty() {
+        return None;
+    }
+    let sorted: BTreeMap<_, _> = params.iter().collect();
+    let query = sorted
+        .into_iter()
+        .map(|(key, value)| {
+            let value = match value {
+                serde_json::Value::String(s) => s.clone(),
+                other => other.to_string(),
+            };
+            format!("{key}={}", urlencoding::encode(&value))
+        })
+        .collect::<Vec<_>>()
+        .join("&");
+    Some(query)
+}
+
+/// Masks sensitive headers for logging; truncates the API key and hides sign and passphrase.
+pub fn mask_headers(headers: &HashMap<String, String>) -> HashMap<String, String> {
+    let mut masked = HashMap::new();
+    for (k, v) in headers.iter() {
+        let key = k.to_lowercase();
+        let value = match key.as_str() {
+            "ok-access-key" => format!("{}...", &v[..6.min(v.len())]),
+            "ok-access-sign" | "ok-access-passphrase" => "***".to_string(),
+            _ => v.clone(),
+        };
+        masked.insert(key, value);
+    }
+    masked
+}
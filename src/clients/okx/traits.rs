@@ -0,0 +1,58 @@
+use async_trait::async_trait;
+
+use crate::error::Result;
+use crate::okx::types::{ApiResponse, InstType, PlaceOrderParams};
+
+/// Trait defining OKX market data API endpoints.
+#[async_trait]
+pub trait MarketApi {
+    /// Retrieves the latest price snapshot, best bid/ask and 24h volume of all instruments of a
+    /// type.
+    ///
+    /// # Arguments
+    /// * `inst_type` – Instrument type: spot, swap, futures or option.
+    /// * `uly` – Underlying, e.g. `"BTC-USD"`; futures, swap and option only.
+    /// * `inst_family` – Instrument family, e.g. `"BTC-USD"`; futures, swap and option only.
+    ///
+    /// # See also
+    /// [OKX API Documentation - Get tickers](https://www.okx.com/docs-v5/en/#order-book-trading-market-data-get-tickers)
+    async fn get_tickers(
+        &self,
+        inst_type: InstType,
+        uly: Option<&str>,
+        inst_family: Option<&str>,
+    ) -> Result<ApiResponse<serde_json::Value>>;
+}
+
+/// Trait defining OKX trade API endpoints.
+#[async_trait]
+pub trait TradeApi {
+    /// Places an order.
+    ///
+    /// # Returns
+    /// A one-element list with `ordId`, `clOrdId`, `sCode` and `sMsg` of the order.
+    ///
+    /// # See also
+    /// [OKX API Documentation - Place order](https://www.okx.com/docs-v5/en/#order-book-trading-trade-post-place-order)
+    async fn place_order(
+        &self,
+        params: &PlaceOrderParams,
+    ) -> Result<ApiResponse<serde_json::Value>>;
+
+    /// Cancels an incomplete order.
+    ///
+    /// # Arguments
+    /// * `inst_id` – Instrument ID, e.g. `"BTC-USDT"`.
+    /// * `ord_id` – Order ID; either `ord_id` or `cl_ord_id` is required, `ord_id` wins if both
+    ///   are given.
+    /// * `cl_ord_id` – Client order ID.
+    ///
+    /// # See also
+    /// [OKX API Documentation - Cancel order](https://www.okx.com/docs-v5/en/#order-book-trading-trade-post-cancel-order)
+    async fn cancel_order(
+        &self,
+        inst_id: &str,
+        ord_id: Option<&str>,
+        cl_ord_id: Option<&str>,
+    ) -> Result<ApiResponse<serde_json::Value>>;
+}
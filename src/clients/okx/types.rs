@@ -0,0 +1,137 @@
+use serde::{Deserialize, Serialize};
+
+use crate::utils::{as_str_f64, as_str_opt, Amount};
+
+/// OKX instrument types.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum InstType {
+    Spot,
+    Margin,
+    Swap,
+    Futures,
+    Option,
+}
+
+impl std::fmt::Display for InstType {
+    fn fmt(
+        &self,
+        f: &mut std::fmt::Formatter<'_>,
+    ) -> std::fmt::Result {
+        let s = match self {
+            InstType::Spot => "SPOT",
+            InstType::Margin => "MARGIN",
+            InstType::Swap => "SWAP",
+            InstType::Futures => "FUTURES",
+            InstType::Option => "OPTION",
+        };
+        write!(f, "{s}")
+    }
+}
+
+/// Order side: "buy" or "sell".
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum Side {
+    #[default]
+    Buy,
+    Sell,
+}
+
+/// Trade mode: "cash" for spot without margin, "cross" or "isolated" for margin and derivatives.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum TdMode {
+    #[default]
+    Cash,
+    Cross,
+    Isolated,
+}
+
+/// Order type: "market", "limit", "post_only", "fok", "ioc".
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum OrderType {
+    #[default]
+    Market,
+    Limit,
+    PostOnly,
+    Fok,
+    Ioc,
+}
+
+/// Parameters for placing an order.
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct PlaceOrderParams {
+    /// Instrument ID, e.g. `"BTC-USDT"` or `"BTC-USDT-SWAP"`. (required)
+    pub inst_id: String,
+
+    /// Trade mode. (required)
+    pub td_mode: TdMode,
+
+    /// Order side. (required)
+    pub side: Side,
+
+    /// Order type. (required)
+    pub ord_type: OrderType,
+
+    /// Quantity: base currency for spot, contracts for derivatives. (required)
+    #[serde(serialize_with = "as_str_f64")]
+    pub sz: Amount,
+
+    /// Order price, required for every type except `market`.
+    #[serde(skip_serializing_if = "Option::is_none", serialize_with = "as_str_opt")]
+    pub px: Option<Amount>,
+
+    /// Client order ID, up to 32 alphanumerics.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cl_ord_id: Option<String>,
+
+    /// Position side in long/short mode: "long" or "short".
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pos_side: Option<String>,
+
+    /// Only reduce the position (margin and derivatives).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reduce_only: Option<bool>,
+}
+
+/// OKX API response for deserialization (fields are received from API, not for sending)
+///
+/// OKX sends `code` as a string, `"0"` on success.
+#[derive(Debug, Clone, Deserialize)]
+pub struct ApiResponse<T> {
+    pub code: String,
+    #[serde(default)]
+    pub msg: String,
+    #[serde(default)]
+    pub data: T,
+}
+
+/// Generic API response for deserialization (fields are received from API, not for sending)
+#[derive(Debug, Clone, Deserialize)]
+pub struct GenericResponse {
+    pub code: String,
+    #[serde(default)]
+    pub msg: String,
+    #[serde(default)]
+    pub data: serde_json::Value,
+}
+
+impl GenericResponse {
+    /// Convert GenericResponse to ApiResponse<serde_json::Value>
+    pub fn into_api_response(self) -> ApiResponse<serde_json::Value> {
+        ApiResponse {
+            code: self.code,
+            msg: self.msg,
+            data: self.data,
+        }
+    }
+}
+
+impl From<GenericResponse> for ApiResponse<serde_json::Value> {
+    fn from(response: GenericResponse) -> Self {
+        response.into_api_response()
+    }
+}
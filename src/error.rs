@@ -2,6 +2,46 @@
 
 use std::fmt;
 
+/// Category of a well-known exchange error code, see [`ExchangeResponseError::classify`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExchangeErrorKind {
+    /// Not enough balance or margin for the order.
+    InsufficientBalance,
+    /// The order does not exist, or is already filled or cancelled.
+    OrderNotFound,
+    /// Too many requests.
+    RateLimited,
+    /// The symbol does not exist or is not tradable.
+    InvalidSymbol,
+    /// The position does not exist.
+    PositionNotExist,
+    /// Any other code.
+    Unknown(i64),
+}
+
+/// Well-known Bybit `retCode`s.
+fn classify_bybit(code: i64) -> ExchangeErrorKind {
+    match code {
+        110004 | 110007 | 110012 | 110045 | 170131 => ExchangeErrorKind::InsufficientBalance,
+        110001 | 170213 => ExchangeErrorKind::OrderNotFound,
+        10006 | 10018 => ExchangeErrorKind::RateLimited,
+        10029 | 170121 => ExchangeErrorKind::InvalidSymbol,
+        code => ExchangeErrorKind::Unknown(code),
+    }
+}
+
+/// Well-known BingX `code`s.
+fn classify_bingx(code: i64) -> ExchangeErrorKind {
+    match code {
+        101204 => ExchangeErrorKind::InsufficientBalance,
+        80016 => ExchangeErrorKind::OrderNotFound,
+        100410 => ExchangeErrorKind::RateLimited,
+        109425 => ExchangeErrorKind::InvalidSymbol,
+        80017 => ExchangeErrorKind::PositionNotExist,
+        code => ExchangeErrorKind::Unknown(code),
+    }
+}
+
 /// Error returned by an exchange, includes the full response object and a message.
 /// This error is meant to make it easy to inspect/pretty-print an exchange API's error response.
 #[derive(Debug, Clone)]
@@ -10,18 +50,66 @@ pub struct ExchangeResponseError {
     pub resp: serde_json::Value,
     /// Human-readable error message, best-effort extracted from the response.
     pub message: String,
+    /// Numeric exchange error code (`retCode` for Bybit, `code` for BingX), `0` if absent.
+    pub code: i64,
 }
 
 impl ExchangeResponseError {
     /// Constructs a new `ExchangeResponseError` with the full response.
     pub fn new(resp: serde_json::Value) -> Self {
         let extracted_msg = Self::extract_message(&resp);
+        let extracted_code = Self::extract_code(&resp);
         Self {
             resp,
             message: extracted_msg,
+            code: extracted_code,
         }
     }
 
+    /// Numeric error code returned by the exchange.
+    pub fn code(&self) -> i64 {
+        self.code
+    }
+
+    /// Human-readable error message returned by the exchange.
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    /// Map the error code to an [`ExchangeErrorKind`].
+    ///
+    /// Bybit responses are recognized by their `retCode` field, anything else is treated as
+    /// BingX. Codes without a known meaning map to [`ExchangeErrorKind::Unknown`].
+    pub fn classify(&self) -> ExchangeErrorKind {
+        if self.resp.get("retCode").is_some() {
+            classify_bybit(self.code)
+        } else {
+            classify_bingx(self.code)
+        }
+    }
+
+    /// The raw exchange response the error was built from.
+    pub fn raw(&self) -> &serde_json::Value {
+        &self.resp
+    }
+
+    /// Try to extract a typical error code field from the response map.
+    ///
+    /// Codes sent as strings (e.g. `"110001"`) are parsed as well; returns `0` if none is found.
+    pub fn extract_code(resp: &serde_json::Value) -> i64 {
+        for key in ["retCode", "code", "error_code"] {
+            if let Some(val) = resp.get(key) {
+                if let Some(code) = val.as_i64() {
+                    return code;
+                }
+                if let Some(code) = val.as_str().and_then(|s| s.parse::<i64>().ok()) {
+                    return code;
+                }
+            }
+        }
+        0
+    }
+
     /// Try to extract a typical error message field from the response map.
     pub fn extract_message(resp: &serde_json::Value) -> String {
         for key in ["msg", "message", "error", "retMsg", "error_message"] {
@@ -54,7 +142,7 @@ impl fmt::Display for ExchangeResponseError {
         &self,
         f: &mut fmt::Formatter<'_>,
     ) -> fmt::Result {
-        writeln!(f, "ExchangeResponseError: {}", self.message)?;
+        writeln!(f, "ExchangeResponseError ({}): {}", self.code, self.message)?;
         writeln!(f, "Response: {}", self.pretty_response())?;
         Ok(())
     }
@@ -85,6 +173,26 @@ pub enum Error {
     Cache(String),
     /// Functionality not implemented.
     NotImplemented(String),
+    /// Response body exceeded the configured size limit (in bytes).
+    ResponseTooLarge(usize),
+    /// The server answered with a redirect, which is not followed unless enabled in
+    /// `SessionConfig::follow_redirects`.
+    Redirect {
+        status: u16,
+        location: Option<String>,
+    },
+    /// The request was abandoned because its cancellation token fired.
+    Cancelled,
+    /// Writing exported data failed.
+    Io(std::io::Error),
+    /// Placing an order with an auto-generated client order id failed.
+    ///
+    /// The order may still have been created (e.g. after a timeout); look it up by
+    /// `order_link_id`. `source` is the error of the last attempt.
+    OrderPlacement {
+        order_link_id: String,
+        source: Box<Error>,
+    },
 }
 
 impl fmt::Display for Error {
@@ -102,11 +210,77 @@ impl fmt::Display for Error {
             Error::Session(msg) => write!(f, "Session error: {msg}"),
             Error::Cache(msg) => write!(f, "Cache error: {msg}"),
             Error::NotImplemented(msg) => write!(f, "Not implemented: {msg}"),
+            Error::ResponseTooLarge(limit) => {
+                write!(f, "Response body exceeds the limit of {limit} bytes")
+            }
+            Error::Redirect { status, location } => write!(
+                f,
+                "Redirect {status} to {} not followed",
+                location.as_deref().unwrap_or("<no location>")
+            ),
+            Error::Cancelled => write!(f, "Request cancelled"),
+            Error::Io(e) => write!(f, "I/O error: {e}"),
+            Error::OrderPlacement {
+                order_link_id,
+                source,
+            } => write!(f, "Placing order {order_link_id} failed: {source}"),
         }
     }
 }
 
-impl std::error::Error for Error {}
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::OrderPlacement { source, .. } => Some(source.as_ref()),
+            _ => None,
+        }
+    }
+}
+
+impl Error {
+    /// The error itself, or the wrapped error of an [`Error::OrderPlacement`].
+    fn cause(&self) -> &Error {
+        match self {
+            Error::OrderPlacement { source, .. } => source.cause(),
+            other => other,
+        }
+    }
+
+    /// Returns the exchange error code if this is an [`Error::Exchange`].
+    ///
+    /// Handy for treating specific codes (e.g. Bybit `110001`, "order does not exist") as non-fatal.
+    pub fn exchange_code(&self) -> Option<i64> {
+        match self.cause() {
+            Error::Exchange(ex) => Some(ex.code()),
+            _ => None,
+        }
+    }
+
+    /// The client order id of an [`Error::OrderPlacement`], to look the order up by.
+    pub fn order_link_id(&self) -> Option<&str> {
+        match self {
+            Error::OrderPlacement { order_link_id, .. } => Some(order_link_id),
+            _ => None,
+        }
+    }
+
+    /// Whether this is an [`Error::Http`] caused by a request, connect or read timeout.
+    pub fn is_timeout(&self) -> bool {
+        matches!(self.cause(), Error::Http(e) if e.is_timeout())
+    }
+
+    /// Whether this is an [`Error::Http`] caused by failing to connect (DNS resolution,
+    /// refused connection, TLS handshake).
+    pub fn is_connect(&self) -> bool {
+        matches!(self.cause(), Error::Http(e) if e.is_connect())
+    }
+
+    /// Whether this is an [`Error::Http`] raised while reading or decompressing the response
+    /// body, e.g. a connection closed mid-body.
+    pub fn is_body(&self) -> bool {
+        matches!(self.cause(), Error::Http(e) if e.is_body() || e.is_decode())
+    }
+}
 
 impl From<reqwest::Error> for Error {
     fn from(err: reqwest::Error) -> Self {
@@ -120,6 +294,12 @@ impl From<serde_json::Error> for Error {
     }
 }
 
+impl From<std::io::Error> for Error {
+    fn from(err: std::io::Error) -> Self {
+        Error::Io(err)
+    }
+}
+
 impl From<ExchangeResponseError> for Error {
     fn from(err: ExchangeResponseError) -> Self {
         Error::Exchange(err)
@@ -4,11 +4,16 @@
 use async_trait::async_trait;
 use reqwest::{Client, Method};
 
-use std::collections::HashMap;
-use std::sync::Arc;
+use std::any::Any;
+use std::collections::{BTreeMap, HashMap};
+use std::sync::atomic::{AtomicBool, AtomicI64, AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+use tokio::sync::{OwnedSemaphorePermit, Semaphore};
+use tokio_util::sync::CancellationToken;
 
 use crate::error::{Error, Result};
-use crate::session::SharedSessionManager;
+use crate::session::{build_proxy, with_tls_backend, InFlightGuard, SharedSessionManager};
 
 /// HTTP request args (owned, ergonomic).
 #[derive(Debug, Clone)]
@@ -20,6 +25,235 @@ pub struct RequestArgs {
     pub data: Option<HashMap<String, String>>,
 }
 
+/// Metadata passed to [`Observer::on_request`] before a request is sent.
+#[derive(Debug, Clone)]
+pub struct RequestMeta<'a> {
+    /// Exchange name ("bybit", "bingx").
+    pub exchange: &'static str,
+    pub method: &'a Method,
+    /// Endpoint path without base URL or query, e.g. "/v5/order/create".
+    pub endpoint: &'a str,
+}
+
+/// Metadata passed to [`Observer::on_response`] once a request has completed.
+#[derive(Debug, Clone)]
+pub struct ResponseMeta<'a> {
+    /// Exchange name ("bybit", "bingx").
+    pub exchange: &'static str,
+    pub method: &'a Method,
+    /// Endpoint path without base URL or query, e.g. "/v5/order/create".
+    pub endpoint: &'a str,
+    /// HTTP status, `None` if no response was received (connection error, timeout).
+    pub status: Option<u16>,
+    /// Time from sending the request to handling the response.
+    pub duration: Duration,
+    /// Exchange return code (`retCode`/`code`), `None` if the body was not read.
+    pub ret_code: Option<i64>,
+}
+
+/// Metadata passed to [`Observer::on_retry`] before a failed request is sent again.
+#[derive(Debug)]
+pub struct RetryMeta<'a> {
+    /// Exchange name ("bybit", "bingx").
+    pub exchange: &'static str,
+    /// Endpoint path without base URL or query, e.g. "/v5/order/create".
+    pub endpoint: &'a str,
+    /// Retry number, starting at 1 for the first re-send.
+    pub attempt: u32,
+    /// Error of the previous attempt.
+    pub reason: &'a Error,
+}
+
+/// Hooks invoked around every HTTP request, e.g. to export metrics.
+///
+/// All methods default to no-ops.
+pub trait Observer: Send + Sync {
+    fn on_request(
+        &self,
+        _meta: &RequestMeta<'_>,
+    ) {
+    }
+
+    fn on_response(
+        &self,
+        _meta: &ResponseMeta<'_>,
+    ) {
+    }
+
+    fn on_retry(
+        &self,
+        _meta: &RetryMeta<'_>,
+    ) {
+    }
+}
+
+/// Span wrapping a single exchange request; `outcome` and `ret_code` are recorded on completion.
+#[cfg(feature = "tracing")]
+pub(crate) fn request_span(
+    exchange: &'static str,
+    method: &Method,
+    endpoint: &str,
+) -> tracing::Span {
+    tracing::info_span!(
+        "request",
+        exchange,
+        method = %method,
+        endpoint,
+        outcome = tracing::field::Empty,
+        ret_code = tracing::field::Empty,
+    )
+}
+
+/// Record the request outcome on the current span; errors are logged with the masked URL.
+#[cfg(feature = "tracing")]
+pub(crate) fn trace_outcome<T>(
+    masked_url: &str,
+    status: Option<u16>,
+    ret_code: Option<i64>,
+    result: &Result<T>,
+) {
+    let span = tracing::Span::current();
+    if let Some(ret_code) = ret_code {
+        span.record("ret_code", ret_code);
+    }
+    match result {
+        Ok(_) => {
+            span.record("outcome", "ok");
+            tracing::debug!(url = masked_url, status, "request completed");
+        }
+        Err(e) => {
+            let outcome = match e {
+                Error::Exchange(_) => "exchange_error",
+                Error::Http(_) | Error::Redirect { .. } => "http_error",
+                Error::Json(_) => "decode_error",
+                _ => "error",
+            };
+            span.record("outcome", outcome);
+            tracing::error!(url = masked_url, status, error = %e, "request failed");
+        }
+    }
+}
+
+/// Error for a non-success response: [`Error::Http`] for 4xx/5xx, [`Error::Redirect`] for 3xx.
+pub(crate) fn status_error(response: reqwest::Response) -> Error {
+    let status = response.status().as_u16();
+    match response.error_for_status() {
+        Err(e) => Error::Http(e),
+        Ok(response) => Error::Redirect {
+            status,
+            location: response
+                .headers()
+                .get(reqwest::header::LOCATION)
+                .and_then(|v| v.to_str().ok())
+                .map(str::to_string),
+        },
+    }
+}
+
+/// Milliseconds since the Unix epoch according to the local clock.
+pub(crate) fn local_time_ms() -> i64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_millis() as i64)
+        .unwrap_or_default()
+}
+
+/// Clock used for signing timestamps and request timing.
+///
+/// Clients use [`SystemTimeSource`] unless another source is set with `set_time_source`,
+/// e.g. a fixed clock to make signatures reproducible in tests.
+pub trait TimeSource: Send + Sync {
+    /// Milliseconds since the Unix epoch (UTC).
+    fn now_ms(&self) -> i64;
+}
+
+/// [`TimeSource`] reading the local system clock.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SystemTimeSource;
+
+impl TimeSource for SystemTimeSource {
+    fn now_ms(&self) -> i64 {
+        local_time_ms()
+    }
+}
+
+/// Opt-in comparison of the server `time` in responses against the local clock.
+#[derive(Debug, Default)]
+struct DriftMonitor {
+    /// Drift above this many milliseconds (either direction) is logged; `None` disables the check.
+    threshold_ms: Option<u64>,
+    /// Log one warning per this many over-threshold responses.
+    warn_every: u64,
+    measured: AtomicBool,
+    last_drift_ms: AtomicI64,
+    exceeded: AtomicU64,
+}
+
+impl DriftMonitor {
+    /// Record the drift of `server_ms` against the midpoint of the local send and receive times.
+    fn record(
+        &self,
+        server_ms: i64,
+        sent_ms: i64,
+        received_ms: i64,
+    ) {
+        let Some(threshold_ms) = self.threshold_ms else {
+            return;
+        };
+        let drift = server_ms - (sent_ms + received_ms) / 2;
+        self.last_drift_ms.store(drift, Ordering::Relaxed);
+        self.measured.store(true, Ordering::Relaxed);
+        if drift.unsigned_abs() > threshold_ms {
+            let count = self.exceeded.fetch_add(1, Ordering::Relaxed);
+            if count.is_multiple_of(self.warn_every.max(1)) {
+                log::warn!(
+                    "Server clock drift of {drift} ms exceeds {threshold_ms} ms; signed requests may be rejected"
+                );
+            }
+        }
+    }
+}
+
+impl Clone for DriftMonitor {
+    /// Same settings, fresh measurements.
+    fn clone(&self) -> Self {
+        Self {
+            threshold_ms: self.threshold_ms,
+            warn_every: self.warn_every,
+            ..Self::default()
+        }
+    }
+}
+
+/// Log level for an exchange error code, see `set_error_log_filter` on the exchange clients.
+pub type ErrorLogFilter = Arc<dyn Fn(i64) -> log::Level + Send + Sync>;
+
+/// Time given to a dedicated pool's connections to shut down in [`BaseHttpClient::close`].
+pub const CLOSE_GRACE: Duration = Duration::from_millis(50);
+
+/// Default limit for response bodies (16 MiB).
+pub const DEFAULT_MAX_RESPONSE_BYTES: usize = 16 * 1024 * 1024;
+
+/// Expiry and response of each `get_cached` key.
+type CachedResponses = HashMap<String, (Instant, Arc<dyn Any + Send + Sync>)>;
+
+/// Short-lived cache of GET responses, see `get_cached` on the exchange HTTP clients.
+#[derive(Default)]
+pub(crate) struct ResponseCache(Mutex<CachedResponses>);
+
+/// Cache key of a GET: endpoint, auth flag and parameters in sorted order.
+pub(crate) fn response_cache_key(
+    endpoint: &str,
+    params: Option<&HashMap<String, serde_json::Value>>,
+    auth: bool,
+) -> String {
+    let params: BTreeMap<_, _> = params.into_iter().flatten().collect();
+    format!(
+        "{endpoint}|{auth}|{}",
+        serde_json::to_string(&params).unwrap_or_default()
+    )
+}
+
 pub struct BaseHttpClient {
     pub base_url: String,
     pub api_key: Option<String>,
@@ -27,6 +261,45 @@ pub struct BaseHttpClient {
     pub recv_window: u32,
     pub client: Arc<Client>,
     use_shared_session: bool,
+    observer: Option<Arc<dyn Observer>>,
+    error_log_filter: Option<ErrorLogFilter>,
+    proxy: Option<String>,
+    max_response_bytes: usize,
+    drift: DriftMonitor,
+    retries: AtomicU64,
+    /// In-flight limit and its permits, `None` when unlimited.
+    concurrency: Option<(usize, Arc<Semaphore>)>,
+    /// Responses of `get_cached` calls, shared by clones.
+    response_cache: Arc<ResponseCache>,
+    time_source: Arc<dyn TimeSource>,
+    #[cfg(feature = "audit")]
+    audit_sink: Option<Arc<dyn crate::audit::AuditSink>>,
+}
+
+impl Clone for BaseHttpClient {
+    /// Share the connection pool, concurrency limit and response cache and copy the settings; retry and drift
+    /// counters start fresh.
+    fn clone(&self) -> Self {
+        Self {
+            base_url: self.base_url.clone(),
+            api_key: self.api_key.clone(),
+            api_secret: self.api_secret.clone(),
+            recv_window: self.recv_window,
+            client: Arc::clone(&self.client),
+            use_shared_session: self.use_shared_session,
+            observer: self.observer.clone(),
+            error_log_filter: self.error_log_filter.clone(),
+            proxy: self.proxy.clone(),
+            max_response_bytes: self.max_response_bytes,
+            drift: self.drift.clone(),
+            retries: AtomicU64::new(0),
+            concurrency: self.concurrency.clone(),
+            response_cache: Arc::clone(&self.response_cache),
+            time_source: Arc::clone(&self.time_source),
+            #[cfg(feature = "audit")]
+            audit_sink: self.audit_sink.clone(),
+        }
+    }
 }
 
 impl BaseHttpClient {
@@ -37,44 +310,80 @@ impl BaseHttpClient {
         api_secret: Option<String>,
         recv_window: u32,
     ) -> Result<Self> {
-        if SharedSessionManager::is_initialized() {
-            Ok(Self {
-                base_url,
-                api_key,
-                api_secret,
-                recv_window,
-                client: SharedSessionManager::get_client(),
-                use_shared_session: true,
-            })
+        let use_shared_session = SharedSessionManager::is_initialized();
+        let client = if use_shared_session {
+            SharedSessionManager::get_client()
         } else {
-            let mut headers = reqwest::header::HeaderMap::new();
-            headers.insert(
-                "Content-Type",
-                "application/json"
-                    .parse()
-                    .map_err(|e| Error::Config(format!("Header parse: {e}")))?,
-            );
-            headers.insert(
-                "Accept",
-                "application/json"
-                    .parse()
-                    .map_err(|e| Error::Config(format!("Header parse: {e}")))?,
-            );
-            let client = Arc::new(
-                Client::builder()
-                    .default_headers(headers)
-                    .pool_max_idle_per_host(50)
-                    .build()
-                    .map_err(Error::Http)?,
-            );
-            Ok(Self {
-                base_url,
-                api_key,
-                api_secret,
-                recv_window,
-                client,
-                use_shared_session: false,
-            })
+            Self::build_individual_client(None)?
+        };
+        Ok(Self {
+            base_url,
+            api_key,
+            api_secret,
+            recv_window,
+            client,
+            use_shared_session,
+            observer: None,
+            error_log_filter: None,
+            proxy: None,
+            max_response_bytes: DEFAULT_MAX_RESPONSE_BYTES,
+            drift: DriftMonitor::default(),
+            retries: AtomicU64::new(0),
+            concurrency: None,
+            response_cache: Arc::default(),
+            time_source: Arc::new(SystemTimeSource),
+            #[cfg(feature = "audit")]
+            audit_sink: None,
+        })
+    }
+
+    /// Build a dedicated reqwest client, optionally routed through a proxy.
+    fn build_individual_client(proxy: Option<reqwest::Proxy>) -> Result<Arc<Client>> {
+        let mut headers = reqwest::header::HeaderMap::new();
+        headers.insert(
+            "Content-Type",
+            "application/json"
+                .parse()
+                .map_err(|e| Error::Config(format!("Header parse: {e}")))?,
+        );
+        headers.insert(
+            "Accept",
+            "application/json"
+                .parse()
+                .map_err(|e| Error::Config(format!("Header parse: {e}")))?,
+        );
+        let mut builder = with_tls_backend(Client::builder())
+            .default_headers(headers)
+            .pool_max_idle_per_host(50)
+            // Never follow redirects: they could forward auth headers to another host.
+            .redirect(reqwest::redirect::Policy::none());
+        if let Some(proxy) = proxy {
+            builder = builder.proxy(proxy);
+        }
+        Ok(Arc::new(builder.build().map_err(Error::Http)?))
+    }
+
+    /// Route this client through a proxy (`http://`, `https://` or `socks5://` URL).
+    ///
+    /// The client switches to a dedicated connection pool, even if a shared session is set up.
+    /// Returns `Error::Config` if the URL is invalid.
+    pub fn set_proxy(
+        &mut self,
+        url: &str,
+    ) -> Result<()> {
+        let proxy = build_proxy(url)?;
+        self.client = Self::build_individual_client(Some(proxy))?;
+        self.use_shared_session = false;
+        self.proxy = Some(url.to_string());
+        Ok(())
+    }
+
+    /// Proxy URL used by this client: its own, or the shared session's.
+    pub fn proxy(&self) -> Option<String> {
+        match &self.proxy {
+            Some(proxy) => Some(proxy.clone()),
+            None if self.use_shared_session => SharedSessionManager::proxy(),
+            None => None,
         }
     }
 
@@ -88,6 +397,329 @@ impl BaseHttpClient {
     pub fn is_shared_session_enabled(&self) -> bool {
         self.use_shared_session
     }
+
+    /// Release this client's connection pool.
+    ///
+    /// For a dedicated pool the reqwest client is dropped and the open connections get
+    /// [`CLOSE_GRACE`] to shut down. Shared-session clients leave the pool to
+    /// [`SharedSessionManager::close`].
+    pub async fn close(self) {
+        if self.use_shared_session {
+            return;
+        }
+        // Only wait when this was the last handle; otherwise the pool stays open anyway.
+        let last_handle = Arc::strong_count(&self.client) == 1;
+        drop(self);
+        if last_handle {
+            tokio::time::sleep(CLOSE_GRACE).await;
+        }
+    }
+
+    /// Client to send the next request with.
+    ///
+    /// Clients on the shared session pick up the current pool, so a
+    /// [`SharedSessionManager::recycle`] takes effect without recreating them.
+    pub fn http_client(&self) -> Arc<Client> {
+        if self.use_shared_session {
+            if let Some(client) = SharedSessionManager::try_get_client() {
+                return client;
+            }
+        }
+        Arc::clone(&self.client)
+    }
+
+    /// Override the base URL, e.g. to point the client at a mock server or a gateway.
+    pub fn set_base_url(
+        &mut self,
+        base_url: &str,
+    ) {
+        self.base_url = base_url.trim_end_matches('/').to_string();
+    }
+
+    /// Set the maximum accepted response body size in bytes.
+    pub fn set_max_response_bytes(
+        &mut self,
+        max_response_bytes: usize,
+    ) {
+        self.max_response_bytes = max_response_bytes;
+    }
+
+    pub fn max_response_bytes(&self) -> usize {
+        self.max_response_bytes
+    }
+
+    /// Limit the requests this client (and its clones) has in flight; further requests wait
+    /// for a free slot. `None` removes the limit, `Some(0)` is treated as 1.
+    pub fn set_max_concurrency(
+        &mut self,
+        max_concurrency: Option<usize>,
+    ) {
+        self.concurrency = max_concurrency.map(|max| {
+            let max = max.max(1);
+            (max, Arc::new(Semaphore::new(max)))
+        });
+    }
+
+    /// Configured in-flight limit, `None` if unlimited.
+    pub fn max_concurrency(&self) -> Option<usize> {
+        self.concurrency.as_ref().map(|(max, _)| *max)
+    }
+
+    /// Request slots currently free under the in-flight limit, `None` if unlimited.
+    pub fn available_request_slots(&self) -> Option<usize> {
+        self.concurrency
+            .as_ref()
+            .map(|(_, semaphore)| semaphore.available_permits())
+    }
+
+    /// Wait for a free request slot; the slot is released when the permit is dropped.
+    ///
+    /// Returns `None` immediately when no limit is set.
+    pub async fn acquire_request_permit(&self) -> Option<OwnedSemaphorePermit> {
+        let (_, semaphore) = self.concurrency.as_ref()?;
+        let semaphore = Arc::clone(semaphore);
+        semaphore.acquire_owned().await.ok()
+    }
+
+    /// Count a request as in flight until the guard is dropped, if it goes through the shared
+    /// pool, so [`SharedSessionManager::close`] waits for it. Requests on a dedicated pool are
+    /// not counted.
+    pub(crate) fn in_flight_guard(&self) -> Option<InFlightGuard> {
+        (self.use_shared_session && SharedSessionManager::is_initialized()).then(InFlightGuard::new)
+    }
+
+    /// Response cached under `key`, if it has not expired.
+    pub(crate) fn cached_response<T: Clone + 'static>(
+        &self,
+        key: &str,
+    ) -> Option<T> {
+        let mut entries = self
+            .response_cache
+            .0
+            .lock()
+            .unwrap_or_else(|e| e.into_inner());
+        match entries.get(key) {
+            Some((expires, response)) if *expires > Instant::now() => {
+                response.downcast_ref::<T>().cloned()
+            }
+            Some(_) => {
+                entries.remove(key);
+                None
+            }
+            None => None,
+        }
+    }
+
+    /// Cache `response` under `key` for `ttl`, dropping expired entries.
+    pub(crate) fn cache_response<T: Clone + Send + Sync + 'static>(
+        &self,
+        key: String,
+        response: &T,
+        ttl: Duration,
+    ) {
+        let now = Instant::now();
+        let mut entries = self
+            .response_cache
+            .0
+            .lock()
+            .unwrap_or_else(|e| e.into_inner());
+        entries.retain(|_, (expires, _)| *expires > now);
+        entries.insert(key, (now + ttl, Arc::new(response.clone())));
+    }
+
+    /// Drop all responses cached by `get_cached`.
+    pub fn clear_response_cache(&self) {
+        self.response_cache
+            .0
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .clear();
+    }
+
+    /// Read a JSON response body, failing with [`Error::ResponseTooLarge`] as soon as the
+    /// `Content-Length` or the bytes received exceed the limit.
+    pub async fn read_json(
+        &self,
+        mut response: reqwest::Response,
+    ) -> Result<serde_json::Value> {
+        let limit = self.max_response_bytes;
+        if response
+            .content_length()
+            .is_some_and(|len| len > limit as u64)
+        {
+            return Err(Error::ResponseTooLarge(limit));
+        }
+
+        let mut body = Vec::new();
+        while let Some(chunk) = response.chunk().await.map_err(Error::Http)? {
+            if body.len() + chunk.len() > limit {
+                return Err(Error::ResponseTooLarge(limit));
+            }
+            body.extend_from_slice(&chunk);
+        }
+        serde_json::from_slice(&body).map_err(Error::Json)
+    }
+
+    /// Register an observer notified around every request of this client.
+    pub fn set_observer(
+        &mut self,
+        observer: Arc<dyn Observer>,
+    ) {
+        self.observer = Some(observer);
+    }
+
+    /// Choose the level exchange errors are logged at by their return code, e.g. `debug` for
+    /// an expected "order not found" on cancel. Without a filter every code logs at `error`.
+    /// The error is returned to the caller either way.
+    pub fn set_error_log_filter(
+        &mut self,
+        filter: impl Fn(i64) -> log::Level + Send + Sync + 'static,
+    ) {
+        self.error_log_filter = Some(Arc::new(filter));
+    }
+
+    /// Level an exchange error with return code `code` is logged at.
+    pub fn error_log_level(
+        &self,
+        code: i64,
+    ) -> log::Level {
+        self.error_log_filter
+            .as_ref()
+            .map_or(log::Level::Error, |filter| filter(code))
+    }
+
+    /// Replace the clock used for signing timestamps (the system clock by default).
+    pub fn set_time_source(
+        &mut self,
+        time_source: Arc<dyn TimeSource>,
+    ) {
+        self.time_source = time_source;
+    }
+
+    /// Current time of the client's [`TimeSource`], in ms since the Unix epoch.
+    pub fn now_ms(&self) -> i64 {
+        self.time_source.now_ms()
+    }
+
+    /// Enable the server clock drift check.
+    ///
+    /// A warning is logged for the first and then every `warn_every`-th response whose
+    /// drift exceeds `threshold_ms`. The last measured drift is kept either way.
+    pub fn set_drift_check(
+        &mut self,
+        threshold_ms: u64,
+        warn_every: u64,
+    ) {
+        self.drift = DriftMonitor {
+            threshold_ms: Some(threshold_ms),
+            warn_every,
+            ..Default::default()
+        };
+    }
+
+    /// Whether the drift check is enabled.
+    pub fn drift_check_enabled(&self) -> bool {
+        self.drift.threshold_ms.is_some()
+    }
+
+    /// Record a server timestamp received between `sent_ms` and `received_ms` (local epoch ms).
+    pub fn record_server_time(
+        &self,
+        server_ms: i64,
+        sent_ms: i64,
+        received_ms: i64,
+    ) {
+        self.drift.record(server_ms, sent_ms, received_ms);
+    }
+
+    /// Last measured server minus local clock difference in milliseconds.
+    ///
+    /// `None` until the drift check is enabled and a response has been received.
+    pub fn last_server_drift_ms(&self) -> Option<i64> {
+        self.drift
+            .measured
+            .load(Ordering::Relaxed)
+            .then(|| self.drift.last_drift_ms.load(Ordering::Relaxed))
+    }
+
+    /// Notify the observer that a request is about to be sent.
+    ///
+    /// Returns the start time only when an observer is set, so nothing is measured otherwise.
+    pub fn notify_request(
+        &self,
+        exchange: &'static str,
+        method: &Method,
+        endpoint: &str,
+    ) -> Option<Instant> {
+        let observer = self.observer.as_ref()?;
+        observer.on_request(&RequestMeta {
+            exchange,
+            method,
+            endpoint,
+        });
+        Some(Instant::now())
+    }
+
+    /// Notify the observer that a request started by [`Self::notify_request`] has completed.
+    pub fn notify_response(
+        &self,
+        started: Option<Instant>,
+        exchange: &'static str,
+        method: &Method,
+        endpoint: &str,
+        status: Option<u16>,
+        ret_code: Option<i64>,
+    ) {
+        if let (Some(observer), Some(started)) = (self.observer.as_ref(), started) {
+            observer.on_response(&ResponseMeta {
+                exchange,
+                method,
+                endpoint,
+                status,
+                duration: started.elapsed(),
+                ret_code,
+            });
+        }
+    }
+
+    /// Count a retry and notify the observer before the request is sent again.
+    pub fn notify_retry(
+        &self,
+        exchange: &'static str,
+        endpoint: &str,
+        attempt: u32,
+        reason: &Error,
+    ) {
+        self.retries.fetch_add(1, Ordering::Relaxed);
+        if let Some(observer) = self.observer.as_ref() {
+            observer.on_retry(&RetryMeta {
+                exchange,
+                endpoint,
+                attempt,
+                reason,
+            });
+        }
+    }
+
+    /// Register a sink receiving an audit record for every request of this client.
+    #[cfg(feature = "audit")]
+    pub fn set_audit_sink(
+        &mut self,
+        sink: Arc<dyn crate::audit::AuditSink>,
+    ) {
+        self.audit_sink = Some(sink);
+    }
+
+    /// Audit sink of this client, if any.
+    #[cfg(feature = "audit")]
+    pub fn audit_sink(&self) -> Option<&Arc<dyn crate::audit::AuditSink>> {
+        self.audit_sink.as_ref()
+    }
+
+    /// Total number of retries made by this client.
+    pub fn total_retries(&self) -> u64 {
+        self.retries.load(Ordering::Relaxed)
+    }
 }
 
 /// Async HTTP trait (owned argument style).
@@ -132,6 +764,36 @@ where
         auth: bool,
     ) -> Result<T>;
 
+    /// Perform an asynchronous HTTP request that is abandoned when `cancel` fires.
+    ///
+    /// If the token is cancelled before the response arrives, the in-flight request is dropped
+    /// and [`Error::Cancelled`] is returned. A request already received by the exchange may
+    /// still be executed there.
+    ///
+    /// # Arguments
+    /// * `method` - The HTTP method.
+    /// * `endpoint` - The API endpoint path.
+    /// * `params` - Optional reference to parameters.
+    /// * `auth` - Whether to include authentication info.
+    /// * `cancel` - Token that aborts the request when cancelled.
+    ///
+    /// # Returns
+    /// Returns a [`Result`] containing the deserialized response of type `T`.
+    async fn async_request_cancellable(
+        &self,
+        method: Method,
+        endpoint: &str,
+        params: Option<&HashMap<String, serde_json::Value>>,
+        auth: bool,
+        cancel: &CancellationToken,
+    ) -> Result<T> {
+        tokio::select! {
+            biased;
+            _ = cancel.cancelled() => Err(Error::Cancelled),
+            result = self.async_request(method, endpoint, params, auth) => result,
+        }
+    }
+
     /// Perform an HTTP GET request.
     ///
     /// # Arguments
@@ -208,3 +870,41 @@ where
             .await
     }
 }
+
+/// Exchange-neutral response returned by [`DynHttpClient::request_dyn`].
+///
+/// Each exchange has its own `ApiResponse<Value>` (Bybit `retCode`/`result`, BingX and OKX
+/// `code`/`data`, with different code types), so one object-safe trait cannot return them.
+/// `DynResponse` carries the fields they share; exchange-specific ones such as Bybit
+/// `retExtInfo` are only available through the client's own `request_raw`.
+#[derive(Debug, Clone)]
+pub struct DynResponse {
+    /// Exchange name ("bybit", "bingx", "okx").
+    pub exchange: &'static str,
+    /// Exchange return code (`retCode`/`code`), 0 on success.
+    pub code: i64,
+    /// Exchange message (`retMsg`/`msg`).
+    pub msg: String,
+    /// Payload: `result` for Bybit, `data` for BingX and OKX.
+    pub data: serde_json::Value,
+}
+
+/// Object-safe façade over the exchange clients, so clients of different exchanges can be
+/// stored together, e.g. as `Vec<Arc<dyn DynHttpClient>>`.
+///
+/// Requests go through each client's `request_raw`, signed like every typed endpoint, and its
+/// `ApiResponse<Value>` is converted into a [`DynResponse`].
+#[async_trait]
+pub trait DynHttpClient: Send + Sync {
+    /// Exchange name ("bybit", "bingx", "okx").
+    fn exchange(&self) -> &'static str;
+
+    /// Send a request to any endpoint of the client's exchange.
+    async fn request_dyn(
+        &self,
+        method: Method,
+        endpoint: &str,
+        params: Option<&HashMap<String, serde_json::Value>>,
+        auth: bool,
+    ) -> Result<DynResponse>;
+}
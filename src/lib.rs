@@ -19,15 +19,34 @@
 //! - **Lazy Cleanup**: Expired entries removed on access, not proactively
 
 #![allow(clippy::too_many_arguments)]
+#[cfg(feature = "audit")]
+pub mod audit;
 mod caches;
 mod clients;
 mod error;
 mod http;
 mod session;
+mod signing;
+#[cfg(feature = "testkit")]
+pub mod testkit;
+pub mod unified;
 mod utils;
 
-pub use caches::{BingxClientsCache, BybitClientsCache, ClientsCache};
-pub use session::SharedSessionManager;
+pub use caches::{
+    BingxClientsCache, BybitClientsCache, CleanupTask, ClientsCache, OkxClientsCache,
+};
+pub use error::{Error, ExchangeErrorKind, ExchangeResponseError, Result};
+pub use http::{
+    DynHttpClient, DynResponse, ErrorLogFilter, Observer, RequestMeta, ResponseMeta, RetryMeta,
+    SystemTimeSource, TimeSource,
+};
+pub use session::{PoolStats, SessionConfig, SharedSessionManager, WarmupSummary, TLS_BACKEND};
+pub use tokio_util::sync::CancellationToken;
+pub use utils::{
+    amount_from_f64, amount_to_f64, round_to_step, round_to_tick, Amount, AmountValue,
+    OptionExpiry, OptionKind, OptionSymbol, PlainDecimal, Symbol,
+};
 
 pub use clients::bingx;
 pub use clients::bybit;
+pub use clients::okx;
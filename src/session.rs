@@ -2,20 +2,252 @@
 
 use once_cell::sync::Lazy;
 use reqwest::Client;
-use std::sync::atomic::{AtomicBool, Ordering};
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::atomic::{AtomicBool, AtomicU64, AtomicUsize, Ordering};
 use std::sync::{Arc, RwLock};
+use std::task::{Context, Poll};
 use std::time::Duration;
+use tokio::sync::Notify;
+use tower_layer::Layer;
+use tower_service::Service;
+
+use crate::error::{Error, Result};
 
 static SHARED_SESSION_MANAGER: Lazy<RwLock<Option<SharedSessionManager>>> =
     Lazy::new(|| RwLock::new(None));
 static SESSION_INITIALIZED: Lazy<AtomicBool> = Lazy::new(|| AtomicBool::new(false));
+static IN_FLIGHT_REQUESTS: AtomicUsize = AtomicUsize::new(0);
+static IN_FLIGHT_DRAINED: Lazy<Notify> = Lazy::new(Notify::new);
+static CONNECTIONS_OPENED: AtomicU64 = AtomicU64::new(0);
+
+/// Counts a request as in flight until dropped, including when the request future is cancelled.
+pub(crate) struct InFlightGuard(());
+
+impl InFlightGuard {
+    pub(crate) fn new() -> Self {
+        IN_FLIGHT_REQUESTS.fetch_add(1, Ordering::AcqRel);
+        Self(())
+    }
+}
+
+impl Drop for InFlightGuard {
+    fn drop(&mut self) {
+        if IN_FLIGHT_REQUESTS.fetch_sub(1, Ordering::AcqRel) == 1 {
+            IN_FLIGHT_DRAINED.notify_waiters();
+        }
+    }
+}
+
+/// Connector layer of the shared pool counting every connection it establishes.
+#[derive(Debug, Clone, Copy)]
+struct CountConnections;
+
+impl<S> Layer<S> for CountConnections {
+    type Service = CountedConnector<S>;
+
+    fn layer(
+        &self,
+        inner: S,
+    ) -> Self::Service {
+        CountedConnector(inner)
+    }
+}
+
+/// Connector wrapped by [`CountConnections`].
+#[derive(Debug, Clone)]
+struct CountedConnector<S>(S);
+
+impl<S, R> Service<R> for CountedConnector<S>
+where
+    S: Service<R>,
+    S::Future: Send + 'static,
+{
+    type Response = S::Response;
+    type Error = S::Error;
+    type Future = Pin<Box<dyn Future<Output = std::result::Result<S::Response, S::Error>> + Send>>;
+
+    fn poll_ready(
+        &mut self,
+        cx: &mut Context<'_>,
+    ) -> Poll<std::result::Result<(), Self::Error>> {
+        self.0.poll_ready(cx)
+    }
+
+    fn call(
+        &mut self,
+        request: R,
+    ) -> Self::Future {
+        let connecting = self.0.call(request);
+        Box::pin(async move {
+            let connection = connecting.await?;
+            CONNECTIONS_OPENED.fetch_add(1, Ordering::Relaxed);
+            Ok(connection)
+        })
+    }
+}
+
+#[cfg(not(any(feature = "tls-rustls", feature = "tls-native")))]
+compile_error!("trade-sdk needs a TLS backend: enable the `tls-rustls` or `tls-native` feature");
+
+/// TLS backend compiled into the HTTP clients ("rustls" or "native-tls").
+#[cfg(feature = "tls-rustls")]
+pub const TLS_BACKEND: &str = "rustls";
+
+/// TLS backend compiled into the HTTP clients ("rustls" or "native-tls").
+#[cfg(all(feature = "tls-native", not(feature = "tls-rustls")))]
+pub const TLS_BACKEND: &str = "native-tls";
+
+/// Select the TLS backend chosen by cargo features on a client builder.
+pub(crate) fn with_tls_backend(builder: reqwest::ClientBuilder) -> reqwest::ClientBuilder {
+    #[cfg(feature = "tls-rustls")]
+    let builder = builder.tls_backend_rustls();
+    #[cfg(all(feature = "tls-native", not(feature = "tls-rustls")))]
+    let builder = builder.tls_backend_native();
+    builder
+}
+
+/// Configuration for the shared session.
+#[derive(Debug, Clone)]
+pub struct SessionConfig {
+    /// Maximum number of connections in pool (default 2000).
+    pub max_connections: usize,
+    /// Proxy URL for all requests, e.g. `http://host:8080` or `socks5://host:1080`.
+    pub proxy: Option<String>,
+    /// How long `close()` waits for in-flight requests to finish (default 5s).
+    pub close_timeout: Duration,
+    /// Follow HTTP redirects (default false). Redirects may forward auth headers to another
+    /// host, so only enable this if a proxy or gateway requires it.
+    pub follow_redirects: bool,
+    /// TCP keep-alive interval for pooled connections (default 60s); `None` disables it.
+    pub tcp_keepalive: Option<Duration>,
+    /// Rebuild the connection pool at this interval so rotated exchange DNS records are
+    /// picked up (default `None`, never). See [`SharedSessionManager::recycle`].
+    pub recycle_interval: Option<Duration>,
+}
+
+impl Default for SessionConfig {
+    fn default() -> Self {
+        Self {
+            max_connections: 2000,
+            proxy: None,
+            close_timeout: Duration::from_secs(5),
+            follow_redirects: false,
+            tcp_keepalive: Some(Duration::from_secs(60)),
+            recycle_interval: None,
+        }
+    }
+}
+
+/// Parse a proxy URL into a `reqwest::Proxy` routing all traffic through it.
+pub(crate) fn build_proxy(url: &str) -> Result<reqwest::Proxy> {
+    reqwest::Proxy::all(url).map_err(|e| Error::Config(format!("Invalid proxy URL {url:?}: {e}")))
+}
+
+/// Outcome of [`SharedSessionManager::warmup`].
+#[derive(Debug, Default)]
+pub struct WarmupSummary {
+    /// Hosts with a connection parked in the pool.
+    pub succeeded: Vec<String>,
+    /// Hosts that could not be reached, with the error.
+    pub failed: Vec<(String, Error)>,
+}
+
+impl WarmupSummary {
+    /// True if every host was warmed up.
+    pub fn all_ok(&self) -> bool {
+        self.failed.is_empty()
+    }
+}
+
+/// Shared pool usage estimate returned by [`SharedSessionManager::pool_stats`].
+///
+/// reqwest does not expose its pool, so the numbers are derived from the in-flight request
+/// count: idle pooled connections are not visible here.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct PoolStats {
+    /// Requests currently in flight on the shared session.
+    pub active: usize,
+    /// `max_connections` minus `active`, assuming each in-flight request holds one connection.
+    /// Not the number of open idle connections.
+    pub estimated_free_slots: usize,
+    /// Configured pool size, 0 if the shared session is not initialized.
+    pub max_connections: usize,
+}
 
 /// Manager for shared reqwest client with high-performance connection pool.
 ///
 /// Equivalent to SharedSessionManager in Python version.
 pub struct SharedSessionManager {
     client: Arc<Client>,
-    max_connections: usize,
+    config: SessionConfig,
+    /// Periodic recycle task, aborted when the manager is dropped.
+    recycle_task: Option<tokio::task::JoinHandle<()>>,
+}
+
+impl Drop for SharedSessionManager {
+    fn drop(&mut self) {
+        if let Some(task) = self.recycle_task.take() {
+            task.abort();
+        }
+    }
+}
+
+/// Build the shared pool client from `config`.
+fn build_shared_client(config: &SessionConfig) -> Result<Client> {
+    let proxy = config.proxy.as_deref().map(build_proxy).transpose()?;
+    let max_connections = config.max_connections;
+
+    // Create client equivalent to aiohttp.ClientSession with TCPConnector
+    let mut builder = with_tls_backend(Client::builder()).connector_layer(CountConnections);
+    if let Some(proxy) = proxy {
+        builder = builder.proxy(proxy);
+    }
+    if !config.follow_redirects {
+        builder = builder.redirect(reqwest::redirect::Policy::none());
+    }
+    builder
+        // Connection pool settings - equivalent to aiohttp connector limits
+        .pool_max_idle_per_host(max_connections / 2) // limit_per_host = max_connections // 2
+        .pool_idle_timeout(Duration::from_secs(60)) // keepalive_timeout=60
+        .tcp_keepalive(config.tcp_keepalive) // Keep connections alive
+        .tcp_nodelay(true) // Disable Nagle's algorithm for lower latency
+        // Timeout settings
+        .timeout(Duration::from_secs(30))
+        // HTTP/1.1 for compatibility with aiohttp
+        .http1_only()
+        .user_agent("trade-sdk/0.1.0")
+        // Default headers - same as Python
+        .default_headers({
+            let mut headers = reqwest::header::HeaderMap::new();
+            headers.insert("Content-Type", "application/json".parse().unwrap());
+            headers.insert("Accept", "application/json".parse().unwrap());
+            // Add keep-alive header explicitly
+            headers.insert("Connection", "keep-alive".parse().unwrap());
+            headers
+        })
+        .build()
+        .map_err(Error::Http)
+}
+
+/// Recycle the pool every `interval` until the session is closed.
+///
+/// Returns `None` (with a warning) outside a Tokio runtime.
+fn spawn_recycle_task(interval: Duration) -> Option<tokio::task::JoinHandle<()>> {
+    let Ok(handle) = tokio::runtime::Handle::try_current() else {
+        log::warn!("recycle_interval ignored: shared session set up outside a Tokio runtime");
+        return None;
+    };
+    Some(handle.spawn(async move {
+        let mut ticker = tokio::time::interval_at(tokio::time::Instant::now() + interval, interval);
+        loop {
+            ticker.tick().await;
+            if let Err(e) = SharedSessionManager::recycle() {
+                log::warn!("Stopping periodic pool recycle: {}", e);
+                return;
+            }
+        }
+    }))
 }
 
 impl SharedSessionManager {
@@ -26,57 +258,92 @@ impl SharedSessionManager {
     /// # Arguments
     /// * `max_connections` - Maximum number of connections in pool (default 2000)
     pub fn setup(max_connections: usize) {
+        Self::setup_with_config(SessionConfig {
+            max_connections,
+            ..Default::default()
+        })
+        .expect("Failed to create HTTP client");
+    }
+
+    /// Initialize shared session from a [`SessionConfig`].
+    ///
+    /// Returns `Error::Config` if the proxy URL is invalid; the session is then left uninitialized.
+    pub fn setup_with_config(config: SessionConfig) -> Result<()> {
+        // Validate the proxy even if the session is already set up.
+        if let Some(url) = &config.proxy {
+            build_proxy(url)?;
+        }
+
         // Fast atomic check first
         if SESSION_INITIALIZED.load(Ordering::Acquire) {
             log::warn!("Session already initialized - skipping setup");
-            return;
+            return Ok(());
         }
 
         let mut manager = SHARED_SESSION_MANAGER.write().unwrap();
 
         if manager.is_some() {
             log::warn!("Session already initialized - skipping setup");
-            return;
+            return Ok(());
         }
 
         log::info!(
             "Initializing shared session with {} max connections",
-            max_connections
+            config.max_connections
         );
 
-        // Create client equivalent to aiohttp.ClientSession with TCPConnector
-        let client = Client::builder()
-            // Connection pool settings - equivalent to aiohttp connector limits
-            .pool_max_idle_per_host(max_connections / 2) // limit_per_host = max_connections // 2
-            .pool_idle_timeout(Duration::from_secs(60)) // keepalive_timeout=60
-            .tcp_keepalive(Duration::from_secs(60)) // Keep connections alive
-            .tcp_nodelay(true) // Disable Nagle's algorithm for lower latency
-            // Timeout settings
-            .timeout(Duration::from_secs(30))
-            // HTTP/1.1 for compatibility with aiohttp
-            .http1_only()
-            .user_agent("trade-sdk/0.1.0")
-            // Default headers - same as Python
-            .default_headers({
-                let mut headers = reqwest::header::HeaderMap::new();
-                headers.insert("Content-Type", "application/json".parse().unwrap());
-                headers.insert("Accept", "application/json".parse().unwrap());
-                // Add keep-alive header explicitly
-                headers.insert("Connection", "keep-alive".parse().unwrap());
-                headers
-            })
-            .build()
-            .expect("Failed to create HTTP client");
+        let client = build_shared_client(&config)?;
+        let recycle_task = config.recycle_interval.and_then(spawn_recycle_task);
 
         *manager = Some(SharedSessionManager {
             client: Arc::new(client),
-            max_connections,
+            config,
+            recycle_task,
         });
 
         // Mark as initialized atomically
         SESSION_INITIALIZED.store(true, Ordering::Release);
 
         log::info!("✅ Shared session initialized with maximum performance settings");
+        Ok(())
+    }
+
+    /// Rebuild the connection pool so new connections resolve DNS again.
+    ///
+    /// The new client is swapped in atomically: clients fetch it on their next request, while
+    /// requests already running finish on the old pool, which is dropped once unused.
+    /// Returns `Error::Session` if the session is not initialized.
+    pub fn recycle() -> Result<()> {
+        let not_initialized = || {
+            Error::Session(
+                "Session not initialized. Call SessionManager::setup() first.".to_string(),
+            )
+        };
+        if !Self::is_initialized() {
+            return Err(not_initialized());
+        }
+        let config = match SHARED_SESSION_MANAGER.read() {
+            Ok(manager) => manager.as_ref().map(|session| session.config.clone()),
+            Err(_) => None,
+        }
+        .ok_or_else(not_initialized)?;
+
+        // Build outside the lock so readers are never blocked on it.
+        let client = Arc::new(build_shared_client(&config)?);
+        let mut manager = SHARED_SESSION_MANAGER.write().unwrap();
+        let session = manager.as_mut().ok_or_else(not_initialized)?;
+        session.client = client;
+        log::debug!("Shared session connection pool recycled");
+        Ok(())
+    }
+
+    /// Shared client if the session is initialized, without panicking otherwise.
+    pub(crate) fn try_get_client() -> Option<Arc<Client>> {
+        if !Self::is_initialized() {
+            return None;
+        }
+        let manager = SHARED_SESSION_MANAGER.read().ok()?;
+        manager.as_ref().map(|session| Arc::clone(&session.client))
     }
 
     /// Check if shared session is initialized and active.
@@ -110,8 +377,59 @@ impl SharedSessionManager {
             .clone()
     }
 
+    /// Open a connection to each host so the first real request skips the TLS handshake.
+    ///
+    /// Hosts are given as `api.bybit.com` or as a full base URL. Any HTTP response counts as
+    /// success; the connection stays parked in the pool for `pool_idle_timeout` (60s).
+    /// Returns `Error::Session` if the session is not initialized and `hosts` is not empty.
+    pub async fn warmup(hosts: &[&str]) -> Result<WarmupSummary> {
+        let mut summary = WarmupSummary::default();
+        if hosts.is_empty() {
+            return Ok(summary);
+        }
+        if !Self::is_initialized() {
+            return Err(Error::Session(
+                "Session not initialized. Call SessionManager::setup() first.".to_string(),
+            ));
+        }
+
+        let client = Self::get_client();
+        let requests = hosts.iter().map(|host| {
+            let client = Arc::clone(&client);
+            let url = if host.contains("://") {
+                host.to_string()
+            } else {
+                format!("https://{host}")
+            };
+            async move {
+                let result = client.head(&url).send().await.map(|_| ());
+                (host.to_string(), result)
+            }
+        });
+
+        for (host, result) in futures::future::join_all(requests).await {
+            match result {
+                Ok(()) => summary.succeeded.push(host),
+                Err(e) => {
+                    log::warn!("Warmup of {} failed: {}", host, e);
+                    summary.failed.push((host, Error::Http(e)));
+                }
+            }
+        }
+        log::info!(
+            "Warmed up {}/{} hosts",
+            summary.succeeded.len(),
+            hosts.len()
+        );
+        Ok(summary)
+    }
+
     /// Close the shared session gracefully.
     /// Call this at application shutdown.
+    ///
+    /// Waits until all in-flight requests on the shared session have finished or
+    /// `SessionConfig::close_timeout` has passed, and logs how many requests were still running
+    /// at the deadline. Clients with their own pool (e.g. behind a proxy) are not waited for.
     pub async fn close() {
         // Atomic flag first
         if !SESSION_INITIALIZED.swap(false, Ordering::AcqRel) {
@@ -119,16 +437,68 @@ impl SharedSessionManager {
             return;
         }
 
+        let close_timeout = match SHARED_SESSION_MANAGER.read() {
+            Ok(manager) => manager.as_ref().map(|session| session.config.close_timeout),
+            Err(_) => None,
+        };
+        let Some(close_timeout) = close_timeout else {
+            return;
+        };
+
+        log::info!("Closing shared session gracefully");
+        let abandoned = Self::drain_in_flight(close_timeout).await;
+        if abandoned > 0 {
+            log::warn!(
+                "Shared session closed with {} in-flight requests abandoned",
+                abandoned
+            );
+        }
+
         // Scope for the manager lock to ensure it's dropped before await
-        let should_wait = {
+        {
             let mut manager = SHARED_SESSION_MANAGER.write().unwrap();
-            manager.take().is_some()
-        };
-        if should_wait {
-            log::info!("Closing shared session gracefully");
-            // Give time for pending requests to complete
-            tokio::time::sleep(Duration::from_millis(200)).await;
-            log::info!("✅ Shared session closed successfully");
+            manager.take();
+        }
+        log::info!("✅ Shared session closed successfully");
+    }
+
+    /// Wait until no requests are in flight or `timeout` passes; returns the number still running.
+    async fn drain_in_flight(timeout: Duration) -> usize {
+        let deadline = tokio::time::Instant::now() + timeout;
+        loop {
+            // Register for the wakeup before checking, so a drain in between is not missed.
+            let drained = IN_FLIGHT_DRAINED.notified();
+            let in_flight = IN_FLIGHT_REQUESTS.load(Ordering::Acquire);
+            if in_flight == 0 {
+                return 0;
+            }
+            if tokio::time::timeout_at(deadline, drained).await.is_err() {
+                return IN_FLIGHT_REQUESTS.load(Ordering::Acquire);
+            }
+        }
+    }
+
+    /// Number of requests currently in flight on the shared session.
+    pub fn in_flight_requests() -> usize {
+        IN_FLIGHT_REQUESTS.load(Ordering::Acquire)
+    }
+
+    /// Number of connections the shared pool has established since the process started,
+    /// including pools replaced by [`Self::recycle`].
+    ///
+    /// With keep-alive working, many clients and requests to one host should add only a few.
+    pub fn connections_opened() -> u64 {
+        CONNECTIONS_OPENED.load(Ordering::Relaxed)
+    }
+
+    /// Snapshot of shared pool usage for monitoring.
+    pub fn pool_stats() -> PoolStats {
+        let active = Self::in_flight_requests();
+        let max_connections = Self::max_connections();
+        PoolStats {
+            active,
+            estimated_free_slots: max_connections.saturating_sub(active),
+            max_connections,
         }
     }
 
@@ -136,9 +506,19 @@ impl SharedSessionManager {
     pub fn max_connections() -> usize {
         if let Ok(manager) = SHARED_SESSION_MANAGER.read() {
             if let Some(ref session) = *manager {
-                return session.max_connections;
+                return session.config.max_connections;
             }
         }
         0
     }
+
+    /// Get the proxy URL of the shared session, if any.
+    pub fn proxy() -> Option<String> {
+        if let Ok(manager) = SHARED_SESSION_MANAGER.read() {
+            if let Some(ref session) = *manager {
+                return session.config.proxy.clone();
+            }
+        }
+        None
+    }
 }
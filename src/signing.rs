@@ -0,0 +1,52 @@
+//! Signing helpers shared by the exchange clients.
+
+use base64::engine::general_purpose::STANDARD as BASE64;
+use base64::Engine;
+use ring::rand::SystemRandom;
+use ring::signature::{RsaKeyPair, RSA_PKCS1_SHA256};
+
+use crate::error::{Error, Result};
+
+/// RSA PKCS#1 v1.5 signature over SHA-256 of `payload`, base64 encoded; `api_secret` is the
+/// private key.
+///
+/// Fails with `Error::Auth` if the secret is not a valid RSA private key.
+pub(crate) fn rsa_sha256_base64(
+    api_secret: &str,
+    payload: &str,
+) -> Result<String> {
+    let (der, pkcs1) = private_key_der(api_secret, "RSA")?;
+    let key = if pkcs1 {
+        RsaKeyPair::from_der(&der)
+    } else {
+        RsaKeyPair::from_pkcs8(&der)
+    }
+    .map_err(|e| Error::Auth(format!("Invalid RSA private key: {e}")))?;
+    let mut signature = vec![0u8; key.public().modulus_len()];
+    key.sign(
+        &RSA_PKCS1_SHA256,
+        &SystemRandom::new(),
+        payload.as_bytes(),
+        &mut signature,
+    )
+    .map_err(|e| Error::Auth(format!("RSA signing failed: {e}")))?;
+    Ok(BASE64.encode(signature))
+}
+
+/// DER bytes of a PEM or bare base64 private key, and whether it is PKCS#1 (`RSA PRIVATE KEY`)
+/// rather than PKCS#8.
+pub(crate) fn private_key_der(
+    secret: &str,
+    key_type: &str,
+) -> Result<(Vec<u8>, bool)> {
+    let pkcs1 = secret.contains("BEGIN RSA PRIVATE KEY");
+    let body: String = secret
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.starts_with("-----"))
+        .collect();
+    let der = BASE64
+        .decode(body)
+        .map_err(|e| Error::Auth(format!("Invalid {key_type} private key encoding: {e}")))?;
+    Ok((der, pkcs1))
+}
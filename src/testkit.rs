@@ -0,0 +1,394 @@
+//! Local mock exchange for testing code built on this SDK (`testkit` feature).
+//!
+//! [`MockExchange`] serves canned JSON responses keyed by method and path on `127.0.0.1` and
+//! records every request it receives. [`MockExchange::respond_sequence`] scripts successive
+//! answers, e.g. a [`MockResponse::with_delay`] timeout followed by a reply. Point a client at
+//! it with `with_base_url`:
+//!
+//! ```no_run
+//! use reqwest::Method;
+//! use serde_json::json;
+//! use trade_sdk::bybit::{traits::MarketApi, BybitClient};
+//! use trade_sdk::testkit::MockExchange;
+//!
+//! # async fn example() -> trade_sdk::Result<()> {
+//! let exchange = MockExchange::start().await;
+//! exchange.respond(
+//!     Method::GET,
+//!     "/v5/market/time",
+//!     json!({"retCode": 0, "retMsg": "OK", "result": {}, "retExtInfo": {}, "time": 1}),
+//! );
+//! let client = BybitClient::new(None, None, false, false, 5000, None)?
+//!     .with_base_url(&exchange.base_url());
+//! client.get_server_time().await?;
+//! assert_eq!(exchange.requests()[0].path, "/v5/market/time");
+//! # Ok(())
+//! # }
+//! ```
+
+use std::collections::{HashMap, VecDeque};
+use std::net::SocketAddr;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use reqwest::Method;
+use serde_json::Value;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::{TcpListener, TcpStream};
+use tokio::task::JoinHandle;
+
+/// Canned response, see [`MockExchange::respond_sequence`].
+#[derive(Debug, Clone)]
+pub struct MockResponse {
+    status: u16,
+    body: Value,
+    delay: Duration,
+    /// Close the connection instead of answering.
+    disconnect: bool,
+}
+
+impl MockResponse {
+    /// `200 OK` with `body`, sent right away.
+    pub fn new(body: Value) -> Self {
+        Self {
+            status: 200,
+            body,
+            delay: Duration::ZERO,
+            disconnect: false,
+        }
+    }
+
+    /// Read the request, then close the connection without answering, as when the exchange
+    /// processed a request but the response was lost. The client sees a transport error.
+    pub fn disconnect() -> Self {
+        Self {
+            disconnect: true,
+            ..Self::new(Value::Null)
+        }
+    }
+
+    /// Answer with `status` instead of `200 OK`.
+    pub fn with_status(
+        self,
+        status: u16,
+    ) -> Self {
+        Self { status, ..self }
+    }
+
+    /// Wait `delay` after reading the request before answering, e.g. to make the client
+    /// time out.
+    pub fn with_delay(
+        self,
+        delay: Duration,
+    ) -> Self {
+        Self { delay, ..self }
+    }
+}
+
+/// Request received by a [`MockExchange`].
+#[derive(Debug, Clone)]
+pub struct RecordedRequest {
+    pub method: Method,
+    /// Path without the query string, e.g. `/v5/market/time`.
+    pub path: String,
+    /// Raw query string without the leading `?`, empty if there was none.
+    pub query: String,
+    /// Headers with lowercased names.
+    pub headers: HashMap<String, String>,
+    pub body: String,
+}
+
+impl RecordedRequest {
+    /// Path and query as sent, e.g. `/v5/market/kline?symbol=BTCUSDT`.
+    pub fn url(&self) -> String {
+        if self.query.is_empty() {
+            self.path.clone()
+        } else {
+            format!("{}?{}", self.path, self.query)
+        }
+    }
+
+    /// Method and path with query, e.g. `GET /v5/market/kline?symbol=BTCUSDT`.
+    pub fn request_line(&self) -> String {
+        format!("{} {}", self.method, self.url())
+    }
+
+    /// Value of a header, matched case-insensitively.
+    pub fn header(
+        &self,
+        name: &str,
+    ) -> Option<&str> {
+        self.headers
+            .get(&name.to_ascii_lowercase())
+            .map(String::as_str)
+    }
+
+    /// Percent-decoded value of a query parameter.
+    pub fn query_param(
+        &self,
+        name: &str,
+    ) -> Option<String> {
+        self.query.split('&').find_map(|pair| {
+            let (key, value) = pair.split_once('=').unwrap_or((pair, ""));
+            (key == name).then(|| {
+                urlencoding::decode(value)
+                    .map(|v| v.into_owned())
+                    .unwrap_or_else(|_| value.to_string())
+            })
+        })
+    }
+
+    /// Body parsed as JSON, `None` if it is empty or not JSON.
+    pub fn json(&self) -> Option<Value> {
+        serde_json::from_str(&self.body).ok()
+    }
+}
+
+/// Canned responses by method and path, plus the one for any other request.
+#[derive(Default)]
+struct RouteTable {
+    /// Responses still to send per route; the last one is repeated.
+    routes: HashMap<(Method, String), VecDeque<MockResponse>>,
+    fallback: Option<MockResponse>,
+}
+
+impl RouteTable {
+    /// Next response for `route`: the route's own, else the fallback.
+    fn next_response(
+        &mut self,
+        route: &(Method, String),
+    ) -> Option<MockResponse> {
+        match self.routes.get_mut(route) {
+            Some(queue) if queue.len() > 1 => queue.pop_front(),
+            Some(queue) => queue.front().cloned(),
+            None => self.fallback.clone(),
+        }
+    }
+}
+
+type Routes = Arc<Mutex<RouteTable>>;
+type Requests = Arc<Mutex<Vec<RecordedRequest>>>;
+
+/// Local HTTP server standing in for an exchange.
+///
+/// Requests without a registered response get the [`MockExchange::respond_to_any`] response,
+/// or a `404` with a JSON error naming the route if none is set.
+/// The server stops when the `MockExchange` is dropped.
+pub struct MockExchange {
+    addr: SocketAddr,
+    routes: Routes,
+    requests: Requests,
+    server: JoinHandle<()>,
+}
+
+impl MockExchange {
+    /// Bind to a free local port and start serving.
+    ///
+    /// # Panics
+    /// If no local port can be bound.
+    pub async fn start() -> Self {
+        let listener = TcpListener::bind("127.0.0.1:0")
+            .await
+            .expect("bind mock exchange");
+        let addr = listener.local_addr().expect("mock exchange address");
+        let routes = Routes::default();
+        let requests = Requests::default();
+        let server = tokio::spawn(serve(listener, Arc::clone(&routes), Arc::clone(&requests)));
+        Self {
+            addr,
+            routes,
+            requests,
+            server,
+        }
+    }
+
+    /// Base URL to pass to `with_base_url`, e.g. `http://127.0.0.1:40123`.
+    pub fn base_url(&self) -> String {
+        format!("http://{}", self.addr)
+    }
+
+    /// Answer `method path` with `200 OK` and `body`, replacing any earlier response.
+    pub fn respond(
+        &self,
+        method: Method,
+        path: &str,
+        body: Value,
+    ) {
+        self.respond_with_status(method, path, 200, body)
+    }
+
+    /// Answer `method path` with `status` and `body`, replacing any earlier response.
+    pub fn respond_with_status(
+        &self,
+        method: Method,
+        path: &str,
+        status: u16,
+        body: Value,
+    ) {
+        self.respond_sequence(method, path, [MockResponse::new(body).with_status(status)])
+    }
+
+    /// Answer successive requests to `method path` with `responses` in order, repeating the
+    /// last one, replacing any earlier response.
+    ///
+    /// # Panics
+    /// If `responses` is empty.
+    pub fn respond_sequence(
+        &self,
+        method: Method,
+        path: &str,
+        responses: impl IntoIterator<Item = MockResponse>,
+    ) {
+        let responses: VecDeque<MockResponse> = responses.into_iter().collect();
+        assert!(
+            !responses.is_empty(),
+            "no mock responses for {method} {path}"
+        );
+        self.routes
+            .lock()
+            .unwrap()
+            .routes
+            .insert((method, path.to_string()), responses);
+    }
+
+    /// Answer every request without a registered response with `200 OK` and `body`,
+    /// replacing any earlier fallback.
+    pub fn respond_to_any(
+        &self,
+        body: Value,
+    ) {
+        self.routes.lock().unwrap().fallback = Some(MockResponse::new(body));
+    }
+
+    /// All requests received so far, oldest first.
+    pub fn requests(&self) -> Vec<RecordedRequest> {
+        self.requests.lock().unwrap().clone()
+    }
+
+    /// Requests received for `method path`, oldest first.
+    pub fn requests_to(
+        &self,
+        method: Method,
+        path: &str,
+    ) -> Vec<RecordedRequest> {
+        self.requests
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|r| r.method == method && r.path == path)
+            .cloned()
+            .collect()
+    }
+}
+
+impl Drop for MockExchange {
+    fn drop(&mut self) {
+        self.server.abort();
+    }
+}
+
+async fn serve(
+    listener: TcpListener,
+    routes: Routes,
+    requests: Requests,
+) {
+    while let Ok((socket, _)) = listener.accept().await {
+        let routes = Arc::clone(&routes);
+        let requests = Arc::clone(&requests);
+        tokio::spawn(async move {
+            let _ = handle(socket, routes, requests).await;
+        });
+    }
+}
+
+/// Serve one request on `socket`, then close it.
+async fn handle(
+    mut socket: TcpStream,
+    routes: Routes,
+    requests: Requests,
+) -> std::io::Result<()> {
+    let Some(request) = read_request(&mut socket).await? else {
+        return Ok(());
+    };
+    let route = (request.method.clone(), request.path.clone());
+    let response = routes.lock().unwrap().next_response(&route);
+    let response = response.unwrap_or_else(|| {
+        MockResponse::new(serde_json::json!({
+            "error": format!("no mock response for {} {}", route.0, route.1)
+        }))
+        .with_status(404)
+    });
+    requests.lock().unwrap().push(request);
+    if !response.delay.is_zero() {
+        tokio::time::sleep(response.delay).await;
+    }
+    if response.disconnect {
+        return Ok(());
+    }
+
+    let body = response.body.to_string();
+    let reason = reqwest::StatusCode::from_u16(response.status)
+        .ok()
+        .and_then(|s| s.canonical_reason())
+        .unwrap_or("");
+    let raw = format!(
+        "HTTP/1.1 {} {reason}\r\ncontent-type: application/json\r\ncontent-length: {}\r\nconnection: close\r\n\r\n{body}",
+        response.status,
+        body.len(),
+    );
+    socket.write_all(raw.as_bytes()).await?;
+    socket.shutdown().await
+}
+
+/// Read one HTTP/1.1 request; `None` if the connection closed before a full request arrived.
+async fn read_request(socket: &mut TcpStream) -> std::io::Result<Option<RecordedRequest>> {
+    let mut buf = Vec::new();
+    let mut chunk = [0u8; 4096];
+    let header_end = loop {
+        if let Some(end) = buf.windows(4).position(|w| w == b"\r\n\r\n") {
+            break end;
+        }
+        let n = socket.read(&mut chunk).await?;
+        if n == 0 {
+            return Ok(None);
+        }
+        buf.extend_from_slice(&chunk[..n]);
+    };
+
+    let head = String::from_utf8_lossy(&buf[..header_end]).into_owned();
+    let mut lines = head.lines();
+    let mut request_line = lines.next().unwrap_or_default().split_whitespace();
+    let method = request_line
+        .next()
+        .and_then(|m| Method::from_bytes(m.as_bytes()).ok())
+        .unwrap_or(Method::GET);
+    let target = request_line.next().unwrap_or("/");
+    let (path, query) = target.split_once('?').unwrap_or((target, ""));
+    let headers: HashMap<String, String> = lines
+        .filter_map(|line| {
+            let (name, value) = line.split_once(':')?;
+            Some((name.trim().to_ascii_lowercase(), value.trim().to_string()))
+        })
+        .collect();
+
+    let content_length = headers
+        .get("content-length")
+        .and_then(|v| v.parse::<usize>().ok())
+        .unwrap_or(0);
+    let mut body = buf[header_end + 4..].to_vec();
+    while body.len() < content_length {
+        let n = socket.read(&mut chunk).await?;
+        if n == 0 {
+            break;
+        }
+        body.extend_from_slice(&chunk[..n]);
+    }
+
+    Ok(Some(RecordedRequest {
+        method,
+        path: path.to_string(),
+        query: query.to_string(),
+        headers,
+        body: String::from_utf8_lossy(&body).into_owned(),
+    }))
+}
@@ -0,0 +1,225 @@
+//! Exchange-agnostic order placement.
+//!
+//! [`UnifiedOrder`] describes an order once and [`UnifiedTrade::submit`] translates it into the
+//! native request of each exchange: Bybit linear perpetuals and BingX USDT-M perpetual swaps.
+//!
+//! Orders target one-way position mode. BingX orders are sent with `positionSide` `BOTH` and
+//! Bybit orders without `positionIdx`, so an account in hedge mode rejects them; place hedge-mode
+//! orders with the exchange clients directly (or enable `BybitClient::with_auto_position_idx`).
+
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::bingx::{self, traits::swap::TradeApi as BingxSwapTradeApi, BingxClient};
+use crate::bybit::{self, traits::TradeApi as BybitTradeApi, BybitClient};
+use crate::error::{Error, Result};
+use crate::utils::{amount_from_f64, Symbol};
+
+/// Order side.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default)]
+pub enum UnifiedSide {
+    #[default]
+    Buy,
+    Sell,
+}
+
+/// Order type.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default)]
+pub enum UnifiedOrderType {
+    #[default]
+    Market,
+    Limit,
+}
+
+/// Exchange-agnostic order description.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UnifiedOrder {
+    /// Trading pair, converted to each exchange's format on submit.
+    pub symbol: Symbol,
+    pub side: UnifiedSide,
+    pub order_type: UnifiedOrderType,
+    /// Order quantity in base coin, must be greater than 0.
+    pub qty: f64,
+    /// Limit price, required for `Limit` orders and ignored for `Market` orders.
+    pub price: Option<f64>,
+    /// Only reduce an existing position.
+    pub reduce_only: bool,
+    /// User-defined order ID (Bybit `orderLinkId`, BingX `clientOrderId`).
+    pub client_order_id: Option<String>,
+}
+
+/// Normalized acknowledgement of a submitted order.
+#[derive(Debug, Clone)]
+pub struct UnifiedOrderAck {
+    /// Exchange-assigned order ID.
+    pub order_id: String,
+    /// User-defined order ID, if the exchange echoed one back.
+    pub client_order_id: Option<String>,
+    /// Raw `result` (Bybit) or `data` (BingX) payload.
+    pub raw: Value,
+}
+
+impl UnifiedOrder {
+    /// Create a market order; set the remaining fields directly for other order kinds.
+    pub fn new(
+        symbol: Symbol,
+        side: UnifiedSide,
+        qty: f64,
+    ) -> Self {
+        Self {
+            symbol,
+            side,
+            order_type: UnifiedOrderType::Market,
+            qty,
+            price: None,
+            reduce_only: false,
+            client_order_id: None,
+        }
+    }
+
+    fn validate(&self) -> Result<()> {
+        if !self.qty.is_finite() || self.qty <= 0.0 {
+            return Err(Error::Validation(format!(
+                "Order qty must be greater than 0, got {}",
+                self.qty
+            )));
+        }
+        if self.order_type == UnifiedOrderType::Limit && self.limit_price().is_none() {
+            return Err(Error::Validation(
+                "Limit orders require a positive price".to_string(),
+            ));
+        }
+        Ok(())
+    }
+
+    fn limit_price(&self) -> Option<f64> {
+        match self.order_type {
+            UnifiedOrderType::Limit => self.price.filter(|p| p.is_finite() && *p > 0.0),
+            UnifiedOrderType::Market => None,
+        }
+    }
+
+    /// Translate into Bybit linear `PlaceOrderParams`.
+    pub fn to_bybit_params(&self) -> Result<bybit::types::PlaceOrderParams> {
+        self.validate()?;
+        Ok(bybit::types::PlaceOrderParams {
+            symbol: self.symbol.to_bybit(),
+            side: match self.side {
+                UnifiedSide::Buy => bybit::types::Side::Buy,
+                UnifiedSide::Sell => bybit::types::Side::Sell,
+            },
+            order_type: match self.order_type {
+                UnifiedOrderType::Market => bybit::types::PlaceOrderType::Market,
+                UnifiedOrderType::Limit => bybit::types::PlaceOrderType::Limit,
+            },
+            qty: amount_from_f64(self.qty)?,
+            price: self.limit_price().map(amount_from_f64).transpose()?,
+            reduce_only: self.reduce_only.then_some(true),
+            order_link_id: self.client_order_id.clone(),
+            ..Default::default()
+        })
+    }
+
+    /// Translate into BingX `PlaceSwapOrderParams` for one-way mode (`positionSide` `BOTH`).
+    pub fn to_bingx_params(&self) -> Result<bingx::types::PlaceSwapOrderParams> {
+        self.validate()?;
+        Ok(bingx::types::PlaceSwapOrderParams {
+            symbol: self.symbol.to_bingx(),
+            side: match self.side {
+                UnifiedSide::Buy => bingx::types::OrderSide::Buy,
+                UnifiedSide::Sell => bingx::types::OrderSide::Sell,
+            },
+            order_type: match self.order_type {
+                UnifiedOrderType::Market => bingx::types::SwapOrderType::Market,
+                UnifiedOrderType::Limit => bingx::types::SwapOrderType::Limit,
+            },
+            position_side: Some(bingx::types::PositionSide::Both),
+            quantity: Some(self.qty),
+            price: self.limit_price(),
+            reduce_only: self.reduce_only.then_some(true),
+            client_order_id: self.client_order_id.clone(),
+            ..Default::default()
+        })
+    }
+}
+
+/// Read an ID field that may be sent as a string or a number.
+fn id_field(
+    obj: &Value,
+    key: &str,
+) -> Option<String> {
+    match obj.get(key)? {
+        Value::String(s) if !s.is_empty() => Some(s.clone()),
+        Value::Number(n) => Some(n.to_string()),
+        _ => None,
+    }
+}
+
+/// Error for an order acknowledgement without an order ID.
+fn missing_order_id(
+    exchange: &str,
+    payload: &Value,
+) -> Error {
+    Error::Json(serde::de::Error::custom(format!(
+        "{exchange} response has no orderId: {payload}"
+    )))
+}
+
+/// Exchange-agnostic order placement.
+#[async_trait]
+pub trait UnifiedTrade {
+    /// Submit an order and return the normalized acknowledgement.
+    ///
+    /// Fails with `Error::Json` if the exchange accepts the order but its response has no
+    /// order ID.
+    async fn submit(
+        &self,
+        order: &UnifiedOrder,
+    ) -> Result<UnifiedOrderAck>;
+}
+
+#[async_trait]
+impl UnifiedTrade for BybitClient {
+    async fn submit(
+        &self,
+        order: &UnifiedOrder,
+    ) -> Result<UnifiedOrderAck> {
+        let params = order.to_bybit_params()?;
+        let response = self
+            .place_order(bybit::types::AllCategories::Linear, &params)
+            .await?;
+        let result = response.result;
+        let order_id =
+            id_field(&result, "orderId").ok_or_else(|| missing_order_id("Bybit", &result))?;
+        Ok(UnifiedOrderAck {
+            order_id,
+            client_order_id: id_field(&result, "orderLinkId"),
+            raw: result,
+        })
+    }
+}
+
+#[async_trait]
+impl UnifiedTrade for BingxClient {
+    async fn submit(
+        &self,
+        order: &UnifiedOrder,
+    ) -> Result<UnifiedOrderAck> {
+        let params = order.to_bingx_params()?;
+        let response = self.place_swap_order(&params).await?;
+        let data = response.data;
+        // The order is nested under `data.order`; the ID key is `orderId` or `orderID`.
+        let inner = data.get("order").unwrap_or(&data);
+        let order_id = id_field(inner, "orderId")
+            .or_else(|| id_field(inner, "orderID"))
+            .ok_or_else(|| missing_order_id("BingX", &data))?;
+        let client_order_id =
+            id_field(inner, "clientOrderId").or_else(|| id_field(inner, "clientOrderID"));
+        Ok(UnifiedOrderAck {
+            order_id,
+            client_order_id,
+            raw: data,
+        })
+    }
+}
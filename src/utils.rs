@@ -1,28 +1,254 @@
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
 use serde::Deserialize;
 
-/// Serialize Option<f64> as Option<String>
-pub fn as_str_opt<S>(
-    opt: &Option<f64>,
+use crate::error::Error;
+
+/// Price/quantity type used in order parameters and response models.
+///
+/// `f64` by default; `rust_decimal::Decimal` with the `decimal` feature, which is sent exactly
+/// as written, trailing zeros included (`1.50` is sent as `"1.50"`).
+#[cfg(not(feature = "decimal"))]
+pub type Amount = f64;
+
+/// Price/quantity type used in order parameters and response models.
+///
+/// `f64` by default; `rust_decimal::Decimal` with the `decimal` feature, which is sent exactly
+/// as written, trailing zeros included (`1.50` is sent as `"1.50"`).
+#[cfg(feature = "decimal")]
+pub type Amount = rust_decimal::Decimal;
+
+/// Convert an `f64` into an [`Amount`].
+///
+/// With the `decimal` feature the value becomes the decimal it would be sent as (see
+/// [`PlainDecimal`]), e.g. `0.1 + 0.2` is `0.3`. Returns `Error::Validation` for a
+/// non-finite value.
+pub fn amount_from_f64(value: f64) -> Result<Amount, Error> {
+    if !value.is_finite() {
+        return Err(Error::Validation(format!(
+            "Cannot represent {value} as an amount"
+        )));
+    }
+    #[cfg(feature = "decimal")]
+    let value = value
+        .to_plain_string()
+        .parse()
+        .map_err(|_| Error::Validation(format!("Cannot represent {value} as an amount")))?;
+    Ok(value)
+}
+
+/// Convert an [`Amount`] into an `f64`, e.g. for arithmetic with other `f64` values.
+pub fn amount_to_f64(value: Amount) -> f64 {
+    #[cfg(feature = "decimal")]
+    let value = rust_decimal::prelude::ToPrimitive::to_f64(&value).unwrap_or(f64::NAN);
+    value
+}
+
+/// How long a fetched maximum leverage is trusted before it is looked up again.
+pub(crate) const LEVERAGE_LIMIT_TTL: Duration = Duration::from_secs(60);
+
+/// Maximum leverage per symbol key, cached for [`LEVERAGE_LIMIT_TTL`]; clones share the cache.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct LeverageLimits(Arc<Mutex<HashMap<String, (f64, Instant)>>>);
+
+impl LeverageLimits {
+    /// Cached maximum for `key`, if fetched within the TTL.
+    pub(crate) fn get(
+        &self,
+        key: &str,
+    ) -> Option<f64> {
+        let limits = self.0.lock().unwrap_or_else(|e| e.into_inner());
+        limits
+            .get(key)
+            .filter(|(_, fetched)| fetched.elapsed() < LEVERAGE_LIMIT_TTL)
+            .map(|(max, _)| *max)
+    }
+
+    pub(crate) fn insert(
+        &self,
+        key: String,
+        max: f64,
+    ) {
+        let mut limits = self.0.lock().unwrap_or_else(|e| e.into_inner());
+        limits.insert(key, (max, Instant::now()));
+    }
+}
+
+/// Reject a leverage outside `1..=max` for `symbol`.
+pub(crate) fn check_leverage(
+    symbol: &str,
+    leverage: f64,
+    max: f64,
+) -> Result<(), Error> {
+    if leverage < 1.0 {
+        return Err(Error::Validation(format!(
+            "Leverage must be at least 1x, got {leverage}x for {symbol}"
+        )));
+    }
+    if leverage > max {
+        return Err(Error::Validation(format!(
+            "Leverage {leverage}x exceeds the {max}x maximum for {symbol}"
+        )));
+    }
+    Ok(())
+}
+
+/// Number that can be written as a plain decimal string (no exponent) for request bodies.
+pub trait PlainDecimal {
+    fn to_plain_string(&self) -> String;
+}
+
+impl PlainDecimal for f64 {
+    /// Format with up to 15 significant digits, trimming trailing zeros.
+    ///
+    /// This drops binary noise such as `0.1 + 0.2 = 0.30000000000000004` and never emits `e`
+    /// notation, which the exchanges reject.
+    fn to_plain_string(&self) -> String {
+        if !self.is_finite() {
+            return self.to_string();
+        }
+        if *self == 0.0 {
+            return "0".to_string();
+        }
+        let int_digits = self.abs().log10().floor() as i32 + 1;
+        let decimals = (15 - int_digits).max(0) as usize;
+        let s = format!("{:.*}", decimals, self);
+        let s = if s.contains('.') {
+            s.trim_end_matches('0').trim_end_matches('.')
+        } else {
+            &s
+        };
+        match s {
+            "-0" => "0".to_string(),
+            s => s.to_string(),
+        }
+    }
+}
+
+#[cfg(feature = "decimal")]
+impl PlainDecimal for rust_decimal::Decimal {
+    /// `Decimal` never uses exponent notation; its scale (trailing zeros) is kept as is.
+    fn to_plain_string(&self) -> String {
+        self.to_string()
+    }
+}
+
+/// Number type [`round_to_tick`] and [`round_to_step`] work on: `f64`, and
+/// `rust_decimal::Decimal` with the `decimal` feature.
+pub trait AmountValue: PlainDecimal + Copy {
+    /// `self` rounded to a multiple of `step`, down if `floor` and to the nearest otherwise;
+    /// unchanged for a non-positive `step`.
+    fn round_to_multiple(
+        self,
+        step: Self,
+        floor: bool,
+    ) -> Self;
+}
+
+impl AmountValue for f64 {
+    fn round_to_multiple(
+        self,
+        step: f64,
+        floor: bool,
+    ) -> f64 {
+        if step <= 0.0 || !step.is_finite() || !self.is_finite() {
+            return self;
+        }
+        let steps = self / step;
+        let nearest = steps.round();
+        // `0.3 / 0.1` is `2.9999999999999996`; treat anything that close as an exact multiple.
+        let steps = if !floor || (steps - nearest).abs() < 1e-9 {
+            nearest
+        } else {
+            steps.floor()
+        };
+        // Trim float noise (`3 * 0.1 = 0.30000000000000004`) to the precision of `step`.
+        let decimals = step
+            .to_plain_string()
+            .split_once('.')
+            .map_or(0, |(_, frac)| frac.len()) as i32;
+        let scale = 10f64.powi(decimals);
+        (steps * step * scale).round() / scale
+    }
+}
+
+#[cfg(feature = "decimal")]
+impl AmountValue for rust_decimal::Decimal {
+    fn round_to_multiple(
+        self,
+        step: Self,
+        floor: bool,
+    ) -> Self {
+        if step <= rust_decimal::Decimal::ZERO {
+            return self;
+        }
+        let steps = self / step;
+        let steps = if floor {
+            steps.floor()
+        } else {
+            steps.round_dp_with_strategy(0, rust_decimal::RoundingStrategy::MidpointAwayFromZero)
+        };
+        (steps * step).normalize()
+    }
+}
+
+/// Round `price` to the nearest multiple of `tick` (an instrument's `tickSize`).
+///
+/// A non-positive `tick` returns `price` unchanged.
+pub fn round_to_tick<A: AmountValue>(
+    price: A,
+    tick: A,
+) -> A {
+    price.round_to_multiple(tick, false)
+}
+
+/// Round `qty` down to a multiple of `step` (an instrument's `qtyStep`), so an order never
+/// exceeds the requested size.
+///
+/// A non-positive `step` returns `qty` unchanged.
+pub fn round_to_step<A: AmountValue>(
+    qty: A,
+    step: A,
+) -> A {
+    qty.round_to_multiple(step, true)
+}
+
+/// Serialize Option<f64> as a plain decimal Option<String>
+pub fn as_str_opt<T, S>(
+    opt: &Option<T>,
     serializer: S,
 ) -> Result<S::Ok, S::Error>
 where
+    T: PlainDecimal,
     S: serde::Serializer,
 {
     match opt {
-        Some(num) => serializer.serialize_some(&num.to_string()),
+        Some(num) => serializer.serialize_some(&num.to_plain_string()),
         None => serializer.serialize_none(),
     }
 }
 
-/// Serialize f64 as String
-pub fn as_str_f64<S>(
-    num: &f64,
+/// Serialize f64 as a plain decimal String
+pub fn as_str_f64<T, S>(
+    num: &T,
     serializer: S,
 ) -> Result<S::Ok, S::Error>
 where
+    T: PlainDecimal,
     S: serde::Serializer,
 {
-    serializer.serialize_str(&num.to_string())
+    serializer.serialize_str(&num.to_plain_string())
+}
+
+/// Boolean as the `"true"`/`"false"` string exchanges expect in request params.
+pub fn bool_str(value: bool) -> &'static str {
+    if value {
+        "true"
+    } else {
+        "false"
+    }
 }
 
 /// Serialize Option<bool> as String ("true" or "false"), or skip if None
@@ -34,7 +260,7 @@ where
     S: serde::Serializer,
 {
     match b {
-        Some(val) => serializer.serialize_some(if *val { "true" } else { "false" }),
+        Some(val) => serializer.serialize_some(bool_str(*val)),
         None => serializer.serialize_none(),
     }
 }
@@ -51,6 +277,39 @@ where
     })
 }
 
+/// Deserialize an [`Amount`] sent as a string (`"0.5"`) or a number; an empty string is zero.
+pub fn amount_from_str<'de, D>(deserializer: D) -> Result<Amount, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    use serde::de::Error as _;
+    let raw = match serde_json::Value::deserialize(deserializer)? {
+        serde_json::Value::String(s) => s,
+        serde_json::Value::Number(n) => n.to_string(),
+        serde_json::Value::Null => return Ok(Amount::default()),
+        other => return Err(D::Error::custom(format!("expected a number, got {other}"))),
+    };
+    if raw.is_empty() {
+        return Ok(Amount::default());
+    }
+    raw.parse::<Amount>()
+        .map_err(|e| D::Error::custom(format!("invalid number {raw:?}: {e}")))
+}
+
+/// Deserialize an ID sent as a string or a number into a `String`; null is an empty string.
+pub fn id_from_str_or_int<'de, D>(deserializer: D) -> Result<String, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    use serde::de::Error as _;
+    match serde_json::Value::deserialize(deserializer)? {
+        serde_json::Value::String(s) => Ok(s),
+        serde_json::Value::Number(n) => Ok(n.to_string()),
+        serde_json::Value::Null => Ok(String::new()),
+        other => Err(D::Error::custom(format!("expected an ID, got {other}"))),
+    }
+}
+
 pub fn serialize_as_json_string<T, S>(
     opt: &Option<T>,
     serializer: S,
@@ -68,3 +327,360 @@ where
         None => serializer.serialize_none(),
     }
 }
+
+/// Quote currencies recognized when splitting concatenated symbols such as `BTCUSDT`.
+///
+/// Ordered longest first so `FDUSD` wins over `USD` and `USDC` over `USD`.
+const KNOWN_QUOTES: &[&str] = &[
+    "FDUSD", "USDT", "USDC", "BUSD", "USDE", "TUSD", "DAI", "USD", "EUR", "TRY", "BRL", "BTC",
+    "ETH", "BNB",
+];
+
+/// Exchange-agnostic trading pair.
+///
+/// The canonical form is `BASE/QUOTE` (e.g. `BTC/USDT`). Bybit uses `BTCUSDT` and BingX uses
+/// `BTC-USDT`; [`Symbol::parse`] accepts all three.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Symbol {
+    base: String,
+    quote: String,
+}
+
+impl Symbol {
+    /// Create a symbol from base and quote assets (uppercased).
+    pub fn new(
+        base: &str,
+        quote: &str,
+    ) -> Result<Self, Error> {
+        let base = base.trim().to_uppercase();
+        let quote = quote.trim().to_uppercase();
+        let valid = |s: &str| !s.is_empty() && s.chars().all(|c| c.is_ascii_alphanumeric());
+        if !valid(&base) || !valid(&quote) {
+            return Err(Error::Validation(format!(
+                "Invalid symbol assets: base={base:?}, quote={quote:?}"
+            )));
+        }
+        Ok(Self { base, quote })
+    }
+
+    /// Parse the canonical `BASE/QUOTE` form.
+    pub fn from_canonical(s: &str) -> Result<Self, Error> {
+        match s.split_once('/') {
+            Some((base, quote)) => Self::new(base, quote),
+            None => Err(Error::Validation(format!(
+                "Expected canonical symbol BASE/QUOTE, got {s:?}"
+            ))),
+        }
+    }
+
+    /// Parse any supported format: `BTC/USDT`, `BTC-USDT`, `BTC_USDT` or `BTCUSDT`.
+    ///
+    /// Concatenated symbols are split on the longest known quote suffix.
+    pub fn parse(s: &str) -> Result<Self, Error> {
+        if let Some((base, quote)) = s.split_once(['/', '-', '_']) {
+            return Self::new(base, quote);
+        }
+        let upper = s.trim().to_uppercase();
+        KNOWN_QUOTES
+            .iter()
+            .find_map(|quote| {
+                upper
+                    .strip_suffix(quote)
+                    .filter(|base| !base.is_empty())
+                    .map(|base| Self::new(base, quote))
+            })
+            .unwrap_or_else(|| {
+                Err(Error::Validation(format!(
+                    "Cannot split symbol {s:?} into base and quote"
+                )))
+            })
+    }
+
+    /// Base asset, e.g. `BTC`.
+    pub fn base(&self) -> &str {
+        &self.base
+    }
+
+    /// Quote asset, e.g. `USDT`.
+    pub fn quote(&self) -> &str {
+        &self.quote
+    }
+
+    /// Canonical form, e.g. `BTC/USDT`.
+    pub fn to_canonical(&self) -> String {
+        format!("{}/{}", self.base, self.quote)
+    }
+
+    /// Bybit form, e.g. `BTCUSDT`.
+    pub fn to_bybit(&self) -> String {
+        format!("{}{}", self.base, self.quote)
+    }
+
+    /// BingX form, e.g. `BTC-USDT`.
+    pub fn to_bingx(&self) -> String {
+        format!("{}-{}", self.base, self.quote)
+    }
+}
+
+impl std::fmt::Display for Symbol {
+    fn fmt(
+        &self,
+        f: &mut std::fmt::Formatter<'_>,
+    ) -> std::fmt::Result {
+        write!(f, "{}/{}", self.base, self.quote)
+    }
+}
+
+impl std::str::FromStr for Symbol {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s)
+    }
+}
+
+impl serde::Serialize for Symbol {
+    fn serialize<S>(
+        &self,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_canonical())
+    }
+}
+
+impl<'de> Deserialize<'de> for Symbol {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Self::parse(&s).map_err(serde::de::Error::custom)
+    }
+}
+
+const MONTHS: [&str; 12] = [
+    "JAN", "FEB", "MAR", "APR", "MAY", "JUN", "JUL", "AUG", "SEP", "OCT", "NOV", "DEC",
+];
+
+/// Expiry date of an option contract (UTC calendar date).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct OptionExpiry {
+    year: u16,
+    month: u8,
+    day: u8,
+}
+
+impl OptionExpiry {
+    /// Create an expiry date, rejecting dates that do not exist.
+    pub fn new(
+        year: u16,
+        month: u8,
+        day: u8,
+    ) -> Result<Self, Error> {
+        let leap =
+            (year.is_multiple_of(4) && !year.is_multiple_of(100)) || year.is_multiple_of(400);
+        let days_in_month = match month {
+            1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
+            4 | 6 | 9 | 11 => 30,
+            2 if leap => 29,
+            2 => 28,
+            _ => 0,
+        };
+        if day == 0 || day > days_in_month {
+            return Err(Error::Validation(format!(
+                "Invalid option expiry date {year:04}-{month:02}-{day:02}"
+            )));
+        }
+        Ok(Self { year, month, day })
+    }
+
+    /// Parse Bybit's `DMMMYY` form, e.g. `29MAR24` or `5APR24`.
+    pub fn parse(s: &str) -> Result<Self, Error> {
+        let invalid = || {
+            Error::Validation(format!(
+                "Invalid option expiry {s:?}, expected e.g. 29MAR24"
+            ))
+        };
+        let s = s.trim().to_uppercase();
+        let digits = s.chars().take_while(char::is_ascii_digit).count();
+        if !(1..=2).contains(&digits) || s.len() != digits + 5 || !s.is_ascii() {
+            return Err(invalid());
+        }
+        let day: u8 = s[..digits].parse().map_err(|_| invalid())?;
+        let month = MONTHS
+            .iter()
+            .position(|m| *m == &s[digits..digits + 3])
+            .ok_or_else(invalid)?;
+        let year: u16 = s[digits + 3..].parse().map_err(|_| invalid())?;
+        Self::new(2000 + year, month as u8 + 1, day)
+    }
+
+    pub fn year(&self) -> u16 {
+        self.year
+    }
+
+    pub fn month(&self) -> u8 {
+        self.month
+    }
+
+    pub fn day(&self) -> u8 {
+        self.day
+    }
+
+    /// ISO form, e.g. `2024-03-29`.
+    pub fn to_iso(&self) -> String {
+        format!("{:04}-{:02}-{:02}", self.year, self.month, self.day)
+    }
+}
+
+impl std::fmt::Display for OptionExpiry {
+    fn fmt(
+        &self,
+        f: &mut std::fmt::Formatter<'_>,
+    ) -> std::fmt::Result {
+        write!(
+            f,
+            "{}{}{:02}",
+            self.day,
+            MONTHS[usize::from(self.month) - 1],
+            self.year % 100
+        )
+    }
+}
+
+/// Call or put.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum OptionKind {
+    Call,
+    Put,
+}
+
+impl OptionKind {
+    /// Bybit suffix: `C` or `P`.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            OptionKind::Call => "C",
+            OptionKind::Put => "P",
+        }
+    }
+}
+
+impl std::fmt::Display for OptionKind {
+    fn fmt(
+        &self,
+        f: &mut std::fmt::Formatter<'_>,
+    ) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Bybit option contract symbol, e.g. `BTC-29MAR24-80000-C`.
+///
+/// USDT-settled contracts carry the settle coin as a fifth part (`BTC-29MAR24-80000-C-USDT`);
+/// it is kept in [`OptionSymbol::settle`] and written back when formatting.
+#[derive(Debug, Clone, PartialEq)]
+pub struct OptionSymbol {
+    pub base: String,
+    pub expiry: OptionExpiry,
+    pub strike: Amount,
+    pub kind: OptionKind,
+    pub settle: Option<String>,
+}
+
+impl OptionSymbol {
+    /// Parse `BASE-DMMMYY-STRIKE-C|P`, optionally followed by `-SETTLE`.
+    pub fn parse(s: &str) -> Result<Self, Error> {
+        let invalid = |reason: &str| {
+            Error::Validation(format!(
+                "Invalid option symbol {s:?} ({reason}), expected e.g. BTC-29MAR24-80000-C"
+            ))
+        };
+        let upper = s.trim().to_uppercase();
+        let parts: Vec<&str> = upper.split('-').collect();
+        if !(4..=5).contains(&parts.len()) {
+            return Err(invalid("wrong number of parts"));
+        }
+        let is_asset = |p: &str| !p.is_empty() && p.chars().all(|c| c.is_ascii_alphanumeric());
+        if !is_asset(parts[0]) {
+            return Err(invalid("bad base asset"));
+        }
+        let expiry = OptionExpiry::parse(parts[1])?;
+        let strike: Amount = parts[2]
+            .parse()
+            .ok()
+            .filter(|strike| *strike > Amount::default())
+            .ok_or_else(|| invalid("bad strike"))?;
+        let kind = match parts[3] {
+            "C" => OptionKind::Call,
+            "P" => OptionKind::Put,
+            _ => return Err(invalid("kind must be C or P")),
+        };
+        let settle = match parts.get(4) {
+            Some(settle) if is_asset(settle) => Some(settle.to_string()),
+            Some(_) => return Err(invalid("bad settle coin")),
+            None => None,
+        };
+        Ok(Self {
+            base: parts[0].to_string(),
+            expiry,
+            strike,
+            kind,
+            settle,
+        })
+    }
+
+    /// Bybit form, e.g. `BTC-29MAR24-80000-C`.
+    pub fn to_bybit(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl std::fmt::Display for OptionSymbol {
+    fn fmt(
+        &self,
+        f: &mut std::fmt::Formatter<'_>,
+    ) -> std::fmt::Result {
+        write!(
+            f,
+            "{}-{}-{}-{}",
+            self.base, self.expiry, self.strike, self.kind
+        )?;
+        if let Some(settle) = &self.settle {
+            write!(f, "-{settle}")?;
+        }
+        Ok(())
+    }
+}
+
+impl std::str::FromStr for OptionSymbol {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s)
+    }
+}
+
+impl serde::Serialize for OptionSymbol {
+    fn serialize<S>(
+        &self,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for OptionSymbol {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Self::parse(&s).map_err(serde::de::Error::custom)
+    }
+}
@@ -1,8 +1,15 @@
-//! List all implemented public methods on BybitClient and BingxClient (using linkme).
+//! List all implemented public methods on BybitClient, BingxClient and OkxClient (using linkme).
 //! Compact, pretty output: lists of methods in nice columns.
+//!
+//! Also checks the registries against the sources: every trait method that is not a `todo!()`
+//! stub must be registered exactly once, and every registered name must be such a method.
+
+use std::collections::BTreeSet;
+use std::path::{Path, PathBuf};
 
 use trade_sdk::bingx::BINGX_IMPLEMENTED;
 use trade_sdk::bybit::BYBIT_IMPLEMENTED;
+use trade_sdk::okx::OKX_IMPLEMENTED;
 
 /// Print the methods in a compact table (2 columns) with a given title.
 fn pretty_print_methods(
@@ -32,9 +39,11 @@ fn pretty_print_methods(
 fn print_all_implemented_methods() {
     let mut bybit_methods: Vec<_> = BYBIT_IMPLEMENTED.iter().cloned().collect();
     let mut bingx_methods: Vec<_> = BINGX_IMPLEMENTED.iter().cloned().collect();
+    let mut okx_methods: Vec<_> = OKX_IMPLEMENTED.iter().cloned().collect();
 
     bybit_methods.sort();
     bingx_methods.sort();
+    okx_methods.sort();
 
     pretty_print_methods(
         &format!("BybitClient methods ({}):", bybit_methods.len()),
@@ -44,4 +53,120 @@ fn print_all_implemented_methods() {
         &format!("BingxClient methods ({}):", bingx_methods.len()),
         &bingx_methods,
     );
+    pretty_print_methods(
+        &format!("OkxClient methods ({}):", okx_methods.len()),
+        &okx_methods,
+    );
+}
+
+/// All `.rs` files under `dir`, recursively.
+fn rust_sources(dir: &Path) -> Vec<PathBuf> {
+    let mut files = Vec::new();
+    for entry in std::fs::read_dir(dir).unwrap() {
+        let path = entry.unwrap().path();
+        if path.is_dir() {
+            files.extend(rust_sources(&path));
+        } else if path.extension().is_some_and(|ext| ext == "rs") {
+            files.push(path);
+        }
+    }
+    files
+}
+
+/// Identifiers that directly follow `marker` in `text`.
+fn names_after(
+    text: &str,
+    marker: &str,
+) -> Vec<String> {
+    text.match_indices(marker)
+        .map(|(i, _)| {
+            text[i + marker.len()..]
+                .chars()
+                .take_while(|c| c.is_alphanumeric() || *c == '_')
+                .collect::<String>()
+        })
+        .filter(|name| !name.is_empty())
+        .collect()
+}
+
+/// Trait methods of a client that have a real implementation, i.e. are not `todo!()` stubs.
+fn implemented_trait_methods(exchange: &str) -> BTreeSet<String> {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("src/clients")
+        .join(exchange);
+    let mut declared = BTreeSet::new();
+    let mut stubs = BTreeSet::new();
+    for path in rust_sources(&root) {
+        let text = std::fs::read_to_string(&path).unwrap();
+        if path.components().any(|c| c.as_os_str() == "traits") || path.ends_with("traits.rs") {
+            declared.extend(names_after(&text, "async fn "));
+        } else {
+            stubs.extend(names_after(&text, "todo!(\""));
+        }
+    }
+    assert!(
+        !declared.is_empty(),
+        "no trait methods found for {exchange}"
+    );
+    declared.difference(&stubs).cloned().collect()
+}
+
+/// Problems with a registry: duplicates, orphans (not an implemented method) and missing entries.
+fn registry_problems(
+    registered: &[&str],
+    implemented: &BTreeSet<String>,
+) -> Vec<String> {
+    let mut problems = Vec::new();
+    let mut seen = BTreeSet::new();
+    for name in registered {
+        if !seen.insert(*name) {
+            problems.push(format!("duplicate entry {name}"));
+        }
+        if !implemented.contains(*name) {
+            problems.push(format!("orphan entry {name}: no implemented trait method"));
+        }
+    }
+    for name in implemented {
+        if !seen.contains(name.as_str()) {
+            problems.push(format!("{name} is implemented but not registered"));
+        }
+    }
+    problems
+}
+
+#[test]
+fn bybit_registry_matches_trait_methods() {
+    let problems = registry_problems(&BYBIT_IMPLEMENTED, &implemented_trait_methods("bybit"));
+    assert!(problems.is_empty(), "BYBIT_IMPLEMENTED: {problems:#?}");
+}
+
+#[test]
+fn bingx_registry_matches_trait_methods() {
+    let problems = registry_problems(&BINGX_IMPLEMENTED, &implemented_trait_methods("bingx"));
+    assert!(problems.is_empty(), "BINGX_IMPLEMENTED: {problems:#?}");
+}
+
+#[test]
+fn okx_registry_matches_trait_methods() {
+    let problems = registry_problems(&OKX_IMPLEMENTED, &implemented_trait_methods("okx"));
+    assert!(problems.is_empty(), "OKX_IMPLEMENTED: {problems:#?}");
+}
+
+#[test]
+fn registry_problems_flags_duplicates_and_orphans() {
+    let implemented: BTreeSet<String> = ["get_kline", "place_order"]
+        .into_iter()
+        .map(String::from)
+        .collect();
+
+    let problems = registry_problems(&["get_kline", "get_kline", "get_tickers"], &implemented);
+
+    assert_eq!(
+        problems,
+        [
+            "duplicate entry get_kline",
+            "orphan entry get_tickers: no implemented trait method",
+            "place_order is implemented but not registered",
+        ]
+    );
 }
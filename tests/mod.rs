@@ -6,7 +6,70 @@
 mod benchmark;
 mod get_all_methods;
 
+mod test_amount;
+mod test_audit;
+mod test_auth_override;
+mod test_bingx_account;
+mod test_bingx_amend;
 mod test_bingx_auth;
+mod test_bingx_batch;
+mod test_bingx_bool_params;
+mod test_bingx_broker;
+mod test_bingx_demo;
+mod test_bingx_models;
+mod test_bingx_order_validation;
+mod test_bingx_signature_scheme;
+mod test_bingx_spot;
+mod test_bingx_swap;
+mod test_bingx_time_sync;
+mod test_bybit_account;
+mod test_bybit_asset;
 mod test_bybit_auth;
+mod test_bybit_batch;
+mod test_bybit_cancel_all;
+mod test_bybit_categories;
+mod test_bybit_chunked;
+mod test_bybit_defaults;
+mod test_bybit_export;
+mod test_bybit_market;
+mod test_bybit_models;
+mod test_bybit_position_mode;
+mod test_bybit_sign_type;
+mod test_bybit_trading_stop_validation;
+mod test_bybit_types;
+mod test_cache;
+mod test_cancellation;
 mod test_client;
+mod test_client_clone;
+mod test_client_close;
+mod test_drift;
+mod test_dyn_client;
+mod test_error_log_filter;
+mod test_errors;
+mod test_idempotency;
+mod test_implemented;
+mod test_key_pool;
+mod test_leverage_limits;
+mod test_max_concurrency;
 mod test_multiclient;
+mod test_observer;
+mod test_okx_client;
+mod test_okx_signing;
+mod test_pagination;
+mod test_pool_stats;
+mod test_proxy;
+mod test_recycle;
+mod test_redirect;
+mod test_request_raw;
+mod test_response_cache;
+mod test_response_limit;
+mod test_retry_metrics;
+mod test_shutdown;
+mod test_signing;
+mod test_symbol;
+mod test_testkit;
+mod test_time_source;
+mod test_tls;
+mod test_tracing;
+mod test_unified;
+mod test_warmup;
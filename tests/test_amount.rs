@@ -0,0 +1,116 @@
+//! Tests for price/quantity serialization in order parameters.
+
+use trade_sdk::bybit::types::{PlaceOrderParams, PositionIdx, SetTradingStopParams, TpSlMode};
+use trade_sdk::{amount_from_f64, amount_to_f64, Amount, PlainDecimal};
+
+fn amount(s: &str) -> Amount {
+    s.parse().unwrap()
+}
+
+#[test]
+fn test_place_order_amounts_are_plain_strings() {
+    let params = PlaceOrderParams {
+        symbol: "BTCUSDT".to_owned(),
+        qty: amount("0.0000000001"),
+        price: Some(amount("1000000.5")),
+        trigger_price: Some(amount("0.1")),
+        ..Default::default()
+    };
+    let body = serde_json::to_value(&params).unwrap();
+
+    assert_eq!(body["qty"], "0.0000000001");
+    assert_eq!(body["price"], "1000000.5");
+    assert_eq!(body["triggerPrice"], "0.1");
+}
+
+#[test]
+fn test_trading_stop_amounts_are_plain_strings() {
+    let params = SetTradingStopParams {
+        symbol: "BTCUSDT".to_owned(),
+        tpsl_mode: TpSlMode::Partial,
+        position_idx: PositionIdx::OneWay,
+        take_profit: Some(amount("1000000.5")),
+        stop_loss: None,
+        trailing_stop: None,
+        tp_trigger_by: None,
+        sl_trigger_by: None,
+        active_price: None,
+        tp_size: Some(amount("0.0000000001")),
+        sl_size: None,
+        tp_limit_price: None,
+        sl_limit_price: None,
+        tp_order_type: None,
+        sl_order_type: None,
+    };
+    let body = serde_json::to_value(&params).unwrap();
+
+    assert_eq!(body["takeProfit"], "1000000.5");
+    assert_eq!(body["tpSize"], "0.0000000001");
+    assert!(body.get("stopLoss").is_none());
+}
+
+#[cfg(feature = "decimal")]
+#[test]
+fn test_decimal_amounts_keep_trailing_zeros() {
+    let params = PlaceOrderParams {
+        symbol: "BTCUSDT".to_owned(),
+        qty: amount("1.50"),
+        price: Some(amount("1000000.500")),
+        ..Default::default()
+    };
+    let body = serde_json::to_value(&params).unwrap();
+    assert_eq!(body["qty"], "1.50");
+    assert_eq!(body["price"], "1000000.500");
+}
+
+#[test]
+fn test_amount_from_f64() {
+    assert_eq!(amount_from_f64(0.1 + 0.2).unwrap().to_plain_string(), "0.3");
+    assert_eq!(amount_to_f64(amount("1.5")), 1.5);
+    assert!(matches!(
+        amount_from_f64(f64::NAN),
+        Err(trade_sdk::Error::Validation(_))
+    ));
+}
+
+fn transfer_amount(value: f64) -> String {
+    use trade_sdk::bingx::types::{AccountType, UniversalTransferParams};
+    let params =
+        UniversalTransferParams::new(&AccountType::Spot, &AccountType::UsdtMPerp, "USDT", value)
+            .unwrap();
+    serde_json::to_value(&params).unwrap()["amount"]
+        .as_str()
+        .unwrap()
+        .to_owned()
+}
+
+#[test]
+fn test_f64_amounts_have_no_exponent() {
+    let cases = [
+        (1e-8, "0.00000001"),
+        (123_456_789.0, "123456789"),
+        (0.1 + 0.2, "0.3"),
+        (1e-10, "0.0000000001"),
+        (1e21, "1000000000000000000000"),
+        (0.5, "0.5"),
+        (1500.0, "1500"),
+    ];
+    for (value, expected) in cases {
+        let s = transfer_amount(value);
+        assert_eq!(s, expected, "{value:e}");
+        assert!(!s.contains(['e', 'E']));
+    }
+}
+
+#[test]
+fn test_f64_order_params_have_no_exponent() {
+    let params = PlaceOrderParams {
+        symbol: "BTCUSDT".to_owned(),
+        qty: amount_from_f64(1e-8).unwrap(),
+        price: Some(amount_from_f64(0.1 + 0.2).unwrap()),
+        ..Default::default()
+    };
+    let body = serde_json::to_value(&params).unwrap();
+    assert_eq!(body["qty"], "0.00000001");
+    assert_eq!(body["price"], "0.3");
+}
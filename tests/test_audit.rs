@@ -0,0 +1,197 @@
+//! Tests for the `audit` request log.
+#![cfg(feature = "audit")]
+
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+
+use reqwest::Method;
+use serde_json::{json, Value};
+use trade_sdk::audit::{AuditRecord, AuditSink, FileAuditSink};
+use trade_sdk::bingx::BingxClient;
+use trade_sdk::bybit::BybitClient;
+use trade_sdk::testkit::MockExchange;
+
+/// Mock exchange answering every request with `body`.
+async fn mock_exchange(body: Value) -> MockExchange {
+    let exchange = MockExchange::start().await;
+    exchange.respond_to_any(body);
+    exchange
+}
+
+/// Fresh path in the temp directory, removed with its backup when dropped.
+struct TempLog(PathBuf);
+
+impl TempLog {
+    fn new() -> Self {
+        Self(std::env::temp_dir().join(format!("trade-sdk-audit-{}.jsonl", uuid::Uuid::now_v7())))
+    }
+
+    fn backup(&self) -> PathBuf {
+        let mut backup = self.0.clone().into_os_string();
+        backup.push(".1");
+        backup.into()
+    }
+
+    fn lines(&self) -> Vec<Value> {
+        std::fs::read_to_string(&self.0)
+            .unwrap()
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect()
+    }
+}
+
+impl Drop for TempLog {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_file(&self.0);
+        let _ = std::fs::remove_file(self.backup());
+    }
+}
+
+fn bingx_client(url: &str) -> BingxClient {
+    BingxClient::new(
+        Some("audit-key".to_string()),
+        Some("audit-secret".to_string()),
+        false,
+        5000,
+        None,
+    )
+    .unwrap()
+    .with_base_url(url)
+}
+
+fn bingx_ok() -> Value {
+    json!({"code": 0, "msg": "", "data": {"balance": "1"}})
+}
+
+#[tokio::test]
+async fn test_signed_get_writes_one_masked_line() {
+    let log = TempLog::new();
+    let exchange = mock_exchange(bingx_ok()).await;
+    let mut client = bingx_client(&exchange.base_url());
+    client.set_audit_sink(Arc::new(FileAuditSink::new(&log.0, 1024 * 1024).unwrap()));
+
+    client
+        .request_raw(Method::GET, "/openApi/swap/v3/user/balance", None, true)
+        .await
+        .unwrap();
+
+    let lines = log.lines();
+    assert_eq!(lines.len(), 1);
+    let line = &lines[0];
+    assert_eq!(line["exchange"], "bingx");
+    assert_eq!(line["method"], "GET");
+    assert_eq!(line["endpoint"], "/openApi/swap/v3/user/balance");
+    assert_eq!(line["status"], 200);
+    assert_eq!(line["ret_code"], 0);
+    assert_eq!(line["response"]["data"]["balance"], "1");
+    assert!(line["timestamp"].as_i64().unwrap() > 0);
+    let logged_url = line["url"].as_str().unwrap();
+    assert!(logged_url.ends_with("signature=***"), "{logged_url}");
+    assert!(!std::fs::read_to_string(&log.0)
+        .unwrap()
+        .contains("audit-secret"));
+}
+
+#[tokio::test]
+async fn test_signed_post_body_masked() {
+    let log = TempLog::new();
+    let exchange = mock_exchange(bingx_ok()).await;
+    let mut client = bingx_client(&exchange.base_url());
+    client.set_audit_sink(Arc::new(FileAuditSink::new(&log.0, 1024 * 1024).unwrap()));
+    let params = HashMap::from([("symbol".to_string(), json!("BTC-USDT"))]);
+
+    client
+        .request_raw(
+            Method::POST,
+            "/openApi/swap/v2/trade/leverage",
+            Some(&params),
+            true,
+        )
+        .await
+        .unwrap();
+
+    let lines = log.lines();
+    assert_eq!(lines.len(), 1);
+    assert_eq!(lines[0]["body"]["signature"], "***");
+    assert_eq!(lines[0]["body"]["symbol"], "BTC-USDT");
+}
+
+/// Sink keeping each record as JSON.
+#[derive(Default)]
+struct Collect(Mutex<Vec<Value>>);
+
+impl AuditSink for Collect {
+    fn record(
+        &self,
+        record: &AuditRecord<'_>,
+    ) {
+        self.0.lock().unwrap().push(record.to_json());
+    }
+}
+
+#[tokio::test]
+async fn test_exchange_error_recorded_with_response() {
+    let exchange = mock_exchange(json!({
+        "retCode": 10001,
+        "retMsg": "params error",
+        "result": {},
+        "retExtInfo": {},
+        "time": 1
+    }))
+    .await;
+    let sink = Arc::new(Collect::default());
+    let mut client = BybitClient::new(None, None, false, false, 5000, None)
+        .unwrap()
+        .with_base_url(&exchange.base_url());
+    client.set_audit_sink(sink.clone());
+
+    let result = client
+        .request_raw(Method::GET, "/v5/market/time", None, false)
+        .await;
+
+    assert!(result.is_err());
+    let records = sink.0.lock().unwrap();
+    assert_eq!(records.len(), 1);
+    assert_eq!(records[0]["exchange"], "bybit");
+    assert_eq!(records[0]["ret_code"], 10001);
+    assert_eq!(records[0]["response"]["retMsg"], "params error");
+    assert!(records[0]["error"]
+        .as_str()
+        .unwrap()
+        .contains("params error"));
+}
+
+#[test]
+fn test_file_sink_rotates() {
+    let log = TempLog::new();
+    let sink = FileAuditSink::new(&log.0, 300).unwrap();
+    let method = Method::GET;
+    let record = AuditRecord {
+        timestamp_ms: 1,
+        exchange: "bybit",
+        method: &method,
+        endpoint: "/v5/market/time",
+        url: "https://api.bybit.com/v5/market/time".to_string(),
+        body: None,
+        status: Some(200),
+        ret_code: Some(0),
+        response: None,
+        error: None,
+    };
+
+    for _ in 0..3 {
+        sink.record(&record);
+    }
+
+    // Each line is ~200 bytes, so every write after the first starts a new file.
+    assert_eq!(log.lines().len(), 1);
+    assert_eq!(
+        std::fs::read_to_string(log.backup())
+            .unwrap()
+            .lines()
+            .count(),
+        1
+    );
+}
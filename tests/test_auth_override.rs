@@ -0,0 +1,178 @@
+//! Tests for signing single requests with override credentials.
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+use reqwest::Method;
+use serde_json::{json, Value};
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpListener;
+use trade_sdk::bingx::{signing as bingx_signing, BingxClient};
+use trade_sdk::bybit::{signing as bybit_signing, BybitClient};
+
+/// Raw text of each request received.
+type Recorded = Arc<Mutex<Vec<String>>>;
+
+/// Serve every connection with `body`, recording the raw request (bodyless GETs only).
+async fn spawn_server(
+    body: Value,
+    requests: Recorded,
+) -> String {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    tokio::spawn(async move {
+        while let Ok((mut socket, _)) = listener.accept().await {
+            let body = body.to_string();
+            let requests = Arc::clone(&requests);
+            tokio::spawn(async move {
+                let mut buf = Vec::new();
+                let mut chunk = [0u8; 4096];
+                while !String::from_utf8_lossy(&buf).contains("\r\n\r\n") {
+                    let n = socket.read(&mut chunk).await.unwrap();
+                    if n == 0 {
+                        break;
+                    }
+                    buf.extend_from_slice(&chunk[..n]);
+                }
+                requests
+                    .lock()
+                    .unwrap()
+                    .push(String::from_utf8_lossy(&buf).into_owned());
+                let response = format!(
+                    "HTTP/1.1 200 OK\r\ncontent-type: application/json\r\ncontent-length: {}\r\nconnection: close\r\n\r\n{}",
+                    body.len(),
+                    body
+                );
+                let _ = socket.write_all(response.as_bytes()).await;
+            });
+        }
+    });
+    format!("http://{addr}")
+}
+
+/// Value of header `name` in a raw request.
+fn header<'a>(
+    request: &'a str,
+    name: &str,
+) -> &'a str {
+    request
+        .lines()
+        .find_map(|line| {
+            let (key, value) = line.split_once(':')?;
+            key.eq_ignore_ascii_case(name).then(|| value.trim())
+        })
+        .unwrap_or_else(|| panic!("header {name} missing from {request}"))
+}
+
+fn params() -> HashMap<String, Value> {
+    HashMap::from([("accountType".to_string(), json!("UNIFIED"))])
+}
+
+#[tokio::test]
+async fn test_bybit_get_with_auth_signs_with_override_key() {
+    let requests = Recorded::default();
+    let body = json!({"retCode": 0, "retMsg": "OK", "result": {}, "retExtInfo": {}, "time": 1});
+    let url = spawn_server(body, Arc::clone(&requests)).await;
+    let client = BybitClient::new(
+        Some("main-key".to_string()),
+        Some("main-secret".to_string()),
+        false,
+        false,
+        5000,
+        None,
+    )
+    .unwrap()
+    .with_base_url(&url);
+
+    client
+        .get_with_auth(
+            "/v5/account/wallet-balance",
+            Some(&params()),
+            Some(("sub-key", "sub-secret")),
+        )
+        .await
+        .unwrap();
+
+    let request = requests.lock().unwrap()[0].clone();
+    assert_eq!(header(&request, "x-bapi-api-key"), "sub-key");
+    let timestamp: i64 = header(&request, "x-bapi-timestamp").parse().unwrap();
+    let payload = bybit_signing::prepare_payload(&Method::GET, &params());
+    let expected =
+        bybit_signing::generate_signature("sub-key", "sub-secret", 5000, &payload, timestamp)
+            .unwrap();
+    let stored =
+        bybit_signing::generate_signature("main-key", "main-secret", 5000, &payload, timestamp)
+            .unwrap();
+    assert_eq!(header(&request, "x-bapi-sign"), expected);
+    assert_ne!(expected, stored);
+}
+
+#[tokio::test]
+async fn test_bybit_with_auth_none_uses_stored_key() {
+    let requests = Recorded::default();
+    let body = json!({"retCode": 0, "retMsg": "OK", "result": {}, "retExtInfo": {}, "time": 1});
+    let url = spawn_server(body, Arc::clone(&requests)).await;
+    let client = BybitClient::new(
+        Some("main-key".to_string()),
+        Some("main-secret".to_string()),
+        false,
+        false,
+        5000,
+        None,
+    )
+    .unwrap()
+    .with_base_url(&url);
+
+    client
+        .get_with_auth("/v5/account/wallet-balance", Some(&params()), None)
+        .await
+        .unwrap();
+
+    let request = requests.lock().unwrap()[0].clone();
+    assert_eq!(header(&request, "x-bapi-api-key"), "main-key");
+}
+
+#[tokio::test]
+async fn test_bybit_with_auth_none_without_stored_key_fails() {
+    let client = BybitClient::new(None, None, false, false, 5000, None)
+        .unwrap()
+        .with_base_url("http://127.0.0.1:9");
+
+    let result = client.post_with_auth("/v5/order/create", None, None).await;
+
+    assert!(
+        matches!(result, Err(trade_sdk::Error::Auth(_))),
+        "{result:?}"
+    );
+}
+
+#[tokio::test]
+async fn test_bingx_get_with_auth_signs_with_override_key() {
+    let requests = Recorded::default();
+    let body = json!({"code": 0, "msg": "", "data": {}});
+    let url = spawn_server(body, Arc::clone(&requests)).await;
+    // Client without stored credentials: the override alone must be enough.
+    let client = BingxClient::new(None, None, false, 5000, None)
+        .unwrap()
+        .with_base_url(&url);
+    let params = HashMap::from([("symbol".to_string(), json!("BTC-USDT"))]);
+
+    client
+        .get_with_auth(
+            "/openApi/swap/v2/user/balance",
+            Some(&params),
+            Some(("sub-key", "sub-secret")),
+        )
+        .await
+        .unwrap();
+
+    let request = requests.lock().unwrap()[0].clone();
+    assert_eq!(header(&request, "x-bx-apikey"), "sub-key");
+    let target = request.split_whitespace().nth(1).unwrap();
+    let query = target.split_once('?').unwrap().1;
+    let (signed, signature) = query.split_once("&signature=").unwrap();
+    assert_eq!(
+        signature,
+        bingx_signing::generate_signature("sub-secret", signed)
+    );
+}
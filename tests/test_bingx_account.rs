@@ -0,0 +1,245 @@
+//! Tests for BingX account and wallet request building (no credentials required).
+
+use serde_json::json;
+use trade_sdk::bingx::{
+    traits::{
+        account::{FundApi, SubAccountApi},
+        swap::AccountApi,
+    },
+    types::{universal_transfer_type, AccountType, ApiResponse, UniversalTransferParams},
+    BingxClient, BINGX_IMPLEMENTED,
+};
+use trade_sdk::Error;
+
+fn public_client() -> BingxClient {
+    BingxClient::new(None, None, false, 5000, None).expect("failed to create BingxClient")
+}
+
+/// Client with dummy credentials: requests get signed and sent, but the exchange rejects them.
+fn dummy_signed_client() -> BingxClient {
+    BingxClient::new(
+        Some("dummy-api-key".to_owned()),
+        Some("dummy-api-secret".to_owned()),
+        false,
+        5000,
+        None,
+    )
+    .expect("failed to create BingxClient")
+}
+
+/// Assert a signed request with dummy credentials reached `path` with `query` params.
+///
+/// With network access the exchange rejects the dummy key; without it the URL is taken
+/// from the connection error.
+fn check_signed_request(
+    path: &str,
+    query: &[(&str, &str)],
+    result: trade_sdk::Result<ApiResponse<serde_json::Value>>,
+) {
+    match result {
+        Err(Error::Exchange(e)) => println!("{path}: rejected by exchange as expected ({e})"),
+        Err(Error::Http(e)) if !e.is_status() => {
+            let url = e.url().expect("request error without URL");
+            assert_eq!(url.path(), path);
+            let pairs: Vec<(String, String)> = url.query_pairs().into_owned().collect();
+            for (k, v) in query {
+                assert!(
+                    pairs.iter().any(|(pk, pv)| pk == k && pv == v),
+                    "{path}: missing query param {k}={v} in {url}"
+                );
+            }
+            // GET requests carry the signature in the query; POST sends it in the body.
+            if url.query().is_some() {
+                assert!(pairs.iter().any(|(k, _)| k == "signature"));
+            }
+        }
+        other => panic!("{path}: unexpected result {other:?}"),
+    }
+}
+
+#[test]
+fn test_universal_transfer_type() {
+    let cases = [
+        (AccountType::Spot, AccountType::UsdtMPerp, "FUND_PFUTURES"),
+        (AccountType::UsdtMPerp, AccountType::Spot, "PFUTURES_FUND"),
+        (AccountType::Spot, AccountType::StdFutures, "FUND_SFUTURES"),
+        (
+            AccountType::StdFutures,
+            AccountType::UsdtMPerp,
+            "SFUTURES_PFUTURES",
+        ),
+    ];
+    for (from, to, expected) in cases {
+        assert_eq!(universal_transfer_type(&from, &to).unwrap(), expected);
+    }
+
+    assert!(matches!(
+        universal_transfer_type(&AccountType::Spot, &AccountType::Spot),
+        Err(Error::Validation(_))
+    ));
+    assert!(matches!(
+        universal_transfer_type(&AccountType::Grid, &AccountType::Spot),
+        Err(Error::Validation(_))
+    ));
+}
+
+#[test]
+fn test_universal_transfer_params_serialization() {
+    let params =
+        UniversalTransferParams::new(&AccountType::Spot, &AccountType::UsdtMPerp, "USDT", 0.1)
+            .unwrap();
+    assert_eq!(
+        serde_json::to_value(&params).unwrap(),
+        json!({"type": "FUND_PFUTURES", "asset": "USDT", "amount": "0.1"})
+    );
+
+    let params =
+        UniversalTransferParams::new(&AccountType::UsdtMPerp, &AccountType::Spot, "USDT", 1500.0)
+            .unwrap();
+    assert_eq!(serde_json::to_value(&params).unwrap()["amount"], "1500");
+}
+
+#[tokio::test]
+async fn test_universal_transfer_rejects_bad_amount() {
+    let client = public_client();
+    for amount in [0.0, -5.0, f64::NAN] {
+        assert!(matches!(
+            client
+                .universal_transfer(AccountType::Spot, AccountType::UsdtMPerp, "USDT", amount)
+                .await,
+            Err(Error::Validation(_))
+        ));
+    }
+}
+
+#[test]
+fn test_fund_methods_registered() {
+    for name in ["universal_transfer", "get_transfer_records"] {
+        assert!(BINGX_IMPLEMENTED.contains(&name), "{name} not registered");
+    }
+}
+
+#[tokio::test]
+async fn test_sub_account_requests() {
+    let client = dummy_signed_client();
+    check_signed_request(
+        "/openApi/subAccount/v1/list",
+        &[("page", "1"), ("limit", "50"), ("subUid", "123456")],
+        client
+            .query_sub_account_list(1, 50, Some(123456), None, None)
+            .await,
+    );
+    check_signed_request(
+        "/openApi/subAccount/v1/assets",
+        &[("subUid", "123456")],
+        client.query_sub_account_assets(123456).await,
+    );
+    check_signed_request(
+        "/openApi/subAccount/v1/create",
+        &[],
+        client.create_sub_account("strategybook01", None).await,
+    );
+    check_signed_request(
+        "/openApi/subAccount/v1/apiKey/create",
+        &[],
+        client
+            .create_sub_account_api_key(123456, "bot", &[1, 2, 3], None)
+            .await,
+    );
+}
+
+#[tokio::test]
+async fn test_sub_account_required_params() {
+    let client = public_client();
+    assert!(matches!(
+        client.query_sub_account_list(0, 50, None, None, None).await,
+        Err(Error::Validation(_))
+    ));
+    assert!(matches!(
+        client.query_sub_account_list(1, 0, None, None, None).await,
+        Err(Error::Validation(_))
+    ));
+    assert!(matches!(
+        client
+            .query_sub_account_list(1, 1001, None, None, None)
+            .await,
+        Err(Error::Validation(_))
+    ));
+    assert!(matches!(
+        client.create_sub_account("", None).await,
+        Err(Error::Validation(_))
+    ));
+    assert!(matches!(
+        client
+            .create_sub_account_api_key(123456, "bot", &[], None)
+            .await,
+        Err(Error::Validation(_))
+    ));
+}
+
+#[test]
+fn test_sub_account_methods_registered() {
+    for name in [
+        "create_sub_account",
+        "query_sub_account_list",
+        "create_sub_account_api_key",
+        "query_sub_account_assets",
+    ] {
+        assert!(BINGX_IMPLEMENTED.contains(&name), "{name} not registered");
+    }
+}
+
+#[tokio::test]
+async fn test_swap_account_requests() {
+    let client = dummy_signed_client();
+    check_signed_request(
+        "/openApi/swap/v3/user/balance",
+        &[],
+        client.get_swap_balance().await,
+    );
+    #[allow(deprecated)]
+    let alias = client.get_swap_account_balance().await;
+    check_signed_request("/openApi/swap/v3/user/balance", &[], alias);
+    check_signed_request(
+        "/openApi/swap/v2/user/positions",
+        &[("symbol", "BTC-USDT")],
+        client.get_swap_positions(Some("BTC-USDT")).await,
+    );
+    check_signed_request(
+        "/openApi/swap/v2/user/income",
+        &[
+            ("symbol", "BTC-USDT"),
+            ("incomeType", "FUNDING_FEE"),
+            ("limit", "100"),
+        ],
+        client
+            .get_swap_income_history(Some("BTC-USDT"), Some("FUNDING_FEE"), None, None, Some(100))
+            .await,
+    );
+}
+
+#[tokio::test]
+async fn test_swap_positions_without_symbol() {
+    let client = dummy_signed_client();
+    match client.get_swap_positions(None).await {
+        Err(Error::Exchange(_)) => {}
+        Err(Error::Http(e)) if !e.is_status() => {
+            let url = e.url().expect("request error without URL");
+            assert_eq!(url.path(), "/openApi/swap/v2/user/positions");
+            assert!(!url.query_pairs().any(|(k, _)| k == "symbol"));
+        }
+        other => panic!("unexpected result {other:?}"),
+    }
+}
+
+#[test]
+fn test_swap_account_methods_registered() {
+    for name in [
+        "get_swap_positions",
+        "get_swap_balance",
+        "get_swap_account_balance",
+        "get_swap_income_history",
+    ] {
+        assert!(BINGX_IMPLEMENTED.contains(&name), "{name} not registered");
+    }
+}
@@ -0,0 +1,171 @@
+//! Tests for amending and cancel-replacing BingX swap orders against a local mock server.
+
+use serde_json::{json, Value};
+use trade_sdk::bingx::{
+    traits::swap::TradeApi,
+    types::{OrderSide, PlaceSwapOrderParams, PositionSide, SwapOrderType},
+    BingxClient,
+};
+use trade_sdk::testkit::MockExchange;
+use trade_sdk::{Amount, Error};
+
+/// Mock exchange answering every request with an amended order.
+async fn mock_exchange() -> MockExchange {
+    let exchange = MockExchange::start().await;
+    exchange.respond_to_any(
+        json!({"code": 0, "msg": "", "data": {"order": {"orderId": 1736011869418901234_u64}}}),
+    );
+    exchange
+}
+
+fn amount(s: &str) -> Amount {
+    s.parse().unwrap()
+}
+
+fn client(base_url: &str) -> BingxClient {
+    BingxClient::new(
+        Some("key".to_string()),
+        Some("secret".to_string()),
+        false,
+        5000,
+        None,
+    )
+    .unwrap()
+    .with_base_url(base_url)
+}
+
+#[tokio::test]
+async fn test_amend_swap_order_requires_an_order_id() {
+    let exchange = mock_exchange().await;
+
+    let result = client(&exchange.base_url())
+        .amend_swap_order("BTC-USDT", None, None, None, Some(amount("42000")), None)
+        .await;
+
+    assert!(matches!(result, Err(Error::Validation(_))), "{result:?}");
+    assert!(exchange.requests().is_empty());
+}
+
+#[tokio::test]
+async fn test_amend_swap_order_requires_a_change() {
+    let exchange = mock_exchange().await;
+
+    let result = client(&exchange.base_url())
+        .amend_swap_order("BTC-USDT", Some(1), None, None, None, None)
+        .await;
+
+    assert!(matches!(result, Err(Error::Validation(_))), "{result:?}");
+    assert!(exchange.requests().is_empty());
+}
+
+#[tokio::test]
+async fn test_amend_swap_order_body() {
+    let exchange = mock_exchange().await;
+
+    client(&exchange.base_url())
+        .amend_swap_order(
+            "BTC-USDT",
+            None,
+            Some("maker-1"),
+            Some(amount("0.002")),
+            Some(amount("42100.5")),
+            None,
+        )
+        .await
+        .unwrap();
+
+    let request = &exchange.requests()[0];
+    assert_eq!(
+        request.request_line(),
+        "POST /openApi/swap/v1/trade/modifyOrder"
+    );
+    let body = request.json().unwrap();
+    assert_eq!(body["symbol"], "BTC-USDT");
+    assert_eq!(body["clientOrderId"], "maker-1");
+    assert_eq!(body["quantity"], "0.002");
+    assert_eq!(body["price"], "42100.5");
+    assert!(body.get("orderId").is_none());
+    assert!(body.get("stopPrice").is_none());
+    assert!(body["signature"].is_string());
+}
+
+#[tokio::test]
+async fn test_amend_swap_order_sends_small_amounts_without_exponent() {
+    let exchange = mock_exchange().await;
+
+    client(&exchange.base_url())
+        .amend_swap_order(
+            "PEPE-USDT",
+            Some(1),
+            None,
+            Some(amount("0.00001")),
+            None,
+            Some(amount("0.0000095")),
+        )
+        .await
+        .unwrap();
+
+    let body = exchange.requests()[0].json().unwrap();
+    assert_eq!(body["quantity"], "0.00001");
+    assert_eq!(body["stopPrice"], "0.0000095");
+}
+
+fn replacement_order() -> PlaceSwapOrderParams {
+    PlaceSwapOrderParams {
+        symbol: "BTC-USDT".to_string(),
+        order_type: SwapOrderType::Limit,
+        side: OrderSide::Buy,
+        position_side: Some(PositionSide::Long),
+        price: Some(42050.0),
+        quantity: Some(0.001),
+        client_order_id: Some("maker-2".to_string()),
+        ..Default::default()
+    }
+}
+
+#[tokio::test]
+async fn test_cancel_replace_rejects_unknown_mode() {
+    let exchange = mock_exchange().await;
+
+    let result = client(&exchange.base_url())
+        .cancel_replace_swap_order(1, &replacement_order(), "STOP_ON_FAIL")
+        .await;
+
+    assert!(matches!(result, Err(Error::Validation(_))), "{result:?}");
+    assert!(exchange.requests().is_empty());
+}
+
+#[tokio::test]
+async fn test_cancel_replace_body() {
+    let exchange = mock_exchange().await;
+
+    client(&exchange.base_url())
+        .cancel_replace_swap_order(1736011869418901234, &replacement_order(), "STOP_ON_FAILURE")
+        .await
+        .unwrap();
+
+    let request = &exchange.requests()[0];
+    assert_eq!(
+        request.request_line(),
+        "POST /openApi/swap/v1/trade/cancelReplace"
+    );
+    let mut body = request.json().unwrap();
+    let body = body.as_object_mut().unwrap();
+    assert!(body.remove("signature").unwrap().is_string());
+    assert!(body.remove("timestamp").is_some());
+    assert!(body.remove("recvWindow").is_some());
+    assert_eq!(
+        Value::Object(body.clone()),
+        json!({
+            "cancelOrderId": 1736011869418901234_i64,
+            "cancelReplaceMode": "STOP_ON_FAILURE",
+            "symbol": "BTC-USDT",
+            "type": "LIMIT",
+            "side": "BUY",
+            "positionSide": "LONG",
+            "price": 42050.0,
+            "quantity": 0.001,
+            "clientOrderId": "maker-2"
+        })
+    );
+}
@@ -33,24 +33,17 @@ async fn print_spot_account_assets(client: &BingxClient) -> Result<(), Box<dyn s
                 serde_json::to_string_pretty(&result.data)?
             );
 
-            // result.data is expected to be a Value (serde_json::Value)
-            let balances = result
-                .data
-                .get("balances")
-                .and_then(|b| b.as_array())
-                .cloned()
-                .unwrap_or_else(Vec::new);
-
+            let balances = result.parse_spot_balances()?.balances;
             if balances.is_empty() {
                 println!("No asset balances found in response.");
                 return Ok(());
             }
             println!("Found {} asset balances:", balances.len());
             for asset in balances.iter().take(5) {
-                let asset_name = asset.get("asset").and_then(|v| v.as_str()).unwrap_or("???");
-                let free = asset.get("free").and_then(|v| v.as_str()).unwrap_or("0");
-                let locked = asset.get("locked").and_then(|v| v.as_str()).unwrap_or("0");
-                println!("   {}: free={}, locked={}", asset_name, free, locked);
+                println!(
+                    "   {}: free={}, locked={}",
+                    asset.asset, asset.free, asset.locked
+                );
             }
             Ok(())
         }
@@ -117,6 +110,7 @@ async fn test_bingx_spot_assets_and_time() -> Result<(), Box<dyn std::error::Err
         Some(api_secret.unwrap()),
         demo,
         5000, // recv_window
+        None,
     )?;
 
     // Fetch spot assets and server time
@@ -0,0 +1,84 @@
+//! Tests for placing BingX swap orders in batches against a local mock server.
+
+use serde_json::{json, Value};
+use trade_sdk::bingx::{
+    traits::swap::TradeApi,
+    types::{OrderSide, PlaceSwapOrderParams, PositionSide, SwapOrderType},
+    BingxClient,
+};
+use trade_sdk::testkit::MockExchange;
+use trade_sdk::Error;
+
+/// Mock exchange answering every request with an empty order list.
+async fn mock_exchange() -> MockExchange {
+    let exchange = MockExchange::start().await;
+    exchange.respond_to_any(json!({"code": 0, "msg": "", "data": {"orders": []}}));
+    exchange
+}
+
+fn client(base_url: &str) -> BingxClient {
+    BingxClient::new(
+        Some("key".to_string()),
+        Some("secret".to_string()),
+        false,
+        5000,
+        None,
+    )
+    .unwrap()
+    .with_base_url(base_url)
+}
+
+fn order(price: f64) -> PlaceSwapOrderParams {
+    PlaceSwapOrderParams {
+        symbol: "BTC-USDT".to_string(),
+        order_type: SwapOrderType::Limit,
+        side: OrderSide::Buy,
+        position_side: Some(PositionSide::Long),
+        price: Some(price),
+        quantity: Some(0.001),
+        ..Default::default()
+    }
+}
+
+#[tokio::test]
+async fn test_batch_orders_sent_as_stringified_array() {
+    let exchange = mock_exchange().await;
+
+    client(&exchange.base_url())
+        .place_swap_batch_orders(&[order(42000.0), order(41900.0)])
+        .await
+        .unwrap();
+
+    let request = &exchange.requests()[0];
+    assert_eq!(
+        request.request_line(),
+        "POST /openApi/swap/v2/trade/batchOrders"
+    );
+    let body = request.json().unwrap();
+    let encoded = body["batchOrders"]
+        .as_str()
+        .expect("batchOrders is a string");
+    let orders: Value = serde_json::from_str(encoded).unwrap();
+    assert_eq!(
+        orders,
+        json!([
+            {"symbol": "BTC-USDT", "type": "LIMIT", "side": "BUY", "positionSide": "LONG", "price": 42000.0, "quantity": 0.001},
+            {"symbol": "BTC-USDT", "type": "LIMIT", "side": "BUY", "positionSide": "LONG", "price": 41900.0, "quantity": 0.001}
+        ])
+    );
+}
+
+#[tokio::test]
+async fn test_batch_orders_size_cap() {
+    let exchange = mock_exchange().await;
+    let client = client(&exchange.base_url());
+
+    let five = vec![order(42000.0); 5];
+    assert!(client.place_swap_batch_orders(&five).await.is_ok());
+
+    for orders in [vec![], vec![order(42000.0); 6]] {
+        let result = client.place_swap_batch_orders(&orders).await;
+        assert!(matches!(result, Err(Error::Validation(_))), "{result:?}");
+    }
+    assert_eq!(exchange.requests().len(), 1);
+}
@@ -0,0 +1,90 @@
+//! Tests that every BingX boolean param is sent as exactly `"true"` or `"false"`.
+
+use std::collections::HashMap;
+
+use reqwest::Method;
+use serde_json::{json, Value};
+use trade_sdk::bingx::{
+    signing,
+    traits::{account::SubAccountApi, swap::TradeApi},
+    types::PlaceSwapOrderParams,
+    BingxClient,
+};
+use trade_sdk::testkit::MockExchange;
+
+/// Mock exchange answering every request with an empty order list.
+async fn mock_exchange() -> MockExchange {
+    let exchange = MockExchange::start().await;
+    exchange.respond_to_any(json!({"code": 0, "msg": "", "data": {"orders": []}}));
+    exchange
+}
+
+fn client(base_url: &str) -> BingxClient {
+    BingxClient::new(
+        Some("key".to_string()),
+        Some("secret".to_string()),
+        false,
+        5000,
+        None,
+    )
+    .unwrap()
+    .with_base_url(base_url)
+}
+
+#[test]
+fn test_place_swap_order_bool_fields() {
+    for (value, expected) in [(true, "true"), (false, "false")] {
+        let params = PlaceSwapOrderParams {
+            symbol: "BTC-USDT".to_string(),
+            reduce_only: Some(value),
+            close_position: Some(value),
+            ..Default::default()
+        };
+        let json = serde_json::to_value(&params).unwrap();
+        assert_eq!(json["reduceOnly"], json!(expected));
+        assert_eq!(json["closePosition"], json!(expected));
+    }
+}
+
+#[test]
+fn test_signed_payload_bool_values() {
+    let mut params = HashMap::from([
+        ("a".to_string(), Value::Bool(true)),
+        ("b".to_string(), Value::Bool(false)),
+    ]);
+    let (payload, query) = signing::prepare_payload(&Method::GET, &mut params, 1);
+    assert_eq!(payload, "a=true&b=false&timestamp=1");
+    assert_eq!(query.unwrap(), "a=true&b=false&timestamp=1");
+}
+
+#[tokio::test]
+async fn test_dual_side_position_param() {
+    let exchange = mock_exchange().await;
+    let client = client(&exchange.base_url());
+
+    client.set_swap_position_mode(true).await.unwrap();
+    client.set_swap_position_mode(false).await.unwrap();
+
+    for (request, expected) in exchange.requests().iter().zip(["true", "false"]) {
+        assert_eq!(request.json().unwrap()["dualSidePosition"], json!(expected));
+    }
+}
+
+#[tokio::test]
+async fn test_is_freeze_param() {
+    let exchange = mock_exchange().await;
+    let client = client(&exchange.base_url());
+
+    client
+        .query_sub_account_list(1, 10, None, None, Some(true))
+        .await
+        .unwrap();
+    client
+        .query_sub_account_list(1, 10, None, None, Some(false))
+        .await
+        .unwrap();
+
+    let requests = exchange.requests();
+    assert_eq!(requests[0].query_param("isFeeze").as_deref(), Some("true"));
+    assert_eq!(requests[1].query_param("isFeeze").as_deref(), Some("false"));
+}
@@ -0,0 +1,74 @@
+//! Tests for the BingX broker source key header.
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpListener;
+use trade_sdk::bingx::{signing, traits::common::CommonApi, BingxClient};
+
+/// Serve every connection with a server time response, recording the request head.
+async fn spawn_server(heads: Arc<Mutex<Vec<String>>>) -> String {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    tokio::spawn(async move {
+        while let Ok((mut socket, _)) = listener.accept().await {
+            let heads = Arc::clone(&heads);
+            tokio::spawn(async move {
+                let mut buf = vec![0u8; 8192];
+                let n = socket.read(&mut buf).await.unwrap_or(0);
+                heads
+                    .lock()
+                    .unwrap()
+                    .push(String::from_utf8_lossy(&buf[..n]).to_lowercase());
+                let body = r#"{"code":0,"msg":"","data":{"serverTime":1700000000000}}"#;
+                let response = format!(
+                    "HTTP/1.1 200 OK\r\ncontent-type: application/json\r\ncontent-length: {}\r\nconnection: close\r\n\r\n{}",
+                    body.len(),
+                    body
+                );
+                let _ = socket.write_all(response.as_bytes()).await;
+            });
+        }
+    });
+    format!("http://{addr}")
+}
+
+#[tokio::test]
+async fn test_broker_header_attached() {
+    let heads = Arc::new(Mutex::new(Vec::new()));
+    let base_url = spawn_server(Arc::clone(&heads)).await;
+
+    let client = BingxClient::new(None, None, false, 5000, Some("broker-123".to_string()))
+        .unwrap()
+        .with_base_url(&base_url);
+    client.get_server_time().await.unwrap();
+
+    let client = BingxClient::new(None, None, false, 5000, None)
+        .unwrap()
+        .with_base_url(&base_url);
+    client.get_server_time().await.unwrap();
+
+    let heads = heads.lock().unwrap();
+    assert!(
+        heads[0].contains("\r\nx-source-key: broker-123\r\n"),
+        "{}",
+        heads[0]
+    );
+    assert!(!heads[1].contains("x-source-key"), "{}", heads[1]);
+}
+
+#[test]
+fn test_broker_header_masked_in_logs() {
+    let headers = HashMap::from([
+        ("X-SOURCE-KEY".to_string(), "broker-secret-key".to_string()),
+        ("X-BX-APIKEY".to_string(), "api-key-value".to_string()),
+        ("Content-Type".to_string(), "application/json".to_string()),
+    ]);
+
+    let masked = signing::mask_headers(&headers);
+
+    assert_eq!(masked["x-source-key"], "broker...");
+    assert_eq!(masked["x-bx-apikey"], "api-ke...");
+    assert_eq!(masked["content-type"], "application/json");
+}
@@ -0,0 +1,83 @@
+//! Tests for the BingX demo (VST) mode guard.
+
+use std::sync::{Arc, Mutex};
+
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpListener;
+use trade_sdk::bingx::BingxClient;
+use trade_sdk::Error;
+
+/// Serve every connection with a balance response, recording each request line.
+async fn spawn_server(requests: Arc<Mutex<Vec<String>>>) -> String {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    tokio::spawn(async move {
+        while let Ok((mut socket, _)) = listener.accept().await {
+            let requests = Arc::clone(&requests);
+            tokio::spawn(async move {
+                let mut buf = vec![0u8; 4096];
+                let n = socket.read(&mut buf).await.unwrap_or(0);
+                let text = String::from_utf8_lossy(&buf[..n]).into_owned();
+                let line = text.lines().next().unwrap_or_default().to_string();
+                requests.lock().unwrap().push(line);
+                let body = r#"{"code":0,"msg":"","data":[{"asset":"VST","balance":"100000.0"}]}"#;
+                let response = format!(
+                    "HTTP/1.1 200 OK\r\ncontent-type: application/json\r\ncontent-length: {}\r\nconnection: close\r\n\r\n{}",
+                    body.len(),
+                    body
+                );
+                let _ = socket.write_all(response.as_bytes()).await;
+            });
+        }
+    });
+    format!("http://{addr}")
+}
+
+fn client(demo: bool) -> BingxClient {
+    BingxClient::new(
+        Some("key".to_string()),
+        Some("secret".to_string()),
+        demo,
+        5000,
+        None,
+    )
+    .unwrap()
+}
+
+#[test]
+fn test_is_demo_reflects_constructor_flag() {
+    assert!(client(true).is_demo());
+    assert!(!client(false).is_demo());
+    // Overriding the base URL keeps the mode.
+    assert!(client(true).with_base_url("http://127.0.0.1:9").is_demo());
+}
+
+#[tokio::test]
+async fn test_vst_balance_rejected_on_live_client() {
+    let requests = Arc::new(Mutex::new(Vec::new()));
+    let base_url = spawn_server(Arc::clone(&requests)).await;
+    let client = client(false).with_base_url(&base_url);
+
+    let result = client.get_vst_balance().await;
+
+    assert!(matches!(result, Err(Error::Config(_))), "{result:?}");
+    assert!(requests.lock().unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn test_vst_balance_on_demo_client() {
+    let requests = Arc::new(Mutex::new(Vec::new()));
+    let base_url = spawn_server(Arc::clone(&requests)).await;
+    let client = client(true).with_base_url(&base_url);
+
+    let response = client.get_vst_balance().await.unwrap();
+
+    assert_eq!(response.data[0]["asset"], "VST");
+    let requests = requests.lock().unwrap();
+    assert_eq!(requests.len(), 1);
+    assert!(
+        requests[0].starts_with("GET /openApi/swap/v3/user/balance?"),
+        "{}",
+        requests[0]
+    );
+}
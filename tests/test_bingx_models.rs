@@ -0,0 +1,155 @@
+//! Tests for the typed BingX response models.
+
+use serde_json::json;
+use trade_sdk::bingx::types::ApiResponse;
+use trade_sdk::Amount;
+
+fn amount(s: &str) -> Amount {
+    s.parse().unwrap()
+}
+
+fn response(data: serde_json::Value) -> ApiResponse<serde_json::Value> {
+    serde_json::from_value(json!({"code": 0, "msg": "", "data": data})).unwrap()
+}
+
+#[test]
+fn test_parse_swap_order_history() {
+    // Recorded `/openApi/swap/v2/trade/allOrders` data: one filled limit and one cancelled market order.
+    let response = response(json!({
+        "orders": [
+            {
+                "symbol": "BTC-USDT",
+                "orderId": 1736011869418901234_u64,
+                "side": "BUY",
+                "positionSide": "LONG",
+                "type": "LIMIT",
+                "origQty": "0.0010",
+                "price": "42000.0",
+                "executedQty": "0.0010",
+                "avgPrice": "41998.5",
+                "cumQuote": "41.9985",
+                "stopPrice": "",
+                "profit": "0.0000",
+                "commission": "-0.020999",
+                "status": "FILLED",
+                "time": 1702731995000_u64,
+                "updateTime": 1702731995000_u64,
+                "clientOrderId": "",
+                "leverage": "10X",
+                "workingType": "MARK_PRICE",
+                "onlyOnePosition": false,
+                "reduceOnly": false
+            },
+            {
+                "symbol": "ETH-USDT",
+                "orderId": "1736011869418905678",
+                "side": "SELL",
+                "positionSide": "SHORT",
+                "type": "MARKET",
+                "origQty": "0.05",
+                "price": "",
+                "executedQty": "0",
+                "avgPrice": "0.00",
+                "status": "CANCELLED",
+                "time": 1702732000000_u64,
+                "updateTime": 1702732001000_u64
+            }
+        ]
+    }));
+
+    let orders = response.parse_swap_orders().unwrap();
+
+    assert_eq!(orders.len(), 2);
+    let filled = &orders[0];
+    assert_eq!(filled.order_id, "1736011869418901234");
+    assert_eq!(filled.symbol, "BTC-USDT");
+    assert_eq!(filled.side, "BUY");
+    assert_eq!(filled.position_side, "LONG");
+    assert_eq!(filled.order_type, "LIMIT");
+    assert_eq!(filled.status, "FILLED");
+    assert_eq!(filled.price, amount("42000.0"));
+    assert_eq!(filled.orig_qty, amount("0.0010"));
+    assert_eq!(filled.executed_qty, amount("0.0010"));
+    assert_eq!(filled.avg_price, amount("41998.5"));
+
+    let cancelled = &orders[1];
+    assert_eq!(cancelled.order_id, "1736011869418905678");
+    assert_eq!(cancelled.order_type, "MARKET");
+    assert_eq!(cancelled.price, Amount::default());
+    assert_eq!(cancelled.executed_qty, Amount::default());
+}
+
+#[test]
+fn test_parse_swap_order_details_and_empty_data() {
+    let details = response(json!({
+        "order": {
+            "symbol": "BTC-USDT",
+            "orderId": 1736011869418901234_u64,
+            "side": "BUY",
+            "positionSide": "LONG",
+            "type": "MARKET",
+            "origQty": "0.0010",
+            "executedQty": "0.0010",
+            "avgPrice": "41998.5",
+            "status": "FILLED"
+        }
+    }));
+    let orders = details.parse_swap_orders().unwrap();
+    assert_eq!(orders.len(), 1);
+    assert_eq!(orders[0].avg_price, amount("41998.5"));
+
+    assert!(response(json!({"orders": []}))
+        .parse_swap_orders()
+        .unwrap()
+        .is_empty());
+    assert!(response(serde_json::Value::Null)
+        .parse_swap_orders()
+        .unwrap()
+        .is_empty());
+}
+
+#[test]
+fn test_parse_swap_orders_rejects_bad_number() {
+    let response = response(json!({"orders": [{"orderId": 1, "price": "not-a-price"}]}));
+    assert!(matches!(
+        response.parse_swap_orders(),
+        Err(trade_sdk::Error::Json(_))
+    ));
+}
+
+#[test]
+fn test_parse_spot_balances() {
+    // Recorded `/openApi/spot/v1/account/balance` data.
+    let response = response(json!({
+        "balances": [
+            {"asset": "USDT", "displayName": "USDT", "free": "152.37410812", "locked": "20.5"},
+            {"asset": "BTC", "displayName": "BTC", "free": "0.00041", "locked": "0"},
+            {"asset": "VST", "displayName": "VST", "free": "", "locked": ""}
+        ]
+    }));
+    let balances = response.parse_spot_balances().unwrap();
+    assert_eq!(balances.balances.len(), 3);
+
+    let usdt = &balances.balances[0];
+    assert_eq!(usdt.asset, "USDT");
+    assert_eq!(usdt.free, amount("152.37410812"));
+    assert_eq!(usdt.locked, amount("20.5"));
+
+    assert_eq!(balances.free("btc"), amount("0.00041"));
+    assert_eq!(balances.get("VST").unwrap().free, amount("0"));
+    assert_eq!(balances.get("VST").unwrap().locked, amount("0"));
+    assert_eq!(balances.free("ETH"), amount("0"));
+    assert!(balances.get("ETH").is_none());
+}
+
+#[test]
+fn test_parse_spot_balances_empty() {
+    assert!(response(json!(null))
+        .parse_spot_balances()
+        .unwrap()
+        .balances
+        .is_empty());
+    assert!(response(json!({"balances": "bad"}))
+        .parse_spot_balances()
+        .is_err());
+}
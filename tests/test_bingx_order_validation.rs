@@ -0,0 +1,98 @@
+//! Tests for `PlaceSwapOrderParams::validate` and its use before sending swap orders.
+
+use trade_sdk::bingx::{
+    traits::swap::TradeApi,
+    types::{OrderSide, PlaceSwapOrderParams, SwapOrderType},
+    BingxClient,
+};
+use trade_sdk::Error;
+
+fn limit_order() -> PlaceSwapOrderParams {
+    PlaceSwapOrderParams {
+        symbol: "BTC-USDT".to_string(),
+        order_type: SwapOrderType::Limit,
+        side: OrderSide::Buy,
+        price: Some(42000.0),
+        quantity: Some(0.001),
+        ..Default::default()
+    }
+}
+
+fn assert_validation_error(
+    params: &PlaceSwapOrderParams,
+    needle: &str,
+) {
+    match params.validate() {
+        Err(Error::Validation(msg)) => assert!(msg.contains(needle), "{msg}"),
+        other => panic!("expected validation error mentioning {needle:?}, got {other:?}"),
+    }
+}
+
+#[test]
+fn test_valid_orders_pass() {
+    limit_order().validate().unwrap();
+    PlaceSwapOrderParams {
+        symbol: "ETH-USDT".to_string(),
+        order_type: SwapOrderType::Market,
+        quote_order_qty: Some(100.0),
+        ..Default::default()
+    }
+    .validate()
+    .unwrap();
+}
+
+#[test]
+fn test_symbol_without_hyphen_rejected() {
+    let params = PlaceSwapOrderParams {
+        symbol: "BTCUSDT".to_string(),
+        ..limit_order()
+    };
+    assert_validation_error(&params, "BTC-USDT");
+}
+
+#[test]
+fn test_quantity_and_quote_order_qty_rejected() {
+    let params = PlaceSwapOrderParams {
+        quote_order_qty: Some(100.0),
+        ..limit_order()
+    };
+    assert_validation_error(&params, "quote_order_qty");
+}
+
+#[test]
+fn test_limit_orders_require_price() {
+    for order_type in [SwapOrderType::Limit, SwapOrderType::TriggerLimit] {
+        let params = PlaceSwapOrderParams {
+            order_type: order_type.clone(),
+            price: None,
+            ..limit_order()
+        };
+        assert_validation_error(&params, &format!("{order_type} orders require a price"));
+    }
+}
+
+#[tokio::test]
+async fn test_place_swap_order_validates_before_sending() {
+    // Nothing listens on the discard port; a request would fail with an HTTP error instead.
+    let client = BingxClient::new(
+        Some("key".to_string()),
+        Some("secret".to_string()),
+        false,
+        5000,
+        None,
+    )
+    .unwrap()
+    .with_base_url("http://127.0.0.1:9");
+    let params = PlaceSwapOrderParams {
+        symbol: "BTCUSDT".to_string(),
+        ..limit_order()
+    };
+
+    let single = client.place_swap_order(&params).await;
+    let batch = client
+        .place_swap_batch_orders(&[limit_order(), params])
+        .await;
+
+    assert!(matches!(single, Err(Error::Validation(_))), "{single:?}");
+    assert!(matches!(batch, Err(Error::Validation(_))), "{batch:?}");
+}
@@ -0,0 +1,152 @@
+//! Tests for BingX spot request building and validation (no network required).
+
+use reqwest::Method;
+use serde_json::json;
+use trade_sdk::bingx::{
+    traits::spot::{TradeApi, WalletApi},
+    types::{OrderSide, PlaceSpotOrderParams, SpotOrderType, TimeInForce},
+    BingxClient, BINGX_IMPLEMENTED,
+};
+use trade_sdk::testkit::MockExchange;
+use trade_sdk::Error;
+
+fn public_client() -> BingxClient {
+    BingxClient::new(None, None, false, 5000, None).expect("failed to create BingxClient")
+}
+
+#[test]
+fn test_place_spot_order_params_serialization() {
+    let params = PlaceSpotOrderParams {
+        symbol: "BTC-USDT".to_owned(),
+        side: OrderSide::Buy,
+        order_type: SpotOrderType::Limit,
+        quantity: Some(0.001),
+        price: Some(50_000.5),
+        client_order_id: Some("my-order-1".to_owned()),
+        time_in_force: Some(TimeInForce::PostOnly),
+        ..Default::default()
+    };
+
+    let body = serde_json::to_value(&params).unwrap();
+    assert_eq!(
+        body,
+        json!({
+            "symbol": "BTC-USDT",
+            "side": "BUY",
+            "type": "LIMIT",
+            "quantity": 0.001,
+            "price": 50_000.5,
+            "newClientOrderId": "my-order-1",
+            "timeInForce": "PostOnly"
+        })
+    );
+}
+
+#[test]
+fn test_place_spot_order_market_quote_qty() {
+    let params = PlaceSpotOrderParams {
+        symbol: "ETH-USDT".to_owned(),
+        side: OrderSide::Sell,
+        quote_order_qty: Some(100.0),
+        ..Default::default()
+    };
+
+    let body = serde_json::to_value(&params).unwrap();
+    assert_eq!(
+        body,
+        json!({
+            "symbol": "ETH-USDT",
+            "side": "SELL",
+            "type": "MARKET",
+            "quoteOrderQty": 100.0
+        })
+    );
+}
+
+#[tokio::test]
+async fn test_place_spot_order_requires_hyphenated_symbol() {
+    let client = public_client();
+    let params = PlaceSpotOrderParams {
+        symbol: "BTCUSDT".to_owned(),
+        quantity: Some(0.001),
+        ..Default::default()
+    };
+
+    match client.place_spot_order(&params).await {
+        Err(Error::Validation(msg)) => assert!(msg.contains("BTCUSDT")),
+        other => panic!("expected validation error, got {other:?}"),
+    }
+
+    // A well-formed symbol passes validation and fails on missing credentials instead.
+    let params = PlaceSpotOrderParams {
+        symbol: "BTC-USDT".to_owned(),
+        ..params
+    };
+    assert!(matches!(
+        client.place_spot_order(&params).await,
+        Err(Error::Auth(_))
+    ));
+}
+
+#[test]
+fn test_place_spot_order_registered() {
+    assert!(BINGX_IMPLEMENTED.contains(&"place_spot_order"));
+}
+
+#[tokio::test]
+async fn test_withdraw_validation() {
+    let client = public_client();
+    for amount in [0.0, -1.0, f64::INFINITY] {
+        match client.withdraw("USDT", "TXyz", amount, "TRC20", None).await {
+            Err(Error::Validation(msg)) => assert!(msg.contains("amount")),
+            other => panic!("expected validation error for {amount}, got {other:?}"),
+        }
+    }
+    for network in ["", "   "] {
+        match client.withdraw("USDT", "TXyz", 10.0, network, None).await {
+            Err(Error::Validation(msg)) => assert!(msg.contains("network")),
+            other => panic!("expected validation error for {network:?}, got {other:?}"),
+        }
+    }
+
+    // Valid input passes validation and fails on missing credentials instead.
+    assert!(matches!(
+        client.withdraw("USDT", "TXyz", 10.0, "TRC20", None).await,
+        Err(Error::Auth(_))
+    ));
+}
+
+#[tokio::test]
+async fn test_withdraw_sends_plain_decimal_amount() {
+    let exchange = MockExchange::start().await;
+    exchange.respond(
+        Method::POST,
+        "/openApi/wallets/v1/capital/withdraw/apply",
+        json!({"code": 0, "msg": "", "data": {"id": "1"}}),
+    );
+    let client = BingxClient::new(
+        Some("key".to_string()),
+        Some("secret".to_string()),
+        false,
+        5000,
+        None,
+    )
+    .unwrap()
+    .with_base_url(&exchange.base_url());
+
+    client
+        .withdraw("USDT", "TXyz", 0.1 + 0.2, "TRC20", None)
+        .await
+        .unwrap();
+
+    let body = exchange.requests()[0].json().unwrap();
+    // Binary noise (0.30000000000000004) must not reach the exchange.
+    assert_eq!(body["amount"], "0.3");
+}
+
+#[test]
+fn test_wallet_methods_registered() {
+    for name in ["get_deposit_address", "withdraw", "get_withdraw_history"] {
+        assert!(BINGX_IMPLEMENTED.contains(&name), "{name} not registered");
+    }
+}
@@ -0,0 +1,130 @@
+//! Tests for BingX swap endpoints.
+//!
+//! Validation tests run offline. Endpoint tests hit the public BingX API; when the network
+//! is unreachable they fall back to checking the URL carried by the connection error.
+
+use trade_sdk::bingx::{
+    traits::swap::MarketApi,
+    types::{ApiResponse, SWAP_KLINE_INTERVALS},
+    BingxClient, BINGX_IMPLEMENTED,
+};
+use trade_sdk::Error;
+
+fn public_client() -> BingxClient {
+    BingxClient::new(None, None, false, 5000, None).expect("failed to create BingxClient")
+}
+
+/// Assert a public endpoint answered successfully.
+///
+/// If the network is unreachable, assert the failed request targeted `path` with `query` params.
+fn check_public_response(
+    path: &str,
+    query: &[(&str, &str)],
+    result: trade_sdk::Result<ApiResponse<serde_json::Value>>,
+) {
+    match result {
+        Ok(resp) => assert_eq!(resp.code, 0, "{path}: unexpected code {}", resp.code),
+        Err(Error::Http(e)) if !e.is_status() => {
+            println!("💡 Network unavailable, checking request URL only ({e})");
+            let url = e.url().expect("request error without URL");
+            assert_eq!(url.path(), path);
+            let pairs: Vec<(String, String)> = url.query_pairs().into_owned().collect();
+            for (k, v) in query {
+                assert!(
+                    pairs.iter().any(|(pk, pv)| pk == k && pv == v),
+                    "{path}: missing query param {k}={v} in {url}"
+                );
+            }
+        }
+        Err(e) => panic!("{path} failed: {e}"),
+    }
+}
+
+#[tokio::test]
+async fn test_swap_klines_rejects_invalid_interval() {
+    let client = public_client();
+    for interval in ["2m", "1H", "7d", ""] {
+        match client
+            .get_swap_klines("BTC-USDT", interval, None, None, None)
+            .await
+        {
+            Err(Error::Validation(msg)) => assert!(msg.contains("interval")),
+            other => panic!("expected validation error for {interval:?}, got {other:?}"),
+        }
+    }
+}
+
+#[test]
+fn test_swap_kline_intervals() {
+    for interval in ["1m", "15m", "1h", "4h", "1d", "1w", "1M"] {
+        assert!(SWAP_KLINE_INTERVALS.contains(&interval));
+    }
+}
+
+#[tokio::test]
+async fn test_swap_market_endpoints() {
+    let client = public_client();
+    check_public_response(
+        "/openApi/swap/v2/quote/depth",
+        &[("symbol", "BTC-USDT"), ("limit", "5")],
+        client.get_swap_depth("BTC-USDT", Some(5)).await,
+    );
+    check_public_response(
+        "/openApi/swap/v2/quote/ticker",
+        &[("symbol", "BTC-USDT")],
+        client.get_swap_ticker(Some("BTC-USDT")).await,
+    );
+    check_public_response(
+        "/openApi/swap/v3/quote/klines",
+        &[("symbol", "BTC-USDT"), ("interval", "1h"), ("limit", "10")],
+        client
+            .get_swap_klines("BTC-USDT", "1h", None, None, Some(10))
+            .await,
+    );
+}
+
+#[tokio::test]
+async fn test_swap_funding_endpoints() {
+    let client = public_client();
+    check_public_response(
+        "/openApi/swap/v2/quote/premiumIndex",
+        &[("symbol", "ETH-USDT")],
+        client.get_swap_funding_rate(Some("ETH-USDT")).await,
+    );
+    check_public_response(
+        "/openApi/swap/v2/quote/fundingRate",
+        &[
+            ("symbol", "ETH-USDT"),
+            ("startTime", "1700000000000"),
+            ("endTime", "1700086400000"),
+            ("limit", "3"),
+        ],
+        client
+            .get_swap_funding_rate_history(
+                "ETH-USDT",
+                Some(1_700_000_000_000),
+                Some(1_700_086_400_000),
+                Some(3),
+            )
+            .await,
+    );
+    check_public_response(
+        "/openApi/swap/v2/quote/openInterest",
+        &[("symbol", "ETH-USDT")],
+        client.get_swap_open_interest("ETH-USDT").await,
+    );
+}
+
+#[test]
+fn test_swap_market_registered() {
+    for name in [
+        "get_swap_depth",
+        "get_swap_ticker",
+        "get_swap_klines",
+        "get_swap_funding_rate",
+        "get_swap_funding_rate_history",
+        "get_swap_open_interest",
+    ] {
+        assert!(BINGX_IMPLEMENTED.contains(&name), "{name} not registered");
+    }
+}
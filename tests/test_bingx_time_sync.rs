@@ -0,0 +1,105 @@
+//! Tests for correcting BingX signing timestamps by the server time offset.
+
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use reqwest::Method;
+use serde_json::json;
+use trade_sdk::bingx::BingxClient;
+use trade_sdk::testkit::MockExchange;
+use trade_sdk::Error;
+
+fn now_ms() -> i64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap()
+        .as_millis() as i64
+}
+
+/// Mock exchange answering with `serverTime` set to the local time at setup plus `skew_ms`.
+async fn mock_exchange(skew_ms: Option<i64>) -> MockExchange {
+    let data = match skew_ms {
+        Some(skew) => json!({"serverTime": now_ms() + skew}),
+        None => json!({}),
+    };
+    let exchange = MockExchange::start().await;
+    exchange.respond_to_any(json!({"code": 0, "msg": "", "data": data}));
+    exchange
+}
+
+fn client(url: &str) -> BingxClient {
+    BingxClient::new(
+        Some("key".to_string()),
+        Some("secret".to_string()),
+        false,
+        5000,
+        None,
+    )
+    .unwrap()
+    .with_base_url(url)
+}
+
+/// `timestamp` query parameter of the last recorded request.
+fn last_timestamp(exchange: &MockExchange) -> i64 {
+    let request = exchange.requests().pop().unwrap();
+    request
+        .query_param("timestamp")
+        .unwrap_or_else(|| panic!("no timestamp in {}", request.request_line()))
+        .parse()
+        .unwrap()
+}
+
+#[tokio::test]
+async fn test_time_offset_applied_to_signing_timestamp() {
+    let exchange = mock_exchange(None).await;
+    let client = client(&exchange.base_url());
+    assert_eq!(client.time_offset_ms(), 0);
+
+    let before = now_ms();
+    client
+        .request_raw(Method::GET, "/openApi/spot/v1/account/balance", None, true)
+        .await
+        .unwrap();
+    let timestamp = last_timestamp(&exchange);
+    assert!((before..=now_ms()).contains(&timestamp));
+
+    // The offset is shared with clones.
+    let clone = client.clone();
+    clone.set_time_offset_ms(-90_000);
+    assert_eq!(client.time_offset_ms(), -90_000);
+
+    let before = now_ms();
+    client
+        .request_raw(Method::GET, "/openApi/spot/v1/account/balance", None, true)
+        .await
+        .unwrap();
+    let timestamp = last_timestamp(&exchange);
+    assert!((before - 90_000..=now_ms() - 90_000).contains(&timestamp));
+}
+
+#[tokio::test]
+async fn test_sync_server_time_measures_and_applies_offset() {
+    let exchange = mock_exchange(Some(120_000)).await;
+    let client = client(&exchange.base_url());
+
+    let offset = client.server_time_offset().await.unwrap();
+    assert!((119_000..=121_000).contains(&offset), "{offset}");
+    // Measuring alone does not change the signing timestamps.
+    assert_eq!(client.time_offset_ms(), 0);
+
+    let offset = client.sync_server_time().await.unwrap();
+    assert_eq!(client.time_offset_ms(), offset);
+
+    client
+        .request_raw(Method::GET, "/openApi/spot/v1/account/balance", None, true)
+        .await
+        .unwrap();
+    let drift = last_timestamp(&exchange) - now_ms();
+    assert!((119_000..=121_000).contains(&drift), "{drift}");
+}
+
+#[tokio::test]
+async fn test_server_time_offset_requires_server_time() {
+    let exchange = mock_exchange(None).await;
+    let result = client(&exchange.base_url()).server_time_offset().await;
+    assert!(matches!(result, Err(Error::Validation(_))), "{result:?}");
+}
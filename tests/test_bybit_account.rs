@@ -0,0 +1,413 @@
+//! Tests for Bybit account endpoints against a local mock server.
+
+use serde_json::{json, Value};
+use trade_sdk::bybit::{
+    models::{AccountInfo, UpgradeToUtaResult},
+    traits::AccountApi,
+    types::{AllCategories, ApiResponse},
+    BybitClient,
+};
+use trade_sdk::testkit::MockExchange;
+
+/// Mock exchange answering every request with `result`.
+async fn mock_exchange(result: Value) -> MockExchange {
+    let exchange = MockExchange::start().await;
+    exchange.respond_to_any(
+        json!({"retCode": 0, "retMsg": "OK", "result": result, "retExtInfo": {}, "time": 0}),
+    );
+    exchange
+}
+
+/// Request lines recorded so far.
+fn request_lines(exchange: &MockExchange) -> Vec<String> {
+    exchange
+        .requests()
+        .iter()
+        .map(|request| request.request_line())
+        .collect()
+}
+
+/// JSON body of the last recorded request.
+fn last_body(exchange: &MockExchange) -> Value {
+    exchange.requests().last().unwrap().json().unwrap()
+}
+
+fn client(base_url: &str) -> BybitClient {
+    BybitClient::new(
+        Some("key".to_string()),
+        Some("secret".to_string()),
+        false,
+        false,
+        5000,
+        None,
+    )
+    .unwrap()
+    .with_base_url(base_url)
+}
+
+#[test]
+fn test_account_info_model() {
+    let response: ApiResponse<Value> = serde_json::from_value(json!({
+        "retCode": 0,
+        "retMsg": "OK",
+        "result": {
+            "marginMode": "REGULAR_MARGIN",
+            "updatedTime": "1697078946000",
+            "unifiedMarginStatus": 4,
+            "dcpStatus": "OFF",
+            "timeWindow": 10,
+            "smpGroup": 0,
+            "isMasterTrader": false,
+            "spotHedgingStatus": "OFF"
+        },
+        "retExtInfo": {},
+        "time": 1697078946000_u64
+    }))
+    .unwrap();
+
+    let info = response.parse::<AccountInfo>().unwrap();
+    assert_eq!(info.unified_margin_status, 4);
+    assert_eq!(info.margin_mode, "REGULAR_MARGIN");
+    assert!(!info.is_master_trader);
+    assert_eq!(info.spot_hedging_status, "OFF");
+    assert_eq!(info.updated_time, "1697078946000");
+    assert!(info.is_unified());
+
+    let classic: AccountInfo = serde_json::from_value(json!({"unifiedMarginStatus": 1})).unwrap();
+    assert!(!classic.is_unified());
+}
+
+#[tokio::test]
+async fn test_upgrade_to_unified_account_pro() {
+    let exchange = mock_exchange(json!({
+        "unifiedUpdateStatus": "FAIL",
+        "unifiedUpdateMsg": {"msg": ["Please cancel all open orders before upgrading."]}
+    }))
+    .await;
+    let base_url = exchange.base_url();
+
+    let response = client(&base_url)
+        .upgrade_to_unified_account_pro()
+        .await
+        .unwrap();
+    assert_eq!(
+        request_lines(&exchange),
+        ["POST /v5/account/upgrade-to-uta"]
+    );
+
+    let status = response.parse::<UpgradeToUtaResult>().unwrap();
+    assert_eq!(status.unified_update_status, "FAIL");
+    assert_eq!(status.unified_update_msg.unwrap().msg.len(), 1);
+}
+
+#[tokio::test]
+async fn test_get_account_info_path() {
+    let exchange =
+        mock_exchange(json!({"unifiedMarginStatus": 5, "marginMode": "PORTFOLIO_MARGIN"})).await;
+    let base_url = exchange.base_url();
+
+    let info = client(&base_url)
+        .get_account_info()
+        .await
+        .unwrap()
+        .parse::<AccountInfo>()
+        .unwrap();
+    assert_eq!(request_lines(&exchange), ["GET /v5/account/info"]);
+    assert_eq!(info.unified_margin_status, 5);
+    assert_eq!(info.margin_mode, "PORTFOLIO_MARGIN");
+}
+
+#[test]
+fn test_upgrade_result_without_message() {
+    let status: UpgradeToUtaResult = serde_json::from_value(json!({
+        "unifiedUpdateStatus": "SUCCESS",
+        "unifiedUpdateMsg": null
+    }))
+    .unwrap();
+    assert_eq!(status.unified_update_status, "SUCCESS");
+    assert!(status.unified_update_msg.is_none());
+}
+
+#[tokio::test]
+async fn test_set_collateral_coin_on_off() {
+    let exchange = mock_exchange(json!({})).await;
+    let client = client(&exchange.base_url());
+
+    client.set_collateral_coin("BTC", true).await.unwrap();
+    assert_eq!(
+        last_body(&exchange),
+        json!({"coin": "BTC", "collateralSwitch": "ON"})
+    );
+
+    client.set_collateral_coin("ETH", false).await.unwrap();
+    assert_eq!(
+        last_body(&exchange),
+        json!({"coin": "ETH", "collateralSwitch": "OFF"})
+    );
+    assert_eq!(
+        request_lines(&exchange),
+        [
+            "POST /v5/account/set-collateral-switch",
+            "POST /v5/account/set-collateral-switch"
+        ]
+    );
+}
+
+#[tokio::test]
+async fn test_batch_set_collateral_coin_body() {
+    let exchange = mock_exchange(json!({"list": []})).await;
+    let client = client(&exchange.base_url());
+
+    client
+        .batch_set_collateral_coin(&[("BTC".to_string(), true), ("SOL".to_string(), false)])
+        .await
+        .unwrap();
+    assert_eq!(
+        request_lines(&exchange),
+        ["POST /v5/account/set-collateral-switch-batch"]
+    );
+    assert_eq!(
+        last_body(&exchange),
+        json!({"request": [
+            {"coin": "BTC", "collateralSwitch": "ON"},
+            {"coin": "SOL", "collateralSwitch": "OFF"}
+        ]})
+    );
+
+    let result = client.batch_set_collateral_coin(&[]).await;
+    assert!(matches!(result, Err(trade_sdk::Error::Validation(_))));
+}
+
+#[tokio::test]
+async fn test_get_collateral_info_query() {
+    let exchange = mock_exchange(json!({"list": []})).await;
+    let client = client(&exchange.base_url());
+
+    client.get_collateral_info(Some("BTC")).await.unwrap();
+    client.get_collateral_info(None).await.unwrap();
+    assert_eq!(
+        request_lines(&exchange),
+        [
+            "GET /v5/account/collateral-info?currency=BTC",
+            "GET /v5/account/collateral-info"
+        ]
+    );
+}
+
+#[tokio::test]
+async fn test_repay_optional_coin_and_routing() {
+    let exchange = mock_exchange(json!({})).await;
+    let client = client(&exchange.base_url());
+
+    client.manual_repay(Some("USDT")).await.unwrap();
+    assert_eq!(last_body(&exchange), json!({"coin": "USDT"}));
+    client.manual_repay(None).await.unwrap();
+    assert_eq!(last_body(&exchange), json!({}));
+
+    client.repay_liability(Some("BTC")).await.unwrap();
+    assert_eq!(last_body(&exchange), json!({"coin": "BTC"}));
+    client.repay_liability(None).await.unwrap();
+    assert_eq!(last_body(&exchange), json!({}));
+
+    assert_eq!(
+        request_lines(&exchange),
+        [
+            "POST /v5/account/quick-repayment",
+            "POST /v5/account/quick-repayment",
+            "POST /v5/account/repay-liability",
+            "POST /v5/account/repay-liability"
+        ]
+    );
+}
+
+#[tokio::test]
+async fn test_manual_borrow() {
+    let exchange = mock_exchange(json!({})).await;
+    let client = client(&exchange.base_url());
+
+    client
+        .manual_borrow("USDT", "250.5".parse().unwrap())
+        .await
+        .unwrap();
+    assert_eq!(request_lines(&exchange), ["POST /v5/account/borrow"]);
+    assert_eq!(
+        last_body(&exchange),
+        json!({"coin": "USDT", "amount": "250.5"})
+    );
+
+    let result = client.manual_borrow("USDT", "0".parse().unwrap()).await;
+    assert!(matches!(result, Err(trade_sdk::Error::Validation(_))));
+}
+
+#[tokio::test]
+async fn test_get_coin_greeks_query() {
+    let exchange = mock_exchange(json!({"list": []})).await;
+    let client = client(&exchange.base_url());
+
+    client.get_coin_greeks(Some("BTC")).await.unwrap();
+    client.get_coin_greeks(None).await.unwrap();
+    assert_eq!(
+        request_lines(&exchange),
+        [
+            "GET /v5/asset/coin-greeks?baseCoin=BTC",
+            "GET /v5/asset/coin-greeks"
+        ]
+    );
+}
+
+#[tokio::test]
+async fn test_set_spot_hedging_on_off() {
+    let exchange = mock_exchange(json!({})).await;
+    let client = client(&exchange.base_url());
+
+    client.set_spot_hedging(true).await.unwrap();
+    assert_eq!(last_body(&exchange), json!({"setHedgingMode": "ON"}));
+    client.set_spot_hedging(false).await.unwrap();
+    assert_eq!(last_body(&exchange), json!({"setHedgingMode": "OFF"}));
+    assert_eq!(
+        request_lines(&exchange),
+        [
+            "POST /v5/account/set-hedging-mode",
+            "POST /v5/account/set-hedging-mode"
+        ]
+    );
+}
+
+#[tokio::test]
+async fn test_get_dcp_info_path() {
+    let exchange = mock_exchange(
+        json!({"dcpInfos": [{"product": "SPOT", "dcpStatus": "ON", "timeWindow": "10"}]}),
+    )
+    .await;
+    let client = client(&exchange.base_url());
+
+    let response = client.get_dcp_info().await.unwrap();
+    assert_eq!(request_lines(&exchange), ["GET /v5/account/query-dcp-info"]);
+    assert_eq!(response.result["dcpInfos"][0]["product"], "SPOT");
+}
+
+#[tokio::test]
+async fn test_wallet_balance_for_coins_joins_uppercase_without_spaces() {
+    let exchange = mock_exchange(json!({"list": []})).await;
+    let url = exchange.base_url();
+
+    client(&url)
+        .get_wallet_balance_for_coins(None, &["btc", " eth"])
+        .await
+        .unwrap();
+
+    let line = &request_lines(&exchange)[0];
+    assert!(
+        line.starts_with("GET /v5/account/wallet-balance?"),
+        "{line}"
+    );
+    assert!(line.contains("coin=BTC,ETH"), "{line}");
+    assert!(
+        !line.contains(' ') || line.split(' ').count() == 2,
+        "{line}"
+    );
+}
+
+#[tokio::test]
+async fn test_wallet_balance_for_coins_rejects_empty() {
+    let client = client("http://127.0.0.1:9");
+
+    for coins in [&[][..], &["btc", " "][..]] {
+        let result = client.get_wallet_balance_for_coins(None, coins).await;
+        assert!(
+            matches!(result, Err(trade_sdk::Error::Validation(_))),
+            "{coins:?}: {result:?}"
+        );
+    }
+}
+
+#[tokio::test]
+async fn test_borrow_history_flattens_optional_params() {
+    let exchange = mock_exchange(json!({"list": [], "nextPageCursor": ""})).await;
+    let url = exchange.base_url();
+    let client = client(&url);
+
+    client
+        .get_borrow_history(None, None, None, None, None)
+        .await
+        .unwrap();
+    client
+        .get_borrow_history(
+            Some("USDT"),
+            Some(1_700_000_000_000),
+            Some(1_702_000_000_000),
+            Some(50),
+            Some("abc"),
+        )
+        .await
+        .unwrap();
+
+    let lines = request_lines(&exchange);
+    assert_eq!(lines[0], "GET /v5/account/borrow-history");
+    assert!(
+        lines[1].starts_with("GET /v5/account/borrow-history?"),
+        "{}",
+        lines[1]
+    );
+    for param in [
+        "currency=USDT",
+        "startTime=1700000000000",
+        "endTime=1702000000000",
+        "limit=50",
+        "cursor=abc",
+    ] {
+        assert!(
+            lines[1].contains(param),
+            "{param} missing from {}",
+            lines[1]
+        );
+    }
+}
+
+#[tokio::test]
+async fn test_smp_and_trade_behaviour_routes() {
+    let exchange = mock_exchange(json!({})).await;
+    let url = exchange.base_url();
+    let client = client(&url);
+
+    client.get_smp_group_id().await.unwrap();
+    client.get_trade_behaviour_setting().await.unwrap();
+    client
+        .set_limit_price_behaviour(AllCategories::Linear, "reject")
+        .await
+        .unwrap();
+
+    assert_eq!(
+        request_lines(&exchange),
+        [
+            "GET /v5/account/smp-group",
+            "GET /v5/account/user-setting-config",
+            "POST /v5/account/set-limit-px-action"
+        ]
+    );
+    assert_eq!(
+        last_body(&exchange),
+        json!({"category": "linear", "modifyEnable": false})
+    );
+}
+
+#[tokio::test]
+async fn test_limit_price_behaviour_validation() {
+    let client = client("http://127.0.0.1:9");
+
+    let unknown = client
+        .set_limit_price_behaviour(AllCategories::Spot, "adjust")
+        .await;
+    let option = client
+        .set_limit_price_behaviour(AllCategories::Option, "modify")
+        .await;
+
+    assert!(
+        matches!(&unknown, Err(trade_sdk::Error::Validation(msg)) if msg.contains("\"adjust\"")),
+        "{unknown:?}"
+    );
+    assert!(
+        matches!(option, Err(trade_sdk::Error::Validation(_))),
+        "{option:?}"
+    );
+}
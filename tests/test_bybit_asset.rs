@@ -0,0 +1,233 @@
+//! Tests for the Bybit asset endpoints: internal transfers, their history, the transferable
+//! amount, coin info and withdrawals.
+
+use serde_json::json;
+use trade_sdk::bybit::traits::{AccountApi, AssetApi};
+use trade_sdk::bybit::types::AccountType;
+use trade_sdk::bybit::BybitClient;
+use trade_sdk::testkit::MockExchange;
+use trade_sdk::{Amount, Error};
+
+fn amount(s: &str) -> Amount {
+    s.parse().unwrap()
+}
+
+/// Mock exchange answering every request with a successful transfer response.
+async fn mock_exchange() -> MockExchange {
+    let exchange = MockExchange::start().await;
+    exchange.respond_to_any(json!({
+        "retCode": 0,
+        "retMsg": "success",
+        "result": {"transferId": "echo", "status": "SUCCESS", "list": []},
+        "retExtInfo": {},
+        "time": 1
+    }));
+    exchange
+}
+
+fn client(url: &str) -> BybitClient {
+    BybitClient::new(
+        Some("key".to_string()),
+        Some("secret".to_string()),
+        false,
+        false,
+        5000,
+        None,
+    )
+    .unwrap()
+    .with_base_url(url)
+}
+
+#[tokio::test]
+async fn test_internal_transfer_body_shape() {
+    let exchange = mock_exchange().await;
+    let client = client(&exchange.base_url());
+
+    client
+        .create_internal_transfer(
+            "USDT",
+            amount("1250.5"),
+            AccountType::Fund,
+            AccountType::Unified,
+        )
+        .await
+        .unwrap();
+
+    let request = &exchange.requests()[0];
+    assert_eq!(
+        request.request_line(),
+        "POST /v5/asset/transfer/inter-transfer"
+    );
+    let mut body = request.json().unwrap();
+    let transfer_id = body.as_object_mut().unwrap().remove("transferId").unwrap();
+    assert_eq!(
+        body,
+        json!({
+            "coin": "USDT",
+            "amount": "1250.5",
+            "fromAccountType": "FUND",
+            "toAccountType": "UNIFIED"
+        })
+    );
+    uuid::Uuid::parse_str(transfer_id.as_str().unwrap()).expect("transferId is not a UUID");
+}
+
+#[tokio::test]
+async fn test_internal_transfer_ids_are_unique() {
+    let exchange = mock_exchange().await;
+    let client = client(&exchange.base_url());
+
+    for _ in 0..2 {
+        client
+            .create_internal_transfer(
+                "USDT",
+                amount("0.00000001"),
+                AccountType::Unified,
+                AccountType::Fund,
+            )
+            .await
+            .unwrap();
+    }
+
+    let requests = exchange.requests();
+    let (first, second) = (requests[0].json().unwrap(), requests[1].json().unwrap());
+    assert_ne!(first["transferId"], second["transferId"]);
+    // Small amounts stay plain decimals, never scientific notation.
+    assert_eq!(first["amount"], "0.00000001");
+}
+
+#[tokio::test]
+async fn test_internal_transfer_validation() {
+    let client = client("http://127.0.0.1:9");
+
+    let err = client
+        .create_internal_transfer("USDT", amount("0"), AccountType::Fund, AccountType::Unified)
+        .await
+        .unwrap_err();
+    assert!(matches!(err, Error::Validation(_)), "{err:?}");
+
+    let err = client
+        .create_internal_transfer("USDT", amount("1"), AccountType::Fund, AccountType::Fund)
+        .await
+        .unwrap_err();
+    assert!(matches!(err, Error::Validation(_)), "{err:?}");
+}
+
+#[tokio::test]
+async fn test_transfer_history_query() {
+    let exchange = mock_exchange().await;
+    let client = client(&exchange.base_url());
+
+    client
+        .get_transfer_history(
+            None,
+            Some("USDT"),
+            Some("SUCCESS"),
+            None,
+            None,
+            Some(50),
+            Some("next"),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(
+        exchange.requests()[0].url(),
+        "/v5/asset/transfer/query-inter-transfer-list?coin=USDT&cursor=next&limit=50&status=SUCCESS"
+    );
+}
+
+#[tokio::test]
+async fn test_transferable_amount_joins_coins() {
+    let exchange = mock_exchange().await;
+    let client = client(&exchange.base_url());
+
+    client
+        .get_transferable_amount(&["usdt", " btc"])
+        .await
+        .unwrap();
+
+    assert_eq!(
+        exchange.requests()[0].url(),
+        "/v5/account/withdrawal?coinName=USDT,BTC"
+    );
+
+    let err = client.get_transferable_amount(&[]).await.unwrap_err();
+    assert!(matches!(err, Error::Validation(_)), "{err:?}");
+}
+
+#[tokio::test]
+async fn test_coin_info_and_withdrawable_amount_queries() {
+    let exchange = mock_exchange().await;
+    let client = client(&exchange.base_url());
+
+    client.get_coin_info(None).await.unwrap();
+    client.get_coin_info(Some("USDT")).await.unwrap();
+    client.get_withdrawable_amount("USDT").await.unwrap();
+
+    let targets: Vec<String> = exchange.requests().iter().map(|r| r.url()).collect();
+    assert_eq!(
+        targets,
+        [
+            "/v5/asset/coin/query-info",
+            "/v5/asset/coin/query-info?coin=USDT",
+            "/v5/asset/withdraw/withdrawable-amount?coin=USDT",
+        ]
+    );
+}
+
+#[tokio::test]
+async fn test_withdraw_body_shape() {
+    let exchange = mock_exchange().await;
+    let client = client(&exchange.base_url());
+
+    client
+        .withdraw(
+            "USDT",
+            "TRX",
+            "TXyz",
+            Some("memo"),
+            amount("25.5"),
+            Some("FUND"),
+            Some("req-1"),
+        )
+        .await
+        .unwrap();
+
+    let request = &exchange.requests()[0];
+    assert_eq!(request.request_line(), "POST /v5/asset/withdraw/create");
+    let mut body = request.json().unwrap();
+    let timestamp = body.as_object_mut().unwrap().remove("timestamp").unwrap();
+    assert!(
+        timestamp.as_i64().unwrap() > 1_600_000_000_000,
+        "{timestamp}"
+    );
+    assert_eq!(
+        body,
+        json!({
+            "coin": "USDT",
+            "chain": "TRX",
+            "address": "TXyz",
+            "tag": "memo",
+            "amount": "25.5",
+            "accountType": "FUND",
+            "requestId": "req-1"
+        })
+    );
+}
+
+#[tokio::test]
+async fn test_withdraw_validation() {
+    let client = client("http://127.0.0.1:9");
+
+    for (address, value) in [("TXyz", "0"), ("TXyz", "-1"), ("", "1"), ("  ", "1")] {
+        let err = client
+            .withdraw("USDT", "TRX", address, None, amount(value), None, None)
+            .await
+            .unwrap_err();
+        assert!(
+            matches!(err, Error::Validation(_)),
+            "{address:?} {value}: {err:?}"
+        );
+    }
+}
@@ -1,6 +1,7 @@
 //! Tests for Bybit API authentication.
 
 use trade_sdk::bybit::{
+    models::WalletBalanceResult,
     traits::{AccountApi, PositionApi, TradeApi},
     types::{AccountType, AllCategories, Side},
     BybitClient,
@@ -17,7 +18,7 @@ static INIT_LOGGER: Once = Once::new();
 /// Safe to call multiple times - logger will be initialized only once.
 pub fn init_test_logger() {
     INIT_LOGGER.call_once(|| {
-        env_logger::init();
+        let _ = env_logger::builder().is_test(true).try_init();
     });
 }
 
@@ -35,41 +36,21 @@ async fn print_wallet_balance(client: &BybitClient) -> Result<(), Box<dyn std::e
                 serde_json::to_string_pretty(&response.result)?
             );
 
-            if let Some(result_obj) = response.result.get("result") {
-                if let Some(accounts) = result_obj.get("list").and_then(|l| l.as_array()) {
-                    println!("Found {} account types", accounts.len());
-                    for account in accounts.iter().take(3) {
-                        let account_type = account
-                            .get("accountType")
-                            .and_then(|s| s.as_str())
-                            .unwrap_or("Unknown");
-                        let total_wallet_balance = account
-                            .get("totalWalletBalance")
-                            .and_then(|s| s.as_str())
-                            .unwrap_or("0");
+            let balance = response.parse::<WalletBalanceResult>()?;
+            println!("Found {} account types", balance.list.len());
+            for account in balance.list.iter().take(3) {
+                println!(
+                    "   {}: wallet = {}",
+                    account.account_type, account.total_wallet_balance
+                );
 
-                        println!("   {}: wallet = {}", account_type, total_wallet_balance);
-
-                        if let Some(coins) = account.get("coin").and_then(|c| c.as_array()) {
-                            if !coins.is_empty() {
-                                println!("      Coins:");
-                                for coin in coins.iter().take(3) {
-                                    let coin_name =
-                                        coin.get("coin").and_then(|s| s.as_str()).unwrap_or("???");
-                                    let coin_balance = coin
-                                        .get("walletBalance")
-                                        .and_then(|s| s.as_str())
-                                        .unwrap_or("0");
-                                    let coin_equity =
-                                        coin.get("equity").and_then(|s| s.as_str()).unwrap_or("0");
-
-                                    println!(
-                                        "         {}: wallet_balance={}, equity={}",
-                                        coin_name, coin_balance, coin_equity
-                                    );
-                                }
-                            }
-                        }
+                if !account.coin.is_empty() {
+                    println!("      Coins:");
+                    for coin in account.coin.iter().take(3) {
+                        println!(
+                            "         {}: wallet_balance={}, equity={}",
+                            coin.coin, coin.wallet_balance, coin.equity
+                        );
                     }
                 }
             }
@@ -209,8 +190,8 @@ async fn test_wallet_balance_and_positions() -> Result<(), Box<dyn std::error::E
                     symbol: "BTCUSDT".to_owned(),
                     side: Side::Buy,
                     order_type: PlaceOrderType::Market,
-                    qty: 0.005,
-                    take_profit: Some(100_000.0),
+                    qty: "0.005".parse().unwrap(),
+                    take_profit: Some("100000".parse().unwrap()),
                     ..Default::default()
                 },
             )
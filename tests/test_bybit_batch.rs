@@ -0,0 +1,95 @@
+//! Tests for Bybit batch order size validation.
+//!
+//! Validation runs before authentication, so an unauthenticated client is enough: a valid
+//! batch size gets as far as `Error::Auth`.
+
+use trade_sdk::bybit::{
+    traits::TradeApi,
+    types::{AllCategories, CancelOrderParams, PlaceOrderParams},
+    BybitClient,
+};
+use trade_sdk::Error;
+
+fn client() -> BybitClient {
+    BybitClient::new(None, None, false, false, 5000, None).unwrap()
+}
+
+fn place_orders(n: usize) -> Vec<PlaceOrderParams> {
+    vec![
+        PlaceOrderParams {
+            symbol: "BTCUSDT".to_string(),
+            qty: "0.001".parse().unwrap(),
+            ..Default::default()
+        };
+        n
+    ]
+}
+
+fn cancel_orders(n: usize) -> Vec<CancelOrderParams> {
+    (0..n)
+        .map(|i| CancelOrderParams::new("BTCUSDT", Some(i.to_string()), None))
+        .collect()
+}
+
+fn assert_over_limit(
+    result: trade_sdk::Result<impl std::fmt::Debug>,
+    len: usize,
+    max: usize,
+) {
+    match result {
+        Err(Error::Validation(msg)) => {
+            assert!(msg.contains(&len.to_string()), "{msg}");
+            assert!(msg.contains(&max.to_string()), "{msg}");
+        }
+        other => panic!("expected validation error, got {other:?}"),
+    }
+}
+
+#[tokio::test]
+async fn test_batch_place_order_over_limit_per_category() {
+    let client = client();
+    for (category, max) in [
+        (AllCategories::Spot, 20),
+        (AllCategories::Linear, 20),
+        (AllCategories::Inverse, 20),
+        (AllCategories::Option, 10),
+    ] {
+        let result = client
+            .batch_place_order(category.clone(), &place_orders(max + 1))
+            .await;
+        assert_over_limit(result, max + 1, max);
+
+        let result = client.batch_place_order(category, &place_orders(max)).await;
+        assert!(matches!(result, Err(Error::Auth(_))), "{result:?}");
+    }
+}
+
+#[tokio::test]
+async fn test_batch_cancel_order_over_limit_per_category() {
+    let client = client();
+    for (category, max) in [
+        (AllCategories::Spot, 20),
+        (AllCategories::Linear, 20),
+        (AllCategories::Inverse, 20),
+        (AllCategories::Option, 10),
+    ] {
+        let result = client
+            .batch_cancel_order(category.clone(), &cancel_orders(max + 1))
+            .await;
+        assert_over_limit(result, max + 1, max);
+
+        let result = client
+            .batch_cancel_order(category, &cancel_orders(max))
+            .await;
+        assert!(matches!(result, Err(Error::Auth(_))), "{result:?}");
+    }
+}
+
+#[tokio::test]
+async fn test_empty_batch_is_rejected() {
+    let client = client();
+    let result = client.batch_place_order(AllCategories::Linear, &[]).await;
+    assert!(matches!(result, Err(Error::Validation(_))), "{result:?}");
+    let result = client.batch_cancel_order(AllCategories::Linear, &[]).await;
+    assert!(matches!(result, Err(Error::Validation(_))), "{result:?}");
+}
@@ -0,0 +1,128 @@
+//! Tests for `cancel_all_and_confirm` against a local mock server.
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
+use serde_json::json;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpListener;
+use trade_sdk::bybit::{types::AllCategories, BybitClient};
+
+#[derive(Default)]
+struct Calls {
+    cancel_all: AtomicUsize,
+    realtime: AtomicUsize,
+}
+
+/// Mock `cancel-all` and `realtime`; the open order count per poll comes from `open_orders`
+/// (the last value repeats).
+async fn spawn_server(
+    open_orders: Vec<usize>,
+    calls: Arc<Calls>,
+) -> String {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    let open_orders = Arc::new(open_orders);
+    tokio::spawn(async move {
+        while let Ok((mut socket, _)) = listener.accept().await {
+            let calls = Arc::clone(&calls);
+            let open_orders = Arc::clone(&open_orders);
+            tokio::spawn(async move {
+                let mut buf = vec![0u8; 8192];
+                let n = socket.read(&mut buf).await.unwrap();
+                let request = String::from_utf8_lossy(&buf[..n]);
+                let path = request.split_whitespace().nth(1).unwrap_or_default();
+
+                let result = if path.starts_with("/v5/order/cancel-all") {
+                    calls.cancel_all.fetch_add(1, Ordering::SeqCst);
+                    json!({"list": [], "success": "1"})
+                } else if path.starts_with("/v5/order/realtime") {
+                    let poll = calls.realtime.fetch_add(1, Ordering::SeqCst);
+                    let open = open_orders[poll.min(open_orders.len() - 1)];
+                    let list: Vec<_> = (0..open)
+                        .map(|i| json!({"orderId": format!("oid-{i}"), "symbol": "BTCUSDT", "orderStatus": "New"}))
+                        .collect();
+                    json!({"list": list, "nextPageCursor": "", "category": "linear"})
+                } else {
+                    panic!("unexpected request: {path}");
+                };
+                let body = json!({"retCode": 0, "retMsg": "OK", "result": result, "retExtInfo": {}, "time": 0})
+                    .to_string();
+                let response = format!(
+                    "HTTP/1.1 200 OK\r\ncontent-type: application/json\r\ncontent-length: {}\r\nconnection: close\r\n\r\n{}",
+                    body.len(),
+                    body
+                );
+                let _ = socket.write_all(response.as_bytes()).await;
+            });
+        }
+    });
+    format!("http://{addr}")
+}
+
+fn client(base_url: &str) -> BybitClient {
+    BybitClient::new(
+        Some("key".to_string()),
+        Some("secret".to_string()),
+        false,
+        false,
+        5000,
+        None,
+    )
+    .unwrap()
+    .with_base_url(base_url)
+}
+
+#[tokio::test]
+async fn test_cancel_all_and_confirm_waits_until_flat() {
+    let calls = Arc::new(Calls::default());
+    let client = client(&spawn_server(vec![3, 3, 0], Arc::clone(&calls)).await);
+
+    let remaining = client
+        .cancel_all_and_confirm(AllCategories::Linear, "BTCUSDT", Duration::from_secs(5))
+        .await
+        .unwrap();
+
+    assert_eq!(remaining, 0);
+    assert_eq!(calls.cancel_all.load(Ordering::SeqCst), 1);
+    assert_eq!(calls.realtime.load(Ordering::SeqCst), 3);
+}
+
+#[tokio::test]
+async fn test_cancel_all_and_confirm_times_out() {
+    let calls = Arc::new(Calls::default());
+    let client = client(&spawn_server(vec![3], Arc::clone(&calls)).await);
+
+    let started = std::time::Instant::now();
+    let remaining = client
+        .cancel_all_and_confirm(AllCategories::Linear, "BTCUSDT", Duration::from_millis(300))
+        .await
+        .unwrap();
+
+    assert_eq!(remaining, 3);
+    assert!(started.elapsed() < Duration::from_secs(2));
+    assert!(calls.realtime.load(Ordering::SeqCst) >= 2);
+}
+
+#[tokio::test]
+async fn test_cancel_all_orders_typed() {
+    let calls = Arc::new(Calls::default());
+    let client = client(&spawn_server(vec![0], Arc::clone(&calls)).await);
+
+    let result = client
+        .cancel_all_orders_typed(
+            AllCategories::Linear,
+            Some("BTCUSDT"),
+            None,
+            None,
+            None,
+            None,
+        )
+        .await
+        .unwrap();
+
+    assert!(result.list.is_empty());
+    assert_eq!(result.success, "1");
+    assert_eq!(calls.cancel_all.load(Ordering::SeqCst), 1);
+}
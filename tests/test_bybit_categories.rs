@@ -0,0 +1,99 @@
+//! Tests for category restrictions on derivative-only Bybit position methods.
+
+use trade_sdk::bybit::{traits::PositionApi, types::AllCategories, BybitClient};
+use trade_sdk::Error;
+
+const REJECTED: [AllCategories; 2] = [AllCategories::Spot, AllCategories::Option];
+
+fn client() -> BybitClient {
+    // Nothing listens on the discard port, so accepted calls fail fast without network access.
+    BybitClient::new(
+        Some("key".to_string()),
+        Some("secret".to_string()),
+        false,
+        false,
+        5000,
+        None,
+    )
+    .unwrap()
+    .with_base_url("http://127.0.0.1:9")
+}
+
+fn assert_rejected(
+    result: trade_sdk::Result<impl std::fmt::Debug>,
+    method: &str,
+    category: &AllCategories,
+) {
+    match result {
+        Err(Error::Validation(msg)) => {
+            assert!(msg.contains(method), "{msg}");
+            assert!(msg.contains(&category.to_string()), "{msg}");
+        }
+        other => panic!("{method} with {category}: expected validation error, got {other:?}"),
+    }
+}
+
+#[test]
+fn test_is_derivative() {
+    assert!(AllCategories::Linear.is_derivative());
+    assert!(AllCategories::Inverse.is_derivative());
+    assert!(!AllCategories::Spot.is_derivative());
+    assert!(!AllCategories::Option.is_derivative());
+}
+
+#[tokio::test]
+async fn test_set_leverage_rejects_non_derivative_categories() {
+    let client = client();
+    for category in REJECTED {
+        let result = client
+            .set_leverage(category.clone(), "BTCUSDT", 10, 10)
+            .await;
+        assert_rejected(result, "set_leverage", &category);
+    }
+}
+
+#[tokio::test]
+async fn test_switch_position_mode_rejects_non_derivative_categories() {
+    let client = client();
+    for category in REJECTED {
+        let result = client
+            .switch_position_mode(category.clone(), 3, Some("BTCUSDT"), None)
+            .await;
+        assert_rejected(result, "switch_position_mode", &category);
+    }
+}
+
+#[tokio::test]
+async fn test_get_closed_pnl_rejects_non_derivative_categories() {
+    let client = client();
+    for category in REJECTED {
+        let result = client
+            .get_closed_pnl(category.clone(), None, None, None, None, None)
+            .await;
+        assert_rejected(result, "get_closed_pnl", &category);
+    }
+}
+
+#[tokio::test]
+async fn test_inverse_passes_category_validation() {
+    let client = client();
+    let leverage = client
+        .set_leverage(AllCategories::Inverse, "BTCUSD", 10, 10)
+        .await;
+    let mode = client
+        .switch_position_mode(AllCategories::Inverse, 3, None, Some("BTC"))
+        .await;
+    let pnl = client
+        .get_closed_pnl(
+            AllCategories::Inverse,
+            Some("BTCUSD"),
+            None,
+            None,
+            None,
+            None,
+        )
+        .await;
+    assert!(!matches!(leverage, Err(Error::Validation(_))));
+    assert!(!matches!(mode, Err(Error::Validation(_))));
+    assert!(!matches!(pnl, Err(Error::Validation(_))));
+}
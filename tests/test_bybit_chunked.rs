@@ -0,0 +1,191 @@
+//! Tests for `batch_place_order_chunked` against a local mock server.
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+
+use serde_json::{json, Value};
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::{TcpListener, TcpStream};
+use trade_sdk::bybit::{
+    types::{AllCategories, PlaceOrderParams},
+    BybitClient,
+};
+
+/// Read one HTTP request and return its JSON body.
+async fn read_json_body(socket: &mut TcpStream) -> Value {
+    let mut buf = Vec::new();
+    let mut chunk = [0u8; 4096];
+    loop {
+        let n = socket.read(&mut chunk).await.unwrap();
+        buf.extend_from_slice(&chunk[..n]);
+        let text = String::from_utf8_lossy(&buf);
+        if let Some(header_end) = text.find("\r\n\r\n") {
+            let content_length = text[..header_end]
+                .lines()
+                .find_map(|l| {
+                    let (name, value) = l.split_once(':')?;
+                    name.eq_ignore_ascii_case("content-length")
+                        .then(|| value.trim().parse::<usize>().unwrap())
+                })
+                .unwrap_or(0);
+            if buf.len() >= header_end + 4 + content_length {
+                return serde_json::from_slice(&buf[header_end + 4..]).unwrap();
+            }
+        }
+        if n == 0 {
+            panic!("connection closed before the body was read");
+        }
+    }
+}
+
+/// Answer `/v5/order/create-batch`; a batch containing `reject-batch` fails as a whole and
+/// orders with a `reject-` link ID are rejected individually.
+async fn spawn_batch_server(requests: Arc<AtomicUsize>) -> String {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    tokio::spawn(async move {
+        while let Ok((mut socket, _)) = listener.accept().await {
+            let requests = Arc::clone(&requests);
+            tokio::spawn(async move {
+                let body = read_json_body(&mut socket).await;
+                requests.fetch_add(1, Ordering::SeqCst);
+                let orders = body["request"].as_array().unwrap();
+                let link_ids: Vec<&str> = orders
+                    .iter()
+                    .map(|o| o["orderLinkId"].as_str().unwrap())
+                    .collect();
+
+                let response = if link_ids.contains(&"reject-batch") {
+                    json!({"retCode": 10001, "retMsg": "params error", "result": {}, "retExtInfo": {}, "time": 0})
+                } else {
+                    let list: Vec<Value> = link_ids
+                        .iter()
+                        .map(|id| {
+                            let order_id = if id.starts_with("reject-") {
+                                String::new()
+                            } else {
+                                format!("oid-{id}")
+                            };
+                            json!({"category": "linear", "symbol": "BTCUSDT", "orderId": order_id, "orderLinkId": id})
+                        })
+                        .collect();
+                    let ext: Vec<Value> = link_ids
+                        .iter()
+                        .map(|id| {
+                            if id.starts_with("reject-") {
+                                json!({"code": 170130, "msg": "Data sent for paramter '' is out of range"})
+                            } else {
+                                json!({"code": 0, "msg": "OK"})
+                            }
+                        })
+                        .collect();
+                    json!({"retCode": 0, "retMsg": "OK", "result": {"list": list}, "retExtInfo": {"list": ext}, "time": 0})
+                };
+                let body = response.to_string();
+                let response = format!(
+                    "HTTP/1.1 200 OK\r\ncontent-type: application/json\r\ncontent-length: {}\r\nconnection: close\r\n\r\n{}",
+                    body.len(),
+                    body
+                );
+                let _ = socket.write_all(response.as_bytes()).await;
+            });
+        }
+    });
+    format!("http://{addr}")
+}
+
+fn orders(link_ids: impl IntoIterator<Item = String>) -> Vec<PlaceOrderParams> {
+    link_ids
+        .into_iter()
+        .map(|id| PlaceOrderParams {
+            symbol: "BTCUSDT".to_string(),
+            qty: "0.001".parse().unwrap(),
+            order_link_id: Some(id),
+            ..Default::default()
+        })
+        .collect()
+}
+
+fn client(base_url: &str) -> BybitClient {
+    BybitClient::new(
+        Some("key".to_string()),
+        Some("secret".to_string()),
+        false,
+        false,
+        5000,
+        None,
+    )
+    .unwrap()
+    .with_base_url(base_url)
+}
+
+#[tokio::test]
+async fn test_chunked_batch_preserves_order() {
+    let requests = Arc::new(AtomicUsize::new(0));
+    let client = client(&spawn_batch_server(Arc::clone(&requests)).await);
+
+    let orders = orders((0..45).map(|i| format!("o-{i}")));
+    let results = client
+        .batch_place_order_chunked(AllCategories::Linear, &orders)
+        .await;
+
+    // 45 linear orders: 20 + 20 + 5.
+    assert_eq!(requests.load(Ordering::SeqCst), 3);
+    assert_eq!(results.len(), 45);
+    for (i, result) in results.iter().enumerate() {
+        assert!(result.is_ok(), "{result:?}");
+        assert_eq!(result.order_link_id, format!("o-{i}"));
+        assert_eq!(result.order_id, format!("oid-o-{i}"));
+    }
+}
+
+#[tokio::test]
+async fn test_chunked_batch_option_limit() {
+    let requests = Arc::new(AtomicUsize::new(0));
+    let client = client(&spawn_batch_server(Arc::clone(&requests)).await);
+
+    let orders = orders((0..45).map(|i| format!("o-{i}")));
+    let results = client
+        .batch_place_order_chunked(AllCategories::Option, &orders)
+        .await;
+
+    assert_eq!(requests.load(Ordering::SeqCst), 5);
+    assert_eq!(results.len(), 45);
+}
+
+#[tokio::test]
+async fn test_chunked_batch_partial_failures() {
+    let requests = Arc::new(AtomicUsize::new(0));
+    let client = client(&spawn_batch_server(Arc::clone(&requests)).await);
+
+    // Second chunk fails as a whole, one order in the first chunk is rejected.
+    let mut link_ids: Vec<String> = (0..45).map(|i| format!("o-{i}")).collect();
+    link_ids[3] = "reject-3".to_string();
+    link_ids[25] = "reject-batch".to_string();
+    let results = client
+        .batch_place_order_chunked(AllCategories::Linear, &orders(link_ids))
+        .await;
+
+    assert_eq!(results.len(), 45);
+    assert_eq!(results[3].code, 170130);
+    assert!(results[3].order_id.is_empty());
+    assert_eq!(results[3].order_link_id, "reject-3");
+    for result in &results[20..40] {
+        assert_eq!(result.code, 10001);
+        assert!(result.order_id.is_empty());
+    }
+    assert_eq!(results[25].order_link_id, "reject-batch");
+    let ok = results.iter().filter(|r| r.is_ok()).count();
+    assert_eq!(ok, 45 - 1 - 20);
+}
+
+#[tokio::test]
+async fn test_chunked_batch_empty() {
+    let requests = Arc::new(AtomicUsize::new(0));
+    let client = client(&spawn_batch_server(Arc::clone(&requests)).await);
+    let results = client
+        .batch_place_order_chunked(AllCategories::Linear, &[])
+        .await;
+    assert!(results.is_empty());
+    assert_eq!(requests.load(Ordering::SeqCst), 0);
+}
@@ -0,0 +1,50 @@
+//! Tests for process-wide Bybit client defaults.
+
+use trade_sdk::bybit::{BybitClient, BybitDefaults};
+use trade_sdk::BybitClientsCache;
+
+#[test]
+fn test_defaults_applied_to_new_clients() {
+    // One test changes the global defaults, so the steps run in order.
+    let client = BybitClient::new_with_defaults(None, None, false, false).unwrap();
+    assert_eq!(client.recv_window(), 5000);
+    assert_eq!(client.referral_id(), None);
+
+    BybitDefaults::set(12_000, Some("ref-partner".to_string()));
+    assert_eq!(BybitDefaults::recv_window(), 12_000);
+    assert_eq!(BybitDefaults::referral_id().as_deref(), Some("ref-partner"));
+
+    let client = BybitClient::new_with_defaults(None, None, true, false).unwrap();
+    assert_eq!(client.recv_window(), 12_000);
+    assert_eq!(client.referral_id(), Some("ref-partner"));
+
+    let cached =
+        BybitClientsCache::get_or_create("defaults-test-key", "secret", false, false).unwrap();
+    assert_eq!(cached.recv_window(), 12_000);
+    assert_eq!(cached.referral_id(), Some("ref-partner"));
+
+    // Existing clients keep the settings they were created with.
+    BybitDefaults::reset();
+    assert_eq!(client.recv_window(), 12_000);
+    let client = BybitClient::new_with_defaults(None, None, false, false).unwrap();
+    assert_eq!(client.recv_window(), 5000);
+    assert_eq!(client.referral_id(), None);
+}
+
+#[test]
+fn test_explicit_args_override_defaults() {
+    let client = BybitClient::new(
+        None,
+        None,
+        false,
+        false,
+        3000,
+        Some("explicit-ref".to_string()),
+    )
+    .unwrap();
+    assert_eq!(client.recv_window(), 3000);
+    assert_eq!(client.referral_id(), Some("explicit-ref"));
+
+    let client = BybitClient::new(None, None, false, false, 5000, None).unwrap();
+    assert_eq!(client.referral_id(), None);
+}
@@ -0,0 +1,150 @@
+//! Tests for exporting Bybit order history to CSV.
+
+use serde_json::json;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpListener;
+use trade_sdk::bybit::types::{AllCategories, GetOrderHistoryParams};
+use trade_sdk::bybit::BybitClient;
+use trade_sdk::Error;
+
+const HEADER: &str = "orderId,orderLinkId,symbol,side,orderType,orderStatus,price,qty,avgPrice,cumExecQty,cumExecValue,cumExecFee,timeInForce,reduceOnly,createdTime,updatedTime";
+
+/// Serve two pages of `/v5/order/history`: page 1 without a cursor, page 2 for `cursor=page2`.
+async fn spawn_server() -> String {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    tokio::spawn(async move {
+        while let Ok((mut socket, _)) = listener.accept().await {
+            tokio::spawn(async move {
+                let mut buf = vec![0u8; 8192];
+                let n = socket.read(&mut buf).await.unwrap();
+                let request = String::from_utf8_lossy(&buf[..n]);
+                let request_line = request.lines().next().unwrap_or_default();
+                assert!(request_line.contains("/v5/order/history"), "{request_line}");
+
+                let result = if request_line.contains("cursor=page2") {
+                    json!({
+                        "category": "linear",
+                        "list": [{
+                            "orderId": "3",
+                            "orderLinkId": "tag,with \"quotes\"",
+                            "symbol": "ETHUSDT",
+                            "side": "Sell",
+                            "orderType": "Limit",
+                            "orderStatus": "Cancelled",
+                            "price": "3000",
+                            "qty": "0.5",
+                            "reduceOnly": true,
+                            "createdTime": "1700000002000"
+                        }],
+                        "nextPageCursor": ""
+                    })
+                } else {
+                    let order = |id: &str| {
+                        json!({
+                            "orderId": id,
+                            "orderLinkId": "",
+                            "symbol": "BTCUSDT",
+                            "side": "Buy",
+                            "orderType": "Market",
+                            "orderStatus": "Filled",
+                            "price": "42000",
+                            "qty": "0.01",
+                            "avgPrice": "41990.5",
+                            "cumExecQty": "0.01",
+                            "cumExecValue": "419.905",
+                            "cumExecFee": "0.2309",
+                            "timeInForce": "IOC",
+                            "reduceOnly": false,
+                            "createdTime": "1700000000000",
+                            "updatedTime": "1700000000100"
+                        })
+                    };
+                    json!({
+                        "category": "linear",
+                        "list": [order("1"), order("2")],
+                        "nextPageCursor": "page2"
+                    })
+                };
+                let body = json!({"retCode": 0, "retMsg": "OK", "result": result, "retExtInfo": {}, "time": 1})
+                    .to_string();
+                let response = format!(
+                    "HTTP/1.1 200 OK\r\ncontent-type: application/json\r\ncontent-length: {}\r\nconnection: close\r\n\r\n{}",
+                    body.len(),
+                    body
+                );
+                let _ = socket.write_all(response.as_bytes()).await;
+            });
+        }
+    });
+    format!("http://{addr}")
+}
+
+fn client(url: &str) -> BybitClient {
+    BybitClient::new(
+        Some("key".to_string()),
+        Some("secret".to_string()),
+        false,
+        false,
+        5000,
+        None,
+    )
+    .unwrap()
+    .with_base_url(url)
+}
+
+#[tokio::test]
+async fn test_export_order_history_csv_two_pages() {
+    let client = client(&spawn_server().await);
+    let params = GetOrderHistoryParams {
+        symbol: Some("BTCUSDT".to_string()),
+        limit: Some(2),
+        ..Default::default()
+    };
+
+    let mut buffer = Vec::new();
+    let rows = client
+        .export_order_history_csv(AllCategories::Linear, Some(&params), &mut buffer)
+        .await
+        .unwrap();
+
+    let csv = String::from_utf8(buffer).unwrap();
+    let lines: Vec<&str> = csv.lines().collect();
+    assert_eq!(rows, 3);
+    assert_eq!(lines.len(), 4);
+    assert_eq!(lines[0], HEADER);
+    assert_eq!(
+        lines[1],
+        "1,,BTCUSDT,Buy,Market,Filled,42000,0.01,41990.5,0.01,419.905,0.2309,IOC,false,1700000000000,1700000000100"
+    );
+    // Missing fields are empty cells; cells with commas or quotes are quoted.
+    assert_eq!(
+        lines[3],
+        "3,\"tag,with \"\"quotes\"\"\",ETHUSDT,Sell,Limit,Cancelled,3000,0.5,,,,,,true,1700000002000,"
+    );
+}
+
+/// Writer failing on every write.
+struct BrokenWriter;
+
+impl std::io::Write for BrokenWriter {
+    fn write(
+        &mut self,
+        _: &[u8],
+    ) -> std::io::Result<usize> {
+        Err(std::io::Error::other("disk full"))
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+#[tokio::test]
+async fn test_export_order_history_csv_write_error() {
+    let client = client(&spawn_server().await);
+    let result = client
+        .export_order_history_csv(AllCategories::Linear, None, BrokenWriter)
+        .await;
+    assert!(matches!(result, Err(Error::Io(_))), "{result:?}");
+}
@@ -0,0 +1,417 @@
+//! Tests for the Bybit market data endpoints.
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
+use serde_json::{json, Value};
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpListener;
+use trade_sdk::bybit::{traits::MarketApi, types::AllCategories, BybitClient, BYBIT_IMPLEMENTED};
+use trade_sdk::testkit::{MockExchange, RecordedRequest};
+use trade_sdk::Error;
+
+/// Mock exchange answering every request with [`ok_body`].
+async fn mock_exchange() -> MockExchange {
+    let exchange = MockExchange::start().await;
+    exchange.respond_to_any(ok_body());
+    exchange
+}
+
+/// Request lines received so far, e.g. `GET /v5/market/insurance?coin=USDT`.
+fn request_lines(exchange: &MockExchange) -> Vec<String> {
+    exchange
+        .requests()
+        .iter()
+        .map(RecordedRequest::request_line)
+        .collect()
+}
+
+/// Serve tickers like Bybit: one ticker for the requested symbol, an exchange error for
+/// `BADUSDT`. Responses are delayed so concurrent requests overlap; the peak number in flight
+/// and the number served are recorded.
+async fn spawn_ticker_server(
+    served: Arc<AtomicUsize>,
+    peak_in_flight: Arc<AtomicUsize>,
+) -> String {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    let in_flight = Arc::new(AtomicUsize::new(0));
+    tokio::spawn(async move {
+        while let Ok((mut socket, _)) = listener.accept().await {
+            let served = Arc::clone(&served);
+            let in_flight = Arc::clone(&in_flight);
+            let peak_in_flight = Arc::clone(&peak_in_flight);
+            tokio::spawn(async move {
+                let now = in_flight.fetch_add(1, Ordering::SeqCst) + 1;
+                peak_in_flight.fetch_max(now, Ordering::SeqCst);
+                // Ticker requests are bodyless GETs, so one read holds the request line.
+                let mut buf = [0u8; 4096];
+                let n = socket.read(&mut buf).await.unwrap();
+                let symbol = String::from_utf8_lossy(&buf[..n])
+                    .split("symbol=")
+                    .nth(1)
+                    .and_then(|rest| rest.split(['&', ' ']).next())
+                    .unwrap_or_default()
+                    .to_string();
+                served.fetch_add(1, Ordering::SeqCst);
+                tokio::time::sleep(Duration::from_millis(50)).await;
+                in_flight.fetch_sub(1, Ordering::SeqCst);
+
+                let body = if symbol == "BADUSDT" {
+                    json!({"retCode": 10001, "retMsg": "Not supported symbols", "result": {}, "retExtInfo": {}, "time": 1})
+                } else {
+                    json!({
+                        "retCode": 0,
+                        "retMsg": "OK",
+                        "result": {"category": "linear", "list": [{"symbol": symbol, "lastPrice": "1"}]},
+                        "retExtInfo": {},
+                        "time": 1
+                    })
+                }
+                .to_string();
+                let response = format!(
+                    "HTTP/1.1 200 OK\r\ncontent-type: application/json\r\ncontent-length: {}\r\nconnection: close\r\n\r\n{}",
+                    body.len(),
+                    body
+                );
+                let _ = socket.write_all(response.as_bytes()).await;
+            });
+        }
+    });
+    format!("http://{addr}")
+}
+
+fn ok_body() -> Value {
+    json!({"retCode": 0, "retMsg": "OK", "result": {"list": []}, "retExtInfo": {}, "time": 1})
+}
+
+fn client(base_url: &str) -> BybitClient {
+    BybitClient::new(None, None, false, false, 5000, None)
+        .unwrap()
+        .with_base_url(base_url)
+}
+
+#[tokio::test]
+async fn test_historical_volatility_rejects_non_option_categories() {
+    // Nothing listens on the discard port; the guard must fail before any request.
+    let client = client("http://127.0.0.1:9");
+    for category in [
+        AllCategories::Spot,
+        AllCategories::Linear,
+        AllCategories::Inverse,
+    ] {
+        match client
+            .get_historical_volatility(category.clone(), None, None, None, None)
+            .await
+        {
+            Err(Error::Validation(msg)) => {
+                assert!(msg.contains("get_historical_volatility"), "{msg}");
+                assert!(msg.contains(&category.to_string()), "{msg}");
+            }
+            other => panic!("{category}: expected validation error, got {other:?}"),
+        }
+    }
+}
+
+#[tokio::test]
+async fn test_historical_volatility_path_and_params() {
+    let exchange = mock_exchange().await;
+    let url = exchange.base_url();
+
+    client(&url)
+        .get_historical_volatility(
+            AllCategories::Option,
+            Some("ETH"),
+            Some(30),
+            Some(1_700_000_000_000),
+            Some(1_700_086_400_000),
+        )
+        .await
+        .unwrap();
+
+    let line = request_lines(&exchange)[0].clone();
+    assert!(
+        line.starts_with("GET /v5/market/historical-volatility?"),
+        "{line}"
+    );
+    for param in [
+        "category=option",
+        "baseCoin=ETH",
+        "period=30",
+        "startTime=1700000000000",
+        "endTime=1700086400000",
+    ] {
+        assert!(line.contains(param), "{param} missing from {line}");
+    }
+}
+
+#[tokio::test]
+async fn test_insurance_pool_path_and_params() {
+    let exchange = mock_exchange().await;
+    let url = exchange.base_url();
+    let client = client(&url);
+
+    client.get_insurance_pool(Some("USDT")).await.unwrap();
+    client.get_insurance_pool(None).await.unwrap();
+
+    let recorded = request_lines(&exchange);
+    assert_eq!(recorded[0], "GET /v5/market/insurance?coin=USDT");
+    assert!(
+        recorded[1].starts_with("GET /v5/market/insurance"),
+        "{}",
+        recorded[1]
+    );
+    assert!(!recorded[1].contains("coin="), "{}", recorded[1]);
+}
+
+#[tokio::test]
+async fn test_delivery_price_rejects_spot_and_linear() {
+    let client = client("http://127.0.0.1:9");
+    for category in [AllCategories::Spot, AllCategories::Linear] {
+        let delivery = client
+            .get_delivery_price(category.clone(), None, None, None, None, None)
+            .await;
+        assert!(
+            matches!(&delivery, Err(Error::Validation(msg)) if msg.contains("get_delivery_price")),
+            "{category}: {delivery:?}"
+        );
+    }
+    for category in [
+        AllCategories::Spot,
+        AllCategories::Linear,
+        AllCategories::Inverse,
+    ] {
+        let latest = client
+            .get_new_delivery_price(category.clone(), "BTC", None)
+            .await;
+        assert!(
+            matches!(&latest, Err(Error::Validation(msg)) if msg.contains("get_new_delivery_price")),
+            "{category}: {latest:?}"
+        );
+    }
+}
+
+#[tokio::test]
+async fn test_delivery_price_paths_and_params() {
+    let exchange = mock_exchange().await;
+    let url = exchange.base_url();
+    let client = client(&url);
+
+    client
+        .get_delivery_price(
+            AllCategories::Inverse,
+            Some("BTCUSDH25"),
+            None,
+            None,
+            Some(10),
+            None,
+        )
+        .await
+        .unwrap();
+    client
+        .get_new_delivery_price(AllCategories::Option, "ETH", Some("USDT"))
+        .await
+        .unwrap();
+
+    let recorded = request_lines(&exchange);
+    let delivery = &recorded[0];
+    assert!(
+        delivery.starts_with("GET /v5/market/delivery-price?"),
+        "{delivery}"
+    );
+    for param in ["category=inverse", "symbol=BTCUSDH25", "limit=10"] {
+        assert!(delivery.contains(param), "{param} missing from {delivery}");
+    }
+    let latest = &recorded[1];
+    assert!(
+        latest.starts_with("GET /v5/market/new-delivery-price?"),
+        "{latest}"
+    );
+    for param in ["category=option", "baseCoin=ETH", "settleCoin=USDT"] {
+        assert!(latest.contains(param), "{param} missing from {latest}");
+    }
+}
+
+#[tokio::test]
+async fn test_price_band_endpoints_require_symbol() {
+    let client = client("http://127.0.0.1:9");
+
+    let components = client.get_index_price_components("").await;
+    let limit = client
+        .get_order_price_limit(AllCategories::Linear, " ")
+        .await;
+
+    assert!(
+        matches!(&components, Err(Error::Validation(msg)) if msg.contains("get_index_price_components")),
+        "{components:?}"
+    );
+    assert!(
+        matches!(&limit, Err(Error::Validation(msg)) if msg.contains("get_order_price_limit")),
+        "{limit:?}"
+    );
+}
+
+#[tokio::test]
+async fn test_price_band_endpoints_paths_and_params() {
+    let exchange = mock_exchange().await;
+    let url = exchange.base_url();
+    let client = client(&url);
+
+    client.get_index_price_components("BTCUSDT").await.unwrap();
+    client
+        .get_order_price_limit(AllCategories::Linear, "BTCUSDT")
+        .await
+        .unwrap();
+
+    let recorded = request_lines(&exchange);
+    assert_eq!(
+        recorded[0],
+        "GET /v5/market/index-price-components?indexName=BTCUSDT"
+    );
+    let limit = &recorded[1];
+    assert!(limit.starts_with("GET /v5/market/price-limit?"), "{limit}");
+    assert!(limit.contains("category=linear"), "{limit}");
+    assert!(limit.contains("symbol=BTCUSDT"), "{limit}");
+}
+
+#[tokio::test]
+async fn test_adl_alert_and_fee_group_paths_and_params() {
+    let exchange = mock_exchange().await;
+    let url = exchange.base_url();
+    let client = client(&url);
+
+    client.get_adl_alert(Some("BTCUSDT")).await.unwrap();
+    client
+        .get_fee_group_structure("contract", Some("1"))
+        .await
+        .unwrap();
+
+    let recorded = request_lines(&exchange);
+    assert_eq!(recorded[0], "GET /v5/market/adlAlert?symbol=BTCUSDT");
+    let fee = &recorded[1];
+    assert!(fee.starts_with("GET /v5/market/fee-group-info?"), "{fee}");
+    assert!(fee.contains("productType=contract"), "{fee}");
+    assert!(fee.contains("groupId=1"), "{fee}");
+}
+
+#[test]
+fn test_market_methods_registered() {
+    for method in [
+        "get_historical_volatility",
+        "get_insurance_pool",
+        "get_delivery_price",
+        "get_new_delivery_price",
+        "get_index_price_components",
+        "get_order_price_limit",
+        "get_adl_alert",
+        "get_fee_group_structure",
+        "get_rpi_orderbook",
+        "get_tickers",
+    ] {
+        assert!(
+            BYBIT_IMPLEMENTED.contains(&method),
+            "{method} missing from BYBIT_IMPLEMENTED"
+        );
+    }
+}
+
+#[tokio::test]
+async fn test_rpi_orderbook_params() {
+    let exchange = mock_exchange().await;
+    let url = exchange.base_url();
+    let client = client(&url);
+
+    client
+        .get_rpi_orderbook(AllCategories::Linear, "BTCUSDT", Some(25))
+        .await
+        .unwrap();
+    client
+        .get_rpi_orderbook(AllCategories::Spot, "ETHUSDT", None)
+        .await
+        .unwrap();
+
+    let recorded = request_lines(&exchange);
+    let explicit = &recorded[0];
+    assert!(
+        explicit.starts_with("GET /v5/market/rpi_orderbook?"),
+        "{explicit}"
+    );
+    for param in ["category=linear", "symbol=BTCUSDT", "limit=25"] {
+        assert!(explicit.contains(param), "{param} missing from {explicit}");
+    }
+    // Bybit requires `limit` on this endpoint, so the default depth is always sent.
+    assert!(recorded[1].contains("limit=50"), "{}", recorded[1]);
+}
+
+#[tokio::test]
+async fn test_rpi_orderbook_validation() {
+    let client = client("http://127.0.0.1:9");
+
+    for limit in [0, 51] {
+        let result = client
+            .get_rpi_orderbook(AllCategories::Linear, "BTCUSDT", Some(limit))
+            .await;
+        assert!(
+            matches!(&result, Err(Error::Validation(msg)) if msg.contains("limit")),
+            "{limit}: {result:?}"
+        );
+    }
+    let option = client
+        .get_rpi_orderbook(AllCategories::Option, "BTC-27JUN25-100000-C", None)
+        .await;
+    assert!(matches!(option, Err(Error::Validation(_))), "{option:?}");
+}
+
+#[tokio::test]
+async fn test_tickers_params() {
+    let exchange = mock_exchange().await;
+    let url = exchange.base_url();
+    let client = client(&url);
+
+    client
+        .get_tickers(AllCategories::Option, None, Some("BTC"), Some("25DEC22"))
+        .await
+        .unwrap();
+
+    let line = request_lines(&exchange)[0].clone();
+    assert!(line.starts_with("GET /v5/market/tickers?"), "{line}");
+    assert!(line.contains("category=option"), "{line}");
+    assert!(line.contains("baseCoin=BTC"), "{line}");
+    assert!(line.contains("expDate=25DEC22"), "{line}");
+    assert!(!line.contains("symbol="), "{line}");
+}
+
+#[tokio::test]
+async fn test_tickers_many_fans_out_per_symbol() {
+    let served = Arc::new(AtomicUsize::new(0));
+    let peak = Arc::new(AtomicUsize::new(0));
+    let url = spawn_ticker_server(Arc::clone(&served), Arc::clone(&peak)).await;
+    let client = client(&url);
+    let symbols = ["BTCUSDT", "ETHUSDT", "SOLUSDT", "XRPUSDT", "DOGEUSDT"];
+
+    let (tickers, errors) = client
+        .get_tickers_many(AllCategories::Linear, &symbols, 2)
+        .await;
+
+    assert!(errors.is_empty(), "{errors:?}");
+    assert_eq!(tickers.len(), symbols.len());
+    for symbol in symbols {
+        assert_eq!(tickers[symbol]["symbol"], symbol);
+    }
+    assert_eq!(served.load(Ordering::SeqCst), symbols.len());
+    assert!(peak.load(Ordering::SeqCst) <= 2, "peak {peak:?}");
+}
+
+#[tokio::test]
+async fn test_tickers_many_separates_errors() {
+    let url = spawn_ticker_server(Arc::default(), Arc::default()).await;
+    let client = client(&url);
+
+    let (tickers, errors) = client
+        .get_tickers_many(AllCategories::Linear, &["BTCUSDT", "BADUSDT"], 0)
+        .await;
+
+    assert_eq!(tickers.keys().collect::<Vec<_>>(), ["BTCUSDT"]);
+    assert_eq!(errors["BADUSDT"].exchange_code(), Some(10001));
+}
@@ -0,0 +1,522 @@
+//! Tests for the typed Bybit response models.
+
+use serde_json::json;
+use serde_json::Value;
+use trade_sdk::bybit::models::{
+    parse_klines, CancelAllResult, Candle, CoinGreeksResult, InstrumentsInfoResult, OrderAck,
+    PositionInfoResult, WalletBalanceResult,
+};
+use trade_sdk::bybit::types::ApiResponse;
+use trade_sdk::{round_to_step, round_to_tick, Amount, Error};
+
+fn amount(s: &str) -> Amount {
+    s.parse().unwrap()
+}
+
+#[test]
+fn test_position_info_result() {
+    // Recorded `/v5/position/list` result (linear, one open position and one empty slot).
+    let payload = json!({
+        "list": [
+            {
+                "positionIdx": 0,
+                "riskId": 1,
+                "riskLimitValue": "2000000",
+                "symbol": "BTCUSDT",
+                "side": "Buy",
+                "size": "0.005",
+                "avgPrice": "64250.5",
+                "positionValue": "321.2525",
+                "tradeMode": 0,
+                "positionStatus": "Normal",
+                "autoAddMargin": 0,
+                "adlRankIndicator": 2,
+                "leverage": "10",
+                "positionBalance": "32.15",
+                "markPrice": "64300.1",
+                "liqPrice": "",
+                "bustPrice": "",
+                "positionMM": "1.77",
+                "positionIM": "32.13",
+                "tpslMode": "Full",
+                "takeProfit": "70000",
+                "stopLoss": "",
+                "trailingStop": "0",
+                "unrealisedPnl": "0.248",
+                "curRealisedPnl": "-0.17",
+                "cumRealisedPnl": "-12.5",
+                "seq": 8172241024_i64,
+                "isReduceOnly": false,
+                "createdTime": "1676538056258",
+                "updatedTime": "1697673600012"
+            },
+            {
+                "positionIdx": 0,
+                "symbol": "ETHUSDT",
+                "side": "",
+                "size": "0",
+                "avgPrice": "0",
+                "positionValue": "0",
+                "leverage": "",
+                "markPrice": "3100.2",
+                "liqPrice": "",
+                "unrealisedPnl": "",
+                "cumRealisedPnl": "0",
+                "positionStatus": "Normal",
+                "createdTime": "1676538056258",
+                "updatedTime": "1676538056258"
+            }
+        ],
+        "nextPageCursor": "BTCUSDT%2C1697673600012%2C0",
+        "category": "linear"
+    });
+
+    let result: PositionInfoResult = serde_json::from_value(payload).unwrap();
+    assert_eq!(result.category, "linear");
+    assert_eq!(result.next_page_cursor, "BTCUSDT%2C1697673600012%2C0");
+    assert_eq!(result.list.len(), 2);
+
+    let btc = &result.list[0];
+    assert_eq!(btc.symbol, "BTCUSDT");
+    assert_eq!(btc.side, "Buy");
+    assert_eq!(btc.size, amount("0.005"));
+    assert_eq!(btc.avg_price, amount("64250.5"));
+    assert_eq!(btc.position_value, amount("321.2525"));
+    assert_eq!(btc.unrealised_pnl, amount("0.248"));
+    assert_eq!(btc.cum_realised_pnl, amount("-12.5"));
+    assert_eq!(btc.leverage, amount("10"));
+    assert_eq!(btc.take_profit, amount("70000"));
+    assert_eq!(btc.liq_price, Amount::default());
+    assert_eq!(btc.stop_loss, Amount::default());
+
+    let eth = &result.list[1];
+    assert_eq!(eth.side, "");
+    assert_eq!(eth.size, Amount::default());
+    assert_eq!(eth.leverage, Amount::default());
+    assert_eq!(eth.unrealised_pnl, Amount::default());
+}
+
+#[test]
+fn test_position_info_result_empty_list() {
+    let payload = json!({"list": [], "nextPageCursor": "", "category": "linear"});
+    let result: PositionInfoResult = serde_json::from_value(payload).unwrap();
+    assert!(result.list.is_empty());
+    assert!(result.next_page_cursor.is_empty());
+}
+
+#[test]
+fn test_position_rejects_non_numeric_size() {
+    let payload = json!({"list": [{"symbol": "BTCUSDT", "size": "abc"}], "nextPageCursor": ""});
+    assert!(serde_json::from_value::<PositionInfoResult>(payload).is_err());
+}
+
+#[test]
+fn test_wallet_balance_result_from_response() {
+    let response: ApiResponse<Value> = serde_json::from_value(json!({
+        "retCode": 0,
+        "retMsg": "OK",
+        "result": {
+            "list": [{
+                "accountType": "UNIFIED",
+                "totalEquity": "3.31216591",
+                "totalWalletBalance": "3.00326056",
+                "accountIMRate": "0",
+                "coin": [
+                    {
+                        "coin": "USDT",
+                        "walletBalance": "3.00326056",
+                        "equity": "3.31216591",
+                        "availableToWithdraw": "",
+                        "usdValue": "3.31"
+                    },
+                    {
+                        "coin": "BTC",
+                        "walletBalance": "",
+                        "equity": "",
+                        "availableToWithdraw": ""
+                    }
+                ]
+            }]
+        },
+        "retExtInfo": {},
+        "time": 1690872862481_u64
+    }))
+    .unwrap();
+
+    let balance = response.parse::<WalletBalanceResult>().unwrap();
+    assert_eq!(balance.list.len(), 1);
+    let account = &balance.list[0];
+    assert_eq!(account.account_type, "UNIFIED");
+    assert_eq!(account.total_equity, amount("3.31216591"));
+    assert_eq!(account.total_wallet_balance, amount("3.00326056"));
+
+    let usdt = &account.coin[0];
+    assert_eq!(usdt.coin, "USDT");
+    assert_eq!(usdt.wallet_balance, amount("3.00326056"));
+    assert_eq!(usdt.equity, amount("3.31216591"));
+    assert_eq!(usdt.available_to_withdraw, Amount::default());
+
+    // Empty strings map to zero.
+    let btc = &account.coin[1];
+    assert_eq!(btc.wallet_balance, Amount::default());
+    assert_eq!(btc.equity, Amount::default());
+    assert_eq!(btc.available_to_withdraw, Amount::default());
+}
+
+#[test]
+fn test_parse_reports_mismatched_result() {
+    let response: ApiResponse<Value> = serde_json::from_value(json!({
+        "retCode": 0,
+        "retMsg": "OK",
+        "result": {"list": "not a list"},
+        "time": 0
+    }))
+    .unwrap();
+    assert!(matches!(
+        response.parse::<WalletBalanceResult>(),
+        Err(Error::Json(_))
+    ));
+}
+
+#[test]
+fn test_coin_greeks_result() {
+    let response: ApiResponse<Value> = serde_json::from_value(json!({
+        "retCode": 0,
+        "retMsg": "OK",
+        "result": {
+            "list": [
+                {
+                    "baseCoin": "BTC",
+                    "totalDelta": "0.00004001",
+                    "totalGamma": "-0.00000009",
+                    "totalVega": "-0.00039689",
+                    "totalTheta": "0.01243824"
+                },
+                {
+                    "baseCoin": "ETH",
+                    "totalDelta": "",
+                    "totalGamma": "",
+                    "totalVega": "",
+                    "totalTheta": ""
+                }
+            ]
+        },
+        "retExtInfo": {},
+        "time": 1672287887942_u64
+    }))
+    .unwrap();
+
+    let greeks = response.parse::<CoinGreeksResult>().unwrap();
+    assert_eq!(greeks.list.len(), 2);
+    let btc = &greeks.list[0];
+    assert_eq!(btc.base_coin, "BTC");
+    assert_eq!(btc.total_delta, amount("0.00004001"));
+    assert_eq!(btc.total_gamma, amount("-0.00000009"));
+    assert_eq!(btc.total_vega, amount("-0.00039689"));
+    assert_eq!(btc.total_theta, amount("0.01243824"));
+    assert_eq!(greeks.list[1].total_delta, Amount::default());
+}
+
+#[test]
+fn test_batch_ext_mixed_success() {
+    // Recorded `/v5/order/create-batch` response: the second order was rejected.
+    let response: ApiResponse<Value> = serde_json::from_value(json!({
+        "retCode": 0,
+        "retMsg": "OK",
+        "result": {
+            "list": [
+                {"category": "linear", "symbol": "BTCUSDT", "orderId": "b6e2f8b1-0001", "orderLinkId": "bot-1", "createAt": "1713434102752"},
+                {"category": "linear", "symbol": "BTCUSDT", "orderId": "", "orderLinkId": "bot-2", "createAt": ""},
+                {"category": "linear", "symbol": "ETHUSDT", "orderId": "b6e2f8b1-0003", "orderLinkId": "bot-3", "createAt": "1713434102753"}
+            ]
+        },
+        "retExtInfo": {
+            "list": [
+                {"code": 0, "msg": "OK"},
+                {"code": 110007, "msg": "ab not enough for new order"},
+                {"code": 0, "msg": "OK"}
+            ]
+        },
+        "time": 1713434102753_u64
+    }))
+    .unwrap();
+
+    let ext = response.batch_ext().unwrap();
+
+    assert_eq!(ext.list.len(), 3);
+    assert!(ext.list[0].is_ok());
+    assert_eq!(ext.list[1].code, 110007);
+    assert_eq!(ext.list[1].msg, "ab not enough for new order");
+    assert_eq!(ext.failed_indices(), [1]);
+}
+
+#[test]
+fn test_batch_ext_absent_is_empty() {
+    let response: ApiResponse<Value> = serde_json::from_value(json!({
+        "retCode": 0,
+        "retMsg": "OK",
+        "result": {},
+        "retExtInfo": {},
+        "time": 0
+    }))
+    .unwrap();
+
+    assert!(response.batch_ext().unwrap().list.is_empty());
+}
+
+#[test]
+fn test_instruments_info_linear() {
+    let payload = json!({
+        "category": "linear",
+        "list": [{
+            "symbol": "BTCUSDT",
+            "contractType": "LinearPerpetual",
+            "status": "Trading",
+            "baseCoin": "BTC",
+            "quoteCoin": "USDT",
+            "launchTime": "1585526400000",
+            "priceScale": "2",
+            "leverageFilter": {"minLeverage": "1", "maxLeverage": "100.00", "leverageStep": "0.01"},
+            "priceFilter": {"minPrice": "0.10", "maxPrice": "1999999.80", "tickSize": "0.10"},
+            "lotSizeFilter": {
+                "maxOrderQty": "1190.000",
+                "minOrderQty": "0.001",
+                "qtyStep": "0.001",
+                "postOnlyMaxOrderQty": "1190.000"
+            }
+        }],
+        "nextPageCursor": ""
+    });
+
+    let result: InstrumentsInfoResult = serde_json::from_value(payload).unwrap();
+
+    assert_eq!(result.category, "linear");
+    let btc = &result.list[0];
+    assert_eq!(btc.symbol, "BTCUSDT");
+    assert_eq!(btc.status, "Trading");
+    assert_eq!(btc.price_filter.tick_size, amount("0.1"));
+    assert_eq!(btc.lot_size_filter.qty_step, amount("0.001"));
+    assert_eq!(btc.lot_size_filter.min_order_qty, amount("0.001"));
+    assert_eq!(btc.lot_size_filter.max_order_qty, amount("1190"));
+    assert_eq!(btc.leverage_filter.max_leverage, amount("100"));
+
+    assert_eq!(
+        round_to_tick(amount("65000.37"), btc.price_filter.tick_size),
+        amount("65000.4")
+    );
+    assert_eq!(
+        round_to_step(amount("0.0129"), btc.lot_size_filter.qty_step),
+        amount("0.012")
+    );
+}
+
+#[test]
+fn test_instruments_info_spot_base_precision() {
+    let payload = json!({
+        "category": "spot",
+        "list": [{
+            "symbol": "ETHUSDT",
+            "status": "Trading",
+            "baseCoin": "ETH",
+            "quoteCoin": "USDT",
+            "lotSizeFilter": {"basePrecision": "0.00001", "minOrderQty": "0.0001", "maxOrderQty": "1000"},
+            "priceFilter": {"tickSize": "0.01"}
+        }],
+        "nextPageCursor": ""
+    });
+
+    let result: InstrumentsInfoResult = serde_json::from_value(payload).unwrap();
+    let eth = &result.list[0];
+
+    assert_eq!(eth.lot_size_filter.qty_step, amount("0.00001"));
+    assert_eq!(eth.leverage_filter.max_leverage, Amount::default());
+}
+
+#[test]
+fn test_round_to_tick_and_step() {
+    assert_eq!(round_to_tick(amount("0.29"), amount("0.1")), amount("0.3"));
+    assert_eq!(round_to_tick(amount("1.25"), amount("0.5")), amount("1.5"));
+    assert_eq!(round_to_tick(amount("101"), amount("5")), amount("100"));
+    // Exact multiples survive float division noise.
+    assert_eq!(round_to_step(amount("0.3"), amount("0.1")), amount("0.3"));
+    assert_eq!(round_to_step(amount("0.39"), amount("0.1")), amount("0.3"));
+    assert_eq!(round_to_step(amount("7"), amount("0")), amount("7"));
+}
+
+#[cfg(feature = "decimal")]
+#[test]
+fn test_round_to_tick_and_step_on_decimals() {
+    use rust_decimal::Decimal;
+
+    let decimal = |s: &str| s.parse::<Decimal>().unwrap();
+    assert_eq!(
+        round_to_tick(decimal("0.29"), decimal("0.1")),
+        decimal("0.3")
+    );
+    assert_eq!(
+        round_to_tick(decimal("1.25"), decimal("0.5")),
+        decimal("1.5")
+    );
+    assert_eq!(
+        round_to_step(decimal("0.39"), decimal("0.1")),
+        decimal("0.3")
+    );
+    assert_eq!(round_to_step(decimal("7"), decimal("0")), decimal("7"));
+}
+
+#[test]
+fn test_order_ack_from_create_response() {
+    // Sample `/v5/order/create` response from the Bybit docs.
+    let response: ApiResponse<Value> = serde_json::from_value(json!({
+        "retCode": 0,
+        "retMsg": "OK",
+        "result": {
+            "orderId": "1321003749386327552",
+            "orderLinkId": "spot-test-postonly"
+        },
+        "retExtInfo": {},
+        "time": 1672211918471u64
+    }))
+    .unwrap();
+
+    let ack = OrderAck::from_result(&response.result).unwrap();
+    assert_eq!(ack.order_id, "1321003749386327552");
+    assert_eq!(ack.order_link_id, "spot-test-postonly");
+}
+
+#[test]
+fn test_order_ack_without_order_id() {
+    for result in [
+        json!({"orderLinkId": "abc"}),
+        json!({"orderId": ""}),
+        json!({}),
+    ] {
+        let err = OrderAck::from_result(&result).unwrap_err();
+        assert!(
+            matches!(&err, Error::Json(e) if e.to_string().contains("no orderId")),
+            "{err:?}"
+        );
+    }
+}
+
+fn kline_response(list: Value) -> ApiResponse<Value> {
+    serde_json::from_value(json!({
+        "retCode": 0,
+        "retMsg": "OK",
+        "result": {"symbol": "BTCUSDT", "category": "linear", "list": list},
+        "retExtInfo": {},
+        "time": 1672025956592u64
+    }))
+    .unwrap()
+}
+
+#[test]
+fn test_parse_klines() {
+    // Sample `/v5/market/kline` list from the Bybit docs, newest first.
+    let response = kline_response(json!([
+        [
+            "1670608800000",
+            "17071",
+            "17073",
+            "17027",
+            "17055.5",
+            "268611",
+            "15.74462667"
+        ],
+        [
+            "1670605200000",
+            "17071.5",
+            "17071.5",
+            "17061",
+            "17071",
+            "4177",
+            "0.24469757"
+        ],
+        [
+            "1670601600000",
+            "17086.5",
+            "17088",
+            "16978",
+            "17071.5",
+            "6356",
+            "0.37288112"
+        ]
+    ]));
+
+    let candles = parse_klines(&response, false).unwrap();
+    assert_eq!(candles.len(), 3);
+    assert_eq!(
+        candles[0],
+        Candle {
+            start: 1670608800000,
+            open: amount("17071"),
+            high: amount("17073"),
+            low: amount("17027"),
+            close: amount("17055.5"),
+            volume: amount("268611"),
+            turnover: amount("15.74462667"),
+        }
+    );
+    let starts: Vec<i64> = candles.iter().map(|c| c.start).collect();
+    assert_eq!(starts, [1670608800000, 1670605200000, 1670601600000]);
+
+    let chronological = parse_klines(&response, true).unwrap();
+    let starts: Vec<i64> = chronological.iter().map(|c| c.start).collect();
+    assert_eq!(starts, [1670601600000, 1670605200000, 1670608800000]);
+}
+
+#[test]
+fn test_parse_klines_empty_and_malformed() {
+    assert!(parse_klines(&kline_response(json!([])), true)
+        .unwrap()
+        .is_empty());
+
+    for row in [
+        json!(["1670608800000", "17071", "17073"]),
+        json!(["soon", "17071", "17073", "17027", "17055.5", "268611", "15.7"]),
+        json!([
+            "1670608800000",
+            "x",
+            "17073",
+            "17027",
+            "17055.5",
+            "268611",
+            "15.7"
+        ]),
+    ] {
+        let result = parse_klines(&kline_response(json!([row])), false);
+        assert!(matches!(result, Err(Error::Json(_))), "{row}: {result:?}");
+    }
+}
+
+#[test]
+fn test_cancel_all_result() {
+    // Recorded `/v5/order/cancel-all` result for linear orders.
+    let result = json!({
+        "list": [
+            {"orderId": "1616024329462743808", "orderLinkId": "1616024329462743809"},
+            {"orderId": "1616024287544869632", "orderLinkId": ""}
+        ],
+        "success": "1"
+    });
+    let parsed = serde_json::from_value::<CancelAllResult>(result).unwrap();
+    assert_eq!(parsed.success, "1");
+    assert_eq!(
+        parsed.order_ids(),
+        ["1616024329462743808", "1616024287544869632"]
+    );
+    assert_eq!(parsed.list[0].order_link_id, "1616024329462743809");
+    assert_eq!(parsed.list[1].order_link_id, "");
+}
+
+#[test]
+fn test_cancel_all_result_empty_list() {
+    let parsed =
+        serde_json::from_value::<CancelAllResult>(json!({"list": [], "success": "1"})).unwrap();
+    assert!(parsed.list.is_empty());
+    assert!(parsed.order_ids().is_empty());
+
+    // Categories without `success` still parse.
+    let parsed = serde_json::from_value::<CancelAllResult>(json!({"list": []})).unwrap();
+    assert_eq!(parsed.success, "");
+}
@@ -0,0 +1,210 @@
+//! Tests for the Bybit position mode lookup and `position_idx` auto-fill on `place_order`.
+
+use reqwest::Method;
+use serde_json::{json, Value};
+use trade_sdk::bybit::traits::{PositionApi, TradeApi};
+use trade_sdk::bybit::types::{
+    AllCategories, PlaceOrderParams, PlaceOrderType, PositionIdx, PositionMode, Side,
+};
+use trade_sdk::bybit::BybitClient;
+use trade_sdk::testkit::MockExchange;
+use trade_sdk::Error;
+
+fn bybit_ok(result: Value) -> Value {
+    json!({"retCode": 0, "retMsg": "OK", "result": result, "retExtInfo": {}, "time": 1})
+}
+
+/// Serve `/v5/position/list` with positions of `indexes` and ack everything else.
+async fn mock_exchange(indexes: &[i64]) -> MockExchange {
+    let positions: Vec<Value> = indexes
+        .iter()
+        .map(|idx| json!({"symbol": "BTCUSDT", "positionIdx": idx, "size": "0"}))
+        .collect();
+    let exchange = MockExchange::start().await;
+    exchange.respond(
+        Method::GET,
+        "/v5/position/list",
+        bybit_ok(json!({"category": "linear", "list": positions})),
+    );
+    exchange.respond_to_any(bybit_ok(json!({"orderId": "1", "orderLinkId": ""})));
+    exchange
+}
+
+fn client(base_url: &str) -> BybitClient {
+    BybitClient::new(
+        Some("key".to_string()),
+        Some("secret".to_string()),
+        false,
+        false,
+        5000,
+        None,
+    )
+    .unwrap()
+    .with_base_url(base_url)
+    .with_auto_position_idx()
+}
+
+fn order(side: Side) -> PlaceOrderParams {
+    PlaceOrderParams {
+        symbol: "BTCUSDT".to_string(),
+        side,
+        order_type: PlaceOrderType::Market,
+        qty: trade_sdk::Amount::from(1u8),
+        ..Default::default()
+    }
+}
+
+/// `positionIdx` of every recorded order.
+fn sent_position_idx(exchange: &MockExchange) -> Vec<Value> {
+    exchange
+        .requests_to(Method::POST, "/v5/order/create")
+        .iter()
+        .map(|r| r.json().unwrap()["positionIdx"].clone())
+        .collect()
+}
+
+fn position_list_calls(exchange: &MockExchange) -> usize {
+    exchange.requests_to(Method::GET, "/v5/position/list").len()
+}
+
+#[test]
+fn test_position_idx_for_side() {
+    assert_eq!(
+        PositionMode::OneWay.position_idx(&Side::Buy, false),
+        PositionIdx::OneWay
+    );
+    assert_eq!(
+        PositionMode::OneWay.position_idx(&Side::Sell, true),
+        PositionIdx::OneWay
+    );
+    assert_eq!(
+        PositionMode::Hedge.position_idx(&Side::Buy, false),
+        PositionIdx::HedgeBuy
+    );
+    assert_eq!(
+        PositionMode::Hedge.position_idx(&Side::Sell, false),
+        PositionIdx::HedgeSell
+    );
+    // Reduce-only orders close the opposite side.
+    assert_eq!(
+        PositionMode::Hedge.position_idx(&Side::Sell, true),
+        PositionIdx::HedgeBuy
+    );
+    assert_eq!(
+        PositionMode::Hedge.position_idx(&Side::Buy, true),
+        PositionIdx::HedgeSell
+    );
+    assert_eq!(PositionMode::from_mode(0), Some(PositionMode::OneWay));
+    assert_eq!(PositionMode::from_mode(3), Some(PositionMode::Hedge));
+    assert_eq!(PositionMode::from_mode(1), None);
+}
+
+#[tokio::test]
+async fn test_hedge_mode_fills_index_from_side() {
+    let exchange = mock_exchange(&[1, 2]).await;
+    let client = client(&exchange.base_url());
+
+    for side in [Side::Buy, Side::Sell] {
+        client
+            .place_order(AllCategories::Linear, &order(side))
+            .await
+            .unwrap();
+    }
+
+    assert_eq!(sent_position_idx(&exchange), vec![json!("1"), json!("2")]);
+    // The mode is looked up once and cached.
+    assert_eq!(position_list_calls(&exchange), 1);
+}
+
+#[tokio::test]
+async fn test_one_way_mode_fills_zero_index() {
+    let exchange = mock_exchange(&[0]).await;
+    let client = client(&exchange.base_url());
+
+    for side in [Side::Buy, Side::Sell] {
+        client
+            .place_order(AllCategories::Linear, &order(side))
+            .await
+            .unwrap();
+    }
+
+    assert_eq!(sent_position_idx(&exchange), vec![json!("0"), json!("0")]);
+    assert_eq!(
+        client
+            .position_mode(AllCategories::Linear, "BTCUSDT")
+            .await
+            .unwrap(),
+        PositionMode::OneWay
+    );
+}
+
+#[tokio::test]
+async fn test_explicit_index_spot_and_disabled_are_left_alone() {
+    let exchange = mock_exchange(&[1, 2]).await;
+    let base_url = exchange.base_url();
+    let client = client(&base_url);
+
+    let mut explicit = order(Side::Sell);
+    explicit.position_idx = Some(PositionIdx::HedgeBuy);
+    client
+        .place_order(AllCategories::Linear, &explicit)
+        .await
+        .unwrap();
+    client
+        .place_order(AllCategories::Spot, &order(Side::Buy))
+        .await
+        .unwrap();
+    let disabled = BybitClient::new(
+        Some("key".to_string()),
+        Some("secret".to_string()),
+        false,
+        false,
+        5000,
+        None,
+    )
+    .unwrap()
+    .with_base_url(&base_url);
+    assert!(!disabled.auto_position_idx());
+    disabled
+        .place_order(AllCategories::Linear, &order(Side::Buy))
+        .await
+        .unwrap();
+
+    assert_eq!(
+        sent_position_idx(&exchange),
+        vec![json!("1"), Value::Null, Value::Null]
+    );
+    assert_eq!(position_list_calls(&exchange), 0);
+}
+
+#[tokio::test]
+async fn test_switch_position_mode_updates_cached_mode() {
+    let exchange = mock_exchange(&[0]).await;
+    let client = client(&exchange.base_url());
+
+    client
+        .switch_position_mode(AllCategories::Linear, 3, Some("BTCUSDT"), None)
+        .await
+        .unwrap();
+    client
+        .place_order(AllCategories::Linear, &order(Side::Sell))
+        .await
+        .unwrap();
+
+    assert_eq!(sent_position_idx(&exchange), vec![json!("2")]);
+    assert_eq!(position_list_calls(&exchange), 0);
+}
+
+#[tokio::test]
+async fn test_position_mode_without_positions_is_rejected() {
+    let exchange = mock_exchange(&[]).await;
+    let client = client(&exchange.base_url());
+
+    let err = client
+        .place_order(AllCategories::Linear, &order(Side::Buy))
+        .await
+        .unwrap_err();
+
+    assert!(matches!(err, Error::Validation(_)), "{err:?}");
+    assert!(sent_position_idx(&exchange).is_empty());
+}
@@ -0,0 +1,131 @@
+//! Tests for `SetTradingStopParams::validate` and its use before sending trading stops.
+
+use trade_sdk::bybit::{
+    traits::PositionApi,
+    types::{AllCategories, PositionIdx, SetTradingStopParams, TpSlMode, TpSlOrderType},
+    BybitClient,
+};
+use trade_sdk::{Amount, Error};
+
+fn amount(s: &str) -> Amount {
+    s.parse().unwrap()
+}
+
+fn full_stop() -> SetTradingStopParams {
+    SetTradingStopParams {
+        symbol: "BTCUSDT".to_string(),
+        tpsl_mode: TpSlMode::Full,
+        position_idx: PositionIdx::OneWay,
+        take_profit: Some(amount("70000")),
+        stop_loss: Some(amount("60000")),
+        trailing_stop: None,
+        tp_trigger_by: None,
+        sl_trigger_by: None,
+        active_price: None,
+        tp_size: None,
+        sl_size: None,
+        tp_limit_price: None,
+        sl_limit_price: None,
+        tp_order_type: None,
+        sl_order_type: None,
+    }
+}
+
+fn partial_stop() -> SetTradingStopParams {
+    SetTradingStopParams {
+        tpsl_mode: TpSlMode::Partial,
+        tp_size: Some(amount("0.01")),
+        sl_size: Some(amount("0.01")),
+        tp_order_type: Some(TpSlOrderType::Limit),
+        tp_limit_price: Some(amount("69900")),
+        sl_order_type: Some(TpSlOrderType::Market),
+        ..full_stop()
+    }
+}
+
+fn assert_validation_error(
+    params: &SetTradingStopParams,
+    needle: &str,
+) {
+    match params.validate() {
+        Err(Error::Validation(msg)) => assert!(msg.contains(needle), "{msg}"),
+        other => panic!("expected validation error mentioning {needle:?}, got {other:?}"),
+    }
+}
+
+#[test]
+fn test_valid_stops_pass() {
+    full_stop().validate().unwrap();
+    partial_stop().validate().unwrap();
+    SetTradingStopParams {
+        take_profit: None,
+        trailing_stop: Some(amount("500")),
+        ..full_stop()
+    }
+    .validate()
+    .unwrap();
+}
+
+#[test]
+fn test_partial_without_sizes_rejected() {
+    let params = SetTradingStopParams {
+        tp_size: None,
+        sl_size: None,
+        ..partial_stop()
+    };
+    assert_validation_error(&params, "tp_size or sl_size");
+
+    // One size is enough.
+    SetTradingStopParams {
+        tp_size: None,
+        ..partial_stop()
+    }
+    .validate()
+    .unwrap();
+}
+
+#[test]
+fn test_limit_tp_without_limit_price_rejected() {
+    let params = SetTradingStopParams {
+        tp_limit_price: None,
+        ..partial_stop()
+    };
+    assert_validation_error(&params, "tp_limit_price");
+}
+
+#[test]
+fn test_limit_sl_without_limit_price_rejected() {
+    let params = SetTradingStopParams {
+        sl_order_type: Some(TpSlOrderType::Limit),
+        ..partial_stop()
+    };
+    assert_validation_error(&params, "sl_limit_price");
+}
+
+#[test]
+fn test_trailing_stop_with_take_profit_rejected() {
+    let params = SetTradingStopParams {
+        trailing_stop: Some(amount("500")),
+        ..full_stop()
+    };
+    assert_validation_error(&params, "trailing_stop");
+}
+
+#[tokio::test]
+async fn test_set_trading_stop_validates_before_sending() {
+    // Nothing listens on the discard port; the check must fail before any request.
+    let client = BybitClient::new(None, None, false, false, 5000, None)
+        .unwrap()
+        .with_base_url("http://127.0.0.1:9");
+    let params = SetTradingStopParams {
+        tp_size: None,
+        sl_size: None,
+        ..partial_stop()
+    };
+
+    let result = client
+        .set_trading_stop(AllCategories::Linear, &params)
+        .await;
+
+    assert!(matches!(result, Err(Error::Validation(_))), "{result:?}");
+}
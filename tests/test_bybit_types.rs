@@ -0,0 +1,59 @@
+//! Serialization tests for shared Bybit parameter types.
+
+use serde_json::{json, Value};
+use trade_sdk::bybit::types::{
+    OrderPriceTriggerBy, PlaceOrderParams, PositionIdx, SetTradingStopParams, TpSlMode,
+    TpSlTriggerBy, TriggerBy,
+};
+
+#[test]
+fn test_trigger_by_serializes_like_display() {
+    for trigger in [
+        TriggerBy::LastPrice,
+        TriggerBy::IndexPrice,
+        TriggerBy::MarkPrice,
+    ] {
+        assert_eq!(
+            serde_json::to_value(trigger).unwrap(),
+            Value::String(trigger.to_string())
+        );
+        let parsed: TriggerBy = serde_json::from_value(json!(trigger.to_string())).unwrap();
+        assert_eq!(parsed, trigger);
+    }
+}
+
+#[test]
+fn test_mark_price_serializes_identically_in_order_and_trading_stop() {
+    let order = PlaceOrderParams {
+        symbol: "BTCUSDT".to_string(),
+        trigger_by: Some(OrderPriceTriggerBy::MarkPrice),
+        tp_trigger_by: Some(TpSlTriggerBy::MarkPrice),
+        sl_trigger_by: Some(TpSlTriggerBy::MarkPrice),
+        ..Default::default()
+    };
+    let stop = SetTradingStopParams {
+        symbol: "BTCUSDT".to_string(),
+        tpsl_mode: TpSlMode::Full,
+        position_idx: PositionIdx::OneWay,
+        take_profit: None,
+        stop_loss: None,
+        trailing_stop: None,
+        tp_trigger_by: Some(TpSlTriggerBy::MarkPrice),
+        sl_trigger_by: Some(TpSlTriggerBy::MarkPrice),
+        active_price: None,
+        tp_size: None,
+        sl_size: None,
+        tp_limit_price: None,
+        sl_limit_price: None,
+        tp_order_type: None,
+        sl_order_type: None,
+    };
+
+    let order = serde_json::to_value(&order).unwrap();
+    let stop = serde_json::to_value(&stop).unwrap();
+    assert_eq!(order["triggerBy"], json!("MarkPrice"));
+    assert_eq!(order["tpTriggerBy"], json!("MarkPrice"));
+    assert_eq!(order["slTriggerBy"], json!("MarkPrice"));
+    assert_eq!(stop["tpTriggerBy"], order["tpTriggerBy"]);
+    assert_eq!(stop["slTriggerBy"], order["slTriggerBy"]);
+}
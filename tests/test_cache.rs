@@ -0,0 +1,323 @@
+//! Tests for the client caches.
+
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex, RwLock};
+use std::time::{Duration, Instant};
+
+use once_cell::sync::Lazy;
+use trade_sdk::bingx::BingxClient;
+use trade_sdk::bybit::BybitClient;
+use trade_sdk::okx::OkxClient;
+use trade_sdk::{BingxClientsCache, BybitClientsCache, ClientsCache, Error, OkxClientsCache};
+
+type BybitKey = (String, String, bool, bool, Option<String>);
+
+fn key(name: &str) -> BybitKey {
+    (
+        format!("cache-test-{name}"),
+        "secret".to_string(),
+        false,
+        false,
+        None,
+    )
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 8)]
+async fn test_concurrent_get_or_create_creates_once() {
+    let key = key("concurrent");
+    let created = Arc::new(AtomicUsize::new(0));
+
+    let tasks: Vec<_> = (0..32)
+        .map(|_| {
+            let key = key.clone();
+            let created = Arc::clone(&created);
+            tokio::task::spawn_blocking(move || {
+                <BybitClientsCache as ClientsCache<BybitKey, BybitClient>>::get_or_create(
+                    key,
+                    || {
+                        created.fetch_add(1, Ordering::SeqCst);
+                        // Slow creation widens the window for a thundering herd.
+                        std::thread::sleep(Duration::from_millis(50));
+                        Arc::new(BybitClient::new(None, None, false, false, 5000, None).unwrap())
+                    },
+                )
+            })
+        })
+        .collect();
+
+    let clients: Vec<_> = futures::future::join_all(tasks)
+        .await
+        .into_iter()
+        .map(|r| r.unwrap())
+        .collect();
+
+    assert_eq!(created.load(Ordering::SeqCst), 1);
+    assert!(clients.iter().all(|c| Arc::ptr_eq(c, &clients[0])));
+}
+
+#[test]
+fn test_failed_creation_is_retried() {
+    let key = key("retry");
+    let result = <BybitClientsCache as ClientsCache<BybitKey, BybitClient>>::try_get_or_create(
+        key.clone(),
+        || Err(Error::Config("boom".to_string())),
+    );
+    assert!(matches!(result, Err(Error::Config(_))));
+
+    let client = <BybitClientsCache as ClientsCache<BybitKey, BybitClient>>::try_get_or_create(
+        key.clone(),
+        || {
+            Ok::<_, Error>(Arc::new(BybitClient::new(
+                None, None, false, false, 5000, None,
+            )?))
+        },
+    )
+    .unwrap();
+    let cached = <BybitClientsCache as ClientsCache<BybitKey, BybitClient>>::get(&key).unwrap();
+    assert!(Arc::ptr_eq(&client, &cached));
+}
+
+#[test]
+fn test_remove_existing_and_missing_key() {
+    let key = key("remove");
+    let client = BybitClientsCache::get_or_create(&key.0, &key.1, false, false).unwrap();
+
+    assert!(<BybitClientsCache as ClientsCache<BybitKey, BybitClient>>::remove(&key));
+    assert!(!<BybitClientsCache as ClientsCache<
+        BybitKey,
+        BybitClient,
+    >>::remove(&key));
+    assert!(BybitClientsCache::get(&key.0, &key.1, false, false).is_none());
+
+    // The next get_or_create rebuilds the client.
+    let rebuilt = BybitClientsCache::get_or_create(&key.0, &key.1, false, false).unwrap();
+    assert!(!Arc::ptr_eq(&client, &rebuilt));
+}
+
+#[test]
+fn test_bybit_key_includes_mode_flags() {
+    let (api_key, api_secret, ..) = key("modes");
+    let mainnet = BybitClientsCache::get_or_create(&api_key, &api_secret, false, false).unwrap();
+    let testnet = BybitClientsCache::get_or_create(&api_key, &api_secret, true, false).unwrap();
+    let demo = BybitClientsCache::get_or_create(&api_key, &api_secret, false, true).unwrap();
+
+    assert!(!Arc::ptr_eq(&mainnet, &testnet));
+    assert!(!Arc::ptr_eq(&mainnet, &demo));
+    assert!(!Arc::ptr_eq(&testnet, &demo));
+    assert!(Arc::ptr_eq(
+        &testnet,
+        &BybitClientsCache::get(&api_key, &api_secret, true, false).unwrap()
+    ));
+    // The key stores the flags in (testnet, demo) order.
+    let key = (api_key, api_secret, true, false, None);
+    assert!(Arc::ptr_eq(
+        &testnet,
+        &<BybitClientsCache as ClientsCache<BybitKey, BybitClient>>::get(&key).unwrap()
+    ));
+}
+
+#[test]
+fn test_bybit_key_includes_base_url_override() {
+    let (api_key, api_secret, ..) = key("base-url");
+    let mock = Arc::new(
+        BybitClient::new(None, None, false, false, 5000, None)
+            .unwrap()
+            .with_base_url("http://127.0.0.1:9/"),
+    );
+    assert_eq!(mock.base_url_override(), Some("http://127.0.0.1:9"));
+    BybitClientsCache::add(Arc::clone(&mock), &api_key, &api_secret, false, false);
+
+    // The mock client must not be handed out for the real Bybit URL.
+    assert!(BybitClientsCache::get(&api_key, &api_secret, false, false).is_none());
+    let real = BybitClientsCache::get_or_create(&api_key, &api_secret, false, false).unwrap();
+    assert!(!Arc::ptr_eq(&real, &mock));
+    assert_eq!(real.base_url_override(), None);
+    assert!(BybitClientsCache::get_with_base_url(
+        &api_key,
+        &api_secret,
+        false,
+        false,
+        "http://127.0.0.1:9"
+    )
+    .is_some_and(|c| Arc::ptr_eq(&c, &mock)));
+}
+
+#[test]
+fn test_bingx_key_is_credentials_and_demo() {
+    let (api_key, api_secret) = ("cache-test-bingx-modes", "secret");
+    let live = BingxClientsCache::get_or_create_client(api_key, api_secret, false).unwrap();
+    let demo = BingxClientsCache::get_or_create_client(api_key, api_secret, true).unwrap();
+    assert!(!Arc::ptr_eq(&live, &demo));
+    assert!(demo.is_demo() && !live.is_demo());
+    assert!(BingxClientsCache::get_client(api_key, api_secret, true)
+        .is_some_and(|c| Arc::ptr_eq(&c, &demo)));
+    let key = (api_key.to_string(), api_secret.to_string(), false);
+    assert!(
+        <BingxClientsCache as ClientsCache<(String, String, bool), BingxClient>>::get(&key)
+            .is_some_and(|c| Arc::ptr_eq(&c, &live))
+    );
+
+    // The deprecated methods ignore `testnet`: BingX has none.
+    #[allow(deprecated)]
+    let testnet = BingxClientsCache::get_or_create(api_key, api_secret, true, false).unwrap();
+    assert!(Arc::ptr_eq(&live, &testnet));
+    #[allow(deprecated)]
+    let cached = BingxClientsCache::get(api_key, api_secret, true, true);
+    assert!(cached.is_some_and(|c| Arc::ptr_eq(&c, &demo)));
+}
+
+type OkxKey = (String, String, String, bool);
+
+/// The only test touching the OKX cache, since it changes the cache-wide lifetime.
+#[tokio::test]
+async fn test_okx_cache_lifecycle() {
+    let (api_key, api_secret) = ("cache-test-okx", "secret");
+    let live = OkxClientsCache::get_or_create(api_key, api_secret, "one", false).unwrap();
+    assert!(Arc::ptr_eq(
+        &live,
+        &OkxClientsCache::get_or_create(api_key, api_secret, "one", false).unwrap()
+    ));
+    let other_passphrase =
+        OkxClientsCache::get_or_create(api_key, api_secret, "two", false).unwrap();
+    let demo = OkxClientsCache::get_or_create(api_key, api_secret, "one", true).unwrap();
+    assert!(!Arc::ptr_eq(&live, &other_passphrase));
+    assert!(!Arc::ptr_eq(&live, &demo));
+    assert!(demo.is_demo() && !live.is_demo());
+    assert_eq!(
+        <OkxClientsCache as ClientsCache<OkxKey, OkxClient>>::size(),
+        3
+    );
+
+    // Entries added with a zero lifetime expire at once and are swept by cleanup.
+    <OkxClientsCache as ClientsCache<OkxKey, OkxClient>>::configure(0);
+    OkxClientsCache::add(live, api_key, api_secret, "three", false);
+    assert!(OkxClientsCache::get(api_key, api_secret, "three", false).is_none());
+    assert_eq!(
+        <OkxClientsCache as ClientsCache<OkxKey, OkxClient>>::cleanup_expired(),
+        1
+    );
+    assert_eq!(
+        <OkxClientsCache as ClientsCache<OkxKey, OkxClient>>::size(),
+        3
+    );
+    <OkxClientsCache as ClientsCache<OkxKey, OkxClient>>::configure(600);
+
+    let task = <OkxClientsCache as ClientsCache<OkxKey, OkxClient>>::create_cleanup_task(3600);
+    tokio::time::timeout(Duration::from_secs(1), task.stop())
+        .await
+        .expect("cleanup task did not stop");
+
+    <OkxClientsCache as ClientsCache<OkxKey, OkxClient>>::clear();
+    assert!(OkxClientsCache::get(api_key, api_secret, "one", true).is_none());
+}
+
+#[test]
+fn test_ttl_remaining_decreases() {
+    let key = key("ttl");
+    assert_eq!(
+        <BybitClientsCache as ClientsCache<BybitKey, BybitClient>>::ttl_remaining(&key),
+        None
+    );
+
+    BybitClientsCache::get_or_create(&key.0, &key.1, false, false).unwrap();
+    let first =
+        <BybitClientsCache as ClientsCache<BybitKey, BybitClient>>::ttl_remaining(&key).unwrap();
+    std::thread::sleep(Duration::from_millis(20));
+    let second =
+        <BybitClientsCache as ClientsCache<BybitKey, BybitClient>>::ttl_remaining(&key).unwrap();
+    assert!(second < first, "{second:?} >= {first:?}");
+    assert!(first > Duration::ZERO);
+}
+
+#[tokio::test]
+async fn test_cleanup_task_stops_promptly() {
+    let task =
+        <BybitClientsCache as ClientsCache<BybitKey, BybitClient>>::create_cleanup_task(3600);
+    tokio::time::sleep(Duration::from_millis(20)).await;
+    assert!(!task.is_finished());
+
+    // The task is asleep for an hour; stop must not wait for the interval.
+    tokio::time::timeout(Duration::from_secs(1), task.stop())
+        .await
+        .expect("cleanup task did not stop");
+}
+
+#[tokio::test]
+async fn test_jittered_cleanup_task_stops_promptly() {
+    let task =
+        <BybitClientsCache as ClientsCache<BybitKey, BybitClient>>::create_jittered_cleanup_task(
+            3600,
+        );
+    tokio::time::timeout(Duration::from_secs(1), task.stop())
+        .await
+        .expect("jittered cleanup task did not stop");
+}
+
+/// Set while `ProbeKey` clones should check the cache lock.
+static PROBING: AtomicBool = AtomicBool::new(false);
+/// Key clones made during the cleanup scan, and how many of them found the cache readable.
+static SCAN_CLONES: AtomicUsize = AtomicUsize::new(0);
+static SCAN_READABLE: AtomicUsize = AtomicUsize::new(0);
+
+/// Cache key whose clones record whether a concurrent reader could take the cache lock.
+#[derive(Debug, PartialEq, Eq, Hash)]
+struct ProbeKey(u32);
+
+impl Clone for ProbeKey {
+    fn clone(&self) -> Self {
+        if PROBING.load(Ordering::SeqCst) {
+            SCAN_CLONES.fetch_add(1, Ordering::SeqCst);
+            let readable = std::thread::scope(|s| {
+                s.spawn(|| ProbeCache::cache().try_read().is_ok())
+                    .join()
+                    .unwrap()
+            });
+            if readable {
+                SCAN_READABLE.fetch_add(1, Ordering::SeqCst);
+            }
+        }
+        Self(self.0)
+    }
+}
+
+type ProbeMap = HashMap<ProbeKey, (Arc<()>, Instant)>;
+
+static PROBE_CACHE: Lazy<RwLock<ProbeMap>> = Lazy::new(Default::default);
+static PROBE_LIFETIME: Lazy<RwLock<Duration>> = Lazy::new(Default::default);
+static PROBE_LOCKS: Lazy<Mutex<HashMap<ProbeKey, Arc<Mutex<()>>>>> = Lazy::new(Default::default);
+
+struct ProbeCache;
+
+impl ClientsCache<ProbeKey, ()> for ProbeCache {
+    fn cache() -> &'static Lazy<RwLock<ProbeMap>> {
+        &PROBE_CACHE
+    }
+
+    fn lifetime() -> &'static Lazy<RwLock<Duration>> {
+        &PROBE_LIFETIME
+    }
+
+    fn creation_locks() -> &'static Lazy<Mutex<HashMap<ProbeKey, Arc<Mutex<()>>>>> {
+        &PROBE_LOCKS
+    }
+}
+
+#[test]
+fn test_cleanup_scan_does_not_block_readers() {
+    // Zero lifetime: every entry is expired as soon as it is added.
+    ProbeCache::configure(0);
+    for i in 0..3 {
+        ProbeCache::add(ProbeKey(i), Arc::new(()));
+    }
+
+    PROBING.store(true, Ordering::SeqCst);
+    let removed = ProbeCache::cleanup_expired();
+    PROBING.store(false, Ordering::SeqCst);
+
+    assert_eq!(removed, 3);
+    assert_eq!(ProbeCache::size(), 0);
+    // Each expired key was cloned during the scan while another thread could still read.
+    assert_eq!(SCAN_CLONES.load(Ordering::SeqCst), 3);
+    assert_eq!(SCAN_READABLE.load(Ordering::SeqCst), 3);
+}
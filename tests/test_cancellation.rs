@@ -0,0 +1,113 @@
+//! Tests for cancelling in-flight requests with a `CancellationToken`.
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use reqwest::Method;
+use serde_json::json;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpListener;
+use trade_sdk::bingx::BingxClient;
+use trade_sdk::bybit::BybitClient;
+use trade_sdk::{CancellationToken, Error};
+
+/// Serve every connection after `delay`, counting accepted connections.
+async fn spawn_slow_server(
+    delay: Duration,
+    connections: Arc<AtomicUsize>,
+) -> String {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    tokio::spawn(async move {
+        while let Ok((mut socket, _)) = listener.accept().await {
+            connections.fetch_add(1, Ordering::SeqCst);
+            tokio::spawn(async move {
+                let mut buf = [0u8; 4096];
+                let _ = socket.read(&mut buf).await;
+                tokio::time::sleep(delay).await;
+                let body = json!({"retCode": 0, "retMsg": "OK", "result": {}, "retExtInfo": {}, "time": 1}).to_string();
+                let response = format!(
+                    "HTTP/1.1 200 OK\r\ncontent-type: application/json\r\ncontent-length: {}\r\nconnection: close\r\n\r\n{body}",
+                    body.len()
+                );
+                let _ = socket.write_all(response.as_bytes()).await;
+            });
+        }
+    });
+    format!("http://{addr}")
+}
+
+fn bybit(url: &str) -> BybitClient {
+    BybitClient::new(None, None, false, false, 10_000, None)
+        .unwrap()
+        .with_base_url(url)
+}
+
+#[tokio::test]
+async fn test_cancel_during_slow_response() {
+    let connections = Arc::new(AtomicUsize::new(0));
+    let url = spawn_slow_server(Duration::from_secs(5), Arc::clone(&connections)).await;
+    let client = bybit(&url);
+    let cancel = CancellationToken::new();
+    let trigger = cancel.clone();
+    tokio::spawn(async move {
+        tokio::time::sleep(Duration::from_millis(100)).await;
+        trigger.cancel();
+    });
+
+    let started = Instant::now();
+    let result = client
+        .request_raw_cancellable(Method::GET, "/v5/market/time", None, false, &cancel)
+        .await;
+
+    assert!(matches!(result, Err(Error::Cancelled)), "{result:?}");
+    assert!(started.elapsed() < Duration::from_secs(2));
+    assert_eq!(connections.load(Ordering::SeqCst), 1);
+}
+
+#[tokio::test]
+async fn test_already_cancelled_token_sends_nothing() {
+    let connections = Arc::new(AtomicUsize::new(0));
+    let url = spawn_slow_server(Duration::ZERO, Arc::clone(&connections)).await;
+    let client = BingxClient::new(None, None, false, 10_000, None)
+        .unwrap()
+        .with_base_url(&url);
+    let cancel = CancellationToken::new();
+    cancel.cancel();
+
+    let result = client
+        .request_raw_cancellable(
+            Method::GET,
+            "/openApi/swap/v2/server/time",
+            None,
+            false,
+            &cancel,
+        )
+        .await;
+
+    assert!(matches!(result, Err(Error::Cancelled)), "{result:?}");
+    tokio::time::sleep(Duration::from_millis(50)).await;
+    assert_eq!(connections.load(Ordering::SeqCst), 0);
+}
+
+#[tokio::test]
+async fn test_uncancelled_token_returns_response() {
+    let connections = Arc::new(AtomicUsize::new(0));
+    let url = spawn_slow_server(Duration::ZERO, Arc::clone(&connections)).await;
+    let client = bybit(&url);
+
+    let response = client
+        .request_raw_cancellable(
+            Method::GET,
+            "/v5/market/time",
+            None,
+            false,
+            &CancellationToken::new(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(response.ret_code, 0);
+    assert_eq!(Error::Cancelled.to_string(), "Request cancelled");
+}
@@ -0,0 +1,93 @@
+//! Tests for cloning the high-level clients.
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpListener;
+use trade_sdk::bingx::BingxClient;
+use trade_sdk::bybit::{traits::MarketApi, BybitClient};
+
+/// Serve server time responses on kept-alive connections, counting accepted connections.
+async fn spawn_keep_alive_server(connections: Arc<AtomicUsize>) -> String {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    tokio::spawn(async move {
+        while let Ok((mut socket, _)) = listener.accept().await {
+            connections.fetch_add(1, Ordering::SeqCst);
+            tokio::spawn(async move {
+                let body = r#"{"retCode":0,"retMsg":"OK","result":{"timeSecond":"1700000000"},"retExtInfo":{},"time":1700000000000}"#;
+                let response = format!(
+                    "HTTP/1.1 200 OK\r\ncontent-type: application/json\r\ncontent-length: {}\r\n\r\n{}",
+                    body.len(),
+                    body
+                );
+                let mut buf = vec![0u8; 4096];
+                // Bodyless GETs: one read per request is enough here.
+                while matches!(socket.read(&mut buf).await, Ok(n) if n > 0) {
+                    if socket.write_all(response.as_bytes()).await.is_err() {
+                        break;
+                    }
+                }
+            });
+        }
+    });
+    format!("http://{addr}")
+}
+
+#[tokio::test]
+async fn test_bybit_clone_shares_pool() {
+    let connections = Arc::new(AtomicUsize::new(0));
+    let url = spawn_keep_alive_server(Arc::clone(&connections)).await;
+    let client = BybitClient::new(
+        Some("key".to_string()),
+        Some("secret".to_string()),
+        false,
+        false,
+        5000,
+        None,
+    )
+    .unwrap()
+    .with_base_url(&url);
+
+    let clone = client.clone();
+
+    assert_eq!(
+        clone.is_shared_session_enabled(),
+        client.is_shared_session_enabled()
+    );
+    client.get_server_time().await.unwrap();
+    clone.get_server_time().await.unwrap();
+    if !client.is_shared_session_enabled() {
+        // The second request reused the first one's pooled connection.
+        assert_eq!(connections.load(Ordering::SeqCst), 1);
+    }
+}
+
+#[tokio::test]
+async fn test_clone_is_usable_from_another_task() {
+    let connections = Arc::new(AtomicUsize::new(0));
+    let url = spawn_keep_alive_server(connections).await;
+    let client = BybitClient::new(None, None, false, false, 5000, None)
+        .unwrap()
+        .with_base_url(&url);
+
+    let clone = client.clone();
+    let handle = tokio::spawn(async move { clone.get_server_time().await });
+
+    assert_eq!(handle.await.unwrap().unwrap().ret_code, 0);
+    client.get_server_time().await.unwrap();
+}
+
+#[test]
+fn test_bingx_clone_keeps_settings() {
+    let client = BingxClient::new(None, None, true, 5000, None).unwrap();
+
+    let clone = client.clone();
+
+    assert!(clone.is_demo());
+    assert_eq!(
+        clone.is_shared_session_enabled(),
+        client.is_shared_session_enabled()
+    );
+}
@@ -0,0 +1,61 @@
+//! Tests for `close` on the high-level clients.
+
+use std::time::Duration;
+
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpListener;
+use tokio::sync::oneshot;
+use trade_sdk::bingx::BingxClient;
+use trade_sdk::bybit::{traits::MarketApi, BybitClient};
+
+/// Answer one request on a kept-alive connection, then report when the client hangs up.
+async fn spawn_keep_alive_server() -> (String, oneshot::Receiver<()>) {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    let (closed_tx, closed_rx) = oneshot::channel();
+    tokio::spawn(async move {
+        let (mut socket, _) = listener.accept().await.unwrap();
+        let mut buf = vec![0u8; 4096];
+        let _ = socket.read(&mut buf).await;
+        let body = r#"{"retCode":0,"retMsg":"OK","result":{"timeSecond":"1700000000"},"retExtInfo":{},"time":1700000000000}"#;
+        let response = format!(
+            "HTTP/1.1 200 OK\r\ncontent-type: application/json\r\ncontent-length: {}\r\n\r\n{}",
+            body.len(),
+            body
+        );
+        socket.write_all(response.as_bytes()).await.unwrap();
+        // The connection stays in the client's pool until the client lets go of it.
+        while matches!(socket.read(&mut buf).await, Ok(n) if n > 0) {}
+        let _ = closed_tx.send(());
+    });
+    (format!("http://{addr}"), closed_rx)
+}
+
+#[tokio::test]
+async fn test_close_drains_individual_pool() {
+    let (url, closed) = spawn_keep_alive_server().await;
+    let client = BybitClient::new(None, None, false, false, 5000, None)
+        .unwrap()
+        .with_base_url(&url);
+    if client.is_shared_session_enabled() {
+        // Another test in this binary has a shared session open; its pool is not ours to close.
+        return;
+    }
+
+    client.get_server_time().await.unwrap();
+    client.close().await;
+
+    tokio::time::timeout(Duration::from_secs(1), closed)
+        .await
+        .expect("pooled connection still open after close")
+        .unwrap();
+}
+
+#[tokio::test]
+async fn test_close_without_requests_completes() {
+    let client = BingxClient::new(None, None, false, 5000, None).unwrap();
+
+    tokio::time::timeout(Duration::from_secs(1), client.close())
+        .await
+        .expect("close hung");
+}
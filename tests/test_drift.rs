@@ -0,0 +1,89 @@
+//! Tests for the server clock drift check against a local mock server.
+
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpListener;
+use trade_sdk::bybit::{traits::MarketApi, BybitClient};
+
+const SKEW_MS: i64 = 600_000;
+
+fn now_ms() -> i64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap()
+        .as_millis() as i64
+}
+
+/// Serve every connection with a server time response whose `time` is `skew_ms` off.
+async fn spawn_server(skew_ms: i64) -> String {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    tokio::spawn(async move {
+        while let Ok((mut socket, _)) = listener.accept().await {
+            tokio::spawn(async move {
+                let mut buf = vec![0u8; 4096];
+                let _ = socket.read(&mut buf).await;
+                let body = format!(
+                    r#"{{"retCode":0,"retMsg":"OK","result":{{"timeSecond":"1700000000"}},"retExtInfo":{{}},"time":{}}}"#,
+                    now_ms() + skew_ms
+                );
+                let response = format!(
+                    "HTTP/1.1 200 OK\r\ncontent-type: application/json\r\ncontent-length: {}\r\nconnection: close\r\n\r\n{}",
+                    body.len(),
+                    body
+                );
+                let _ = socket.write_all(response.as_bytes()).await;
+            });
+        }
+    });
+    format!("http://{addr}")
+}
+
+fn client(base_url: &str) -> BybitClient {
+    BybitClient::new(None, None, false, false, 5000, None)
+        .unwrap()
+        .with_base_url(base_url)
+}
+
+#[tokio::test]
+async fn test_drift_is_measured_from_skewed_server_time() {
+    let base_url = spawn_server(SKEW_MS).await;
+    let mut client = client(&base_url);
+    client.set_drift_check(1_000, 10);
+    assert_eq!(client.last_server_drift_ms(), None);
+
+    client.get_server_time().await.unwrap();
+
+    let drift = client.last_server_drift_ms().unwrap();
+    assert!(
+        (drift - SKEW_MS).abs() < 5_000,
+        "expected drift near {SKEW_MS} ms, got {drift} ms"
+    );
+}
+
+#[tokio::test]
+async fn test_drift_is_signed() {
+    let base_url = spawn_server(-SKEW_MS).await;
+    let mut client = client(&base_url);
+    client.set_drift_check(1_000, 1);
+
+    client.get_server_time().await.unwrap();
+    client.get_server_time().await.unwrap();
+
+    let drift = client.last_server_drift_ms().unwrap();
+    assert!(
+        (drift + SKEW_MS).abs() < 5_000,
+        "expected drift near -{SKEW_MS} ms, got {drift} ms"
+    );
+}
+
+#[tokio::test]
+async fn test_drift_not_measured_when_disabled() {
+    let base_url = spawn_server(SKEW_MS).await;
+    let client = client(&base_url);
+
+    client.get_server_time().await.unwrap();
+
+    assert_eq!(client.last_server_drift_ms(), None);
+}
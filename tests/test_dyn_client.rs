@@ -0,0 +1,110 @@
+//! Tests for holding clients of different exchanges behind `dyn DynHttpClient`.
+
+use std::sync::Arc;
+
+use reqwest::Method;
+use serde_json::json;
+use trade_sdk::bingx::BingxClient;
+use trade_sdk::bybit::BybitClient;
+use trade_sdk::okx::OkxClient;
+use trade_sdk::testkit::MockExchange;
+use trade_sdk::{DynHttpClient, Error};
+
+/// Mock answering the public server-time endpoint of each exchange in its own response shape,
+/// and anything else with an OKX error.
+async fn mock_exchange() -> MockExchange {
+    let exchange = MockExchange::start().await;
+    exchange.respond(
+        Method::GET,
+        "/v5/market/time",
+        json!({
+            "retCode": 0,
+            "retMsg": "OK",
+            "result": {"timeSecond": "1700000000"},
+            "retExtInfo": {},
+            "time": 1700000000000u64
+        }),
+    );
+    exchange.respond(
+        Method::GET,
+        "/openApi/swap/v2/server/time",
+        json!({"code": 0, "msg": "", "data": {"serverTime": 1700000000000u64}}),
+    );
+    exchange.respond(
+        Method::GET,
+        "/api/v5/public/time",
+        json!({"code": "0", "msg": "", "data": [{"ts": "1700000000000"}]}),
+    );
+    exchange.respond_to_any(json!({"code": "50000", "msg": "unknown path", "data": []}));
+    exchange
+}
+
+#[tokio::test]
+async fn test_clients_of_all_exchanges_behind_dyn() {
+    let exchange = mock_exchange().await;
+    let url = exchange.base_url();
+    let clients: Vec<(Arc<dyn DynHttpClient>, &str)> = vec![
+        (
+            Arc::new(
+                BybitClient::new(None, None, false, false, 5000, None)
+                    .unwrap()
+                    .with_base_url(&url),
+            ),
+            "/v5/market/time",
+        ),
+        (
+            Arc::new(
+                BingxClient::new(None, None, false, 5000, None)
+                    .unwrap()
+                    .with_base_url(&url),
+            ),
+            "/openApi/swap/v2/server/time",
+        ),
+        (
+            Arc::new(
+                OkxClient::new(None, None, None, false)
+                    .unwrap()
+                    .with_base_url(&url),
+            ),
+            "/api/v5/public/time",
+        ),
+    ];
+
+    let mut exchanges = Vec::new();
+    for (client, endpoint) in &clients {
+        let response = client
+            .request_dyn(Method::GET, endpoint, None, false)
+            .await
+            .unwrap();
+        assert_eq!(response.exchange, client.exchange());
+        assert_eq!(response.code, 0);
+        exchanges.push(response.exchange);
+
+        let time = match response.exchange {
+            "bybit" => response.data["timeSecond"].clone(),
+            "bingx" => response.data["serverTime"].clone(),
+            _ => response.data[0]["ts"].clone(),
+        };
+        assert!(!time.is_null(), "{response:?}");
+    }
+    assert_eq!(exchanges, ["bybit", "bingx", "okx"]);
+}
+
+#[tokio::test]
+async fn test_dyn_request_keeps_exchange_errors() {
+    let exchange = mock_exchange().await;
+    let url = exchange.base_url();
+    let client: Arc<dyn DynHttpClient> = Arc::new(
+        OkxClient::new(None, None, None, false)
+            .unwrap()
+            .with_base_url(&url),
+    );
+
+    let err = client
+        .request_dyn(Method::GET, "/api/v5/unknown", None, false)
+        .await
+        .unwrap_err();
+
+    assert!(matches!(err, Error::Exchange(_)), "{err:?}");
+    assert_eq!(err.exchange_code(), Some(50000));
+}
@@ -0,0 +1,120 @@
+//! Tests for choosing the log level of exchange errors by return code.
+
+use std::sync::{Arc, Mutex};
+
+use reqwest::Method;
+use serde_json::json;
+use trade_sdk::bingx::BingxClient;
+use trade_sdk::bybit::BybitClient;
+use trade_sdk::testkit::MockExchange;
+use trade_sdk::Error;
+
+/// Bybit "order not exists or too late to cancel".
+const BYBIT_ORDER_NOT_FOUND: i64 = 110001;
+
+/// BingX "order not exist".
+const BINGX_ORDER_NOT_FOUND: i64 = 80016;
+
+/// Mock exchange answering every request with `body`.
+async fn mock_exchange(body: serde_json::Value) -> MockExchange {
+    let exchange = MockExchange::start().await;
+    exchange.respond_to_any(body);
+    exchange
+}
+
+/// Filter downgrading `benign` to debug, recording every code it is asked about.
+fn benign_filter(
+    benign: i64,
+    seen: Arc<Mutex<Vec<i64>>>,
+) -> impl Fn(i64) -> log::Level + Send + Sync + 'static {
+    move |code| {
+        seen.lock().unwrap().push(code);
+        if code == benign {
+            log::Level::Debug
+        } else {
+            log::Level::Error
+        }
+    }
+}
+
+#[test]
+fn test_default_logs_every_code_at_error() {
+    let client = BybitClient::new(None, None, false, false, 5000, None).unwrap();
+    assert_eq!(
+        client.error_log_level(BYBIT_ORDER_NOT_FOUND),
+        log::Level::Error
+    );
+    assert_eq!(client.error_log_level(10001), log::Level::Error);
+}
+
+#[tokio::test]
+async fn test_bybit_benign_code_logs_at_debug_and_still_fails() {
+    let exchange = mock_exchange(json!({
+        "retCode": BYBIT_ORDER_NOT_FOUND,
+        "retMsg": "Order does not exist.",
+        "result": {},
+        "retExtInfo": {},
+        "time": 1
+    }))
+    .await;
+    let seen: Arc<Mutex<Vec<i64>>> = Arc::default();
+    let mut client = BybitClient::new(
+        Some("key".to_string()),
+        Some("secret".to_string()),
+        false,
+        false,
+        5000,
+        None,
+    )
+    .unwrap()
+    .with_base_url(&exchange.base_url());
+    client.set_error_log_filter(benign_filter(BYBIT_ORDER_NOT_FOUND, Arc::clone(&seen)));
+
+    let err = client
+        .request_raw(Method::POST, "/v5/order/cancel", None, true)
+        .await
+        .unwrap_err();
+
+    assert!(matches!(err, Error::Exchange(_)), "{err:?}");
+    assert_eq!(err.exchange_code(), Some(BYBIT_ORDER_NOT_FOUND));
+    assert_eq!(*seen.lock().unwrap(), vec![BYBIT_ORDER_NOT_FOUND]);
+    assert_eq!(
+        client.error_log_level(BYBIT_ORDER_NOT_FOUND),
+        log::Level::Debug
+    );
+    assert_eq!(client.error_log_level(10001), log::Level::Error);
+}
+
+#[tokio::test]
+async fn test_bingx_filter_is_kept_by_clones() {
+    let exchange = mock_exchange(json!({
+        "code": BINGX_ORDER_NOT_FOUND,
+        "msg": "order not exist",
+        "data": {}
+    }))
+    .await;
+    let seen: Arc<Mutex<Vec<i64>>> = Arc::default();
+    let mut client = BingxClient::new(
+        Some("key".to_string()),
+        Some("secret".to_string()),
+        false,
+        5000,
+        None,
+    )
+    .unwrap()
+    .with_base_url(&exchange.base_url());
+    client.set_error_log_filter(benign_filter(BINGX_ORDER_NOT_FOUND, Arc::clone(&seen)));
+    let clone = client.clone();
+
+    let err = clone
+        .request_raw(Method::DELETE, "/openApi/swap/v2/trade/order", None, true)
+        .await
+        .unwrap_err();
+
+    assert_eq!(err.exchange_code(), Some(BINGX_ORDER_NOT_FOUND));
+    assert_eq!(*seen.lock().unwrap(), vec![BINGX_ORDER_NOT_FOUND]);
+    assert_eq!(
+        clone.error_log_level(BINGX_ORDER_NOT_FOUND),
+        log::Level::Debug
+    );
+}
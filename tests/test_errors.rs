@@ -0,0 +1,222 @@
+//! Tests for exchange error introspection (codes, messages, raw responses).
+
+use std::time::Duration;
+
+use reqwest::Method;
+use serde_json::json;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpListener;
+use trade_sdk::bybit::idempotency::is_retryable;
+use trade_sdk::bybit::BybitClient;
+use trade_sdk::{Error, ExchangeErrorKind, ExchangeResponseError};
+
+/// Accept connections, read the request, then write `response` (if any) and hold the socket
+/// open for a while.
+async fn spawn_server(response: Option<&'static str>) -> String {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    tokio::spawn(async move {
+        while let Ok((mut socket, _)) = listener.accept().await {
+            tokio::spawn(async move {
+                let mut buf = [0u8; 4096];
+                let _ = socket.read(&mut buf).await;
+                if let Some(response) = response {
+                    let _ = socket.write_all(response.as_bytes()).await;
+                    let _ = socket.shutdown().await;
+                } else {
+                    tokio::time::sleep(Duration::from_secs(5)).await;
+                }
+            });
+        }
+    });
+    format!("http://{addr}")
+}
+
+async fn request_error(base_url: &str) -> Error {
+    BybitClient::new(None, None, false, false, 5000, None)
+        .unwrap()
+        .with_base_url(base_url)
+        .request_raw(Method::GET, "/v5/market/time", None, false)
+        .await
+        .unwrap_err()
+}
+
+#[test]
+fn test_bybit_error_code() {
+    let body = json!({
+        "retCode": 110001,
+        "retMsg": "Order does not exist.",
+        "result": {},
+        "retExtInfo": {},
+        "time": 1_700_000_000_000u64
+    });
+    let err = ExchangeResponseError::from(body.clone());
+
+    assert_eq!(err.code(), 110001);
+    assert_eq!(err.message(), "Order does not exist.");
+    assert_eq!(err.raw(), &body);
+
+    let err = Error::from(err);
+    assert_eq!(err.exchange_code(), Some(110001));
+}
+
+#[test]
+fn test_bingx_error_code() {
+    let body = json!({
+        "code": 100001,
+        "msg": "Signature verification failed",
+        "data": {}
+    });
+    let err = ExchangeResponseError::from(body.clone());
+
+    assert_eq!(err.code(), 100001);
+    assert_eq!(err.message(), "Signature verification failed");
+    assert_eq!(err.raw(), &body);
+
+    let err = Error::Exchange(err);
+    assert_eq!(err.exchange_code(), Some(100001));
+}
+
+#[test]
+fn test_error_code_string_and_missing() {
+    let err = ExchangeResponseError::new(json!({"code": "80012", "msg": "service unavailable"}));
+    assert_eq!(err.code(), 80012);
+
+    let err = ExchangeResponseError::new(json!({"message": "unknown"}));
+    assert_eq!(err.code(), 0);
+    assert_eq!(err.message(), "unknown");
+}
+
+#[test]
+fn test_non_exchange_error_has_no_code() {
+    let err = Error::Validation("bad input".to_string());
+    assert_eq!(err.exchange_code(), None);
+}
+
+#[test]
+fn test_classify_bybit_codes() {
+    let cases = [
+        (
+            110007,
+            "ab not enough for new order",
+            ExchangeErrorKind::InsufficientBalance,
+        ),
+        (
+            110001,
+            "order not exists or too late to cancel",
+            ExchangeErrorKind::OrderNotFound,
+        ),
+        (10006, "Too many visits!", ExchangeErrorKind::RateLimited),
+        (10029, "symbol invalid", ExchangeErrorKind::InvalidSymbol),
+        (
+            10003,
+            "API key is invalid.",
+            ExchangeErrorKind::Unknown(10003),
+        ),
+    ];
+    for (code, msg, kind) in cases {
+        let err = ExchangeResponseError::new(json!({
+            "retCode": code,
+            "retMsg": msg,
+            "result": {},
+            "retExtInfo": {},
+            "time": 1_700_000_000_000u64
+        }));
+        assert_eq!(err.classify(), kind, "retCode {code}");
+    }
+}
+
+#[test]
+fn test_classify_bingx_codes() {
+    let cases = [
+        (
+            101204,
+            "Insufficient margin",
+            ExchangeErrorKind::InsufficientBalance,
+        ),
+        (
+            80016,
+            "order does not exist",
+            ExchangeErrorKind::OrderNotFound,
+        ),
+        (100410, "rate limitation", ExchangeErrorKind::RateLimited),
+        (
+            109425,
+            "BTC-USDC does not exist",
+            ExchangeErrorKind::InvalidSymbol,
+        ),
+        (
+            80017,
+            "position not exist",
+            ExchangeErrorKind::PositionNotExist,
+        ),
+        (
+            100001,
+            "Signature verification failed",
+            ExchangeErrorKind::Unknown(100001),
+        ),
+    ];
+    for (code, msg, kind) in cases {
+        let err = ExchangeResponseError::new(json!({"code": code, "msg": msg, "data": {}}));
+        assert_eq!(err.classify(), kind, "code {code}");
+    }
+}
+
+#[test]
+fn test_classify_string_code() {
+    let err = ExchangeResponseError::new(json!({"code": "80016", "msg": "order does not exist"}));
+    assert_eq!(err.classify(), ExchangeErrorKind::OrderNotFound);
+}
+
+#[tokio::test]
+async fn test_timeout_predicate() {
+    let url = spawn_server(None).await;
+    let err = reqwest::Client::builder()
+        .timeout(Duration::from_millis(100))
+        .build()
+        .unwrap()
+        .get(&url)
+        .send()
+        .await
+        .unwrap_err();
+    let err = Error::from(err);
+
+    assert!(err.is_timeout(), "{err:?}");
+    assert!(!err.is_connect());
+    assert!(!err.is_body());
+    assert!(is_retryable(&err));
+}
+
+#[tokio::test]
+async fn test_connect_predicate() {
+    // Nothing listens on the discard port, so the connection is refused.
+    let err = request_error("http://127.0.0.1:9").await;
+
+    assert!(err.is_connect(), "{err:?}");
+    assert!(!err.is_timeout());
+    assert!(!err.is_body());
+    assert!(is_retryable(&err));
+}
+
+#[tokio::test]
+async fn test_body_predicate() {
+    // Promise 100 bytes, send 9 and close.
+    let url = spawn_server(Some(
+        "HTTP/1.1 200 OK\r\ncontent-type: application/json\r\ncontent-length: 100\r\n\r\n{\"retCode\"",
+    ))
+    .await;
+    let err = request_error(&url).await;
+
+    assert!(err.is_body(), "{err:?}");
+    assert!(!err.is_timeout());
+    assert!(!err.is_connect());
+}
+
+#[test]
+fn test_predicates_false_for_non_http_errors() {
+    let err = Error::Validation("bad".to_string());
+
+    assert!(!err.is_timeout());
+    assert!(!err.is_connect());
+    assert!(!err.is_body());
+}
@@ -0,0 +1,238 @@
+//! Tests for auto-generated Bybit order link ids and retries with a stable id.
+
+use std::sync::Mutex;
+
+use reqwest::Method;
+use serde_json::json;
+use trade_sdk::bybit::idempotency::{
+    generate_order_link_id, place_with_stable_order_link_id, AutoClientOrderId,
+    ORDER_LINK_ID_MAX_LEN,
+};
+use trade_sdk::bybit::traits::TradeApi;
+use trade_sdk::bybit::types::{AllCategories, PlaceOrderParams};
+use trade_sdk::bybit::BybitClient;
+use trade_sdk::testkit::{MockExchange, MockResponse};
+use trade_sdk::{Error, ExchangeResponseError};
+
+/// A transport error (connection refused), as returned when a request times out or fails.
+async fn transport_error() -> Error {
+    let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+    let addr = listener.local_addr().unwrap();
+    drop(listener);
+    let err = reqwest::get(format!("http://{addr}/")).await.unwrap_err();
+    Error::Http(err)
+}
+
+fn order() -> PlaceOrderParams {
+    PlaceOrderParams {
+        symbol: "BTCUSDT".to_owned(),
+        qty: "0.01".parse().unwrap(),
+        ..Default::default()
+    }
+}
+
+#[test]
+fn test_generated_id_fits_length_limit() {
+    let id = generate_order_link_id("");
+    assert_eq!(id.len(), 22);
+    assert!(id.chars().all(|c| c.is_ascii_alphanumeric()));
+
+    let id = generate_order_link_id("strategy-0001_");
+    assert_eq!(id.len(), ORDER_LINK_ID_MAX_LEN);
+    assert!(id.starts_with("strategy-0001_"));
+
+    // Over-long prefixes are truncated rather than exceeding the limit.
+    let id = generate_order_link_id("a-very-long-strategy-name");
+    assert_eq!(id.len(), ORDER_LINK_ID_MAX_LEN);
+
+    assert_ne!(generate_order_link_id("x"), generate_order_link_id("x"));
+}
+
+#[test]
+fn test_invalid_prefix() {
+    assert!(matches!(
+        AutoClientOrderId::new("this-prefix-is-too-long", 2),
+        Err(Error::Validation(_))
+    ));
+    assert!(matches!(
+        AutoClientOrderId::new("bad prefix", 2),
+        Err(Error::Validation(_))
+    ));
+    assert!(AutoClientOrderId::new("bot_1-", 2).is_ok());
+}
+
+#[tokio::test]
+async fn test_retries_reuse_same_id() {
+    let settings = AutoClientOrderId::new("bot-", 3).unwrap();
+    let seen = Mutex::new(Vec::new());
+
+    let (id, result) = place_with_stable_order_link_id(&order(), &settings, |params| {
+        let attempt = {
+            let mut seen = seen.lock().unwrap();
+            seen.push(params.order_link_id.clone().unwrap());
+            seen.len()
+        };
+        async move {
+            if attempt < 3 {
+                Err(transport_error().await)
+            } else {
+                Ok(attempt)
+            }
+        }
+    })
+    .await;
+
+    assert_eq!(result.unwrap(), 3);
+    let seen = seen.into_inner().unwrap();
+    assert_eq!(seen.len(), 3);
+    assert!(seen.iter().all(|s| *s == id));
+    assert!(id.starts_with("bot-"));
+    assert!(id.len() <= ORDER_LINK_ID_MAX_LEN);
+}
+
+#[tokio::test]
+async fn test_retries_are_bounded_and_skip_exchange_errors() {
+    let settings = AutoClientOrderId::new("", 2).unwrap();
+
+    let calls = Mutex::new(0);
+    let (_, result): (_, trade_sdk::Result<()>) =
+        place_with_stable_order_link_id(&order(), &settings, |_| {
+            *calls.lock().unwrap() += 1;
+            async { Err(transport_error().await) }
+        })
+        .await;
+    assert!(matches!(result, Err(Error::Http(_))));
+    assert_eq!(*calls.lock().unwrap(), 3);
+
+    let calls = Mutex::new(0);
+    let (_, result): (_, trade_sdk::Result<()>) =
+        place_with_stable_order_link_id(&order(), &settings, |_| {
+            *calls.lock().unwrap() += 1;
+            async {
+                Err(Error::Exchange(ExchangeResponseError::new(
+                    json!({"retCode": 110072, "retMsg": "OrderLinkedID is duplicate"}),
+                )))
+            }
+        })
+        .await;
+    assert!(matches!(result, Err(Error::Exchange(_))));
+    assert_eq!(*calls.lock().unwrap(), 1);
+}
+
+#[tokio::test]
+async fn test_existing_order_link_id_is_kept() {
+    let settings = AutoClientOrderId::new("bot-", 0).unwrap();
+    let params = PlaceOrderParams {
+        order_link_id: Some("my-own-id".to_owned()),
+        ..order()
+    };
+    let (id, result) =
+        place_with_stable_order_link_id(&params, &settings, |p| async move { Ok(p.order_link_id) })
+            .await;
+    assert_eq!(id, "my-own-id");
+    assert_eq!(result.unwrap().as_deref(), Some("my-own-id"));
+}
+
+#[tokio::test]
+async fn test_client_auto_client_order_id_mode() {
+    let client = BybitClient::new(None, None, false, false, 5000, None).unwrap();
+    assert!(client.auto_client_order_id().is_none());
+
+    let client = client.with_auto_client_order_id("bot-", 2).unwrap();
+    let settings = client.auto_client_order_id().unwrap();
+    assert_eq!((settings.prefix(), settings.max_retries()), ("bot-", 2));
+
+    // Missing credentials is not a transport error and fails immediately.
+    let result = client.place_order(AllCategories::Linear, &order()).await;
+    assert!(
+        matches!(&result, Err(Error::OrderPlacement { source, .. }) if matches!(**source, Error::Auth(_))),
+        "{result:?}"
+    );
+}
+
+fn bybit_ok(result: serde_json::Value) -> serde_json::Value {
+    json!({"retCode": 0, "retMsg": "OK", "result": result, "retExtInfo": {}, "time": 1})
+}
+
+fn signed_client(exchange: &MockExchange) -> BybitClient {
+    BybitClient::new(
+        Some("key".to_string()),
+        Some("secret".to_string()),
+        false,
+        false,
+        5000,
+        None,
+    )
+    .unwrap()
+    .with_base_url(&exchange.base_url())
+    .with_auto_client_order_id("bot-", 2)
+    .unwrap()
+}
+
+#[tokio::test]
+async fn test_duplicate_after_lost_response_returns_placed_order() {
+    let exchange = MockExchange::start().await;
+    // Attempt 1 is accepted but its response never arrives; the retry is a duplicate.
+    exchange.respond_sequence(
+        Method::POST,
+        "/v5/order/create",
+        [
+            MockResponse::disconnect(),
+            MockResponse::new(json!({
+                "retCode": 110072,
+                "retMsg": "OrderLinkedID is duplicate",
+                "result": {},
+                "retExtInfo": {},
+                "time": 1
+            })),
+        ],
+    );
+    exchange.respond(
+        Method::GET,
+        "/v5/order/realtime",
+        bybit_ok(json!({"category": "linear", "list": [{"orderId": "42", "orderStatus": "New"}]})),
+    );
+
+    let response = signed_client(&exchange)
+        .place_order(AllCategories::Linear, &order())
+        .await
+        .unwrap();
+
+    let sent = exchange.requests_to(Method::POST, "/v5/order/create");
+    assert_eq!(sent.len(), 2);
+    let order_link_id = sent[0].json().unwrap()["orderLinkId"].clone();
+    assert_eq!(sent[1].json().unwrap()["orderLinkId"], order_link_id);
+    assert_eq!(response.result["orderId"], "42");
+    assert_eq!(response.result["orderLinkId"], order_link_id);
+    let lookup = &exchange.requests_to(Method::GET, "/v5/order/realtime")[0];
+    assert_eq!(
+        lookup.query_param("orderLinkId").as_deref(),
+        order_link_id.as_str()
+    );
+}
+
+#[tokio::test]
+async fn test_failed_placement_carries_order_link_id() {
+    let exchange = MockExchange::start().await;
+    exchange.respond_sequence(
+        Method::POST,
+        "/v5/order/create",
+        [MockResponse::disconnect()],
+    );
+
+    let error = signed_client(&exchange)
+        .place_order(AllCategories::Linear, &order())
+        .await
+        .unwrap_err();
+
+    let sent = exchange.requests_to(Method::POST, "/v5/order/create");
+    assert_eq!(sent.len(), 3);
+    assert_eq!(
+        Some(sent[0].json().unwrap()["orderLinkId"].as_str().unwrap()),
+        error.order_link_id()
+    );
+    assert!(matches!(
+        &error,
+        Error::OrderPlacement { source, .. } if matches!(**source, Error::Http(_))
+    ));
+}
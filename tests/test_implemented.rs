@@ -0,0 +1,33 @@
+//! Tests for the runtime implemented-method lookups on the clients.
+
+use trade_sdk::bingx::{BingxClient, BINGX_IMPLEMENTED};
+use trade_sdk::bybit::{BybitClient, BYBIT_IMPLEMENTED};
+
+#[test]
+fn test_bybit_is_implemented() {
+    assert!(BybitClient::is_implemented("place_order"));
+    assert!(BybitClient::is_implemented("get_kline"));
+    // Still a `todo!()` stub.
+    assert!(!BybitClient::is_implemented("amend_order"));
+    assert!(!BybitClient::is_implemented("no_such_method"));
+    assert!(!BybitClient::is_implemented(""));
+}
+
+#[test]
+fn test_bingx_is_implemented() {
+    assert!(BingxClient::is_implemented("get_swap_balance"));
+    assert!(!BingxClient::is_implemented("place_order"));
+    assert!(!BingxClient::is_implemented("no_such_method"));
+}
+
+#[test]
+fn test_implemented_methods_sorted_and_complete() {
+    let bybit = BybitClient::implemented_methods();
+    let bingx = BingxClient::implemented_methods();
+
+    assert_eq!(bybit.len(), BYBIT_IMPLEMENTED.len());
+    assert_eq!(bingx.len(), BINGX_IMPLEMENTED.len());
+    assert!(bybit.is_sorted());
+    assert!(bingx.is_sorted());
+    assert!(bybit.contains(&"get_server_time"));
+}
@@ -0,0 +1,149 @@
+//! Tests for rotating requests across several Bybit API keys.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+
+use reqwest::Method;
+use serde_json::json;
+use trade_sdk::bybit::KeyPool;
+use trade_sdk::testkit::{MockExchange, MockResponse};
+use trade_sdk::{BybitClientsCache, Error};
+
+fn bybit_ok() -> serde_json::Value {
+    json!({"retCode": 0, "retMsg": "OK", "result": {}, "retExtInfo": {}, "time": 1})
+}
+
+fn pool_keys(keys: &[&str]) -> Vec<(String, String)> {
+    keys.iter()
+        .map(|key| (key.to_string(), format!("{key}-secret")))
+        .collect()
+}
+
+#[tokio::test]
+async fn test_key_pool_distributes_requests_evenly() {
+    let exchange = MockExchange::start().await;
+    exchange.respond_to_any(bybit_ok());
+    let keys = ["pool-even-a", "pool-even-b", "pool-even-c"];
+    let pool = KeyPool::new(pool_keys(&keys), false, false)
+        .unwrap()
+        .with_base_url(&exchange.base_url());
+
+    for _ in 0..30 {
+        pool.request_raw(Method::GET, "/v5/account/wallet-balance", None, true)
+            .await
+            .unwrap();
+    }
+
+    let mut counts: HashMap<String, usize> = HashMap::new();
+    for request in exchange.requests() {
+        *counts
+            .entry(request.header("X-BAPI-API-KEY").unwrap().to_string())
+            .or_default() += 1;
+    }
+    for key in keys {
+        assert_eq!(counts.get(key), Some(&10), "{counts:?}");
+        assert_eq!(pool.usage()[key], 10);
+    }
+}
+
+#[tokio::test]
+async fn test_key_pool_respects_weights() {
+    let keys = ["pool-weight-a", "pool-weight-b"];
+    let pool = KeyPool::with_weights(
+        vec![
+            (keys[0].to_string(), format!("{}-secret", keys[0]), 3),
+            (keys[1].to_string(), format!("{}-secret", keys[1]), 1),
+        ],
+        false,
+        false,
+    )
+    .unwrap();
+
+    for _ in 0..40 {
+        pool.client();
+    }
+    let usage = pool.usage();
+    assert_eq!((usage[keys[0]], usage[keys[1]]), (30, 10));
+}
+
+#[tokio::test]
+async fn test_key_pool_reuses_its_clients() {
+    let keys = ["pool-reuse-a", "pool-reuse-b"];
+    let pool = KeyPool::new(pool_keys(&keys), false, false).unwrap();
+
+    let first = pool.client();
+    let second = pool.client();
+    let third = pool.client();
+    assert!(!Arc::ptr_eq(&first, &second));
+    assert!(Arc::ptr_eq(&first, &third));
+    assert_eq!(first.max_concurrency(), None);
+}
+
+#[tokio::test]
+async fn test_key_pool_skips_keys_without_free_slot() {
+    let exchange = MockExchange::start().await;
+    // Keep requests in flight well past the end of the test.
+    exchange.respond_sequence(
+        Method::GET,
+        "/v5/market/time",
+        [MockResponse::new(bybit_ok()).with_delay(Duration::from_secs(60))],
+    );
+    let keys = ["pool-busy-a", "pool-busy-b"];
+    let pool = KeyPool::new(pool_keys(&keys), false, false)
+        .unwrap()
+        .with_max_concurrency(Some(1))
+        .with_base_url(&exchange.base_url());
+
+    // Occupy key a's only slot; every request goes to key b until it is released.
+    let busy = pool.client();
+    assert_eq!(busy.max_concurrency(), Some(1));
+    let stalled = {
+        let busy = Arc::clone(&busy);
+        tokio::spawn(async move {
+            let _ = busy
+                .request_raw(Method::GET, "/v5/market/time", None, false)
+                .await;
+        })
+    };
+    while busy.available_request_slots() != Some(0) {
+        tokio::time::sleep(Duration::from_millis(5)).await;
+    }
+    for _ in 0..4 {
+        let client = pool.client();
+        assert!(!Arc::ptr_eq(&client, &busy));
+        // Each key has its own limit.
+        assert_eq!(client.available_request_slots(), Some(1));
+    }
+
+    stalled.abort();
+    let _ = stalled.await;
+    assert_eq!(busy.available_request_slots(), Some(1));
+    assert!(Arc::ptr_eq(&pool.client(), &busy));
+}
+
+#[test]
+fn test_key_pool_rejects_invalid_config() {
+    assert!(matches!(
+        KeyPool::new(Vec::new(), false, false),
+        Err(Error::Config(_))
+    ));
+    assert!(matches!(
+        KeyPool::with_weights(vec![("k".into(), "s".into(), 0)], false, false),
+        Err(Error::Config(_))
+    ));
+}
+
+#[test]
+fn test_key_pool_limit_does_not_depend_on_the_clients_cache() {
+    let pool = KeyPool::new(pool_keys(&["pool-own-a"]), false, false)
+        .unwrap()
+        .with_max_concurrency(Some(2));
+    let cached =
+        BybitClientsCache::get_or_create("pool-own-a", "pool-own-a-secret", false, false).unwrap();
+
+    let client = pool.client();
+    assert!(!Arc::ptr_eq(&client, &cached));
+    assert_eq!(client.max_concurrency(), Some(2));
+    assert_eq!(cached.max_concurrency(), None);
+}
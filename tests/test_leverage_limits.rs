@@ -0,0 +1,151 @@
+//! Tests for leverage limit lookups and `set_leverage_checked` / `set_swap_leverage_checked`.
+
+use reqwest::Method;
+use serde_json::{json, Value};
+use trade_sdk::bingx::{types::PositionSide, BingxClient};
+use trade_sdk::bybit::{types::AllCategories, BybitClient};
+use trade_sdk::testkit::MockExchange;
+use trade_sdk::Error;
+
+fn bybit_ok(result: Value) -> Value {
+    json!({"retCode": 0, "retMsg": "OK", "result": result, "retExtInfo": {}, "time": 1})
+}
+
+/// Mock Bybit serving one linear instrument with a 50x max leverage, and a client pointed at it.
+async fn bybit_client() -> (MockExchange, BybitClient) {
+    let instruments = bybit_ok(json!({
+        "category": "linear",
+        "list": [{
+            "symbol": "BTCUSDT",
+            "status": "Trading",
+            "leverageFilter": {"minLeverage": "1", "maxLeverage": "50.00", "leverageStep": "0.01"}
+        }],
+        "nextPageCursor": ""
+    }));
+    let exchange = MockExchange::start().await;
+    exchange.respond(Method::GET, "/v5/market/instruments-info", instruments);
+    exchange.respond(
+        Method::POST,
+        "/v5/position/set-leverage",
+        bybit_ok(json!({})),
+    );
+    let client = BybitClient::new(
+        Some("key".to_string()),
+        Some("secret".to_string()),
+        false,
+        false,
+        5000,
+        None,
+    )
+    .unwrap()
+    .with_base_url(&exchange.base_url());
+    (exchange, client)
+}
+
+#[tokio::test]
+async fn test_set_leverage_checked_rejects_above_max() {
+    let (exchange, client) = bybit_client().await;
+
+    let result = client
+        .set_leverage_checked(AllCategories::Linear, "BTCUSDT", 100, 100)
+        .await;
+
+    match result {
+        Err(Error::Validation(msg)) => {
+            assert!(msg.contains("100x"), "{msg}");
+            assert!(msg.contains("50x"), "{msg}");
+        }
+        other => panic!("expected validation error, got {other:?}"),
+    }
+    assert!(exchange
+        .requests_to(Method::POST, "/v5/position/set-leverage")
+        .is_empty());
+}
+
+#[tokio::test]
+async fn test_set_leverage_checked_sends_within_max_and_caches_limit() {
+    let (exchange, client) = bybit_client().await;
+
+    client
+        .set_leverage_checked(AllCategories::Linear, "BTCUSDT", 50, 20)
+        .await
+        .unwrap();
+    let zero = client
+        .set_leverage_checked(AllCategories::Linear, "BTCUSDT", 0, 10)
+        .await;
+
+    assert!(matches!(zero, Err(Error::Validation(_))), "{zero:?}");
+    assert_eq!(
+        client
+            .clone()
+            .max_leverage(AllCategories::Linear, "BTCUSDT")
+            .await
+            .unwrap(),
+        50.0
+    );
+    assert_eq!(
+        exchange
+            .requests_to(Method::GET, "/v5/market/instruments-info")
+            .len(),
+        1
+    );
+    assert_eq!(
+        exchange
+            .requests_to(Method::POST, "/v5/position/set-leverage")
+            .len(),
+        1
+    );
+}
+
+#[tokio::test]
+async fn test_set_swap_leverage_checked_per_side() {
+    let leverage = json!({
+        "code": 0,
+        "msg": "",
+        "data": {"longLeverage": 10, "shortLeverage": 10, "maxLongLeverage": 75, "maxShortLeverage": 50}
+    });
+    let exchange = MockExchange::start().await;
+    for method in [Method::GET, Method::POST] {
+        exchange.respond(method, "/openApi/swap/v2/trade/leverage", leverage.clone());
+    }
+    let client = BingxClient::new(
+        Some("key".to_string()),
+        Some("secret".to_string()),
+        false,
+        5000,
+        None,
+    )
+    .unwrap()
+    .with_base_url(&exchange.base_url());
+
+    client
+        .set_swap_leverage_checked("BTC-USDT", PositionSide::Long, 75)
+        .await
+        .unwrap();
+    let short = client
+        .set_swap_leverage_checked("BTC-USDT", PositionSide::Short, 60)
+        .await;
+    let both = client
+        .max_swap_leverage("BTC-USDT", &PositionSide::Both)
+        .await
+        .unwrap();
+
+    assert!(
+        matches!(&short, Err(Error::Validation(msg)) if msg.contains("50x")),
+        "{short:?}"
+    );
+    assert_eq!(both, 50.0);
+    // One lookup (GET) and one accepted update (POST).
+    let lines: Vec<String> = exchange
+        .requests()
+        .iter()
+        .map(|r| format!("{} {}", r.method, r.path))
+        .collect();
+    assert_eq!(
+        lines,
+        [
+            "GET /openApi/swap/v2/trade/leverage",
+            "POST /openApi/swap/v2/trade/leverage"
+        ]
+    );
+}
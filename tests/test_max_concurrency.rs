@@ -0,0 +1,114 @@
+//! Tests for the per-client in-flight request limit.
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpListener;
+use trade_sdk::bybit::{traits::MarketApi, BybitClient};
+
+const DELAY: Duration = Duration::from_millis(300);
+
+/// Requests the server is handling now and the most it handled at once.
+#[derive(Default)]
+struct Load {
+    current: AtomicUsize,
+    peak: AtomicUsize,
+}
+
+/// Serve every connection with a server time response after `DELAY`, tracking `load`.
+async fn spawn_slow_server(load: Arc<Load>) -> String {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    tokio::spawn(async move {
+        while let Ok((mut socket, _)) = listener.accept().await {
+            let load = Arc::clone(&load);
+            tokio::spawn(async move {
+                let mut buf = vec![0u8; 4096];
+                let _ = socket.read(&mut buf).await;
+                let now = load.current.fetch_add(1, Ordering::SeqCst) + 1;
+                load.peak.fetch_max(now, Ordering::SeqCst);
+                tokio::time::sleep(DELAY).await;
+                load.current.fetch_sub(1, Ordering::SeqCst);
+                let body = r#"{"retCode":0,"retMsg":"OK","result":{"timeSecond":"1700000000"},"retExtInfo":{},"time":1700000000000}"#;
+                let response = format!(
+                    "HTTP/1.1 200 OK\r\ncontent-type: application/json\r\ncontent-length: {}\r\nconnection: close\r\n\r\n{}",
+                    body.len(),
+                    body
+                );
+                let _ = socket.write_all(response.as_bytes()).await;
+            });
+        }
+    });
+    format!("http://{addr}")
+}
+
+fn client(base_url: &str) -> BybitClient {
+    BybitClient::new(None, None, false, false, 5000, None)
+        .unwrap()
+        .with_base_url(base_url)
+}
+
+#[test]
+fn test_max_concurrency_setting() {
+    let mut client = client("http://127.0.0.1:9");
+    assert_eq!(client.max_concurrency(), None);
+
+    client.set_max_concurrency(Some(0));
+    assert_eq!(client.max_concurrency(), Some(1));
+    assert_eq!(client.clone().max_concurrency(), Some(1));
+
+    client.set_max_concurrency(None);
+    assert_eq!(client.max_concurrency(), None);
+}
+
+#[tokio::test]
+async fn test_third_request_waits_for_a_slot() {
+    let load = Arc::new(Load::default());
+    let url = spawn_slow_server(Arc::clone(&load)).await;
+    let mut client = client(&url);
+    client.set_max_concurrency(Some(2));
+
+    let started = Instant::now();
+    let (a, b, c) = tokio::join!(
+        client.get_server_time(),
+        client.get_server_time(),
+        client.get_server_time()
+    );
+
+    assert!(a.is_ok() && b.is_ok() && c.is_ok());
+    assert_eq!(load.peak.load(Ordering::SeqCst), 2);
+    // The third request only starts once one of the first two has completed.
+    assert!(started.elapsed() >= DELAY * 2, "{:?}", started.elapsed());
+}
+
+#[tokio::test]
+async fn test_clones_share_the_limit() {
+    let load = Arc::new(Load::default());
+    let url = spawn_slow_server(Arc::clone(&load)).await;
+    let mut client = client(&url);
+    client.set_max_concurrency(Some(1));
+    let clone = client.clone();
+
+    let (a, b) = tokio::join!(client.get_server_time(), clone.get_server_time());
+
+    assert!(a.is_ok() && b.is_ok());
+    assert_eq!(load.peak.load(Ordering::SeqCst), 1);
+}
+
+#[tokio::test]
+async fn test_unlimited_by_default() {
+    let load = Arc::new(Load::default());
+    let url = spawn_slow_server(Arc::clone(&load)).await;
+    let client = client(&url);
+
+    let (a, b, c) = tokio::join!(
+        client.get_server_time(),
+        client.get_server_time(),
+        client.get_server_time()
+    );
+
+    assert!(a.is_ok() && b.is_ok() && c.is_ok());
+    assert_eq!(load.peak.load(Ordering::SeqCst), 3);
+}
@@ -0,0 +1,95 @@
+//! Tests for request/response observer hooks.
+
+use std::sync::{Arc, Mutex};
+
+use trade_sdk::bingx::{traits::common::CommonApi as BingxCommonApi, BingxClient};
+use trade_sdk::bybit::{traits::MarketApi, BybitClient};
+use trade_sdk::{Observer, RequestMeta, ResponseMeta};
+
+/// (exchange, endpoint, status, ret_code)
+type RecordedResponse = (String, String, Option<u16>, Option<i64>);
+
+#[derive(Default)]
+struct RecordingObserver {
+    requests: Mutex<Vec<(String, String)>>,
+    responses: Mutex<Vec<RecordedResponse>>,
+}
+
+impl Observer for RecordingObserver {
+    fn on_request(
+        &self,
+        meta: &RequestMeta<'_>,
+    ) {
+        self.requests
+            .lock()
+            .unwrap()
+            .push((meta.exchange.to_owned(), meta.endpoint.to_owned()));
+    }
+
+    fn on_response(
+        &self,
+        meta: &ResponseMeta<'_>,
+    ) {
+        self.responses.lock().unwrap().push((
+            meta.exchange.to_owned(),
+            meta.endpoint.to_owned(),
+            meta.status,
+            meta.ret_code,
+        ));
+    }
+}
+
+#[tokio::test]
+async fn test_bybit_observer_sees_request_and_response() {
+    let observer = Arc::new(RecordingObserver::default());
+    let mut client = BybitClient::new(None, None, false, false, 5000, None).unwrap();
+    client.set_observer(observer.clone());
+
+    let result = client.get_server_time().await;
+
+    let requests = observer.requests.lock().unwrap();
+    let responses = observer.responses.lock().unwrap();
+    assert_eq!(
+        *requests,
+        [("bybit".to_owned(), "/v5/market/time".to_owned())]
+    );
+    assert_eq!(responses.len(), 1);
+    let (exchange, endpoint, status, ret_code) = &responses[0];
+    assert_eq!(
+        (exchange.as_str(), endpoint.as_str()),
+        ("bybit", "/v5/market/time")
+    );
+    // Offline there is no HTTP status; online the call succeeds with retCode 0.
+    if result.is_ok() {
+        assert_eq!((*status, *ret_code), (Some(200), Some(0)));
+    } else {
+        assert_eq!(*status, None);
+    }
+}
+
+#[tokio::test]
+async fn test_bingx_observer_sees_request_and_response() {
+    let observer = Arc::new(RecordingObserver::default());
+    let mut client = BingxClient::new(None, None, false, 5000, None).unwrap();
+    client.set_observer(observer.clone());
+
+    let _ = client.get_server_time().await;
+
+    assert_eq!(observer.requests.lock().unwrap().len(), 1);
+    let responses = observer.responses.lock().unwrap();
+    assert_eq!(responses.len(), 1);
+    assert_eq!(responses[0].0, "bingx");
+}
+
+#[tokio::test]
+async fn test_observer_not_called_when_request_not_sent() {
+    let observer = Arc::new(RecordingObserver::default());
+    let mut client = BingxClient::new(None, None, false, 5000, None).unwrap();
+    client.set_observer(observer.clone());
+
+    // Missing credentials fail before anything is sent.
+    let _ = trade_sdk::bingx::traits::swap::AccountApi::get_swap_balance(&client).await;
+
+    assert!(observer.requests.lock().unwrap().is_empty());
+    assert!(observer.responses.lock().unwrap().is_empty());
+}
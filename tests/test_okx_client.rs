@@ -0,0 +1,175 @@
+//! Tests for the OKX client: base URL and demo header, signed headers and request shapes.
+
+use reqwest::Method;
+use serde_json::{json, Value};
+use trade_sdk::okx::signing::sign;
+use trade_sdk::okx::traits::{MarketApi, TradeApi};
+use trade_sdk::okx::types::{InstType, OrderType, PlaceOrderParams, Side, TdMode};
+use trade_sdk::okx::OkxClient;
+use trade_sdk::testkit::MockExchange;
+use trade_sdk::{Amount, Error};
+
+/// Mock exchange answering every request with `body`.
+async fn mock_exchange(body: Value) -> MockExchange {
+    let exchange = MockExchange::start().await;
+    exchange.respond_to_any(body);
+    exchange
+}
+
+fn amount(s: &str) -> Amount {
+    s.parse().unwrap()
+}
+
+fn ok_response() -> Value {
+    json!({"code": "0", "msg": "", "data": [{"ordId": "312269865356374016", "sCode": "0"}]})
+}
+
+fn client(
+    url: &str,
+    demo: bool,
+) -> OkxClient {
+    OkxClient::new(
+        Some("key".to_string()),
+        Some("secret".to_string()),
+        Some("passphrase".to_string()),
+        demo,
+    )
+    .unwrap()
+    .with_base_url(url)
+}
+
+#[test]
+fn test_live_and_demo_share_base_url() {
+    let live = OkxClient::new(None, None, None, false).unwrap();
+    let demo = OkxClient::new(None, None, None, true).unwrap();
+    assert_eq!(live.base_url(), "https://www.okx.com");
+    assert_eq!(demo.base_url(), "https://www.okx.com");
+    assert!(!live.is_demo());
+    assert!(demo.is_demo());
+}
+
+#[tokio::test]
+async fn test_demo_sends_simulated_trading_header() {
+    for demo in [false, true] {
+        let exchange = mock_exchange(json!({"code": "0", "msg": "", "data": []})).await;
+
+        client(&exchange.base_url(), demo)
+            .get_tickers(InstType::Spot, None, None)
+            .await
+            .unwrap();
+
+        assert_eq!(
+            exchange.requests()[0].header("x-simulated-trading"),
+            demo.then_some("1"),
+            "demo = {demo}"
+        );
+    }
+}
+
+#[tokio::test]
+async fn test_get_tickers_sends_query() {
+    let exchange = mock_exchange(json!({"code": "0", "msg": "", "data": []})).await;
+
+    let response = client(&exchange.base_url(), true)
+        .get_tickers(InstType::Swap, None, Some("BTC-USD"))
+        .await
+        .unwrap();
+
+    assert_eq!(response.code, "0");
+    let request = &exchange.requests()[0];
+    assert_eq!(
+        request.request_line(),
+        "GET /api/v5/market/tickers?instFamily=BTC-USD&instType=SWAP"
+    );
+    assert_eq!(request.header("x-simulated-trading"), Some("1"));
+    assert_eq!(request.header("OK-ACCESS-KEY"), None);
+}
+
+#[tokio::test]
+async fn test_place_order_is_signed() {
+    let exchange = mock_exchange(ok_response()).await;
+    let params = PlaceOrderParams {
+        inst_id: "BTC-USDT".to_string(),
+        td_mode: TdMode::Cash,
+        side: Side::Buy,
+        ord_type: OrderType::Limit,
+        sz: amount("1"),
+        px: Some(amount("50000")),
+        ..Default::default()
+    };
+
+    let response = client(&exchange.base_url(), false)
+        .place_order(&params)
+        .await
+        .unwrap();
+
+    assert_eq!(response.data[0]["ordId"], "312269865356374016");
+    let request = &exchange.requests()[0];
+    assert_eq!(request.request_line(), "POST /api/v5/trade/order");
+    assert_eq!(
+        request.json().unwrap(),
+        json!({"instId": "BTC-USDT", "tdMode": "cash", "side": "buy", "ordType": "limit", "sz": "1", "px": "50000"})
+    );
+    assert_eq!(request.header("OK-ACCESS-KEY"), Some("key"));
+    assert_eq!(request.header("OK-ACCESS-PASSPHRASE"), Some("passphrase"));
+    assert_eq!(request.header("x-simulated-trading"), None);
+    let timestamp = request.header("OK-ACCESS-TIMESTAMP").unwrap();
+    assert_eq!(
+        request.header("OK-ACCESS-SIGN").unwrap(),
+        sign(
+            "secret",
+            timestamp,
+            &Method::POST,
+            "/api/v5/trade/order",
+            &request.body
+        )
+    );
+}
+
+#[tokio::test]
+async fn test_cancel_order_prefers_ord_id() {
+    let exchange = mock_exchange(ok_response()).await;
+
+    client(&exchange.base_url(), false)
+        .cancel_order("BTC-USDT", Some("123"), Some("mine"))
+        .await
+        .unwrap();
+
+    assert_eq!(
+        exchange.requests()[0].json().unwrap(),
+        json!({"instId": "BTC-USDT", "ordId": "123"})
+    );
+}
+
+#[tokio::test]
+async fn test_cancel_order_requires_an_id() {
+    let err = client("http://127.0.0.1:9", false)
+        .cancel_order("BTC-USDT", None, None)
+        .await
+        .unwrap_err();
+    assert!(matches!(err, Error::Validation(_)), "{err:?}");
+}
+
+#[tokio::test]
+async fn test_non_zero_code_is_exchange_error() {
+    let exchange =
+        mock_exchange(json!({"code": "51001", "msg": "Instrument ID does not exist", "data": []}))
+            .await;
+
+    let err = client(&exchange.base_url(), false)
+        .cancel_order("NOPE-USDT", Some("1"), None)
+        .await
+        .unwrap_err();
+
+    assert_eq!(err.exchange_code(), Some(51001));
+}
+
+#[tokio::test]
+async fn test_auth_requires_passphrase() {
+    let err = OkxClient::new(Some("key".into()), Some("secret".into()), None, false)
+        .unwrap()
+        .cancel_order("BTC-USDT", Some("1"), None)
+        .await
+        .unwrap_err();
+    assert!(matches!(err, Error::Auth(_)), "{err:?}");
+}
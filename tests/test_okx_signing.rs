@@ -0,0 +1,78 @@
+//! Tests for OKX request signing with fixed inputs.
+
+use std::collections::HashMap;
+
+use reqwest::Method;
+use serde_json::json;
+use trade_sdk::okx::signing::{iso_timestamp, mask_headers, prehash, query_string, sign};
+
+const SECRET: &str = "test_secret";
+const TIMESTAMP: &str = "2020-12-08T09:08:57.715Z";
+
+#[test]
+fn test_sign_get_with_query() {
+    let signature = sign(
+        SECRET,
+        TIMESTAMP,
+        &Method::GET,
+        "/api/v5/account/balance?ccy=BTC",
+        "",
+    );
+    assert_eq!(signature, "7ySiPVi/lhUA5KcmuyAYnW1qir695kDljxmxmNmQ1Sw=");
+}
+
+#[test]
+fn test_sign_post_with_body() {
+    let body = r#"{"instId":"BTC-USDT","sz":"1"}"#;
+    assert_eq!(
+        prehash(TIMESTAMP, &Method::POST, "/api/v5/trade/order", body),
+        r#"2020-12-08T09:08:57.715ZPOST/api/v5/trade/order{"instId":"BTC-USDT","sz":"1"}"#
+    );
+    assert_eq!(
+        sign(
+            SECRET,
+            TIMESTAMP,
+            &Method::POST,
+            "/api/v5/trade/order",
+            body
+        ),
+        "BeWERntEH9Kmt4Gc1veBRDRqpQJY1ffBmTiQbE0wbxI="
+    );
+}
+
+#[test]
+fn test_iso_timestamp() {
+    assert_eq!(iso_timestamp(1_607_418_537_715), TIMESTAMP);
+    assert_eq!(iso_timestamp(0), "1970-01-01T00:00:00.000Z");
+    assert_eq!(iso_timestamp(951_782_400_000), "2000-02-29T00:00:00.000Z");
+    assert_eq!(iso_timestamp(951_782_399_999), "2000-02-28T23:59:59.999Z");
+}
+
+#[test]
+fn test_query_string_is_sorted_and_unquoted() {
+    let params = HashMap::from([
+        ("instType".to_string(), json!("SWAP")),
+        ("limit".to_string(), json!(100)),
+        ("instFamily".to_string(), json!("BTC-USD")),
+    ]);
+    assert_eq!(
+        query_string(&params).as_deref(),
+        Some("instFamily=BTC-USD&instType=SWAP&limit=100")
+    );
+    assert_eq!(query_string(&HashMap::new()), None);
+}
+
+#[test]
+fn test_mask_headers_hides_credentials() {
+    let headers = HashMap::from([
+        ("OK-ACCESS-KEY".to_string(), "abcdef123456".to_string()),
+        ("OK-ACCESS-SIGN".to_string(), "signature".to_string()),
+        ("OK-ACCESS-PASSPHRASE".to_string(), "passphrase".to_string()),
+        ("OK-ACCESS-TIMESTAMP".to_string(), TIMESTAMP.to_string()),
+    ]);
+    let masked = mask_headers(&headers);
+    assert_eq!(masked["ok-access-key"], "abcdef...");
+    assert_eq!(masked["ok-access-sign"], "***");
+    assert_eq!(masked["ok-access-passphrase"], "***");
+    assert_eq!(masked["ok-access-timestamp"], TIMESTAMP);
+}
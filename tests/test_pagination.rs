@@ -0,0 +1,97 @@
+//! Tests for Bybit cursor auto-pagination against a local two-page mock server.
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+
+use futures::StreamExt;
+use serde_json::{json, Value};
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpListener;
+use trade_sdk::bybit::{paginate, types::ApiResponse};
+use trade_sdk::Error;
+
+/// Serve page 1 (`cursor` absent) and page 2 (`cursor=page2`) of a Bybit list response.
+async fn spawn_mock_server(hits: Arc<AtomicUsize>) -> String {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    tokio::spawn(async move {
+        loop {
+            let (mut socket, _) = listener.accept().await.unwrap();
+            hits.fetch_add(1, Ordering::SeqCst);
+            let mut buf = vec![0u8; 4096];
+            let n = socket.read(&mut buf).await.unwrap();
+            let request = String::from_utf8_lossy(&buf[..n]);
+            let request_line = request.lines().next().unwrap_or_default();
+
+            let result = if request_line.contains("cursor=page2") {
+                json!({"list": [{"orderId": "3"}, {"orderId": "4"}], "nextPageCursor": ""})
+            } else {
+                json!({"list": [{"orderId": "1"}, {"orderId": "2"}], "nextPageCursor": "page2"})
+            };
+            let body = json!({
+                "retCode": 0,
+                "retMsg": "OK",
+                "result": result,
+                "retExtInfo": {},
+                "time": 1_700_000_000_000u64
+            })
+            .to_string();
+            let response = format!(
+                "HTTP/1.1 200 OK\r\ncontent-type: application/json\r\ncontent-length: {}\r\nconnection: close\r\n\r\n{}",
+                body.len(),
+                body
+            );
+            socket.write_all(response.as_bytes()).await.unwrap();
+        }
+    });
+    format!("http://{addr}/v5/order/history")
+}
+
+async fn fetch_page(
+    http: &reqwest::Client,
+    url: &str,
+    cursor: Option<String>,
+) -> trade_sdk::Result<ApiResponse<Value>> {
+    let url = match cursor {
+        Some(cursor) => format!("{url}?cursor={cursor}"),
+        None => url.to_string(),
+    };
+    Ok(http.get(url).send().await?.json().await?)
+}
+
+#[tokio::test]
+async fn test_paginate_streams_all_pages_once() {
+    let hits = Arc::new(AtomicUsize::new(0));
+    let url = spawn_mock_server(hits.clone()).await;
+    let http = reqwest::Client::new();
+
+    let items: Vec<Value> = paginate(|cursor| fetch_page(&http, &url, cursor), "nextPageCursor")
+        .map(|item| item.unwrap())
+        .collect()
+        .await;
+
+    let ids: Vec<&str> = items
+        .iter()
+        .map(|item| item["orderId"].as_str().unwrap())
+        .collect();
+    assert_eq!(ids, ["1", "2", "3", "4"]);
+    assert_eq!(hits.load(Ordering::SeqCst), 2);
+}
+
+#[tokio::test]
+async fn test_paginate_stops_on_error() {
+    let calls = AtomicUsize::new(0);
+    let results: Vec<_> = paginate(
+        |_cursor| {
+            calls.fetch_add(1, Ordering::SeqCst);
+            async { Err(Error::Validation("boom".to_string())) }
+        },
+        "nextPageCursor",
+    )
+    .collect()
+    .await;
+
+    assert_eq!(results.len(), 1);
+    assert!(matches!(results[0], Err(Error::Validation(_))));
+    assert_eq!(calls.load(Ordering::SeqCst), 1);
+}
@@ -0,0 +1,131 @@
+//! Tests for `SharedSessionManager::pool_stats` and `connections_opened`.
+//!
+//! The connection reuse check against Bybit only runs with `TRADE_SDK_NETWORK_TESTS=1`.
+
+use std::time::{Duration, Instant};
+
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpListener;
+use trade_sdk::bybit::{traits::MarketApi, BybitClient};
+use trade_sdk::{SessionConfig, SharedSessionManager};
+
+/// Serve every connection with a server time response after `delay`.
+async fn spawn_slow_server(delay: Duration) -> String {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    tokio::spawn(async move {
+        while let Ok((mut socket, _)) = listener.accept().await {
+            tokio::spawn(async move {
+                let mut buf = vec![0u8; 4096];
+                let _ = socket.read(&mut buf).await;
+                tokio::time::sleep(delay).await;
+                let body = r#"{"retCode":0,"retMsg":"OK","result":{"timeSecond":"1700000000"},"retExtInfo":{},"time":1700000000000}"#;
+                let response = format!(
+                    "HTTP/1.1 200 OK\r\ncontent-type: application/json\r\ncontent-length: {}\r\nconnection: close\r\n\r\n{}",
+                    body.len(),
+                    body
+                );
+                let _ = socket.write_all(response.as_bytes()).await;
+            });
+        }
+    });
+    format!("http://{addr}")
+}
+
+fn network_tests_enabled() -> bool {
+    std::env::var("TRADE_SDK_NETWORK_TESTS").is_ok_and(|v| v == "1")
+}
+
+/// Poll until `done` holds, failing after `timeout`.
+async fn wait_for(
+    timeout: Duration,
+    done: impl Fn() -> bool,
+) {
+    let deadline = Instant::now() + timeout;
+    while !done() {
+        assert!(Instant::now() < deadline, "condition not reached in time");
+        tokio::time::sleep(Duration::from_millis(1)).await;
+    }
+}
+
+#[test]
+fn test_session_config_default_keepalive() {
+    assert_eq!(
+        SessionConfig::default().tcp_keepalive,
+        Some(Duration::from_secs(60))
+    );
+}
+
+#[test]
+fn test_pool_stats_free_slots_are_remaining_capacity() {
+    let stats = SharedSessionManager::pool_stats();
+    assert_eq!(
+        stats.estimated_free_slots,
+        stats.max_connections.saturating_sub(stats.active)
+    );
+}
+
+#[tokio::test]
+async fn test_in_flight_gauge_rises_and_falls_around_slow_request() {
+    // Only requests on the shared session are counted.
+    SharedSessionManager::setup(100);
+    let base_url = spawn_slow_server(Duration::from_millis(300)).await;
+    let client = BybitClient::new(None, None, false, false, 5000, None)
+        .unwrap()
+        .with_base_url(&base_url);
+    assert!(client.is_shared_session_enabled());
+
+    let request = tokio::spawn(async move { client.get_server_time().await });
+    wait_for(Duration::from_secs(5), || {
+        SharedSessionManager::pool_stats().active >= 1
+    })
+    .await;
+    assert!(!request.is_finished());
+
+    assert!(request.await.unwrap().is_ok());
+    // Other tests in the same binary may have requests in flight, so wait for a quiet moment.
+    wait_for(Duration::from_secs(10), || {
+        SharedSessionManager::pool_stats().active == 0
+    })
+    .await;
+}
+
+#[tokio::test]
+async fn test_connections_opened_counts_new_connections() {
+    SharedSessionManager::setup(100);
+    // The server closes every connection, so each request has to open a new one.
+    let base_url = spawn_slow_server(Duration::ZERO).await;
+    let client = SharedSessionManager::get_client();
+
+    let before = SharedSessionManager::connections_opened();
+    for _ in 0..3 {
+        let response = client
+            .get(format!("{base_url}/v5/market/time"))
+            .send()
+            .await
+            .unwrap();
+        assert!(response.status().is_success());
+    }
+
+    // Other tests may open connections concurrently, so only the lower bound is exact.
+    assert!(SharedSessionManager::connections_opened() >= before + 3);
+}
+
+#[tokio::test]
+async fn test_clients_reuse_shared_connections() {
+    if !network_tests_enabled() {
+        println!("Skipping: set TRADE_SDK_NETWORK_TESTS=1 to run");
+        return;
+    }
+    SharedSessionManager::setup(100);
+
+    let before = SharedSessionManager::connections_opened();
+    for _ in 0..100 {
+        let client = BybitClient::new(None, None, false, false, 5000, None).unwrap();
+        assert!(client.is_shared_session_enabled());
+        client.get_server_time().await.unwrap();
+    }
+    let opened = SharedSessionManager::connections_opened() - before;
+
+    assert!(opened < 10, "100 clients opened {opened} connections");
+}
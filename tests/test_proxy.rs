@@ -0,0 +1,62 @@
+//! Tests for routing client traffic through a proxy.
+
+use tokio::io::AsyncReadExt;
+use tokio::net::TcpListener;
+use trade_sdk::bingx::BingxClient;
+use trade_sdk::bybit::{traits::MarketApi, BybitClient};
+use trade_sdk::{Error, SessionConfig, SharedSessionManager};
+
+#[test]
+fn test_with_proxy_records_url() {
+    let client = BybitClient::new(None, None, false, false, 5000, None)
+        .unwrap()
+        .with_proxy("http://127.0.0.1:3128")
+        .unwrap();
+    assert_eq!(client.proxy().as_deref(), Some("http://127.0.0.1:3128"));
+    assert!(!client.is_shared_session_enabled());
+
+    let client = BingxClient::new(None, None, false, 5000, None)
+        .unwrap()
+        .with_proxy("socks5://127.0.0.1:1080")
+        .unwrap();
+    assert_eq!(client.proxy().as_deref(), Some("socks5://127.0.0.1:1080"));
+}
+
+#[test]
+fn test_invalid_proxy_url() {
+    let result = BybitClient::new(None, None, false, false, 5000, None)
+        .unwrap()
+        .with_proxy("http://[::1");
+    assert!(matches!(result, Err(Error::Config(_))));
+
+    let result = SharedSessionManager::setup_with_config(SessionConfig {
+        proxy: Some("http://[::1".to_owned()),
+        ..Default::default()
+    });
+    assert!(matches!(result, Err(Error::Config(_))));
+}
+
+#[tokio::test]
+async fn test_requests_go_through_proxy() {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let proxy_url = format!("http://{}", listener.local_addr().unwrap());
+    let proxy = tokio::spawn(async move {
+        let (mut socket, _) = listener.accept().await.unwrap();
+        let mut buf = vec![0u8; 1024];
+        let n = socket.read(&mut buf).await.unwrap();
+        String::from_utf8_lossy(&buf[..n]).into_owned()
+        // Dropping the socket fails the request on the client side.
+    });
+
+    let client = BybitClient::new(None, None, false, false, 5000, None)
+        .unwrap()
+        .with_proxy(&proxy_url)
+        .unwrap();
+    assert!(client.get_server_time().await.is_err());
+
+    let request = proxy.await.unwrap();
+    assert!(
+        request.starts_with("CONNECT api.bybit.com:443"),
+        "unexpected proxy request: {request}"
+    );
+}
@@ -0,0 +1,62 @@
+//! Tests for recycling the shared session connection pool.
+
+use std::sync::Arc;
+
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpListener;
+use trade_sdk::bybit::{traits::MarketApi, BybitClient};
+use trade_sdk::{SessionConfig, SharedSessionManager};
+
+/// Serve every connection with a server time response.
+async fn spawn_server() -> String {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    tokio::spawn(async move {
+        while let Ok((mut socket, _)) = listener.accept().await {
+            tokio::spawn(async move {
+                let mut buf = vec![0u8; 4096];
+                let _ = socket.read(&mut buf).await;
+                let body = r#"{"retCode":0,"retMsg":"OK","result":{"timeSecond":"1700000000"},"retExtInfo":{},"time":1700000000000}"#;
+                let response = format!(
+                    "HTTP/1.1 200 OK\r\ncontent-type: application/json\r\ncontent-length: {}\r\nconnection: close\r\n\r\n{}",
+                    body.len(),
+                    body
+                );
+                let _ = socket.write_all(response.as_bytes()).await;
+            });
+        }
+    });
+    format!("http://{addr}")
+}
+
+#[test]
+fn test_recycle_interval_disabled_by_default() {
+    assert_eq!(SessionConfig::default().recycle_interval, None);
+}
+
+#[tokio::test]
+async fn test_forced_recycle_swaps_client() {
+    SharedSessionManager::setup(100);
+    let base_url = spawn_server().await;
+    let client = BybitClient::new(None, None, false, false, 5000, None)
+        .unwrap()
+        .with_base_url(&base_url);
+    assert!(client.is_shared_session_enabled());
+    client.get_server_time().await.unwrap();
+
+    let before = SharedSessionManager::get_client();
+    SharedSessionManager::recycle().unwrap();
+    let after = SharedSessionManager::get_client();
+    assert!(!Arc::ptr_eq(&before, &after));
+
+    // A handle to the old pool keeps working until it is dropped.
+    let response = before
+        .get(format!("{base_url}/v5/market/time"))
+        .send()
+        .await
+        .unwrap();
+    assert!(response.status().is_success());
+
+    // Clients created before the recycle switch to the new pool transparently.
+    client.get_server_time().await.unwrap();
+}
@@ -0,0 +1,91 @@
+//! Tests that clients do not follow redirects by default.
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpListener;
+use trade_sdk::bingx::{traits::common::CommonApi, BingxClient};
+use trade_sdk::bybit::{traits::MarketApi, BybitClient};
+use trade_sdk::Error;
+
+/// Serve every connection with `response`, counting the requests received.
+async fn spawn_server(
+    response: String,
+    hits: Arc<AtomicUsize>,
+) -> String {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    tokio::spawn(async move {
+        while let Ok((mut socket, _)) = listener.accept().await {
+            let response = response.clone();
+            let hits = Arc::clone(&hits);
+            tokio::spawn(async move {
+                let mut buf = vec![0u8; 4096];
+                let _ = socket.read(&mut buf).await;
+                hits.fetch_add(1, Ordering::SeqCst);
+                let _ = socket.write_all(response.as_bytes()).await;
+            });
+        }
+    });
+    format!("http://{addr}")
+}
+
+/// A redirecting server and the target it points to.
+async fn redirect_pair() -> (String, String, Arc<AtomicUsize>) {
+    let target_hits = Arc::new(AtomicUsize::new(0));
+    let body = r#"{"retCode":0,"retMsg":"OK","result":{},"retExtInfo":{},"time":0}"#;
+    let target = spawn_server(
+        format!(
+            "HTTP/1.1 200 OK\r\ncontent-type: application/json\r\ncontent-length: {}\r\nconnection: close\r\n\r\n{}",
+            body.len(),
+            body
+        ),
+        Arc::clone(&target_hits),
+    )
+    .await;
+    let location = format!("{target}/stolen");
+    let redirect = spawn_server(
+        format!(
+            "HTTP/1.1 302 Found\r\nlocation: {location}\r\ncontent-length: 0\r\nconnection: close\r\n\r\n"
+        ),
+        Arc::new(AtomicUsize::new(0)),
+    )
+    .await;
+    (redirect, location, target_hits)
+}
+
+#[tokio::test]
+async fn test_bybit_does_not_follow_redirects() {
+    let (redirect, location, target_hits) = redirect_pair().await;
+    let client = BybitClient::new(None, None, false, false, 5000, None)
+        .unwrap()
+        .with_base_url(&redirect);
+
+    match client.get_server_time().await {
+        Err(Error::Redirect {
+            status,
+            location: Some(to),
+        }) => {
+            assert_eq!(status, 302);
+            assert_eq!(to, location);
+        }
+        other => panic!("expected redirect error, got {other:?}"),
+    }
+    assert_eq!(target_hits.load(Ordering::SeqCst), 0);
+}
+
+#[tokio::test]
+async fn test_bingx_does_not_follow_redirects() {
+    let (redirect, _, target_hits) = redirect_pair().await;
+    let client = BingxClient::new(None, None, false, 5000, None)
+        .unwrap()
+        .with_base_url(&redirect);
+
+    let result = client.get_server_time().await;
+    assert!(
+        matches!(result, Err(Error::Redirect { status: 302, .. })),
+        "{result:?}"
+    );
+    assert_eq!(target_hits.load(Ordering::SeqCst), 0);
+}
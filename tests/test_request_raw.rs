@@ -0,0 +1,153 @@
+//! Tests for `request_raw` on the high-level clients.
+//!
+//! The testnet check only runs with `TRADE_SDK_NETWORK_TESTS=1`.
+
+use std::collections::HashMap;
+
+use reqwest::Method;
+use serde_json::{json, Value};
+use trade_sdk::bingx::BingxClient;
+use trade_sdk::bybit::BybitClient;
+use trade_sdk::testkit::MockExchange;
+use trade_sdk::Error;
+
+fn network_tests_enabled() -> bool {
+    std::env::var("TRADE_SDK_NETWORK_TESTS").is_ok_and(|v| v == "1")
+}
+
+/// Mock exchange answering every request with `body`.
+async fn mock_exchange(body: Value) -> MockExchange {
+    let exchange = MockExchange::start().await;
+    exchange.respond_to_any(body);
+    exchange
+}
+
+fn mixed_params() -> HashMap<String, Value> {
+    HashMap::from([
+        ("symbol".to_string(), json!("BTCUSDT")),
+        ("limit".to_string(), json!(5)),
+        ("reduceOnly".to_string(), json!(true)),
+        ("cursor".to_string(), json!("")),
+        ("orderId".to_string(), Value::Null),
+    ])
+}
+
+fn bybit_body() -> Value {
+    json!({"retCode": 0, "retMsg": "OK", "result": {"ok": true}, "retExtInfo": {}, "time": 0})
+}
+
+#[tokio::test]
+async fn test_bybit_raw_get_flattens_params_into_sorted_query() {
+    let exchange = mock_exchange(bybit_body()).await;
+    let base_url = exchange.base_url();
+    let client = BybitClient::new(None, None, false, false, 5000, None)
+        .unwrap()
+        .with_base_url(&base_url);
+
+    let response = client
+        .request_raw(
+            Method::GET,
+            "/v5/new/endpoint",
+            Some(&mixed_params()),
+            false,
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(response.result, json!({"ok": true}));
+    let line = exchange.requests()[0].request_line();
+    // Null and empty-string values are dropped, the rest are stringified in key order.
+    assert_eq!(
+        line,
+        "GET /v5/new/endpoint?limit=5&reduceOnly=true&symbol=BTCUSDT"
+    );
+}
+
+#[tokio::test]
+async fn test_bybit_raw_post_sends_params_as_json_body() {
+    let exchange = mock_exchange(bybit_body()).await;
+    let base_url = exchange.base_url();
+    let client = BybitClient::new(
+        Some("key".to_string()),
+        Some("secret".to_string()),
+        false,
+        false,
+        5000,
+        None,
+    )
+    .unwrap()
+    .with_base_url(&base_url);
+    let params = HashMap::from([
+        ("coin".to_string(), json!("BTC")),
+        ("amount".to_string(), json!("0.5")),
+    ]);
+
+    client
+        .request_raw(Method::POST, "/v5/new/endpoint", Some(&params), true)
+        .await
+        .unwrap();
+
+    let request = &exchange.requests()[0];
+    assert_eq!(request.request_line(), "POST /v5/new/endpoint");
+    assert_eq!(
+        request.json().unwrap(),
+        json!({"coin": "BTC", "amount": "0.5"})
+    );
+}
+
+#[tokio::test]
+async fn test_raw_auth_request_without_credentials_fails() {
+    let client = BybitClient::new(None, None, false, false, 5000, None)
+        .unwrap()
+        .with_base_url("http://127.0.0.1:9");
+
+    let result = client
+        .request_raw(Method::GET, "/v5/account/info", None, true)
+        .await;
+
+    assert!(matches!(result, Err(Error::Auth(_))), "{result:?}");
+}
+
+#[tokio::test]
+async fn test_bingx_raw_get_flattens_params_into_sorted_query() {
+    let exchange = mock_exchange(json!({"code": 0, "msg": "", "data": {"ok": true}})).await;
+    let base_url = exchange.base_url();
+    let client = BingxClient::new(None, None, false, 5000, None)
+        .unwrap()
+        .with_base_url(&base_url);
+    let params = HashMap::from([
+        ("symbol".to_string(), json!("BTC-USDT")),
+        ("limit".to_string(), json!(5)),
+    ]);
+
+    let response = client
+        .request_raw(Method::GET, "/openApi/new/endpoint", Some(&params), false)
+        .await
+        .unwrap();
+
+    assert_eq!(response.data, json!({"ok": true}));
+    let line = exchange.requests()[0].request_line();
+    assert!(
+        line.starts_with(
+            "GET /openApi/new/endpoint?limit=5&recvWindow=5000&symbol=BTC-USDT&timestamp="
+        ),
+        "{line}"
+    );
+}
+
+#[tokio::test]
+async fn test_bybit_raw_server_time_testnet() {
+    if !network_tests_enabled() {
+        println!("Skipping: set TRADE_SDK_NETWORK_TESTS=1 to run");
+        return;
+    }
+
+    let client = BybitClient::new(None, None, true, false, 5000, None).unwrap();
+    let response = client
+        .request_raw(Method::GET, "/v5/market/time", None, false)
+        .await
+        .unwrap();
+
+    assert_eq!(response.ret_code, 0);
+    assert!(response.result.get("timeSecond").is_some());
+}
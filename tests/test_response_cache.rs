@@ -0,0 +1,165 @@
+//! Tests for opt-in caching of GET responses.
+
+use std::collections::HashMap;
+use std::time::Duration;
+
+use serde_json::{json, Value};
+use trade_sdk::bingx::BingxClient;
+use trade_sdk::bybit::BybitClient;
+use trade_sdk::testkit::MockExchange;
+
+/// Mock exchange answering every request with `body`.
+async fn mock_exchange(body: Value) -> MockExchange {
+    let exchange = MockExchange::start().await;
+    exchange.respond_to_any(body);
+    exchange
+}
+
+fn bybit_body() -> Value {
+    json!({
+        "retCode": 0,
+        "retMsg": "OK",
+        "result": {"category": "linear", "list": [{"symbol": "BTCUSDT"}]},
+        "retExtInfo": {},
+        "time": 1
+    })
+}
+
+fn params(symbol: &str) -> HashMap<String, Value> {
+    HashMap::from([
+        ("category".to_string(), json!("linear")),
+        ("symbol".to_string(), json!(symbol)),
+    ])
+}
+
+#[tokio::test]
+async fn test_get_cached_within_ttl_sends_one_request() {
+    let exchange = mock_exchange(bybit_body()).await;
+    let client = BybitClient::new(None, None, false, false, 5000, None)
+        .unwrap()
+        .with_base_url(&exchange.base_url());
+    let ttl = Duration::from_secs(60);
+
+    let first = client
+        .get_cached(
+            "/v5/market/instruments-info",
+            Some(&params("BTCUSDT")),
+            false,
+            ttl,
+        )
+        .await
+        .unwrap();
+    let second = client
+        .get_cached(
+            "/v5/market/instruments-info",
+            Some(&params("BTCUSDT")),
+            false,
+            ttl,
+        )
+        .await
+        .unwrap();
+    assert_eq!(exchange.requests().len(), 1);
+    assert_eq!(
+        first.into_api_response().result,
+        second.into_api_response().result
+    );
+
+    // Clones share the cache; other parameters are a different entry.
+    client
+        .clone()
+        .get_cached(
+            "/v5/market/instruments-info",
+            Some(&params("BTCUSDT")),
+            false,
+            ttl,
+        )
+        .await
+        .unwrap();
+    assert_eq!(exchange.requests().len(), 1);
+    client
+        .get_cached(
+            "/v5/market/instruments-info",
+            Some(&params("ETHUSDT")),
+            false,
+            ttl,
+        )
+        .await
+        .unwrap();
+    assert_eq!(exchange.requests().len(), 2);
+
+    client.clear_response_cache();
+    client
+        .get_cached(
+            "/v5/market/instruments-info",
+            Some(&params("BTCUSDT")),
+            false,
+            ttl,
+        )
+        .await
+        .unwrap();
+    assert_eq!(exchange.requests().len(), 3);
+}
+
+#[tokio::test]
+async fn test_get_cached_refetches_after_ttl() {
+    let exchange = mock_exchange(bybit_body()).await;
+    let client = BybitClient::new(None, None, false, false, 5000, None)
+        .unwrap()
+        .with_base_url(&exchange.base_url());
+    let ttl = Duration::from_millis(50);
+
+    client
+        .get_cached("/v5/market/instruments-info", None, false, ttl)
+        .await
+        .unwrap();
+    tokio::time::sleep(Duration::from_millis(80)).await;
+    client
+        .get_cached("/v5/market/instruments-info", None, false, ttl)
+        .await
+        .unwrap();
+    assert_eq!(exchange.requests().len(), 2);
+}
+
+#[tokio::test]
+async fn test_get_cached_does_not_cache_errors() {
+    let body = json!({"retCode": 10001, "retMsg": "params error", "result": {}, "retExtInfo": {}, "time": 1});
+    let exchange = mock_exchange(body).await;
+    let client = BybitClient::new(None, None, false, false, 5000, None)
+        .unwrap()
+        .with_base_url(&exchange.base_url());
+
+    for _ in 0..2 {
+        let result = client
+            .get_cached(
+                "/v5/market/instruments-info",
+                None,
+                false,
+                Duration::from_secs(60),
+            )
+            .await;
+        assert!(result.is_err());
+    }
+    assert_eq!(exchange.requests().len(), 2);
+}
+
+#[tokio::test]
+async fn test_bingx_get_cached() {
+    let body = json!({"code": 0, "msg": "", "data": {"symbols": []}});
+    let exchange = mock_exchange(body).await;
+    let client = BingxClient::new(None, None, false, 5000, None)
+        .unwrap()
+        .with_base_url(&exchange.base_url());
+
+    for _ in 0..3 {
+        client
+            .get_cached(
+                "/openApi/spot/v1/common/symbols",
+                None,
+                false,
+                Duration::from_secs(60),
+            )
+            .await
+            .unwrap();
+    }
+    assert_eq!(exchange.requests().len(), 1);
+}
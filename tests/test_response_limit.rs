@@ -0,0 +1,87 @@
+//! Tests for the response body size limit against a local mock server.
+
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpListener;
+use trade_sdk::bybit::{traits::MarketApi, BybitClient};
+use trade_sdk::Error;
+
+/// Serve every connection with `body`, either with a `Content-Length` or chunked.
+async fn spawn_server(
+    body: String,
+    chunked: bool,
+) -> String {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    tokio::spawn(async move {
+        while let Ok((mut socket, _)) = listener.accept().await {
+            let body = body.clone();
+            tokio::spawn(async move {
+                let mut buf = vec![0u8; 4096];
+                let _ = socket.read(&mut buf).await;
+                let response = if chunked {
+                    let mut out = String::from(
+                        "HTTP/1.1 200 OK\r\ncontent-type: application/json\r\ntransfer-encoding: chunked\r\nconnection: close\r\n\r\n",
+                    );
+                    for part in body.as_bytes().chunks(512) {
+                        out.push_str(&format!("{:x}\r\n", part.len()));
+                        out.push_str(std::str::from_utf8(part).unwrap());
+                        out.push_str("\r\n");
+                    }
+                    out.push_str("0\r\n\r\n");
+                    out
+                } else {
+                    format!(
+                        "HTTP/1.1 200 OK\r\ncontent-type: application/json\r\ncontent-length: {}\r\nconnection: close\r\n\r\n{}",
+                        body.len(),
+                        body
+                    )
+                };
+                let _ = socket.write_all(response.as_bytes()).await;
+            });
+        }
+    });
+    format!("http://{addr}")
+}
+
+fn server_time_body(padding: usize) -> String {
+    format!(
+        r#"{{"retCode":0,"retMsg":"OK","result":{{"timeSecond":"1700000000","pad":"{}"}},"retExtInfo":{{}},"time":1700000000000}}"#,
+        "x".repeat(padding)
+    )
+}
+
+fn client(
+    base_url: &str,
+    limit: usize,
+) -> BybitClient {
+    let mut client = BybitClient::new(None, None, false, false, 5000, None)
+        .unwrap()
+        .with_base_url(base_url);
+    client.set_max_response_bytes(limit);
+    client
+}
+
+#[tokio::test]
+async fn test_small_response_within_limit() {
+    let url = spawn_server(server_time_body(10), false).await;
+    let response = client(&url, 1024).get_server_time().await.unwrap();
+    assert_eq!(response.result["timeSecond"], "1700000000");
+}
+
+#[tokio::test]
+async fn test_oversized_content_length_rejected() {
+    let url = spawn_server(server_time_body(4096), false).await;
+    match client(&url, 1024).get_server_time().await {
+        Err(Error::ResponseTooLarge(limit)) => assert_eq!(limit, 1024),
+        other => panic!("expected ResponseTooLarge, got {other:?}"),
+    }
+}
+
+#[tokio::test]
+async fn test_oversized_chunked_body_rejected() {
+    let url = spawn_server(server_time_body(4096), true).await;
+    match client(&url, 1024).get_server_time().await {
+        Err(Error::ResponseTooLarge(limit)) => assert_eq!(limit, 1024),
+        other => panic!("expected ResponseTooLarge, got {other:?}"),
+    }
+}
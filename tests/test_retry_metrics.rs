@@ -0,0 +1,99 @@
+//! Tests for retry notifications and the retry counter.
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpListener;
+use trade_sdk::bybit::traits::TradeApi;
+use trade_sdk::bybit::types::{AllCategories, PlaceOrderParams};
+use trade_sdk::bybit::BybitClient;
+use trade_sdk::{Observer, RetryMeta};
+
+/// (exchange, endpoint, attempt)
+type RecordedRetry = (String, String, u32);
+
+#[derive(Default)]
+struct RetryObserver {
+    retries: Mutex<Vec<RecordedRetry>>,
+}
+
+impl Observer for RetryObserver {
+    fn on_retry(
+        &self,
+        meta: &RetryMeta<'_>,
+    ) {
+        self.retries.lock().unwrap().push((
+            meta.exchange.to_owned(),
+            meta.endpoint.to_owned(),
+            meta.attempt,
+        ));
+    }
+}
+
+/// Drop the first `failures` connections without answering, then acknowledge orders.
+async fn spawn_flaky_server(failures: usize) -> String {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    let connections = Arc::new(AtomicUsize::new(0));
+    tokio::spawn(async move {
+        while let Ok((mut socket, _)) = listener.accept().await {
+            let connection = connections.fetch_add(1, Ordering::SeqCst);
+            tokio::spawn(async move {
+                let mut buf = vec![0u8; 8192];
+                let _ = socket.read(&mut buf).await;
+                if connection < failures {
+                    return;
+                }
+                let body = r#"{"retCode":0,"retMsg":"OK","result":{"orderId":"1","orderLinkId":""},"retExtInfo":{},"time":1700000000000}"#;
+                let response = format!(
+                    "HTTP/1.1 200 OK\r\ncontent-type: application/json\r\ncontent-length: {}\r\nconnection: close\r\n\r\n{}",
+                    body.len(),
+                    body
+                );
+                let _ = socket.write_all(response.as_bytes()).await;
+            });
+        }
+    });
+    format!("http://{addr}")
+}
+
+#[tokio::test]
+async fn test_on_retry_fires_before_each_resend() {
+    let base_url = spawn_flaky_server(2).await;
+    let observer = Arc::new(RetryObserver::default());
+    let mut client = BybitClient::new(
+        Some("key".to_string()),
+        Some("secret".to_string()),
+        false,
+        false,
+        5000,
+        None,
+    )
+    .unwrap()
+    .with_base_url(&base_url)
+    .with_auto_client_order_id("bot-", 3)
+    .unwrap();
+    client.set_observer(observer.clone());
+    assert_eq!(client.total_retries(), 0);
+
+    let params = PlaceOrderParams {
+        symbol: "BTCUSDT".to_owned(),
+        qty: "0.01".parse().unwrap(),
+        ..Default::default()
+    };
+    let response = client
+        .place_order(AllCategories::Linear, &params)
+        .await
+        .unwrap();
+
+    assert_eq!(response.result["orderId"], "1");
+    assert_eq!(
+        *observer.retries.lock().unwrap(),
+        [
+            ("bybit".to_owned(), "/v5/order/create".to_owned(), 1),
+            ("bybit".to_owned(), "/v5/order/create".to_owned(), 2),
+        ]
+    );
+    assert_eq!(client.total_retries(), 2);
+}
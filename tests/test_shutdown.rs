@@ -0,0 +1,103 @@
+//! Tests for draining in-flight requests in `SharedSessionManager::close`.
+
+use std::time::{Duration, Instant};
+
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpListener;
+use trade_sdk::bybit::{traits::MarketApi, BybitClient};
+use trade_sdk::{SessionConfig, SharedSessionManager};
+
+/// Serve every connection with a server time response after `delay`.
+async fn spawn_slow_server(delay: Duration) -> String {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    tokio::spawn(async move {
+        while let Ok((mut socket, _)) = listener.accept().await {
+            tokio::spawn(async move {
+                let mut buf = vec![0u8; 4096];
+                let _ = socket.read(&mut buf).await;
+                tokio::time::sleep(delay).await;
+                let body = r#"{"retCode":0,"retMsg":"OK","result":{"timeSecond":"1700000000"},"retExtInfo":{},"time":1700000000000}"#;
+                let response = format!(
+                    "HTTP/1.1 200 OK\r\ncontent-type: application/json\r\ncontent-length: {}\r\nconnection: close\r\n\r\n{}",
+                    body.len(),
+                    body
+                );
+                let _ = socket.write_all(response.as_bytes()).await;
+            });
+        }
+    });
+    format!("http://{addr}")
+}
+
+async fn wait_until_in_flight() {
+    while SharedSessionManager::in_flight_requests() == 0 {
+        tokio::time::sleep(Duration::from_millis(5)).await;
+    }
+}
+
+#[tokio::test]
+async fn test_close_waits_for_in_flight_requests() {
+    // Both scenarios share the global session, so they run in sequence.
+
+    // The request finishes before the deadline: close waits for it.
+    SharedSessionManager::setup_with_config(SessionConfig {
+        close_timeout: Duration::from_secs(5),
+        ..Default::default()
+    })
+    .unwrap();
+    let base_url = spawn_slow_server(Duration::from_millis(500)).await;
+    let client = BybitClient::new(None, None, false, false, 5000, None)
+        .unwrap()
+        .with_base_url(&base_url);
+    assert!(client.is_shared_session_enabled());
+    let request = tokio::spawn(async move { client.get_server_time().await });
+    wait_until_in_flight().await;
+
+    let started = Instant::now();
+    SharedSessionManager::close().await;
+    assert!(started.elapsed() >= Duration::from_millis(300));
+    assert!(request.is_finished());
+    assert!(request.await.unwrap().is_ok());
+
+    // The request outlives the deadline: close gives up and returns.
+    SharedSessionManager::setup_with_config(SessionConfig {
+        close_timeout: Duration::from_millis(100),
+        ..Default::default()
+    })
+    .unwrap();
+    let base_url = spawn_slow_server(Duration::from_secs(3)).await;
+    let client = BybitClient::new(None, None, false, false, 5000, None)
+        .unwrap()
+        .with_base_url(&base_url);
+    let request = tokio::spawn(async move { client.get_server_time().await });
+    wait_until_in_flight().await;
+
+    let started = Instant::now();
+    SharedSessionManager::close().await;
+    assert!(started.elapsed() < Duration::from_secs(2));
+    assert!(!request.is_finished());
+    request.abort();
+
+    // A client with its own pool is not waited for. The slow server doubles as its proxy.
+    SharedSessionManager::setup_with_config(SessionConfig {
+        close_timeout: Duration::from_secs(5),
+        ..Default::default()
+    })
+    .unwrap();
+    let proxy = spawn_slow_server(Duration::from_secs(3)).await;
+    let client = BybitClient::new(None, None, false, false, 5000, None)
+        .unwrap()
+        .with_base_url("http://bybit.invalid")
+        .with_proxy(&proxy)
+        .unwrap();
+    assert!(!client.is_shared_session_enabled());
+    let request = tokio::spawn(async move { client.get_server_time().await });
+    tokio::time::sleep(Duration::from_millis(100)).await;
+
+    let started = Instant::now();
+    SharedSessionManager::close().await;
+    assert!(started.elapsed() < Duration::from_secs(2));
+    assert!(!request.is_finished());
+    request.abort();
+}
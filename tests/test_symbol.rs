@@ -0,0 +1,175 @@
+//! Tests for cross-exchange symbol normalization.
+
+use trade_sdk::bybit::traits::{MarketApi, PositionApi};
+use trade_sdk::bybit::types::AllCategories;
+use trade_sdk::bybit::BybitClient;
+use trade_sdk::{Amount, Error, OptionExpiry, OptionKind, OptionSymbol, Symbol};
+
+#[test]
+fn test_symbol_round_trip() {
+    let cases = [
+        ("BTC/USDT", "BTCUSDT", "BTC-USDT"),
+        ("ETH/USDC", "ETHUSDC", "ETH-USDC"),
+        ("1000PEPE/USDT", "1000PEPEUSDT", "1000PEPE-USDT"),
+        ("SOL/FDUSD", "SOLFDUSD", "SOL-FDUSD"),
+        ("ETH/BTC", "ETHBTC", "ETH-BTC"),
+        ("BTC/USD", "BTCUSD", "BTC-USD"),
+    ];
+    for (canonical, bybit, bingx) in cases {
+        let symbol = Symbol::from_canonical(canonical).unwrap();
+        assert_eq!(symbol.to_bybit(), bybit);
+        assert_eq!(symbol.to_bingx(), bingx);
+        assert_eq!(symbol.to_canonical(), canonical);
+
+        assert_eq!(Symbol::parse(bybit).unwrap(), symbol, "{bybit}");
+        assert_eq!(Symbol::parse(bingx).unwrap(), symbol, "{bingx}");
+        assert_eq!(canonical.parse::<Symbol>().unwrap(), symbol);
+    }
+}
+
+#[test]
+fn test_symbol_base_quote() {
+    let symbol = Symbol::parse("1000pepe-usdt").unwrap();
+    assert_eq!(symbol.base(), "1000PEPE");
+    assert_eq!(symbol.quote(), "USDT");
+    assert_eq!(symbol.to_string(), "1000PEPE/USDT");
+
+    // USDC must not be split as USD + C.
+    let symbol = Symbol::parse("BTCUSDC").unwrap();
+    assert_eq!((symbol.base(), symbol.quote()), ("BTC", "USDC"));
+}
+
+#[test]
+fn test_symbol_invalid() {
+    for s in ["", "USDT", "BTC/", "/USDT", "BTC/US DT", "FOOBAR"] {
+        assert!(
+            matches!(Symbol::parse(s), Err(Error::Validation(_))),
+            "{s:?} should not parse"
+        );
+    }
+    assert!(matches!(
+        Symbol::from_canonical("BTCUSDT"),
+        Err(Error::Validation(_))
+    ));
+}
+
+#[test]
+fn test_symbol_serde() {
+    let symbol = Symbol::from_canonical("BTC/USDT").unwrap();
+    assert_eq!(serde_json::to_string(&symbol).unwrap(), "\"BTC/USDT\"");
+    let parsed: Symbol = serde_json::from_str("\"BTC-USDT\"").unwrap();
+    assert_eq!(parsed, symbol);
+}
+
+fn amount(s: &str) -> Amount {
+    s.parse().unwrap()
+}
+
+#[test]
+fn test_option_symbol_call() {
+    let option = OptionSymbol::parse("BTC-29MAR24-80000-C").unwrap();
+    assert_eq!(option.base, "BTC");
+    assert_eq!(option.expiry, OptionExpiry::new(2024, 3, 29).unwrap());
+    assert_eq!(option.expiry.to_iso(), "2024-03-29");
+    assert_eq!(option.strike, amount("80000"));
+    assert_eq!(option.kind, OptionKind::Call);
+    assert_eq!(option.settle, None);
+    assert_eq!(option.to_bybit(), "BTC-29MAR24-80000-C");
+}
+
+#[test]
+fn test_option_symbol_put() {
+    let option: OptionSymbol = "eth-5apr24-3200-p-usdt".parse().unwrap();
+    assert_eq!(option.base, "ETH");
+    assert_eq!(option.expiry, OptionExpiry::new(2024, 4, 5).unwrap());
+    assert_eq!(option.strike, amount("3200"));
+    assert_eq!(option.kind, OptionKind::Put);
+    assert_eq!(option.settle.as_deref(), Some("USDT"));
+    assert_eq!(option.to_string(), "ETH-5APR24-3200-P-USDT");
+
+    // Zero-padded days are accepted and formatted without the padding, as Bybit does.
+    let option = OptionSymbol::parse("SOL-07JUN24-2.5-P").unwrap();
+    assert_eq!(option.strike, amount("2.5"));
+    assert_eq!(option.to_bybit(), "SOL-7JUN24-2.5-P");
+}
+
+#[test]
+fn test_option_symbol_invalid() {
+    for s in [
+        "",
+        "BTCUSDT",
+        "BTC-29MAR24-80000",
+        "BTC-29MAR24-80000-X",
+        "BTC-29MAR24-abc-C",
+        "BTC-29MAR24-0-C",
+        "BTC-29MAR24--80000-C",
+        "BTC-30FEB24-80000-C",
+        "BTC-29FOO24-80000-C",
+        "BTC-2024-03-29-80000-C",
+        "-29MAR24-80000-C",
+        "BTC-29MAR24-80000-C-",
+    ] {
+        assert!(
+            matches!(OptionSymbol::parse(s), Err(Error::Validation(_))),
+            "{s:?} should not parse"
+        );
+    }
+    // 2024 is a leap year, 2025 is not.
+    assert!(OptionSymbol::parse("BTC-29FEB24-80000-C").is_ok());
+    assert!(OptionSymbol::parse("BTC-29FEB25-80000-C").is_err());
+}
+
+#[test]
+fn test_option_symbol_serde() {
+    let option = OptionSymbol::parse("BTC-29MAR24-80000-C").unwrap();
+    assert_eq!(
+        serde_json::to_string(&option).unwrap(),
+        "\"BTC-29MAR24-80000-C\""
+    );
+    let parsed: OptionSymbol = serde_json::from_str("\"BTC-29MAR24-80000-C\"").unwrap();
+    assert_eq!(parsed, option);
+    assert!(serde_json::from_str::<OptionSymbol>("\"BTCUSDT\"").is_err());
+}
+
+#[tokio::test]
+async fn test_option_methods_reject_malformed_symbol() {
+    // Nothing listens here, so only a pre-request validation error can come back as Validation.
+    let client = BybitClient::new(None, None, false, false, 5000, None)
+        .unwrap()
+        .with_base_url("http://127.0.0.1:9");
+
+    let result = client
+        .get_tickers(AllCategories::Option, Some("BTCUSDT"), None, None)
+        .await;
+    assert!(matches!(result, Err(Error::Validation(_))), "{result:?}");
+
+    let result = client
+        .get_delivery_price(
+            AllCategories::Option,
+            Some("BTC-29MAR24-C"),
+            None,
+            None,
+            None,
+            None,
+        )
+        .await;
+    assert!(matches!(result, Err(Error::Validation(_))), "{result:?}");
+
+    let result = client
+        .get_position_info(
+            AllCategories::Option,
+            Some("BTC-31APR24-80000-P"),
+            None,
+            None,
+            None,
+            None,
+        )
+        .await;
+    assert!(matches!(result, Err(Error::Validation(_))), "{result:?}");
+
+    // Linear symbols are not checked as option symbols.
+    let result = client
+        .get_tickers(AllCategories::Linear, Some("BTCUSDT"), None, None)
+        .await;
+    assert!(!matches!(result, Err(Error::Validation(_))), "{result:?}");
+}
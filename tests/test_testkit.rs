@@ -0,0 +1,155 @@
+//! Self-tests of the `testkit` mock exchange.
+#![cfg(feature = "testkit")]
+
+use std::collections::HashMap;
+use std::time::Duration;
+
+use reqwest::Method;
+use serde_json::json;
+use trade_sdk::bingx::BingxClient;
+use trade_sdk::bybit::{traits::MarketApi, BybitClient};
+use trade_sdk::testkit::{MockExchange, MockResponse};
+use trade_sdk::Error;
+
+fn bybit_ok(result: serde_json::Value) -> serde_json::Value {
+    json!({"retCode": 0, "retMsg": "OK", "result": result, "retExtInfo": {}, "time": 1})
+}
+
+#[tokio::test]
+async fn test_canned_response_and_recorded_get() {
+    let exchange = MockExchange::start().await;
+    exchange.respond(
+        Method::GET,
+        "/v5/market/time",
+        bybit_ok(json!({"timeSecond": "1700000000"})),
+    );
+    let client = BybitClient::new(None, None, false, false, 5000, None)
+        .unwrap()
+        .with_base_url(&exchange.base_url());
+
+    let response = client.get_server_time().await.unwrap();
+
+    assert_eq!(response.result["timeSecond"], "1700000000");
+    let requests = exchange.requests();
+    assert_eq!(requests.len(), 1);
+    assert_eq!(requests[0].method, Method::GET);
+    assert_eq!(requests[0].path, "/v5/market/time");
+    assert_eq!(requests[0].url(), "/v5/market/time");
+}
+
+#[tokio::test]
+async fn test_records_query_headers_and_body() {
+    let exchange = MockExchange::start().await;
+    exchange.respond(Method::GET, "/v5/market/kline", bybit_ok(json!({})));
+    exchange.respond(Method::POST, "/v5/order/create", bybit_ok(json!({})));
+    let client = BybitClient::new(
+        Some("key".to_string()),
+        Some("secret".to_string()),
+        false,
+        false,
+        5000,
+        None,
+    )
+    .unwrap()
+    .with_base_url(&exchange.base_url());
+
+    client
+        .get_kline("BTCUSDT", "60", None, None, None, Some(2))
+        .await
+        .unwrap();
+    let params = HashMap::from([("symbol".to_string(), json!("BTCUSDT"))]);
+    client
+        .request_raw(Method::POST, "/v5/order/create", Some(&params), true)
+        .await
+        .unwrap();
+
+    let kline = &exchange.requests_to(Method::GET, "/v5/market/kline")[0];
+    assert_eq!(kline.query_param("symbol").as_deref(), Some("BTCUSDT"));
+    assert_eq!(kline.query_param("limit").as_deref(), Some("2"));
+    assert_eq!(kline.query_param("missing"), None);
+    let order = &exchange.requests_to(Method::POST, "/v5/order/create")[0];
+    assert_eq!(order.header("X-BAPI-API-KEY"), Some("key"));
+    assert_eq!(order.json().unwrap()["symbol"], "BTCUSDT");
+}
+
+#[tokio::test]
+async fn test_status_override_and_unmatched_route() {
+    let exchange = MockExchange::start().await;
+    exchange.respond_with_status(
+        Method::GET,
+        "/openApi/swap/v2/quote/price",
+        503,
+        json!({"code": 0}),
+    );
+    let client = BingxClient::new(None, None, false, 5000, None)
+        .unwrap()
+        .with_base_url(&exchange.base_url());
+
+    let unavailable = client
+        .request_raw(Method::GET, "/openApi/swap/v2/quote/price", None, false)
+        .await;
+    let unmatched = client
+        .request_raw(Method::GET, "/openApi/unknown", None, false)
+        .await;
+
+    assert!(
+        matches!(unavailable, Err(Error::Http(ref e)) if e.status().map(|s| s.as_u16()) == Some(503)),
+        "{unavailable:?}"
+    );
+    assert!(
+        matches!(unmatched, Err(Error::Http(ref e)) if e.status().map(|s| s.as_u16()) == Some(404)),
+        "{unmatched:?}"
+    );
+    assert_eq!(exchange.requests().len(), 2);
+}
+
+#[tokio::test]
+async fn test_fallback_answers_unregistered_routes() {
+    let exchange = MockExchange::start().await;
+    exchange.respond(
+        Method::GET,
+        "/v5/market/time",
+        bybit_ok(json!({"timeSecond": "1"})),
+    );
+    exchange.respond_to_any(bybit_ok(json!({"fallback": true})));
+    let client = BybitClient::new(None, None, false, false, 5000, None)
+        .unwrap()
+        .with_base_url(&exchange.base_url());
+
+    let time = client.get_server_time().await.unwrap();
+    let other = client
+        .request_raw(Method::GET, "/v5/market/tickers", None, false)
+        .await
+        .unwrap();
+
+    assert_eq!(time.result["timeSecond"], "1");
+    assert_eq!(other.result["fallback"], true);
+    assert_eq!(exchange.requests().len(), 2);
+}
+
+#[tokio::test]
+async fn test_response_sequence_and_delay() {
+    let exchange = MockExchange::start().await;
+    exchange.respond_sequence(
+        Method::GET,
+        "/v5/market/time",
+        [
+            MockResponse::new(bybit_ok(json!({"n": 1}))).with_delay(Duration::from_secs(5)),
+            MockResponse::new(bybit_ok(json!({"n": 2}))),
+            MockResponse::new(bybit_ok(json!({"n": 3}))),
+        ],
+    );
+    let client = BybitClient::new(None, None, false, false, 5000, None)
+        .unwrap()
+        .with_base_url(&exchange.base_url());
+
+    let delayed = tokio::time::timeout(Duration::from_millis(200), client.get_server_time()).await;
+    assert!(delayed.is_err(), "{delayed:?}");
+    let mut seen = Vec::new();
+    for _ in 0..3 {
+        seen.push(client.get_server_time().await.unwrap().result["n"].clone());
+    }
+
+    assert_eq!(seen, [json!(2), json!(3), json!(3)]);
+    assert_eq!(exchange.requests().len(), 4);
+}
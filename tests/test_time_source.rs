@@ -0,0 +1,132 @@
+//! Tests for injecting the clock used for signing timestamps.
+
+use std::sync::Arc;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use reqwest::Method;
+use serde_json::json;
+use trade_sdk::bingx::BingxClient;
+use trade_sdk::bybit::{signing, BybitClient};
+use trade_sdk::okx::OkxClient;
+use trade_sdk::testkit::MockExchange;
+use trade_sdk::{SystemTimeSource, TimeSource};
+
+const FIXED_MS: i64 = 1_700_000_000_123;
+
+/// Clock that always reports the same time.
+struct FixedClock(i64);
+
+impl TimeSource for FixedClock {
+    fn now_ms(&self) -> i64 {
+        self.0
+    }
+}
+
+/// Mock exchange answering every request with `body`.
+async fn mock_exchange(body: serde_json::Value) -> MockExchange {
+    let exchange = MockExchange::start().await;
+    exchange.respond_to_any(body);
+    exchange
+}
+
+#[test]
+fn test_system_time_source_is_default() {
+    let before = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap()
+        .as_millis() as i64;
+    let client = BybitClient::new(None, None, false, false, 5000, None).unwrap();
+    let now = client.now_ms();
+    let system = SystemTimeSource.now_ms();
+    assert!(before <= now && now <= system, "{before} {now} {system}");
+}
+
+#[tokio::test]
+async fn test_bybit_signs_with_injected_time() {
+    let exchange = mock_exchange(
+        json!({"retCode": 0, "retMsg": "OK", "result": {}, "retExtInfo": {}, "time": 1}),
+    )
+    .await;
+    let mut client = BybitClient::new(
+        Some("bybit-key".to_string()),
+        Some("secret".to_string()),
+        false,
+        false,
+        5000,
+        None,
+    )
+    .unwrap()
+    .with_base_url(&exchange.base_url());
+    client.set_time_source(Arc::new(FixedClock(FIXED_MS)));
+    assert_eq!(client.now_ms(), FIXED_MS);
+
+    client
+        .request_raw(Method::GET, "/v5/position/list", None, true)
+        .await
+        .unwrap();
+
+    let request = &exchange.requests()[0];
+    assert_eq!(
+        request.header("X-BAPI-TIMESTAMP"),
+        Some(FIXED_MS.to_string().as_str())
+    );
+    // With a fixed clock the signature is reproducible.
+    assert_eq!(
+        request.header("X-BAPI-SIGN"),
+        Some(
+            signing::generate_signature("bybit-key", "secret", 5000, "", FIXED_MS)
+                .unwrap()
+                .as_str()
+        )
+    );
+}
+
+#[tokio::test]
+async fn test_bingx_signs_with_injected_time_and_offset() {
+    let exchange = mock_exchange(json!({"code": 0, "msg": "", "data": {}})).await;
+    let mut client = BingxClient::new(
+        Some("bingx-key".to_string()),
+        Some("secret".to_string()),
+        false,
+        5000,
+        None,
+    )
+    .unwrap()
+    .with_base_url(&exchange.base_url());
+    client.set_time_source(Arc::new(FixedClock(FIXED_MS)));
+    client.set_time_offset_ms(250);
+
+    client
+        .request_raw(Method::GET, "/openApi/swap/v2/user/balance", None, true)
+        .await
+        .unwrap();
+
+    assert_eq!(
+        exchange.requests()[0].query_param("timestamp"),
+        Some((FIXED_MS + 250).to_string())
+    );
+}
+
+#[tokio::test]
+async fn test_okx_signs_with_injected_time() {
+    let exchange = mock_exchange(json!({"code": "0", "msg": "", "data": []})).await;
+    let mut client = OkxClient::new(
+        Some("okx-key".to_string()),
+        Some("secret".to_string()),
+        Some("passphrase".to_string()),
+        false,
+    )
+    .unwrap()
+    .with_base_url(&exchange.base_url());
+    client.set_time_source(Arc::new(FixedClock(FIXED_MS)));
+
+    client
+        .request_raw(Method::GET, "/api/v5/account/balance", None, true)
+        .await
+        .unwrap();
+
+    assert_eq!(
+        exchange.requests()[0].header("OK-ACCESS-TIMESTAMP"),
+        Some("2023-11-14T22:13:20.123Z")
+    );
+}
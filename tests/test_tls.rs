@@ -0,0 +1,27 @@
+//! Smoke tests for the TLS backend selected by cargo features.
+//!
+//! Run for each backend:
+//! `cargo test --test test_tls` and
+//! `cargo test --test test_tls --no-default-features --features tls-native`.
+
+use trade_sdk::bingx::BingxClient;
+use trade_sdk::bybit::BybitClient;
+use trade_sdk::TLS_BACKEND;
+
+#[test]
+fn test_tls_backend_matches_features() {
+    if cfg!(feature = "tls-rustls") {
+        assert_eq!(TLS_BACKEND, "rustls");
+    } else {
+        assert_eq!(TLS_BACKEND, "native-tls");
+    }
+}
+
+#[test]
+fn test_clients_build_with_tls_backend() {
+    let bybit = BybitClient::new(None, None, false, false, 5000, None);
+    assert!(bybit.is_ok(), "{TLS_BACKEND}: {:?}", bybit.err());
+    let bingx = BingxClient::new(None, None, false, 5000, None)
+        .and_then(|c| c.with_proxy("http://127.0.0.1:3128"));
+    assert!(bingx.is_ok(), "{TLS_BACKEND}: {:?}", bingx.err());
+}
@@ -0,0 +1,26 @@
+//! Tests for request spans (requires the `tracing` feature).
+#![cfg(feature = "tracing")]
+
+use tracing_test::traced_test;
+use trade_sdk::bybit::{traits::MarketApi, BybitClient};
+
+#[traced_test]
+#[tokio::test]
+async fn test_request_span_fields() {
+    let client = BybitClient::new(None, None, false, false, 5000, None).unwrap();
+    let result = client.get_server_time().await;
+
+    assert!(logs_contain("exchange=\"bybit\""));
+    assert!(logs_contain("method=GET"));
+    assert!(logs_contain("endpoint=\"/v5/market/time\""));
+    if result.is_ok() {
+        assert!(logs_contain("outcome=\"ok\""));
+        assert!(logs_contain("ret_code=0"));
+        assert!(logs_contain("request completed"));
+    } else {
+        // Offline: the connection error is logged with the request URL.
+        assert!(logs_contain("outcome=\"http_error\""));
+        assert!(logs_contain("request failed"));
+        assert!(logs_contain("url=\"https://api.bybit.com/v5/market/time\""));
+    }
+}
@@ -0,0 +1,151 @@
+//! Tests for translating unified orders into native exchange requests.
+
+use serde_json::json;
+use trade_sdk::testkit::MockExchange;
+use trade_sdk::unified::{UnifiedOrder, UnifiedOrderType, UnifiedSide, UnifiedTrade};
+use trade_sdk::{bingx::BingxClient, bybit::BybitClient, Error, Symbol};
+
+fn limit_sell() -> UnifiedOrder {
+    UnifiedOrder {
+        symbol: Symbol::from_canonical("BTC/USDT").unwrap(),
+        side: UnifiedSide::Sell,
+        order_type: UnifiedOrderType::Limit,
+        qty: 0.01,
+        price: Some(65_000.5),
+        reduce_only: true,
+        client_order_id: Some("strat-1".to_owned()),
+    }
+}
+
+#[test]
+fn test_unified_order_to_bybit() {
+    let params = limit_sell().to_bybit_params().unwrap();
+    assert_eq!(
+        serde_json::to_value(&params).unwrap(),
+        json!({
+            "symbol": "BTCUSDT",
+            "side": "Sell",
+            "orderType": "Limit",
+            "qty": "0.01",
+            "price": "65000.5",
+            "reduceOnly": true,
+            "orderLinkId": "strat-1"
+        })
+    );
+}
+
+#[test]
+fn test_unified_order_to_bingx() {
+    let params = limit_sell().to_bingx_params().unwrap();
+    assert_eq!(
+        serde_json::to_value(&params).unwrap(),
+        json!({
+            "symbol": "BTC-USDT",
+            "side": "SELL",
+            "positionSide": "BOTH",
+            "type": "LIMIT",
+            "quantity": 0.01,
+            "price": 65_000.5,
+            "reduceOnly": "true",
+            "clientOrderId": "strat-1"
+        })
+    );
+}
+
+#[test]
+fn test_unified_market_order_drops_price() {
+    let order = UnifiedOrder {
+        price: Some(3_000.0),
+        ..UnifiedOrder::new(Symbol::parse("ETHUSDT").unwrap(), UnifiedSide::Buy, 1.5)
+    };
+
+    let bybit = serde_json::to_value(order.to_bybit_params().unwrap()).unwrap();
+    assert_eq!(
+        bybit,
+        json!({"symbol": "ETHUSDT", "side": "Buy", "orderType": "Market", "qty": "1.5"})
+    );
+
+    let bingx = serde_json::to_value(order.to_bingx_params().unwrap()).unwrap();
+    assert_eq!(
+        bingx,
+        json!({
+            "symbol": "ETH-USDT",
+            "side": "BUY",
+            "positionSide": "BOTH",
+            "type": "MARKET",
+            "quantity": 1.5
+        })
+    );
+}
+
+#[test]
+fn test_unified_order_validation() {
+    let no_price = UnifiedOrder {
+        price: None,
+        ..limit_sell()
+    };
+    assert!(matches!(
+        no_price.to_bybit_params(),
+        Err(Error::Validation(_))
+    ));
+
+    for qty in [0.0, -1.0, f64::NAN] {
+        let order = UnifiedOrder {
+            qty,
+            ..limit_sell()
+        };
+        assert!(matches!(order.to_bingx_params(), Err(Error::Validation(_))));
+    }
+}
+
+#[tokio::test]
+async fn test_unified_submit_requires_credentials() {
+    let bybit = BybitClient::new(None, None, false, false, 5000, None).unwrap();
+    assert!(matches!(
+        bybit.submit(&limit_sell()).await,
+        Err(Error::Auth(_))
+    ));
+
+    let bingx = BingxClient::new(None, None, false, 5000, None).unwrap();
+    assert!(matches!(
+        bingx.submit(&limit_sell()).await,
+        Err(Error::Auth(_))
+    ));
+}
+
+#[tokio::test]
+async fn test_unified_submit_without_order_id_is_a_parse_error() {
+    let exchange = MockExchange::start().await;
+    exchange.respond_to_any(
+        json!({"retCode": 0, "retMsg": "OK", "result": {}, "retExtInfo": {}, "time": 1}),
+    );
+    let bybit = BybitClient::new(
+        Some("key".to_string()),
+        Some("secret".to_string()),
+        false,
+        false,
+        5000,
+        None,
+    )
+    .unwrap()
+    .with_base_url(&exchange.base_url());
+    let err = bybit.submit(&limit_sell()).await.unwrap_err();
+    assert!(
+        matches!(&err, Error::Json(e) if e.to_string().contains("no orderId")),
+        "{err:?}"
+    );
+
+    let exchange = MockExchange::start().await;
+    exchange.respond_to_any(json!({"code": 0, "msg": "", "data": {"order": {}}}));
+    let bingx = BingxClient::new(
+        Some("key".to_string()),
+        Some("secret".to_string()),
+        false,
+        5000,
+        None,
+    )
+    .unwrap()
+    .with_base_url(&exchange.base_url());
+    let err = bingx.submit(&limit_sell()).await.unwrap_err();
+    assert!(matches!(err, Error::Json(_)), "{err:?}");
+}
@@ -0,0 +1,55 @@
+//! Tests for `SharedSessionManager::warmup`.
+//!
+//! The testnet check only runs with `TRADE_SDK_NETWORK_TESTS=1`.
+
+use std::time::Instant;
+
+use trade_sdk::SharedSessionManager;
+
+const TESTNET_HOST: &str = "api-testnet.bybit.com";
+
+fn network_tests_enabled() -> bool {
+    std::env::var("TRADE_SDK_NETWORK_TESTS").is_ok_and(|v| v == "1")
+}
+
+#[tokio::test]
+async fn test_warmup_empty_host_list() {
+    let summary = SharedSessionManager::warmup(&[]).await.unwrap();
+    assert!(summary.all_ok());
+    assert!(summary.succeeded.is_empty());
+    assert!(summary.failed.is_empty());
+}
+
+#[tokio::test]
+async fn test_warmup_testnet_primes_pool() {
+    if !network_tests_enabled() {
+        println!("Skipping: set TRADE_SDK_NETWORK_TESTS=1 to run");
+        return;
+    }
+
+    SharedSessionManager::setup(100);
+
+    // The warmup request pays DNS, TCP and TLS setup.
+    let started = Instant::now();
+    let summary = SharedSessionManager::warmup(&[TESTNET_HOST]).await.unwrap();
+    let cold = started.elapsed();
+    assert!(summary.all_ok(), "warmup failed: {:?}", summary.failed);
+    assert_eq!(summary.succeeded, vec![TESTNET_HOST.to_string()]);
+
+    // The next request reuses the parked connection.
+    let client = SharedSessionManager::get_client();
+    let started = Instant::now();
+    client
+        .head(format!("https://{TESTNET_HOST}"))
+        .send()
+        .await
+        .unwrap();
+    let warm = started.elapsed();
+    println!("cold: {cold:?}, warm: {warm:?}");
+    assert!(
+        warm < cold,
+        "warm request ({warm:?}) not faster than cold ({cold:?})"
+    );
+
+    SharedSessionManager::close().await;
+}